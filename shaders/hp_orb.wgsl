@@ -0,0 +1,66 @@
+// Player HP orb: a circle with a black rim, filled with a gently waving liquid up to `fill`.
+// Drawn on a square quad; everything outside the circle is transparent.
+// An overshield lights up the rim clockwise from the top, up to `shield`.
+
+#import bevy_sprite::{
+    mesh2d_vertex_output::VertexOutput,
+    mesh2d_view_bindings::globals,
+}
+
+struct HpOrbMaterial {
+    color: vec4<f32>,
+    fill: f32,
+    shield: f32,
+};
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> material: HpOrbMaterial;
+
+const RIM_WIDTH: f32 = 0.1;      // Rim thickness as a fraction of the orb radius
+const WAVE_HEIGHT: f32 = 0.03;   // Surface wave amplitude as a fraction of the orb radius
+const EMPTY_COLOR: vec3<f32> = vec3<f32>(0.02, 0.02, 0.03);
+const SHIELD_COLOR: vec3<f32> = vec3<f32>(0.3, 0.85, 1.0);
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    // -1..1 across the quad, y pointing up
+    let p = vec2<f32>(mesh.uv.x * 2.0 - 1.0, 1.0 - mesh.uv.y * 2.0);
+    let dist = length(p);
+    let aa = fwidth(dist);
+
+    let orb_alpha = 1.0 - smoothstep(1.0 - aa, 1.0, dist);
+    if orb_alpha <= 0.0 {
+        discard;
+    }
+
+    let inner_radius = 1.0 - RIM_WIDTH;
+    let inside = 1.0 - smoothstep(inner_radius - aa, inner_radius, dist);
+
+    // Liquid surface height: the waves flatten out when the orb is nearly empty or full
+    let fill = clamp(material.fill, 0.0, 1.0);
+    let wave_scale = clamp(min(fill, 1.0 - fill) * 10.0, 0.0, 1.0) * WAVE_HEIGHT;
+    let t = globals.time;
+    let surface = mix(-inner_radius, inner_radius, fill)
+        + sin(p.x * 6.0 + t * 2.5) * wave_scale
+        + sin(p.x * 11.0 - t * 1.7) * wave_scale * 0.5;
+    let in_liquid = 1.0 - smoothstep(surface - aa, surface + aa, p.y);
+
+    // Darker toward the bottom, with a lighter band just under the surface
+    var liquid = material.color.rgb * (0.6 + 0.4 * (p.y + 1.0) * 0.5);
+    let surface_band = 1.0 - smoothstep(0.0, 0.1, surface - p.y);
+    liquid = mix(liquid, vec3<f32>(1.0), surface_band * 0.25);
+
+    var color = mix(EMPTY_COLOR, liquid, in_liquid);
+
+    // Glassy highlight in the upper left
+    let highlight = 1.0 - smoothstep(0.0, 0.35, length(p - vec2<f32>(-0.35, 0.4)));
+    color += vec3<f32>(highlight * 0.2);
+
+    // Rim: black, with the overshield ring over it (0 at the top, growing clockwise)
+    let shield = clamp(material.shield, 0.0, 1.0);
+    let angle = fract(atan2(p.x, p.y) / 6.2831853 + 1.0);
+    let in_shield = step(angle, shield) * step(0.0001, shield);
+    let rim = mix(vec3<f32>(0.0), SHIELD_COLOR, in_shield);
+    color = mix(rim, color, inside);
+
+    return vec4<f32>(color, orb_alpha);
+}
@@ -1,11 +1,13 @@
+use crate::systems::hazards::HazardConfig;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Marker component for boss entities
 #[derive(Component)]
 pub struct Boss;
 
 /// Different types of bosses in the game
-#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum BossType {
     /// Default/test boss
     Default,
@@ -63,21 +65,77 @@ pub enum AttackPattern {
     SingleShot {
         cooldown: f32,
         projectile_speed: f32,
+        gravity: f32,
         cardinal_only: bool,
     },
     /// Triple shot pattern
     TripleShot {
         cooldown: f32,
         projectile_speed: f32,
+        gravity: f32,
         spread_angle: f32,
     },
     /// Rapid fire
     RapidFire {
         cooldown: f32,
         projectile_speed: f32,
+        gravity: f32,
         burst_count: u32,
         burst_delay: f32,
     },
+    /// Melee swing in front of the boss: windup (telegraph), active hitbox, then recovery
+    Melee {
+        cooldown: f32,
+        windup: f32,
+        active: f32,
+        recovery: f32,
+        reach: f32,
+        height: f32,
+    },
+    /// Ring of `count` projectiles fired evenly in every direction
+    Ring {
+        cooldown: f32,
+        projectile_speed: f32,
+        gravity: f32,
+        count: u32,
+        angular_velocity: f32,
+    },
+    /// Rotating emitter with `arms` evenly spaced streams, firing every `cooldown` seconds
+    Spiral {
+        cooldown: f32,
+        projectile_speed: f32,
+        gravity: f32,
+        arms: u32,
+        rotation_speed: f32,
+        angular_velocity: f32,
+    },
+    /// Fan of `count` projectiles spread over `spread_angle` degrees, aimed at the player
+    Fan {
+        cooldown: f32,
+        projectile_speed: f32,
+        gravity: f32,
+        count: u32,
+        spread_angle: f32,
+        angular_velocity: f32,
+    },
+    /// Arcing shells aimed to land on (and around) the player after `flight_time` seconds
+    Mortar {
+        cooldown: f32,
+        flight_time: f32,
+        gravity: f32,
+        count: u32,
+        spacing: f32,
+        hazard: Option<HazardConfig>,
+    },
+    /// Telegraphed laser that sweeps `sweep_angle` degrees (centered on the player) while firing
+    Beam {
+        cooldown: f32,
+        telegraph: f32,
+        duration: f32,
+        sweep_angle: f32,
+        length: f32,
+        width: f32,
+    },
     /// Custom pattern (extend as needed)
     Custom {
         cooldown: f32,
@@ -90,6 +148,7 @@ impl Default for AttackPattern {
         AttackPattern::SingleShot {
             cooldown: 2.0,
             projectile_speed: 300.0,
+            gravity: 0.0,
             cardinal_only: false,
         }
     }
@@ -118,6 +177,14 @@ pub enum MovementPattern {
         radius: f32,
         speed: f32,
     },
+    /// Walk toward the player, stopping at a distance
+    Chase { speed: f32, stop_distance: f32 },
+    /// Back away from the player until hitting a wall
+    Retreat { speed: f32 },
+    /// Periodically jump in an arc that lands on the player's position
+    JumpTowardPlayer { interval: f32, arc_height: f32 },
+    /// Periodically leap above the player, hang in the air, then slam straight down
+    GroundSlam { interval: f32, height: f32 },
     /// Custom movement (extend as needed)
     Custom,
 }
@@ -128,12 +195,26 @@ impl Default for MovementPattern {
     }
 }
 
+/// Stage of a timed boss attack (melee swings and beams)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AttackPhase {
+    #[default]
+    Idle,
+    Windup,   // Telegraph, no damage yet
+    Active,   // Damaging window
+    Recovery, // Attack finished, boss is open before the cooldown starts
+}
+
 /// Component to track boss attack state
 #[derive(Component)]
 pub struct BossAttackState {
     pub timer: f32,
     pub burst_count: u32,
     pub burst_timer: f32,
+    pub attack_phase: AttackPhase,
+    pub phase_timer: f32,              // Time left in the current attack phase
+    pub attack_entity: Option<Entity>, // Hitbox/beam entity of the attack in progress
+    pub spiral_angle: f32,             // Current emitter angle of spiral patterns (radians)
 }
 
 impl Default for BossAttackState {
@@ -142,15 +223,43 @@ impl Default for BossAttackState {
             timer: 0.0,
             burst_count: 0,
             burst_timer: 0.0,
+            attack_phase: AttackPhase::Idle,
+            phase_timer: 0.0,
+            attack_entity: None,
+            spiral_angle: 0.0,
         }
     }
 }
 
+/// Component for a boss melee hitbox (spawned as a child of the boss for the duration of a swing)
+#[derive(Component)]
+pub struct BossMeleeHitbox {
+    pub size: Vec2,
+    pub facing: f32,  // -1.0 for left, 1.0 for right
+    pub active: bool, // Only deals damage during the active window (windup is just a telegraph)
+}
+
+/// Component for a boss laser beam (spawned as a child of the boss, pivoting around its center)
+#[derive(Component)]
+pub struct BossBeam {
+    pub angle: f32,       // Current beam angle in radians
+    pub start_angle: f32, // Angle at the start of the sweep (shown by the telegraph line)
+    pub end_angle: f32,   // Angle at the end of the sweep
+    pub length: f32,
+    pub width: f32,
+    pub firing: bool, // Only deals damage while firing (the telegraph line is harmless)
+}
+
 /// Component to track boss movement state
 #[derive(Component)]
 pub struct BossMovementState {
     pub direction: f32,     // -1.0 for left/up, 1.0 for right/down
     pub current_angle: f32, // For circular movement
+    pub velocity: Vec2,     // Velocity while airborne (jumps and slams)
+    pub airborne: bool,     // Whether the boss is mid-jump (gravity applies until it lands)
+    pub jump_timer: f32,    // Time until the next jump
+    pub slam_pending: bool, // Hang at the top of the current jump, then slam down
+    pub hover_timer: f32,   // Remaining hang time before slamming down
 }
 
 impl Default for BossMovementState {
@@ -158,10 +267,50 @@ impl Default for BossMovementState {
         Self {
             direction: 1.0,
             current_angle: 0.0,
+            velocity: Vec2::ZERO,
+            airborne: false,
+            jump_timer: 0.0,
+            slam_pending: false,
+            hover_timer: 0.0,
         }
     }
 }
 
+/// Component with the order a boss was spawned in among the bosses of its fight (0 = first)
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct BossSpawnIndex(pub usize);
+
+/// Component to track which phase of the fight the boss is in (1-indexed)
+#[derive(Component)]
+pub struct BossPhaseState {
+    pub phase: u32,
+    pub desperate: bool, // Boss is nearly beaten (drives the music's final layer, not the patterns)
+}
+
+impl Default for BossPhaseState {
+    fn default() -> Self {
+        Self {
+            phase: 1,
+            desperate: false,
+        }
+    }
+}
+
+/// Component marking a boss as invulnerable during a survival phase (removed when the timer runs out)
+#[derive(Component)]
+pub struct BossInvulnerable {
+    pub timer: f32,
+}
+
+/// Marker component for bosses that stand on the floor and platforms (gravity pulls them down
+/// when nothing is under them)
+#[derive(Component)]
+pub struct GroundedBoss;
+
+/// Component scaling how fast a boss moves (endless mode bosses get faster every wave)
+#[derive(Component)]
+pub struct BossSpeedMultiplier(pub f32);
+
 /// Resource to store boss configurations
 /// This allows you to load boss data from files or define them in code
 #[derive(Resource)]
@@ -181,6 +330,7 @@ impl Default for BossRegistry {
                     attack_pattern: AttackPattern::SingleShot {
                         cooldown: 2.0,
                         projectile_speed: 300.0,
+                        gravity: 0.0,
                         cardinal_only: false,
                     },
                     movement_pattern: MovementPattern::Stationary,
@@ -193,7 +343,7 @@ impl Default for BossRegistry {
     }
 }
 
-/// Component to identify boss HP bar container nodes for cleanup
+/// Component for the UI column that holds every boss HP bar
 #[derive(Component)]
 pub struct BossHealthBarContainer;
 
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+/// Marker component for everything taking part in a fight (fighters, projectiles, effects,
+/// pickups, hazards, minions, the arena itself). They're all despawned together when the fight
+/// is left, so new kinds of entities don't need a cleanup of their own. Components of
+/// short-lived gameplay entities require it, and the spawn helpers add it to the rest.
+#[derive(Component, Default)]
+pub struct GameplayEntity;
+
+/// Component for gameplay entities that expire on their own: despawned once `remaining`
+/// seconds have run out (a safety net for projectiles that never hit or leave the arena)
+#[derive(Component)]
+pub struct Lifetime {
+    pub remaining: f32,
+}
@@ -1,2 +1,3 @@
 pub mod boss;
+pub mod gameplay;
 pub mod player;
@@ -1,9 +1,28 @@
+use crate::components::gameplay::GameplayEntity;
 use bevy::prelude::*;
 
 /// Marker component for the player character
 #[derive(Component)]
+#[require(CharacterKit)]
 pub struct Player;
 
+/// Component with the movement and weight stats of the character being played
+/// (see `SelectedCharacter::kit`)
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct CharacterKit {
+    pub move_speed_scale: f32, // Walking and dash speed multiplier
+    pub knockback_scale: f32,  // Knockback taken multiplier (heavier characters get pushed less)
+}
+
+impl Default for CharacterKit {
+    fn default() -> Self {
+        Self {
+            move_speed_scale: 1.0,
+            knockback_scale: 1.0,
+        }
+    }
+}
+
 /// A component to store an entity's health.
 #[derive(Component)]
 pub struct Hp {
@@ -11,23 +30,30 @@ pub struct Hp {
     pub max: f32,
 }
 
+/// Marker component added when an entity's HP reaches 0, so its death is only reported once.
+#[derive(Component)]
+pub struct Defeated;
+
 /// A marker component for the health bar's fill, linking it to the entity it represents.
 #[derive(Component)]
 pub struct HealthBar {
     pub entity: Entity,
 }
 
-/// A mask component for the circular player HP bar. This is used to hide the top
-/// portion of the circular fill so the bar appears to drain from the top in a
-/// linear, damage-proportional way.
+/// Component for the top-level entity of a health bar, despawned along with its children
+/// once the tracked entity no longer exists.
 #[derive(Component)]
-pub struct HealthBarMask {
+pub struct HealthBarRoot {
     pub entity: Entity,
 }
 
-/// A component to identify the circular HP bar background circle for cleanup purposes
+/// Marker component for the remaining lives counter shown under the HP bar
 #[derive(Component)]
-pub struct HealthBarBackground;
+pub struct LivesDisplay;
+
+/// Marker component for the potion counter shown under the lives counter
+#[derive(Component)]
+pub struct PotionDisplay;
 
 /// Component to track player velocity (for jumping and gravity)
 #[derive(Component)]
@@ -45,7 +71,7 @@ pub struct JumpCharge {
 }
 
 /// Type of jump the player is currently performing
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum JumpType {
     None,
     High,
@@ -67,17 +93,43 @@ pub struct Dash {
     pub direction: f32,
 }
 
+/// Component for a player climbing a ladder (no gravity or walking until they step off or let go)
+#[derive(Component)]
+pub struct Climbing {
+    pub ladder: Rect,
+}
+
+/// Component to track the time until the player's next footstep while running
+#[derive(Component)]
+pub struct Footsteps {
+    pub timer: f32,
+}
+
+/// Component to track the time left before the player can dash again
+#[derive(Component)]
+pub struct DashCooldown {
+    pub timer: f32,
+}
+
 /// Component for projectiles
 #[derive(Component)]
+#[require(GameplayEntity)]
 pub struct Projectile {
     pub direction: Vec2,
     pub charge_level: f32, // 0.0 = uncharged, 1.0 = fully charged
+    pub weapon: Option<crate::components::boss::BossType>, // Boss weapon that fired this shot (None = default shot)
 }
 
 /// Marker component to indicate a projectile has already hit something (prevents multiple hits)
 #[derive(Component)]
 pub struct ProjectileHasHit;
 
+/// Component to track HP regeneration (only ticks once the player has gone a while without damage)
+#[derive(Component)]
+pub struct Regeneration {
+    pub delay_timer: f32, // Time left before regeneration resumes
+}
+
 /// Component to track shooting cooldown
 #[derive(Component)]
 pub struct Shooting {
@@ -93,8 +145,11 @@ pub struct ChargeShot {
 
 /// Component to mark the visual charge effect (glow/particles around player)
 #[derive(Component)]
+#[require(GameplayEntity)]
 pub struct ChargeEffect {
     pub player_entity: Entity,
+    pub ready: bool,       // Whether the charge reached full (switches to the steady "ready" look)
+    pub flash_timer: f32,  // Seconds left of the flash played when the charge becomes ready
 }
 
 /// Component to track invincibility frames (prevents damage spam)
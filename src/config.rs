@@ -1 +0,0 @@
-pub const SMALL_JUMP_CHARGE_RATIO: f32 = 0.42;
@@ -4,19 +4,103 @@ mod components;
 mod plugins;
 mod stages;
 mod systems;
+mod ui;
 
+use plugins::analytics_plugin::AnalyticsPlugin;
+use plugins::announcer_plugin::AnnouncerPlugin;
+use plugins::audio_plugin::FeedbackAudioPlugin;
+use plugins::bench_plugin::BenchPlugin;
+use plugins::camera_plugin::CameraPlugin;
+use plugins::capture_plugin::CapturePlugin;
+use plugins::collision_plugin::CollisionPlugin;
+use plugins::ghost_plugin::GhostPlugin;
+use plugins::input_display_plugin::InputDisplayPlugin;
+use plugins::low_hp_warning_plugin::LowHpWarningPlugin;
+use plugins::medal_plugin::MedalPlugin;
+use plugins::minimap_plugin::MinimapPlugin;
+use plugins::offscreen_warning_plugin::OffscreenWarningPlugin;
+use plugins::particle_plugin::FeedbackParticlePlugin;
 use plugins::player_plugin::PlayerPlugin;
+use plugins::restart_plugin::RestartPlugin;
+use plugins::run_plugin::RunPlugin;
+use plugins::secret_token_plugin::SecretTokenPlugin;
+use plugins::shield_plugin::ShieldPickupPlugin;
+use plugins::transition_plugin::TransitionPlugin;
+use plugins::tutorial_plugin::TutorialPlugin;
+use stages::cutscene::CutscenePlugin;
+use stages::endless::EndlessPlugin;
 use stages::game_menu::{GameMenuPlugin, GameState, SelectedCharacter};
+use stages::main_menu::MainMenuPlugin;
+use stages::pattern_preview::PatternPreviewPlugin;
+use stages::pause_menu::PauseMenuPlugin;
+use stages::randomizer::RandomizerPlugin;
+use stages::settings_menu::SettingsPlugin;
+use stages::shop::ShopPlugin;
+use stages::stage_editor::StageEditorPlugin;
+use stages::stage_title::StageTitlePlugin;
+use stages::tutorial_stage::TutorialStagePlugin;
+use stages::weapon_get::WeaponGetPlugin;
 
 fn main() {
+    // Developer CLI mode: check every boss pattern file and exit without opening a window
+    if std::env::args().any(|arg| arg == "--validate-patterns") {
+        std::process::exit(systems::pattern_validation::validate_all_pattern_files());
+    }
+
     App::new()
-        .add_plugins(DefaultPlugins.set(AssetPlugin {
-            file_path: ".".into(),
-            ..default()
-        }))
+        .add_plugins(
+            DefaultPlugins
+                .set(AssetPlugin {
+                    file_path: ".".into(),
+                    ..default()
+                })
+                // Closing the window goes through handle_quit_requests, which saves the run first
+                .set(WindowPlugin {
+                    close_when_requested: false,
+                    ..default()
+                }),
+        )
         .init_state::<GameState>()
         .init_resource::<SelectedCharacter>()
+        .add_plugins(CameraPlugin)
+        .add_plugins(RunPlugin)
+        .add_plugins(TransitionPlugin)
+        .add_plugins(MainMenuPlugin)
         .add_plugins(GameMenuPlugin)
+        .add_plugins(SettingsPlugin)
+        .add_plugins(CutscenePlugin)
+        .add_plugins(ShopPlugin)
+        .add_plugins(WeaponGetPlugin)
+        .add_plugins(EndlessPlugin)
+        .add_plugins(RandomizerPlugin)
         .add_plugins(PlayerPlugin)
+        .add_plugins(PauseMenuPlugin)
+        .add_plugins(StageTitlePlugin)
+        .add_plugins(CollisionPlugin)
+        .add_plugins(FeedbackAudioPlugin)
+        .add_plugins(AnnouncerPlugin)
+        .add_plugins(FeedbackParticlePlugin)
+        .add_plugins(LowHpWarningPlugin)
+        .add_plugins(ShieldPickupPlugin)
+        .add_plugins(SecretTokenPlugin)
+        .add_plugins(MedalPlugin)
+        .add_plugins(RestartPlugin)
+        .add_plugins(MinimapPlugin)
+        .add_plugins(OffscreenWarningPlugin)
+        .add_plugins(GhostPlugin)
+        .add_plugins(InputDisplayPlugin)
+        .add_plugins(TutorialPlugin)
+        .add_plugins(TutorialStagePlugin)
+        .add_plugins(PatternPreviewPlugin)
+        .add_plugins(StageEditorPlugin)
+        .add_plugins(CapturePlugin)
+        // Balance analysis: log every fight's hits to ANALYTICS_DIR
+        .add_plugins(AnalyticsPlugin {
+            enabled: std::env::args().any(|arg| arg == "--analytics"),
+        })
+        // Stress test: bullet-hell bosses and thousands of projectiles, logging frame times
+        .add_plugins(BenchPlugin {
+            enabled: std::env::args().any(|arg| arg == "--bench"),
+        })
         .run();
 }
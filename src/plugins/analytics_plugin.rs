@@ -0,0 +1,197 @@
+use crate::components::boss::{Boss, BossData, BossPhaseState};
+use crate::components::player::{Defeated, Hp, Player};
+use crate::stages::game_menu::{CurrentStage, GameState, SelectedCharacter};
+use crate::stages::pattern_preview::pattern_name;
+use crate::systems::config::ANALYTICS_DIR;
+use crate::systems::health::HealthChanged;
+use crate::systems::player::{BossDamaged, BossDefeated, PlayerDied};
+use bevy::prelude::*;
+use serde::Serialize;
+
+/// Who took the damage of a logged hit
+#[derive(Clone, Copy, Debug, Serialize)]
+pub enum HitTarget {
+    Boss,
+    Player,
+}
+
+/// One hit of a fight, with what the boss was doing when it landed
+#[derive(Clone, Debug, Serialize)]
+pub struct HitRecord {
+    pub time: f32, // Seconds since the fight started
+    pub target: HitTarget,
+    pub amount: f32,
+    pub x: f32, // Position of the fighter that was hit
+    pub y: f32,
+    pub boss_attack: String, // Attack pattern the boss was running (first boss if there are several)
+    pub boss_movement: String,
+    pub boss_phase: u32,
+    pub boss_hp: f32,
+    pub player_hp: f32,
+}
+
+/// Log of the current fight, written to `ANALYTICS_DIR` as JSON and CSV when the fight ends
+#[derive(Resource, Default, Debug, Serialize)]
+pub struct FightLog {
+    pub stage: u32,
+    pub character: String,
+    pub duration: f32,
+    pub outcome: String, // "won", "lost" or "quit"
+    pub hits: Vec<HitRecord>,
+}
+
+impl FightLog {
+    fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "time,target,amount,x,y,boss_attack,boss_movement,boss_phase,boss_hp,player_hp\n",
+        );
+        for hit in &self.hits {
+            csv.push_str(&format!(
+                "{:.3},{:?},{:.1},{:.1},{:.1},{},{},{},{:.1},{:.1}\n",
+                hit.time,
+                hit.target,
+                hit.amount,
+                hit.x,
+                hit.y,
+                hit.boss_attack,
+                hit.boss_movement,
+                hit.boss_phase,
+                hit.boss_hp,
+                hit.player_hp,
+            ));
+        }
+        csv
+    }
+
+    /// Write the log as `<dir>/fight_<timestamp>_stage_<N>.json` and `.csv`
+    fn save(&self) -> Result<String, Box<dyn std::error::Error>> {
+        std::fs::create_dir_all(ANALYTICS_DIR)?;
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_millis())
+            .unwrap_or(0);
+        let base_path = format!("{}/fight_{}_stage_{}", ANALYTICS_DIR, timestamp, self.stage);
+        std::fs::write(
+            format!("{}.json", base_path),
+            serde_json::to_string_pretty(self)?,
+        )?;
+        std::fs::write(format!("{}.csv", base_path), self.to_csv())?;
+        Ok(base_path)
+    }
+}
+
+/// Starts a new fight log
+pub fn start_fight_log(
+    mut log: ResMut<FightLog>,
+    current_stage: Res<CurrentStage>,
+    selected_character: Res<SelectedCharacter>,
+) {
+    *log = FightLog {
+        stage: current_stage.0,
+        character: format!("{:?}", *selected_character),
+        outcome: "quit".to_string(),
+        ..default()
+    };
+}
+
+/// System to log boss hits (from `BossDamaged`), player hits (any HP lost, hazards included)
+/// and how the fight ended
+pub fn log_fight_events(
+    time: Res<Time>,
+    mut log: ResMut<FightLog>,
+    mut boss_damaged: MessageReader<BossDamaged>,
+    mut boss_defeated: MessageReader<BossDefeated>,
+    mut player_died: MessageReader<PlayerDied>,
+    mut health_changed: MessageReader<HealthChanged>,
+    boss_query: Query<(&Transform, &BossData, &BossPhaseState, &Hp), With<Boss>>,
+    remaining_boss_query: Query<(), (With<Boss>, Without<Defeated>)>,
+    player_query: Query<(&Transform, &Hp), With<Player>>,
+) {
+    log.duration += time.delta_secs();
+
+    let time_stamp = log.duration;
+    let player = player_query.iter().next();
+    let player_hp = player.map_or(0.0, |(_, hp)| hp.current);
+    let record = |target: HitTarget,
+                  amount: f32,
+                  position: Vec3,
+                  boss: Option<(&BossData, &BossPhaseState, &Hp)>| HitRecord {
+        time: time_stamp,
+        target,
+        amount,
+        x: position.x,
+        y: position.y,
+        boss_attack: boss.map_or_else(String::new, |(data, _, _)| {
+            pattern_name(&data.attack_pattern)
+        }),
+        boss_movement: boss.map_or_else(String::new, |(data, _, _)| {
+            pattern_name(&data.movement_pattern)
+        }),
+        boss_phase: boss.map_or(0, |(_, phase, _)| phase.phase),
+        boss_hp: boss.map_or(0.0, |(_, _, hp)| hp.current),
+        player_hp,
+    };
+
+    for message in boss_damaged.read() {
+        if let Ok((transform, data, phase, hp)) = boss_query.get(message.boss) {
+            let hit = record(
+                HitTarget::Boss,
+                message.amount,
+                transform.translation,
+                Some((data, phase, hp)),
+            );
+            log.hits.push(hit);
+        }
+    }
+
+    for message in health_changed.read() {
+        if let Ok((transform, _)) = player_query.get(message.entity)
+            && message.damage() > 0.0
+        {
+            let boss = boss_query
+                .iter()
+                .next()
+                .map(|(_, data, phase, boss_hp)| (data, phase, boss_hp));
+            let hit = record(
+                HitTarget::Player,
+                message.damage(),
+                transform.translation,
+                boss,
+            );
+            log.hits.push(hit);
+        }
+    }
+
+    // Same priority as check_game_outcome: losing wins a tie
+    if player_died.read().count() > 0 {
+        log.outcome = "lost".to_string();
+    } else if boss_defeated.read().count() > 0 && remaining_boss_query.is_empty() {
+        log.outcome = "won".to_string();
+    }
+}
+
+/// System to write the fight log when the fight ends
+pub fn save_fight_log(log: Res<FightLog>) {
+    match log.save() {
+        Ok(base_path) => info!("Fight log written to {}.json/.csv", base_path),
+        Err(e) => warn!("Failed to write fight log to {}: {}", ANALYTICS_DIR, e),
+    }
+}
+
+/// Optional per-fight analytics for balancing boss patterns (enabled with `--analytics`):
+/// a damage timeline with hit locations and the boss's pattern at every hit, as JSON and CSV
+pub struct AnalyticsPlugin {
+    pub enabled: bool,
+}
+
+impl Plugin for AnalyticsPlugin {
+    fn build(&self, app: &mut App) {
+        if !self.enabled {
+            return;
+        }
+        app.init_resource::<FightLog>()
+            .add_systems(OnEnter(GameState::InGame), start_fight_log)
+            .add_systems(Update, log_fight_events.run_if(in_state(GameState::InGame)))
+            .add_systems(OnExit(GameState::InGame), save_fight_log);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::plugins::low_hp_warning_plugin::{LowHpWarning, low_hp_warning};
+use crate::stages::game_menu::GameState;
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::arena_doors::fight_started;
+use crate::systems::config::{ANNOUNCER_LINE_GAP, ANNOUNCER_LINES_PATH};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Moment the announcer can speak up at (sent as a message by the systems watching for it)
+#[derive(Message, Clone, Copy, PartialEq, Eq, Debug, Deserialize)]
+pub enum AnnouncerTrigger {
+    FightStart, // The boss fight begins (after any corridor and walk-in)
+    LowHp,      // The player's HP drops into the low-HP warning
+    Victory,    // Every boss of the stage is beaten
+}
+
+/// One voice line: the clip played when its trigger fires, unless the line is cooling down
+#[derive(Clone, Debug, Deserialize)]
+pub struct AnnouncerLine {
+    pub trigger: AnnouncerTrigger,
+    pub clip: String, // Audio file path (relative to the game directory)
+    #[serde(default)]
+    pub cooldown: f32, // Seconds before this line can play again
+    #[serde(skip)]
+    pub sound: Option<Handle<AudioSource>>, // Loaded `clip` (None if the file is missing)
+    #[serde(skip)]
+    pub cooldown_timer: f32,
+}
+
+/// Resource with the announcer's voice lines, loaded from `ANNOUNCER_LINES_PATH`. When a trigger
+/// fires, the first of its lines that isn't cooling down plays.
+#[derive(Resource, Default, Deserialize)]
+pub struct Announcer {
+    pub lines: Vec<AnnouncerLine>,
+    #[serde(skip)]
+    pub busy_timer: f32, // Seconds until another line can start (a line is still playing)
+    #[serde(skip)]
+    pub fight_announced: bool,
+    #[serde(skip)]
+    pub low_hp_announced: bool, // Cleared once HP recovers, so a later drop is announced again
+}
+
+impl Announcer {
+    /// Load the voice lines from a JSON file
+    pub fn load_from_file(file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let json_content = std::fs::read_to_string(file_path)?;
+        Ok(serde_json::from_str(&json_content)?)
+    }
+}
+
+/// Loads the voice lines and their clips at startup (the announcer stays silent if the file is
+/// missing or invalid, and lines whose clip is missing are skipped)
+pub fn load_announcer(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let mut announcer = match Announcer::load_from_file(ANNOUNCER_LINES_PATH) {
+        Ok(announcer) => announcer,
+        Err(e) => {
+            info!("No announcer lines ({}: {})", ANNOUNCER_LINES_PATH, e);
+            Announcer::default()
+        }
+    };
+    for line in &mut announcer.lines {
+        line.sound = std::path::Path::new(&line.clip)
+            .exists()
+            .then(|| asset_server.load(line.clip.clone()));
+    }
+    commands.insert_resource(announcer);
+}
+
+/// System to announce the start of the fight, once per stage
+pub fn announce_fight_start(
+    mut announcer: ResMut<Announcer>,
+    mut triggers: MessageWriter<AnnouncerTrigger>,
+) {
+    if !announcer.fight_announced {
+        announcer.fight_announced = true;
+        triggers.write(AnnouncerTrigger::FightStart);
+    }
+}
+
+/// System to announce the player's HP dropping into the low-HP warning
+pub fn announce_low_hp(
+    warning: Res<LowHpWarning>,
+    mut announcer: ResMut<Announcer>,
+    mut triggers: MessageWriter<AnnouncerTrigger>,
+) {
+    if warning.active && !announcer.low_hp_announced {
+        triggers.write(AnnouncerTrigger::LowHp);
+    }
+    announcer.low_hp_announced = warning.active;
+}
+
+/// System to announce a won stage
+pub fn announce_victory(mut triggers: MessageWriter<AnnouncerTrigger>) {
+    triggers.write(AnnouncerTrigger::Victory);
+}
+
+/// System to let the next stage (or a retry) be announced again
+pub fn reset_announcements(mut announcer: ResMut<Announcer>) {
+    announcer.fight_announced = false;
+    announcer.low_hp_announced = false;
+}
+
+/// System to play the voice line of each trigger sent this frame. Lines never overlap: a trigger
+/// firing while a line is still playing is dropped. Runs on real time, so hit-stop and pausing
+/// don't hold the cooldowns.
+pub fn play_announcer_lines(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    settings: Res<GameSettings>,
+    mut announcer: ResMut<Announcer>,
+    mut triggers: MessageReader<AnnouncerTrigger>,
+) {
+    let delta = time.delta_secs();
+    announcer.busy_timer = (announcer.busy_timer - delta).max(0.0);
+    for line in &mut announcer.lines {
+        line.cooldown_timer = (line.cooldown_timer - delta).max(0.0);
+    }
+
+    for trigger in triggers.read() {
+        if settings.mute_announcer || announcer.busy_timer > 0.0 {
+            continue;
+        }
+        let Some(line) = announcer.lines.iter_mut().find(|line| {
+            line.trigger == *trigger && line.cooldown_timer <= 0.0 && line.sound.is_some()
+        }) else {
+            continue;
+        };
+        if let Some(sound) = &line.sound {
+            commands.spawn((AudioPlayer::new(sound.clone()), PlaybackSettings::DESPAWN));
+        }
+        line.cooldown_timer = line.cooldown;
+        announcer.busy_timer = ANNOUNCER_LINE_GAP;
+    }
+}
+
+/// Announcer voice lines at the start of a fight, on low HP and on victory, set up in
+/// `ANNOUNCER_LINES_PATH` (silenced with the Announcer setting)
+pub struct AnnouncerPlugin;
+
+impl Plugin for AnnouncerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Announcer>()
+            .add_message::<AnnouncerTrigger>()
+            .add_systems(Startup, load_announcer)
+            .add_systems(OnEnter(GameState::InGame), reset_announcements)
+            .add_systems(OnEnter(GameState::GameWin), announce_victory)
+            .add_systems(
+                Update,
+                (
+                    announce_fight_start.run_if(fight_started),
+                    announce_low_hp.after(low_hp_warning),
+                )
+                    .before(play_announcer_lines)
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(Update, play_announcer_lines);
+    }
+}
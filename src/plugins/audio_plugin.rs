@@ -0,0 +1,323 @@
+use crate::components::boss::{Boss, BossPhaseState};
+use crate::components::player::{Defeated, Player};
+use crate::plugins::camera_plugin::GameCamera;
+use crate::stages::game_menu::GameState;
+use crate::systems::boss::BossProjectile;
+use crate::systems::config::{
+    FEEDBACK_SOUND_DIR, MUSIC_CROSSFADE_TIME, MUSIC_DIR, MUSIC_VOLUME, SOUND_EAR_GAP,
+    SOUND_FULL_VOLUME_DISTANCE,
+};
+use crate::systems::feedback::{FeedbackEvent, player_surface};
+use crate::systems::platforms::{Ground, SurfaceMaterial};
+use crate::systems::player::BossDamaged;
+use bevy::audio::{SpatialScale, Volume};
+use bevy::prelude::*;
+
+/// Resource with the sound loaded for each feedback event (indexed like `FeedbackEvent::ALL`),
+/// plus the per-material sounds of the events that depend on the surface (indexed like
+/// `FeedbackEvent::ALL`, then like `SurfaceMaterial::ALL`)
+#[derive(Resource, Default)]
+pub struct FeedbackSounds {
+    pub sounds: Vec<Option<Handle<AudioSource>>>,
+    pub surface_sounds: Vec<Vec<Option<Handle<AudioSource>>>>,
+}
+
+impl FeedbackSounds {
+    /// Sound of `event` on `surface`, falling back to the event's plain sound
+    fn get(&self, event: FeedbackEvent, surface: SurfaceMaterial) -> Option<&Handle<AudioSource>> {
+        self.surface_sounds
+            .get(event as usize)
+            .and_then(|sounds| sounds.get(surface as usize)?.as_ref())
+            .or_else(|| self.sounds.get(event as usize)?.as_ref())
+    }
+}
+
+/// Loads `<FEEDBACK_SOUND_DIR>/<path>.ogg`, if the file exists
+fn load_optional_sound(asset_server: &AssetServer, path: &str) -> Option<Handle<AudioSource>> {
+    let path = format!("{}/{}.ogg", FEEDBACK_SOUND_DIR, path);
+    std::path::Path::new(&path)
+        .exists()
+        .then(|| asset_server.load(path))
+}
+
+/// Loads `<FEEDBACK_SOUND_DIR>/<event>.ogg` for every feedback event that has a sound file, and
+/// `<event>_<material>.ogg` for the events that depend on the surface (e.g. `footstep_metal.ogg`).
+/// Events without one stay silent, so sounds can be added one at a time.
+pub fn load_feedback_sounds(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let sounds = FeedbackEvent::ALL
+        .into_iter()
+        .map(|event| load_optional_sound(&asset_server, event.name()))
+        .collect();
+    let surface_sounds = FeedbackEvent::ALL
+        .into_iter()
+        .map(|event| {
+            if !event.on_surface() {
+                return Vec::new();
+            }
+            SurfaceMaterial::ALL
+                .into_iter()
+                .map(|surface| {
+                    let path = format!("{}_{}", event.name(), surface.name());
+                    load_optional_sound(&asset_server, &path)
+                })
+                .collect()
+        })
+        .collect();
+    commands.insert_resource(FeedbackSounds {
+        sounds,
+        surface_sounds,
+    });
+}
+
+/// System to play the sound of each feedback event sent this frame (footsteps and landings in
+/// the sound of the surface under the player)
+pub fn play_feedback_sounds(
+    mut commands: Commands,
+    mut feedback: MessageReader<FeedbackEvent>,
+    sounds: Res<FeedbackSounds>,
+    ground: Ground,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    let surface = player_query
+        .iter()
+        .next()
+        .map_or(SurfaceMaterial::default(), |transform| {
+            player_surface(&ground, transform)
+        });
+    for event in feedback.read() {
+        if let Some(sound) = sounds.get(*event, surface) {
+            commands.spawn((AudioPlayer::new(sound.clone()), PlaybackSettings::DESPAWN));
+        }
+    }
+}
+
+/// A one-shot sound played at `position` in the arena: panned toward the side of the camera it's
+/// on, and quieter the farther from the camera it is (see `SOUND_FULL_VOLUME_DISTANCE`)
+pub fn positional_sound(sound: Handle<AudioSource>, position: Vec2) -> impl Bundle {
+    (
+        AudioPlayer::new(sound),
+        PlaybackSettings::DESPAWN
+            .with_spatial(true)
+            .with_spatial_scale(SpatialScale::new_2d(1.0 / SOUND_FULL_VOLUME_DISTANCE)),
+        Transform::from_translation(position.extend(0.0)),
+    )
+}
+
+/// Gives the game camera the ears positional sounds are heard with, so they pan and fade as it
+/// scrolls
+pub fn attach_sound_listener(
+    mut commands: Commands,
+    camera_query: Query<Entity, With<GameCamera>>,
+) {
+    for camera in &camera_query {
+        commands
+            .entity(camera)
+            .insert(SpatialListener::new(SOUND_EAR_GAP));
+    }
+}
+
+/// A boss sound effect, played where it happens
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BossSound {
+    Shot, // A boss fired (played once per frame it fires, however many projectiles it fired)
+    Hurt, // A boss took damage
+}
+
+impl BossSound {
+    pub const ALL: [BossSound; 2] = [BossSound::Shot, BossSound::Hurt];
+
+    /// Name used for the sound's file (e.g. `sounds/boss_shot.ogg`)
+    pub fn name(self) -> &'static str {
+        match self {
+            BossSound::Shot => "boss_shot",
+            BossSound::Hurt => "boss_hurt",
+        }
+    }
+}
+
+/// Resource with the sound loaded for each boss sound effect (indexed like `BossSound::ALL`)
+#[derive(Resource, Default)]
+pub struct BossSounds {
+    pub sounds: Vec<Option<Handle<AudioSource>>>,
+}
+
+impl BossSounds {
+    fn get(&self, sound: BossSound) -> Option<&Handle<AudioSource>> {
+        self.sounds.get(sound as usize)?.as_ref()
+    }
+}
+
+/// Loads `<FEEDBACK_SOUND_DIR>/<sound>.ogg` for every boss sound effect that has a file
+pub fn load_boss_sounds(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let sounds = BossSound::ALL
+        .into_iter()
+        .map(|sound| {
+            let path = format!("{}/{}.ogg", FEEDBACK_SOUND_DIR, sound.name());
+            std::path::Path::new(&path)
+                .exists()
+                .then(|| asset_server.load(path))
+        })
+        .collect();
+    commands.insert_resource(BossSounds { sounds });
+}
+
+/// System to play the boss sound effects of this frame where they happen: a shot sound where
+/// new boss projectiles appeared, and a hurt sound on each boss that took damage
+pub fn play_boss_sounds(
+    mut commands: Commands,
+    sounds: Res<BossSounds>,
+    mut boss_damaged: MessageReader<BossDamaged>,
+    boss_query: Query<&GlobalTransform, With<Boss>>,
+    new_projectile_query: Query<&Transform, Added<BossProjectile>>,
+) {
+    if let Some(sound) = sounds.get(BossSound::Shot)
+        && let Some(transform) = new_projectile_query.iter().next()
+    {
+        commands.spawn(positional_sound(
+            sound.clone(),
+            transform.translation.truncate(),
+        ));
+    }
+
+    let hurt_sound = sounds.get(BossSound::Hurt);
+    for damaged in boss_damaged.read() {
+        if let Some(sound) = hurt_sound
+            && let Ok(transform) = boss_query.get(damaged.boss)
+        {
+            commands.spawn(positional_sound(
+                sound.clone(),
+                transform.translation().truncate(),
+            ));
+        }
+    }
+}
+
+/// A layer of the fight music. All layers loop in sync, faded in as the boss weakens.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MusicLayer {
+    Base,        // Plays through the whole fight
+    Percussion,  // Joins once a boss reaches phase 2 (half HP)
+    Desperation, // Joins once a boss is desperate
+}
+
+impl MusicLayer {
+    pub const ALL: [MusicLayer; 3] = [
+        MusicLayer::Base,
+        MusicLayer::Percussion,
+        MusicLayer::Desperation,
+    ];
+
+    /// Name used for the layer's file (e.g. `music/fight_percussion.ogg`)
+    pub fn name(self) -> &'static str {
+        match self {
+            MusicLayer::Base => "base",
+            MusicLayer::Percussion => "percussion",
+            MusicLayer::Desperation => "desperation",
+        }
+    }
+
+    /// Whether the layer should be playing while the fight's furthest-along boss is at `phase`
+    fn wanted(self, phase: &BossPhaseState) -> bool {
+        match self {
+            MusicLayer::Base => true,
+            MusicLayer::Percussion => phase.phase >= 2,
+            MusicLayer::Desperation => phase.desperate,
+        }
+    }
+}
+
+/// Resource with the file loaded for each music layer (indexed like `MusicLayer::ALL`)
+#[derive(Resource, Default)]
+pub struct MusicLayers {
+    pub sounds: Vec<Option<Handle<AudioSource>>>,
+}
+
+/// Component for a playing music layer, with how far it's faded in (0.0-1.0)
+#[derive(Component)]
+pub struct MusicLayerPlayer {
+    pub layer: MusicLayer,
+    pub level: f32,
+}
+
+/// Loads `<MUSIC_DIR>/fight_<layer>.ogg` for every music layer that has a file
+pub fn load_music_layers(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let sounds = MusicLayer::ALL
+        .into_iter()
+        .map(|layer| {
+            let path = format!("{}/fight_{}.ogg", MUSIC_DIR, layer.name());
+            std::path::Path::new(&path)
+                .exists()
+                .then(|| asset_server.load(path))
+        })
+        .collect();
+    commands.insert_resource(MusicLayers { sounds });
+}
+
+/// Starts every music layer together as the fight starts, only the base one audible, so the
+/// others stay in time with it when they fade in
+pub fn start_fight_music(mut commands: Commands, music: Res<MusicLayers>) {
+    for (layer, sound) in MusicLayer::ALL.into_iter().zip(&music.sounds) {
+        let Some(sound) = sound else {
+            continue;
+        };
+        let level = if layer == MusicLayer::Base { 1.0 } else { 0.0 };
+        commands.spawn((
+            DespawnOnExit(GameState::InGame),
+            AudioPlayer::new(sound.clone()),
+            PlaybackSettings::LOOP.with_volume(Volume::Linear(level * MUSIC_VOLUME)),
+            MusicLayerPlayer { layer, level },
+        ));
+    }
+}
+
+/// System to crossfade the music layers toward the boss's phase (real time, so hit-stop doesn't
+/// stall a fade). Layers stay as they are once every boss is beaten.
+pub fn crossfade_music_layers(
+    time: Res<Time<Real>>,
+    boss_query: Query<&BossPhaseState, (With<Boss>, Without<Defeated>)>,
+    mut layer_query: Query<(&mut MusicLayerPlayer, &mut AudioSink)>,
+) {
+    // The boss furthest into its fight sets the intensity
+    let Some(phase) = boss_query
+        .iter()
+        .max_by_key(|phase| (phase.desperate, phase.phase))
+    else {
+        return;
+    };
+
+    let step = time.delta_secs() / MUSIC_CROSSFADE_TIME;
+    for (mut player, mut sink) in &mut layer_query {
+        let target = if player.layer.wanted(phase) { 1.0 } else { 0.0 };
+        let level = player.level + (target - player.level).clamp(-step, step);
+        if level != player.level {
+            player.level = level;
+            sink.set_volume(Volume::Linear(level * MUSIC_VOLUME));
+        }
+    }
+}
+
+/// Sound effects for gameplay feedback events (jumps, footsteps and landings, dashes, hits and
+/// shots), boss sound effects panned by where they happen, and the fight music, which gets more
+/// layers as the boss weakens
+pub struct FeedbackAudioPlugin;
+
+impl Plugin for FeedbackAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FeedbackSounds>()
+            .init_resource::<BossSounds>()
+            .init_resource::<MusicLayers>()
+            .add_systems(
+                Startup,
+                (load_feedback_sounds, load_boss_sounds, load_music_layers),
+            )
+            // The cameras are spawned in Startup
+            .add_systems(PostStartup, attach_sound_listener)
+            .add_systems(Update, play_feedback_sounds)
+            .add_systems(Update, play_boss_sounds.run_if(in_state(GameState::InGame)))
+            .add_systems(OnEnter(GameState::InGame), start_fight_music)
+            .add_systems(
+                Update,
+                crossfade_music_layers.run_if(in_state(GameState::InGame)),
+            );
+    }
+}
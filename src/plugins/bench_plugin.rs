@@ -0,0 +1,157 @@
+use crate::components::player::Projectile;
+use crate::plugins::run_plugin::StartPatternPreview;
+use crate::stages::game_menu::GameState;
+use crate::stages::pattern_preview::{PatternPreview, in_pattern_preview};
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::boss::{BossPatternRegistry, BossProjectile, spawn_boss_projectile};
+use crate::systems::config::{
+    BENCH_BOSS_COUNT, BENCH_DURATION, BENCH_PATTERN_PATH, BENCH_PROJECTILE_COUNT,
+    BENCH_REPORT_INTERVAL, BENCH_SPAWN_PER_FRAME, BOUNDARY_LEFT, BOUNDARY_RIGHT, BOUNDARY_TOP,
+    FLOOR_TOP, PATTERN_PREVIEW_STAGE,
+};
+use crate::systems::player::ProjectileAssets;
+use crate::systems::projectile_cap::UncappedProjectile;
+use crate::systems::rng::GameRng;
+use bevy::prelude::*;
+
+/// Frame time statistics of the benchmark, over the whole run and the current report window
+#[derive(Resource, Default)]
+pub struct BenchStats {
+    pub elapsed: f32,
+    pub frames: u32,
+    pub total_ms: f32,
+    pub worst_ms: f32,
+    window_elapsed: f32,
+    window_frames: u32,
+    window_ms: f32,
+    window_worst_ms: f32,
+}
+
+/// Loads the bullet-hell pattern with several bosses and starts it through the pattern preview,
+/// which keeps the player and the bosses alive for the whole benchmark
+pub fn start_bench(
+    mut pattern_registry: ResMut<BossPatternRegistry>,
+    mut preview: ResMut<PatternPreview>,
+    mut start_preview: MessageWriter<StartPatternPreview>,
+) {
+    let pattern_name = format!("stage_{}", PATTERN_PREVIEW_STAGE);
+    let report = pattern_registry.load_from_file(pattern_name.clone(), BENCH_PATTERN_PATH);
+    if report.has_errors() {
+        warn!("Benchmark pattern {} failed to load, using the default boss", BENCH_PATTERN_PATH);
+    }
+    if let Some(pattern) = pattern_registry.patterns.get_mut(&pattern_name) {
+        pattern.count = BENCH_BOSS_COUNT;
+    }
+
+    *preview = PatternPreview::new(format!("{} (benchmark)", BENCH_PATTERN_PATH));
+    start_preview.write(StartPatternPreview);
+    info!(
+        "Benchmark: {} bosses, {} extra projectiles, {}s",
+        BENCH_BOSS_COUNT, BENCH_PROJECTILE_COUNT, BENCH_DURATION
+    );
+}
+
+/// System to keep the arena filled with `BENCH_PROJECTILE_COUNT` slow boss projectiles
+/// (on top of the bosses' own), topping up as they leave the arena or hit the player. They're
+/// exempt from the boss projectile cap, which would otherwise cull them.
+pub fn spawn_bench_projectiles(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    projectile_assets: Res<ProjectileAssets>,
+    settings: Res<GameSettings>,
+    mut rng: ResMut<GameRng>,
+    projectile_query: Query<(), With<BossProjectile>>,
+) {
+    let missing = BENCH_PROJECTILE_COUNT.saturating_sub(projectile_query.iter().count());
+    for _ in 0..missing.min(BENCH_SPAWN_PER_FRAME) {
+        let position = Vec3::new(
+            BOUNDARY_LEFT + rng.next_f32() * (BOUNDARY_RIGHT - BOUNDARY_LEFT),
+            FLOOR_TOP + rng.next_f32() * (BOUNDARY_TOP - FLOOR_TOP),
+            0.0,
+        );
+        let velocity = Vec2::from_angle(rng.next_f32() * std::f32::consts::TAU) * 60.0;
+        let projectile = spawn_boss_projectile(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &projectile_assets,
+            settings.palette,
+            position,
+            BossProjectile {
+                velocity,
+                gravity: 0.0,
+                angular_velocity: 0.0,
+                hazard: None,
+            },
+        );
+        commands.entity(projectile).insert(UncappedProjectile);
+    }
+}
+
+/// System to log frame times every `BENCH_REPORT_INTERVAL` seconds, and a summary before
+/// exiting once the benchmark has run for `BENCH_DURATION` seconds
+pub fn log_bench_frame_times(
+    real_time: Res<Time<Real>>,
+    mut stats: ResMut<BenchStats>,
+    projectile_query: Query<(), With<Projectile>>,
+    mut app_exit: MessageWriter<AppExit>,
+) {
+    let delta = real_time.delta_secs();
+    let frame_ms = delta * 1000.0;
+    stats.elapsed += delta;
+    stats.frames += 1;
+    stats.total_ms += frame_ms;
+    stats.worst_ms = stats.worst_ms.max(frame_ms);
+    stats.window_elapsed += delta;
+    stats.window_frames += 1;
+    stats.window_ms += frame_ms;
+    stats.window_worst_ms = stats.window_worst_ms.max(frame_ms);
+
+    if stats.window_elapsed >= BENCH_REPORT_INTERVAL {
+        info!(
+            "Benchmark {:.0}s: {} projectiles, avg {:.2} ms ({:.0} fps), worst {:.2} ms",
+            stats.elapsed,
+            projectile_query.iter().count(),
+            stats.window_ms / stats.window_frames as f32,
+            stats.window_frames as f32 / stats.window_elapsed,
+            stats.window_worst_ms,
+        );
+        stats.window_elapsed = 0.0;
+        stats.window_frames = 0;
+        stats.window_ms = 0.0;
+        stats.window_worst_ms = 0.0;
+    }
+
+    if stats.elapsed >= BENCH_DURATION {
+        info!(
+            "Benchmark done: {} frames in {:.1}s, avg {:.2} ms, worst {:.2} ms",
+            stats.frames,
+            stats.elapsed,
+            stats.total_ms / stats.frames as f32,
+            stats.worst_ms,
+        );
+        app_exit.write(AppExit::Success);
+    }
+}
+
+/// Stress test (enabled with `--bench`): several bullet-hell bosses plus thousands of extra
+/// projectiles, logging frame times to check the collision broadphase holds up
+pub struct BenchPlugin {
+    pub enabled: bool,
+}
+
+impl Plugin for BenchPlugin {
+    fn build(&self, app: &mut App) {
+        if !self.enabled {
+            return;
+        }
+        app.init_resource::<BenchStats>()
+            .add_systems(Startup, start_bench)
+            .add_systems(
+                Update,
+                (spawn_bench_projectiles, log_bench_frame_times)
+                    .run_if(in_state(GameState::InGame).and(in_pattern_preview)),
+            );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::stages::game_menu::GameState;
+use crate::systems::display::game_camera_projection;
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+
+/// Render layer of the fighters, platforms and everything else in play. Entities spawned without
+/// `RenderLayers` are on it.
+pub const GAME_RENDER_LAYER: usize = 0;
+/// Render layer of the UI camera. No world entity is on it, so that camera only ever draws UI.
+pub const UI_RENDER_LAYER: usize = 1;
+/// Render layer of the stage background, drawn before anything else
+pub const BACKGROUND_RENDER_LAYER: usize = 2;
+/// Render layer of particles and overlays, drawn over the fighters
+pub const EFFECTS_RENDER_LAYER: usize = 3;
+/// Render layer of the HUD drawn in the world (HP orbs, counters), drawn over the effects
+pub const HUD_RENDER_LAYER: usize = 4;
+
+/// The arena's render layers in drawing order, with the order of the camera drawing each one.
+/// Every camera draws a single layer, so nothing is drawn twice and the layers never z-fight.
+const ARENA_LAYERS: [(usize, isize); 4] = [
+    (BACKGROUND_RENDER_LAYER, -1),
+    (GAME_RENDER_LAYER, 0),
+    (EFFECTS_RENDER_LAYER, 1),
+    (HUD_RENDER_LAYER, 2),
+];
+
+/// Component to identify the UI camera
+#[derive(Component)]
+pub struct UiCamera;
+
+/// Component to identify the in-game camera (also used by the stage editor). It draws the game
+/// layer, and the cameras of the other arena layers are its children so they scroll and shake
+/// along with it.
+#[derive(Component)]
+pub struct GameCamera;
+
+/// Component for every camera drawing one of the arena's render layers, only active while the
+/// arena is shown
+#[derive(Component)]
+pub struct ArenaCamera;
+
+/// Camera drawing the arena render layer `layer`. The background camera clears the screen, and
+/// the ones after it draw over its picture.
+fn arena_layer_camera(layer: usize, order: isize) -> impl Bundle {
+    let clear_color = if layer == BACKGROUND_RENDER_LAYER {
+        ClearColorConfig::Default
+    } else {
+        ClearColorConfig::None
+    };
+    (
+        Camera2d,
+        Camera {
+            order,
+            is_active: false,
+            clear_color,
+            ..default()
+        },
+        game_camera_projection(), // Keeps the arena fully visible at any window size
+        RenderLayers::layer(layer),
+        ArenaCamera,
+    )
+}
+
+/// Spawns the cameras, which live for the whole app: one per arena render layer, only active
+/// while the arena is shown, and the UI camera drawn on top of them
+pub fn spawn_cameras(mut commands: Commands) {
+    let game_camera = commands
+        .spawn((arena_layer_camera(GAME_RENDER_LAYER, 0), GameCamera))
+        .id();
+    for (layer, order) in ARENA_LAYERS {
+        if layer != GAME_RENDER_LAYER {
+            commands.spawn((arena_layer_camera(layer, order), ChildOf(game_camera)));
+        }
+    }
+    commands.spawn((
+        Camera2d,
+        Camera {
+            order: 3, // UI camera renders on top
+            ..default()
+        },
+        RenderLayers::layer(UI_RENDER_LAYER),
+        IsDefaultUiCamera,
+        UiCamera,
+    ));
+}
+
+/// Turns the arena cameras on as the arena is shown, with the UI camera drawing over their
+/// picture instead of clearing it
+pub fn activate_game_camera(
+    mut arena_camera_query: Query<&mut Camera, (With<ArenaCamera>, Without<UiCamera>)>,
+    mut ui_camera_query: Query<&mut Camera, With<UiCamera>>,
+) {
+    for mut camera in &mut arena_camera_query {
+        camera.is_active = true;
+    }
+    for mut camera in &mut ui_camera_query {
+        camera.clear_color = ClearColorConfig::None;
+    }
+}
+
+/// Turns the arena cameras off once the arena is left, back at the origin without any scroll or
+/// shake, and lets the UI camera clear the screen for the menus again
+pub fn deactivate_game_camera(
+    mut arena_camera_query: Query<&mut Camera, (With<ArenaCamera>, Without<UiCamera>)>,
+    mut game_camera_query: Query<&mut Transform, With<GameCamera>>,
+    mut ui_camera_query: Query<&mut Camera, With<UiCamera>>,
+) {
+    for mut camera in &mut arena_camera_query {
+        camera.is_active = false;
+    }
+    for mut transform in &mut game_camera_query {
+        *transform = Transform::default();
+    }
+    for mut camera in &mut ui_camera_query {
+        camera.clear_color = ClearColorConfig::Default;
+    }
+}
+
+/// Persistent arena and UI cameras: state transitions switch the arena cameras on and off
+/// instead of spawning and despawning cameras
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_cameras);
+        for state in [GameState::InGame, GameState::StageEditor] {
+            app.add_systems(OnEnter(state), activate_game_camera)
+                .add_systems(OnExit(state), deactivate_game_camera);
+        }
+    }
+}
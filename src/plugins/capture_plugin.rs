@@ -0,0 +1,97 @@
+use crate::systems::config::{CAPTURE_DIR, CAPTURE_FRAME_INTERVAL, CAPTURE_MAX_FRAMES};
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, save_to_disk};
+
+/// Resource to track the clip being recorded while F11 is held
+#[derive(Resource, Default)]
+pub struct ClipRecording {
+    pub directory: Option<String>, // Folder the current clip's frames go to (None = not recording)
+    pub frames: u32,
+    pub timer: f32, // Real-time seconds until the next frame is captured
+}
+
+/// Milliseconds since the Unix epoch, used to give every capture a unique name
+fn capture_timestamp() -> u128 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or(0)
+}
+
+/// Creates `directory` if needed, returning whether captures can be written to it
+fn ensure_capture_dir(directory: &str) -> bool {
+    match std::fs::create_dir_all(directory) {
+        Ok(()) => true,
+        Err(e) => {
+            warn!("Failed to create capture directory {}: {}", directory, e);
+            false
+        }
+    }
+}
+
+/// System to save a screenshot of the window when F12 is pressed
+pub fn take_screenshot(mut commands: Commands, keyboard_input: Res<ButtonInput<KeyCode>>) {
+    if !keyboard_input.just_pressed(KeyCode::F12) || !ensure_capture_dir(CAPTURE_DIR) {
+        return;
+    }
+
+    let path = format!("{}/screenshot_{}.png", CAPTURE_DIR, capture_timestamp());
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(path));
+}
+
+/// System to record a short clip while F11 is held, saved as a numbered PNG frame sequence
+/// (turn it into a GIF with e.g. `ffmpeg -framerate 10 -i frame_%03d.png clip.gif`).
+/// Frames are timed with real time so hit-stop and the game speed setting don't change the clip's pace.
+pub fn record_clip(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    real_time: Res<Time<Real>>,
+    mut recording: ResMut<ClipRecording>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F11) {
+        let directory = format!("{}/clip_{}", CAPTURE_DIR, capture_timestamp());
+        if ensure_capture_dir(&directory) {
+            info!("Recording clip to {}", directory);
+            *recording = ClipRecording {
+                directory: Some(directory),
+                ..default()
+            };
+        }
+    }
+
+    if !keyboard_input.pressed(KeyCode::F11) || recording.frames >= CAPTURE_MAX_FRAMES {
+        if let Some(directory) = recording.directory.take() {
+            info!("Saved {} clip frames to {}", recording.frames, directory);
+        }
+        return;
+    }
+
+    let Some(directory) = recording.directory.clone() else {
+        return;
+    };
+
+    recording.timer -= real_time.delta_secs();
+    if recording.timer > 0.0 {
+        return;
+    }
+    recording.timer += CAPTURE_FRAME_INTERVAL;
+
+    let path = format!("{}/frame_{:03}.png", directory, recording.frames);
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(path));
+    recording.frames += 1;
+}
+
+/// Screenshot (F12) and clip (hold F11) capture, saved into `CAPTURE_DIR` for sharing fights
+/// and attaching to bug reports. Works in every state, menus included.
+pub struct CapturePlugin;
+
+impl Plugin for CapturePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ClipRecording>()
+            .add_systems(Update, (take_screenshot, record_clip));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::stages::game_menu::GameState;
+use crate::systems::boss::{
+    boss_projectile_movement, boss_projectile_player_collision, boss_shockwave_movement,
+    boss_shockwave_player_collision,
+};
+use crate::systems::collision::UpdateColliders;
+use crate::systems::player::{
+    apply_boss_knockback, player_boss_collision, player_movement, projectile_hits_enemies,
+    projectile_movement,
+};
+use bevy::prelude::*;
+
+#[cfg(not(feature = "avian"))]
+use crate::systems::collision_grid::{CollisionGrid, update_collision_grid};
+#[cfg(feature = "avian")]
+use crate::systems::physics::attach_physics_colliders;
+#[cfg(feature = "avian")]
+use avian2d::prelude::{PhysicsPlugins, PhysicsSystems};
+
+/// Collision backend behind `CollisionLookup`: the built-in uniform grid,
+/// or the avian2d physics engine when built with the `avian` feature.
+/// avian only answers collision queries here; gravity, the ground and the arena bounds are
+/// still handled by the movement systems (`physics::kinematics`) with either backend.
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        // Colliders are brought up to date once everything has moved this frame
+        app.configure_sets(
+            Update,
+            UpdateColliders
+                .after(player_movement)
+                .after(projectile_movement)
+                .after(apply_boss_knockback)
+                .after(boss_projectile_movement)
+                .after(boss_shockwave_movement)
+                .before(player_boss_collision)
+                .before(projectile_hits_enemies)
+                .before(boss_projectile_player_collision)
+                .before(boss_shockwave_player_collision)
+                .run_if(in_state(GameState::InGame)),
+        );
+
+        #[cfg(not(feature = "avian"))]
+        app.init_resource::<CollisionGrid>()
+            .add_systems(Update, update_collision_grid.in_set(UpdateColliders));
+
+        // avian steps in `Update` inside `UpdateColliders` rather than on its fixed timestep, so
+        // spatial queries see this frame's transforms, shots fired this frame included
+        #[cfg(feature = "avian")]
+        app.add_plugins(PhysicsPlugins::new(Update))
+            .configure_sets(
+                Update,
+                (
+                    PhysicsSystems::First,
+                    PhysicsSystems::Prepare,
+                    PhysicsSystems::StepSimulation,
+                    PhysicsSystems::Writeback,
+                    PhysicsSystems::Last,
+                )
+                    .in_set(UpdateColliders),
+            )
+            .add_systems(
+                Update,
+                attach_physics_colliders
+                    .in_set(UpdateColliders)
+                    .before(PhysicsSystems::First),
+            );
+    }
+}
@@ -0,0 +1,225 @@
+use crate::components::gameplay::GameplayEntity;
+use crate::components::player::Player;
+use crate::stages::endless::in_endless_mode;
+use crate::stages::game_menu::{CurrentStage, FightCheckpoint, GameState};
+use crate::stages::pattern_preview::in_pattern_preview;
+use crate::stages::randomizer::in_randomizer_mode;
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::config::{GHOST_ALPHA, GHOST_FILE_PATH, GHOST_SAMPLE_INTERVAL};
+use crate::systems::player::spawn_boss;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Player positions sampled every `GHOST_SAMPLE_INTERVAL` seconds through a stage's clear
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StageGhost {
+    pub stage: u32,
+    pub clear_time: f32, // Seconds from the fight's start to the last boss falling
+    pub samples: Vec<[f32; 2]>,
+}
+
+impl StageGhost {
+    /// Player position `elapsed` seconds into the recorded clear (None once the recording is over)
+    fn position_at(&self, elapsed: f32) -> Option<Vec2> {
+        let step = elapsed / GHOST_SAMPLE_INTERVAL;
+        let index = step as usize;
+        let [x0, y0] = *self.samples.get(index)?;
+        let [x1, y1] = *self.samples.get(index + 1).unwrap_or(&[x0, y0]);
+        Some(Vec2::new(x0, y0).lerp(Vec2::new(x1, y1), step.fract()))
+    }
+}
+
+/// Best (fastest) clear of every stage, saved to `GHOST_FILE_PATH` (JSON).
+/// Kept out of the save file since the traces are much bigger than the rest of the progress.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GhostRecords {
+    pub stages: Vec<StageGhost>,
+}
+
+impl GhostRecords {
+    /// Load ghost records from a JSON file
+    pub fn load_from_file(file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let json_content = std::fs::read_to_string(file_path)?;
+        Ok(serde_json::from_str(&json_content)?)
+    }
+
+    /// Save ghost records to a JSON file
+    pub fn save_to_file(&self, file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        std::fs::write(file_path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Load the ghost file, starting with no ghosts if it is missing or invalid
+    pub fn load_or_default() -> Self {
+        Self::load_from_file(GHOST_FILE_PATH).unwrap_or_default()
+    }
+
+    pub fn best(&self, stage: u32) -> Option<&StageGhost> {
+        self.stages.iter().find(|ghost| ghost.stage == stage)
+    }
+
+    /// Keep `ghost` if it is the stage's first or fastest clear, returning whether it was kept
+    pub fn record(&mut self, ghost: StageGhost) -> bool {
+        match self
+            .stages
+            .iter_mut()
+            .find(|best| best.stage == ghost.stage)
+        {
+            Some(best) if best.clear_time <= ghost.clear_time => false,
+            Some(best) => {
+                *best = ghost;
+                true
+            }
+            None => {
+                self.stages.push(ghost);
+                true
+            }
+        }
+    }
+}
+
+/// Resource tracking the current fight's recording and the ghost being replayed
+#[derive(Resource, Default)]
+pub struct GhostRun {
+    pub recording: bool, // False for fights resumed from a checkpoint (not a full clear)
+    pub elapsed: f32,
+    pub samples: Vec<[f32; 2]>,
+    pub replay: Option<StageGhost>,
+}
+
+/// Marker component for the translucent ghost replaying the best clear
+#[derive(Component)]
+pub struct PlayerGhost;
+
+/// Starts recording the fight and, with the ghost setting on, spawns the stage's best clear ghost
+pub fn start_ghost_run(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    settings: Res<GameSettings>,
+    current_stage: Res<CurrentStage>,
+    checkpoint: Res<FightCheckpoint>,
+    mut ghost_run: ResMut<GhostRun>,
+) {
+    let replay = if settings.show_ghost {
+        GhostRecords::load_or_default()
+            .best(current_stage.0)
+            .cloned()
+    } else {
+        None
+    };
+    if let Some(ghost) = &replay
+        && let Some([x, y]) = ghost.samples.first()
+    {
+        commands.spawn((
+            GameplayEntity,
+            Mesh2d(meshes.add(Rectangle::new(32.0, 64.0))), // Same size as the player
+            MeshMaterial2d(materials.add(Color::srgba(1.0, 1.0, 1.0, GHOST_ALPHA))),
+            Transform::from_xyz(*x, *y, 0.5), // Behind the player
+            PlayerGhost,
+        ));
+    }
+
+    *ghost_run = GhostRun {
+        recording: !checkpoint.resume,
+        replay,
+        ..default()
+    };
+}
+
+/// System to sample the player's position and move the ghost along its recorded clear
+pub fn update_ghost_run(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut ghost_run: ResMut<GhostRun>,
+    player_query: Query<&Transform, (With<Player>, Without<PlayerGhost>)>,
+    mut ghost_query: Query<(Entity, &mut Transform), With<PlayerGhost>>,
+) {
+    ghost_run.elapsed += time.delta_secs();
+
+    // Samples are taken on a fixed grid of the fight's time, so they can be replayed by index
+    let due_samples = (ghost_run.elapsed / GHOST_SAMPLE_INTERVAL) as usize + 1;
+    if ghost_run.recording
+        && ghost_run.samples.len() < due_samples
+        && let Some(player_transform) = player_query.iter().next()
+    {
+        let position = player_transform.translation;
+        while ghost_run.samples.len() < due_samples {
+            ghost_run.samples.push([position.x, position.y]);
+        }
+    }
+
+    for (entity, mut transform) in &mut ghost_query {
+        let position = ghost_run
+            .replay
+            .as_ref()
+            .and_then(|ghost| ghost.position_at(ghost_run.elapsed));
+        match position {
+            Some(position) => {
+                transform.translation.x = position.x;
+                transform.translation.y = position.y;
+            }
+            // The best clear ended here
+            None => {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+/// System to keep the stage's trace as its ghost when the fight was won faster than the best clear
+pub fn save_stage_ghost(current_stage: Res<CurrentStage>, mut ghost_run: ResMut<GhostRun>) {
+    if !ghost_run.recording || ghost_run.samples.is_empty() {
+        return;
+    }
+    ghost_run.recording = false;
+
+    let mut records = GhostRecords::load_or_default();
+    let ghost = StageGhost {
+        stage: current_stage.0,
+        clear_time: ghost_run.elapsed,
+        samples: std::mem::take(&mut ghost_run.samples),
+    };
+    if records.record(ghost) {
+        info!(
+            "New best clear of stage {}: {:.1}s",
+            current_stage.0, ghost_run.elapsed
+        );
+        if let Err(e) = records.save_to_file(GHOST_FILE_PATH) {
+            warn!("Failed to save ghost to {}: {}", GHOST_FILE_PATH, e);
+        }
+    }
+}
+
+/// Records the player's movement through each stage and replays the fastest clear as a
+/// translucent ghost on later attempts (toggled in the settings menu)
+pub struct GhostPlugin;
+
+impl Plugin for GhostPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GhostRun>()
+            .add_systems(
+                OnEnter(GameState::InGame),
+                // Before spawn_boss takes the checkpoint's resume flag
+                start_ghost_run
+                    .before(spawn_boss)
+                    // The pattern preview, endless waves and randomized bosses aren't stage clears
+                    .run_if(not(in_pattern_preview))
+                    .run_if(not(in_endless_mode))
+                    .run_if(not(in_randomizer_mode)),
+            )
+            .add_systems(
+                Update,
+                update_ghost_run
+                    .run_if(in_state(GameState::InGame))
+                    .run_if(not(in_pattern_preview))
+                    .run_if(not(in_endless_mode))
+                    .run_if(not(in_randomizer_mode)),
+            )
+            .add_systems(
+                OnEnter(GameState::GameWin),
+                save_stage_ghost.run_if(not(in_randomizer_mode)),
+            );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::input::InputAction;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+
+/// Marker component for the input display root
+#[derive(Component)]
+pub struct InputDisplay;
+
+/// Component for one action's box on the input display
+#[derive(Component)]
+pub struct InputDisplayKey(pub InputAction);
+
+fn key_background(pressed: bool) -> Color {
+    if pressed {
+        Color::srgb(1.0, 0.9, 0.0) // Lit (bright yellow/gold)
+    } else {
+        Color::srgba(0.1, 0.1, 0.15, 0.7)
+    }
+}
+
+/// Spawns the input display in the bottom-right corner (hidden until enabled)
+pub fn spawn_input_display(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: px(12.0),
+                bottom: px(12.0),
+                column_gap: px(4.0),
+                ..default()
+            },
+            GlobalZIndex(20), // Above every screen, menus included
+            Visibility::Hidden,
+            InputDisplay,
+        ))
+        .with_children(|parent| {
+            for action in InputAction::ALL {
+                parent
+                    .spawn((
+                        Node {
+                            min_width: px(32.0),
+                            height: px(32.0),
+                            padding: UiRect::horizontal(px(6.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(key_background(false)),
+                        BorderColor::all(Color::srgb(0.2, 0.2, 0.3)),
+                        InputDisplayKey(action),
+                    ))
+                    .with_child((
+                        Text::new(action.label()),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(WHITE.into()),
+                    ));
+            }
+        });
+}
+
+/// System to toggle the input display with F9 (also in the settings menu)
+pub fn toggle_input_display(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<GameSettings>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F9) {
+        settings.input_display = !settings.input_display;
+    }
+}
+
+/// System to show or hide the input display and light up the actions currently held
+pub fn update_input_display(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    settings: Res<GameSettings>,
+    mut display_query: Query<&mut Visibility, With<InputDisplay>>,
+    mut key_query: Query<(&InputDisplayKey, &mut BackgroundColor)>,
+) {
+    let visibility = if settings.input_display {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut display_visibility in &mut display_query {
+        display_visibility.set_if_neq(visibility);
+    }
+    if !settings.input_display {
+        return;
+    }
+
+    for (key, mut background) in &mut key_query {
+        background.set_if_neq(BackgroundColor(key_background(key.0.pressed(&keyboard_input))));
+    }
+}
+
+/// On-screen input viewer showing which gameplay actions are held (for tutorials and streams)
+pub struct InputDisplayPlugin;
+
+impl Plugin for InputDisplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_input_display).add_systems(
+            Update,
+            (toggle_input_display, update_input_display).chain(),
+        );
+    }
+}
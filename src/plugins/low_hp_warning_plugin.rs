@@ -0,0 +1,127 @@
+use crate::components::player::{Defeated, Hp, Player};
+use crate::stages::game_menu::GameState;
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::config::{
+    FEEDBACK_SOUND_DIR, LOW_HP_VIGNETTE_ALPHA, LOW_HP_WARNING_FRACTION, LOW_HP_WARNING_PULSE_RATE,
+};
+use crate::systems::player::update_health_bars;
+use bevy::prelude::*;
+
+/// Resource to track whether a player's HP is low enough for the warning, and the current
+/// strength (0.0-1.0) of the red pulse on the HP orb
+#[derive(Resource, Default)]
+pub struct LowHpWarning {
+    pub active: bool,
+    pub pulse: f32,
+}
+
+/// Resource with the looping heartbeat sound (None if `sounds/heartbeat.ogg` is missing)
+#[derive(Resource, Default)]
+pub struct HeartbeatSound(pub Option<Handle<AudioSource>>);
+
+/// Marker component for the red screen-edge vignette shown while HP is low
+#[derive(Component)]
+pub struct LowHpVignette;
+
+/// Marker component for the heartbeat loop playing while HP is low
+#[derive(Component)]
+pub struct HeartbeatLoop;
+
+pub fn load_heartbeat_sound(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let path = format!("{}/heartbeat.ogg", FEEDBACK_SOUND_DIR);
+    let sound = std::path::Path::new(&path)
+        .exists()
+        .then(|| asset_server.load(path));
+    commands.insert_resource(HeartbeatSound(sound));
+}
+
+fn spawn_vignette(commands: &mut Commands) {
+    commands.spawn((
+        DespawnOnExit(GameState::InGame),
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100.0),
+            height: percent(100.0),
+            ..default()
+        },
+        BackgroundGradient::from(RadialGradient::new(
+            UiPosition::CENTER,
+            RadialGradientShape::FarthestCorner,
+            vec![
+                ColorStop::new(Color::NONE, percent(60.0)),
+                ColorStop::new(Color::srgba(0.8, 0.0, 0.0, LOW_HP_VIGNETTE_ALPHA), percent(100.0)),
+            ],
+        )),
+        LowHpVignette,
+    ));
+}
+
+/// System to warn the player when their HP drops below `LOW_HP_WARNING_FRACTION`:
+/// the HP orb pulses red (see `update_health_bars`), the screen edges get a red vignette
+/// and a heartbeat loops until the HP recovers or the fight ends.
+pub fn low_hp_warning(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    heartbeat: Res<HeartbeatSound>,
+    player_query: Query<&Hp, (With<Player>, Without<Defeated>)>,
+    mut warning: ResMut<LowHpWarning>,
+    effect_query: Query<Entity, Or<(With<LowHpVignette>, With<HeartbeatLoop>)>>,
+) {
+    let low = player_query
+        .iter()
+        .any(|hp| hp.current > 0.0 && hp.current / hp.max < LOW_HP_WARNING_FRACTION);
+
+    warning.pulse = if !low {
+        0.0
+    } else if settings.reduced_flashing {
+        0.5 // Steady tint instead of a pulse
+    } else {
+        let phase = time.elapsed_secs() * LOW_HP_WARNING_PULSE_RATE * std::f32::consts::TAU;
+        phase.sin() * 0.5 + 0.5
+    };
+
+    if low == warning.active {
+        return;
+    }
+    warning.active = low;
+
+    if low {
+        spawn_vignette(&mut commands);
+        if let Some(sound) = &heartbeat.0 {
+            commands.spawn((
+                DespawnOnExit(GameState::InGame),
+                AudioPlayer::new(sound.clone()),
+                PlaybackSettings::LOOP,
+                HeartbeatLoop,
+            ));
+        }
+    } else {
+        for entity in &effect_query {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// System to clear the warning when the fight ends (its vignette and sound despawn with the fight)
+pub fn reset_low_hp_warning(mut warning: ResMut<LowHpWarning>) {
+    *warning = LowHpWarning::default();
+}
+
+/// Low-HP warning: red-pulsing HP orb, screen vignette and heartbeat while a player is nearly dead
+pub struct LowHpWarningPlugin;
+
+impl Plugin for LowHpWarningPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LowHpWarning>()
+            .init_resource::<HeartbeatSound>()
+            .add_systems(Startup, load_heartbeat_sound)
+            .add_systems(
+                Update,
+                low_hp_warning
+                    .before(update_health_bars)
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(OnExit(GameState::InGame), reset_low_hp_warning);
+    }
+}
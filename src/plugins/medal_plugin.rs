@@ -0,0 +1,132 @@
+use crate::stages::endless::in_endless_mode;
+use crate::stages::game_menu::{CurrentStage, FightCheckpoint, GameState, spawn_game_win_screen};
+use crate::stages::pattern_preview::in_pattern_preview;
+use crate::stages::pause_menu::game_unpaused;
+use crate::stages::randomizer::in_randomizer_mode;
+use crate::systems::boss::BossPatternRegistry;
+use crate::systems::config::{MAX_STAGES, SAVE_FILE_PATH};
+use crate::systems::player::spawn_boss;
+use crate::systems::save_data::SaveData;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Medal for clearing a stage within one of the times in its pattern's `medals`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Medal {
+    Bronze,
+    Silver,
+    Gold,
+}
+
+impl Medal {
+    pub fn label(self) -> &'static str {
+        match self {
+            Medal::Bronze => "Bronze",
+            Medal::Silver => "Silver",
+            Medal::Gold => "Gold",
+        }
+    }
+}
+
+/// Result of the last full clear of a stage, shown on the results screens
+#[derive(Clone, Copy, Debug)]
+pub struct StageClear {
+    pub time: f32,
+    pub medal: Option<Medal>,
+    pub new_best: bool,
+}
+
+impl StageClear {
+    /// One-line summary, like "Clear time 41.2s - Gold medal (new best!)"
+    pub fn summary(&self) -> String {
+        let medal = match self.medal {
+            Some(medal) => format!("{} medal", medal.label()),
+            None => "no medal".to_string(),
+        };
+        let best = if self.new_best { " (new best!)" } else { "" };
+        format!("Clear time {:.1}s - {}{}", self.time, medal, best)
+    }
+}
+
+/// Resource timing the current stage, with the result of the last full clear
+#[derive(Resource, Default)]
+pub struct StageClock {
+    pub elapsed: f32,
+    pub timing: bool, // False for fights resumed from a checkpoint (not a full clear)
+    pub last_clear: Option<StageClear>,
+}
+
+/// Starts timing the stage, unless it's a retry resumed from a checkpoint
+pub fn start_stage_clock(checkpoint: Res<FightCheckpoint>, mut clock: ResMut<StageClock>) {
+    *clock = StageClock {
+        timing: !checkpoint.resume,
+        ..default()
+    };
+}
+
+/// System to count the stage's time (paused time doesn't count)
+pub fn tick_stage_clock(time: Res<Time>, mut clock: ResMut<StageClock>) {
+    clock.elapsed += time.delta_secs();
+}
+
+/// Records a won stage's clear time and medal in the save file
+pub fn record_stage_clear(
+    current_stage: Res<CurrentStage>,
+    pattern_registry: Res<BossPatternRegistry>,
+    mut clock: ResMut<StageClock>,
+) {
+    if !clock.timing || !(1..=MAX_STAGES).contains(&current_stage.0) {
+        return;
+    }
+    clock.timing = false;
+
+    let time = clock.elapsed;
+    let medal = pattern_registry
+        .get_pattern(&format!("stage_{}", current_stage.0))
+        .and_then(|pattern| pattern.medals.as_ref())
+        .and_then(|medals| medals.medal_for(time));
+
+    let mut save_data = SaveData::load_or_default();
+    let new_best = save_data.record_clear(current_stage.0, time, medal);
+    if let Err(e) = save_data.save_to_file(SAVE_FILE_PATH) {
+        warn!("Failed to save stage clear to {}: {}", SAVE_FILE_PATH, e);
+    }
+
+    let clear = StageClear {
+        time,
+        medal,
+        new_best,
+    };
+    info!("Stage {} cleared: {}", current_stage.0, clear.summary());
+    clock.last_clear = Some(clear);
+}
+
+/// Stage clear times and medals: each full clear of a regular stage is timed against the medal
+/// times in its pattern file, with the best time and medal kept in the save file
+pub struct MedalPlugin;
+
+impl Plugin for MedalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StageClock>()
+            .add_systems(
+                OnEnter(GameState::InGame),
+                // Before spawn_boss takes the checkpoint's resume flag
+                start_stage_clock.before(spawn_boss),
+            )
+            .add_systems(
+                Update,
+                tick_stage_clock
+                    .run_if(in_state(GameState::InGame))
+                    .run_if(game_unpaused),
+            )
+            .add_systems(
+                OnEnter(GameState::GameWin),
+                record_stage_clear
+                    .before(spawn_game_win_screen)
+                    // The pattern preview, endless waves and randomized bosses aren't stage clears
+                    .run_if(not(in_pattern_preview))
+                    .run_if(not(in_endless_mode))
+                    .run_if(not(in_randomizer_mode)),
+            );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::components::boss::Boss;
+use crate::components::player::{Defeated, Player};
+use crate::stages::game_menu::GameState;
+use crate::systems::boundaries::ArenaBounds;
+use crate::systems::config::{MINIMAP_HEIGHT, MINIMAP_WIDTH};
+use bevy::prelude::*;
+
+/// Marker component for the minimap strip (holds the position markers)
+#[derive(Component)]
+pub struct MinimapStrip;
+
+/// Component for a position marker on the minimap, following `entity`
+#[derive(Component)]
+pub struct MinimapMarker {
+    pub entity: Entity,
+}
+
+/// Spawns the minimap strip along the bottom of the screen
+pub fn spawn_minimap(mut commands: Commands) {
+    commands
+        .spawn((
+            DespawnOnExit(GameState::InGame),
+            Node {
+                position_type: PositionType::Absolute,
+                width: percent(100.0),
+                bottom: px(8.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+        ))
+        .with_child((
+            Node {
+                width: px(MINIMAP_WIDTH),
+                height: px(MINIMAP_HEIGHT),
+                border: UiRect::all(px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.4)),
+            MinimapStrip,
+        ));
+}
+
+/// Where `x` lies across the arena, from 0.0 (left wall) to 1.0 (right wall)
+fn arena_fraction(arena: &ArenaBounds, x: f32) -> f32 {
+    ((x - arena.left) / arena.width()).clamp(0.0, 1.0)
+}
+
+/// System to keep one marker per player and boss on the minimap, at their horizontal
+/// position in the arena, so bosses can be tracked when they're off-screen
+pub fn update_minimap(
+    mut commands: Commands,
+    strip_query: Query<Entity, With<MinimapStrip>>,
+    player_query: Query<Entity, Added<Player>>,
+    boss_query: Query<Entity, Added<Boss>>,
+    tracked_query: Query<&Transform, Without<Defeated>>,
+    mut marker_query: Query<(Entity, &MinimapMarker, &mut Node)>,
+    arena: Res<ArenaBounds>,
+) {
+    let Some(strip) = strip_query.iter().next() else {
+        return;
+    };
+
+    let new_markers = player_query
+        .iter()
+        .map(|entity| (entity, Color::srgb(0.3, 0.9, 0.3)))
+        .chain(boss_query.iter().map(|entity| (entity, Color::srgb(0.9, 0.2, 0.2))));
+    for (entity, color) in new_markers {
+        commands.entity(strip).with_child((
+            Node {
+                position_type: PositionType::Absolute,
+                width: px(MINIMAP_HEIGHT - 2.0),
+                height: percent(100.0),
+                ..default()
+            },
+            BackgroundColor(color),
+            MinimapMarker { entity },
+        ));
+    }
+
+    // Markers sit centered on their entity's position; defeated or despawned entities lose theirs
+    let marker_width = MINIMAP_HEIGHT - 2.0;
+    for (marker_entity, marker, mut node) in &mut marker_query {
+        match tracked_query.get(marker.entity) {
+            Ok(transform) => {
+                let x = arena_fraction(&arena, transform.translation.x) * (MINIMAP_WIDTH - 2.0);
+                node.left = px(x - marker_width / 2.0);
+            }
+            Err(_) => {
+                commands.entity(marker_entity).despawn();
+            }
+        }
+    }
+}
+
+/// Minimap strip showing where the players and bosses are across the arena
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::InGame), spawn_minimap)
+            .add_systems(Update, update_minimap.run_if(in_state(GameState::InGame)));
+    }
+}
@@ -1 +1,21 @@
+pub mod analytics_plugin;
+pub mod announcer_plugin;
+pub mod audio_plugin;
+pub mod bench_plugin;
+pub mod camera_plugin;
+pub mod capture_plugin;
+pub mod collision_plugin;
+pub mod ghost_plugin;
+pub mod input_display_plugin;
+pub mod low_hp_warning_plugin;
+pub mod medal_plugin;
+pub mod minimap_plugin;
+pub mod offscreen_warning_plugin;
+pub mod particle_plugin;
 pub mod player_plugin;
+pub mod restart_plugin;
+pub mod run_plugin;
+pub mod secret_token_plugin;
+pub mod shield_plugin;
+pub mod transition_plugin;
+pub mod tutorial_plugin;
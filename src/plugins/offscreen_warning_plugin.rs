@@ -0,0 +1,120 @@
+use crate::components::boss::Boss;
+use crate::components::player::Defeated;
+use crate::plugins::camera_plugin::GameCamera;
+use crate::stages::game_menu::GameState;
+use crate::systems::boss::BossProjectile;
+use crate::systems::config::{OFFSCREEN_WARNING_MARGIN, OFFSCREEN_WARNING_SIZE};
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// Component for an edge-of-screen warning pointing at an off-screen boss or boss projectile
+#[derive(Component)]
+pub struct OffscreenWarning {
+    pub target: Entity,
+}
+
+/// Arrow text for a warning clamped to the screen edge in `direction` (from the screen center)
+fn warning_label(direction: Vec2) -> &'static str {
+    if direction.x.abs() >= direction.y.abs() {
+        if direction.x < 0.0 { "<!" } else { "!>" }
+    } else if direction.y < 0.0 {
+        "^!" // Viewport y points down
+    } else {
+        "v!"
+    }
+}
+
+/// System to show a warning arrow at the screen edge for every boss and boss projectile outside
+/// the camera view, so attacks coming from off-screen can be seen before they arrive
+pub fn update_offscreen_warnings(
+    mut commands: Commands,
+    camera_query: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    target_query: Query<
+        (Entity, &GlobalTransform),
+        Or<(With<BossProjectile>, (With<Boss>, Without<Defeated>))>,
+    >,
+    mut warning_query: Query<(Entity, &OffscreenWarning, &mut Node, &mut Text)>,
+) {
+    let Some((camera, camera_transform)) = camera_query.iter().next() else {
+        return;
+    };
+    let Some(viewport_size) = camera.logical_viewport_size() else {
+        return;
+    };
+
+    let half_size = OFFSCREEN_WARNING_SIZE / 2.0;
+    let min = Vec2::splat(OFFSCREEN_WARNING_MARGIN + half_size);
+    let max = viewport_size - min;
+
+    // Top-left corner and arrow of a target's warning, or None while the target is visible
+    let edge_position = |target: &GlobalTransform| {
+        let position = camera
+            .world_to_viewport(camera_transform, target.translation())
+            .ok()?;
+        let on_screen = position.cmpge(Vec2::ZERO).all() && position.cmple(viewport_size).all();
+        if on_screen {
+            return None;
+        }
+        let direction = position - viewport_size / 2.0;
+        Some((position.clamp(min, max) - half_size, warning_label(direction)))
+    };
+
+    // Move existing warnings, removing those whose target is gone or back on screen
+    let mut warned = HashSet::new();
+    for (entity, warning, mut node, mut text) in &mut warning_query {
+        let edge = target_query
+            .get(warning.target)
+            .ok()
+            .and_then(|(_, transform)| edge_position(transform));
+        match edge {
+            Some((position, label)) => {
+                node.left = px(position.x);
+                node.top = px(position.y);
+                if text.0 != label {
+                    text.0 = label.to_string();
+                }
+                warned.insert(warning.target);
+            }
+            None => {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+
+    for (target, transform) in &target_query {
+        if warned.contains(&target) {
+            continue;
+        }
+        let Some((position, label)) = edge_position(transform) else {
+            continue;
+        };
+        commands.spawn((
+            DespawnOnExit(GameState::InGame),
+            Text::new(label),
+            TextFont {
+                font_size: OFFSCREEN_WARNING_SIZE,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.25, 0.2)),
+            Node {
+                position_type: PositionType::Absolute,
+                left: px(position.x),
+                top: px(position.y),
+                ..default()
+            },
+            OffscreenWarning { target },
+        ));
+    }
+}
+
+/// Edge-of-screen warning arrows for bosses and boss projectiles outside the camera view
+pub struct OffscreenWarningPlugin;
+
+impl Plugin for OffscreenWarningPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            update_offscreen_warnings.run_if(in_state(GameState::InGame)),
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use crate::components::gameplay::GameplayEntity;
+use crate::components::player::Player;
+use crate::plugins::camera_plugin::EFFECTS_RENDER_LAYER;
+use crate::stages::game_menu::GameState;
+use crate::systems::config::{FEEDBACK_PARTICLE_LIFETIME, FEEDBACK_PARTICLE_SIZE};
+use crate::systems::feedback::{FeedbackEvent, player_surface};
+use crate::systems::platforms::{Ground, SurfaceMaterial};
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+
+/// How a feedback event's particle burst looks
+struct BurstStyle {
+    color: Color,
+    count: u32,
+    speed: f32,      // Units/second the particles fly out at
+    offset_y: f32,   // Burst origin relative to the player's center
+    spread: f32,     // Half-angle of the burst in radians (PI = full circle)
+    direction: Vec2, // Center direction of the burst
+}
+
+fn burst_style(event: FeedbackEvent) -> BurstStyle {
+    match event {
+        FeedbackEvent::Jumped => BurstStyle {
+            color: Color::srgb(0.9, 0.9, 0.9),
+            count: 6,
+            speed: 80.0,
+            offset_y: -25.0,
+            spread: 0.6,
+            direction: Vec2::NEG_Y,
+        },
+        FeedbackEvent::Landed => BurstStyle {
+            color: SurfaceMaterial::Stone.dust_color(), // Dust (colored by the surface landed on)
+            count: 8,
+            speed: 90.0,
+            offset_y: -25.0,
+            spread: 0.3,
+            direction: Vec2::Y,
+        },
+        FeedbackEvent::Footstep => BurstStyle {
+            color: SurfaceMaterial::Stone.dust_color(), // Dust (colored by the surface stepped on)
+            count: 2,
+            speed: 50.0,
+            offset_y: -30.0,
+            spread: 0.5,
+            direction: Vec2::Y,
+        },
+        FeedbackEvent::Dashed => BurstStyle {
+            color: Color::srgb(0.4, 0.7, 1.0),
+            count: 8,
+            speed: 140.0,
+            offset_y: 0.0,
+            spread: std::f32::consts::PI,
+            direction: Vec2::Y,
+        },
+        FeedbackEvent::Hurt => BurstStyle {
+            color: Color::srgb(1.0, 0.2, 0.2),
+            count: 12,
+            speed: 160.0,
+            offset_y: 0.0,
+            spread: std::f32::consts::PI,
+            direction: Vec2::Y,
+        },
+        FeedbackEvent::ShotFired => BurstStyle {
+            color: Color::srgb(1.0, 0.9, 0.4),
+            count: 3,
+            speed: 60.0,
+            offset_y: 0.0,
+            spread: std::f32::consts::PI,
+            direction: Vec2::Y,
+        },
+        FeedbackEvent::ChargedShotFired => BurstStyle {
+            color: Color::srgb(1.0, 0.6, 0.1),
+            count: 10,
+            speed: 120.0,
+            offset_y: 0.0,
+            spread: std::f32::consts::PI,
+            direction: Vec2::Y,
+        },
+        FeedbackEvent::ChargeReady => BurstStyle {
+            color: Color::srgb(1.0, 1.0, 0.85), // Bright sparkle
+            count: 8,
+            speed: 100.0,
+            offset_y: 0.0,
+            spread: std::f32::consts::PI,
+            direction: Vec2::Y,
+        },
+    }
+}
+
+/// Resource with the shared particle mesh, one material per feedback event (indexed like
+/// `FeedbackEvent::ALL`) and one dust material per surface (indexed like `SurfaceMaterial::ALL`)
+#[derive(Resource)]
+pub struct FeedbackParticleAssets {
+    pub mesh: Handle<Mesh>,
+    pub materials: Vec<Handle<ColorMaterial>>,
+    pub dust_materials: Vec<Handle<ColorMaterial>>,
+}
+
+/// Component for a feedback particle flying out and shrinking away
+#[derive(Component)]
+#[require(GameplayEntity)]
+pub struct FeedbackParticle {
+    pub velocity: Vec2,
+    pub age: f32,
+}
+
+pub fn setup_feedback_particle_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.insert_resource(FeedbackParticleAssets {
+        mesh: meshes.add(Rectangle::new(FEEDBACK_PARTICLE_SIZE, FEEDBACK_PARTICLE_SIZE)),
+        materials: FeedbackEvent::ALL
+            .into_iter()
+            .map(|event| materials.add(burst_style(event).color))
+            .collect(),
+        dust_materials: SurfaceMaterial::ALL
+            .into_iter()
+            .map(|surface| materials.add(surface.dust_color()))
+            .collect(),
+    });
+}
+
+/// System to spawn a particle burst at the player for each feedback event sent this frame
+/// (footstep and landing dust takes the color of the surface under the player)
+pub fn spawn_feedback_particles(
+    mut commands: Commands,
+    mut feedback: MessageReader<FeedbackEvent>,
+    assets: Res<FeedbackParticleAssets>,
+    ground: Ground,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    let Some(player_transform) = player_query.iter().next() else {
+        feedback.clear();
+        return;
+    };
+    let surface = player_surface(&ground, player_transform);
+
+    for event in feedback.read() {
+        let style = burst_style(*event);
+        let origin = player_transform.translation.truncate() + Vec2::new(0.0, style.offset_y);
+        let base_angle = style.direction.to_angle();
+        let material = if event.on_surface() {
+            &assets.dust_materials[surface as usize]
+        } else {
+            &assets.materials[*event as usize]
+        };
+
+        for i in 0..style.count {
+            // Spread the particles evenly across the burst's arc
+            let t = if style.count > 1 {
+                i as f32 / (style.count - 1) as f32
+            } else {
+                0.5
+            };
+            let angle = base_angle + style.spread * (t * 2.0 - 1.0);
+            commands.spawn((
+                Mesh2d(assets.mesh.clone()),
+                MeshMaterial2d(material.clone()),
+                Transform::from_xyz(origin.x, origin.y, 0.0),
+                RenderLayers::layer(EFFECTS_RENDER_LAYER),
+                FeedbackParticle {
+                    velocity: Vec2::from_angle(angle) * style.speed,
+                    age: 0.0,
+                },
+            ));
+        }
+    }
+}
+
+/// System to move feedback particles, shrink them over their lifetime and despawn them at the end
+pub fn update_feedback_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut particle_query: Query<(Entity, &mut FeedbackParticle, &mut Transform)>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut particle, mut transform) in &mut particle_query {
+        particle.age += delta;
+        if particle.age >= FEEDBACK_PARTICLE_LIFETIME {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation += (particle.velocity * delta).extend(0.0);
+        transform.scale = Vec3::splat(1.0 - particle.age / FEEDBACK_PARTICLE_LIFETIME);
+    }
+}
+
+/// Particle bursts for gameplay feedback events (jump puffs, footstep and landing dust, dash
+/// trails, hit sparks and muzzle flashes)
+pub struct FeedbackParticlePlugin;
+
+impl Plugin for FeedbackParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_feedback_particle_assets).add_systems(
+            Update,
+            (spawn_feedback_particles, update_feedback_particles)
+                .run_if(in_state(GameState::InGame)),
+        );
+    }
+}
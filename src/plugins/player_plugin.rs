@@ -1,90 +1,359 @@
-use crate::components::boss::{Boss, BossHealthBarContainer, BossRegistry};
-use crate::components::player::{BoundaryWall, ChargeEffect, Floor, HealthBar, HealthBarBackground, HealthBarMask, Player, Projectile};
-use crate::stages::game_menu::{BackgroundImage, CurrentStage, GameState, PlayerUpgrades, despawn_screen};
+use crate::components::boss::BossRegistry;
+use crate::components::gameplay::GameplayEntity;
+use crate::stages::endless::in_endless_mode;
+use crate::stages::game_menu::{CurrentStage, GameState, despawn_screen};
+use crate::stages::pause_menu::game_unpaused;
+use crate::stages::pattern_preview::in_pattern_preview;
+use crate::systems::arena_doors::{
+    ArenaDoors, animate_arena_doors, fight_started, player_in_control, reset_arena_doors,
+    setup_arena_doors, update_arena_doors,
+};
 use crate::systems::boss::{
-    BossPatternRegistry, BossProjectile, boss_attacks, boss_movement, boss_projectile_movement,
-    boss_projectile_player_collision, load_stage_boss_pattern, setup_boss_hp_bar,
+    BossPatternRegistry, boss_attacks, boss_beam_player_collision, boss_melee_player_collision,
+    boss_movement, boss_projectile_movement, boss_projectile_player_collision,
+    boss_shockwave_movement, boss_shockwave_player_collision, load_stage_boss_pattern,
+    spawn_boss_hp_bars, update_boss_invulnerability, update_boss_phase, update_survival_timer_text,
+};
+use crate::systems::blocks::break_blocks;
+use crate::systems::boss_ai::boss_behavior;
+use crate::systems::config::TUTORIAL_STAGE;
+use crate::systems::boundaries::{
+    ArenaBounds, apply_stage_arena_bounds, reset_arena_bounds, spawn_boundaries,
+};
+use crate::systems::collision::UpdateColliders;
+use crate::systems::corridor::{
+    Corridor, enter_arena_from_corridor, reach_checkpoint_flags, reset_corridor, spawn_corridor,
+};
+use crate::systems::effects::{
+    CameraScroll, CameraZoom, ScreenShake, anchor_hud_to_camera, apply_camera_zoom,
+    apply_screen_shake, follow_player_with_camera, player_hit_flash, reset_camera_scroll,
+    reset_camera_zoom, update_camera_zoom,
 };
-use crate::systems::boundaries::spawn_boundaries;
+use crate::systems::feedback::FeedbackEvent;
+use crate::systems::game_config::{
+    GameConfig, GameConfigWatcher, hot_reload_game_config, load_game_config,
+};
+use crate::systems::hazards::{hazard_player_damage, update_hazard_zones};
+use crate::systems::health::{EntityDied, HealthChanged, emit_health_events};
+use crate::systems::hit_stop::{HitStop, update_hit_stop};
+use crate::systems::hp_orb::HpOrbMaterial;
+use crate::systems::kill_cam::{KillCam, reset_kill_cam, start_kill_cam, update_kill_cam};
+use crate::systems::minions::{minion_player_collision, patrol_minions};
+use crate::systems::pattern_validation::{PatternDiagnostics, spawn_pattern_diagnostics_panel};
+use crate::systems::platforms::{StagePlatforms, clear_stage_platforms, spawn_stage_platforms};
 use crate::systems::player::{
-    animate_charge_effect, apply_boss_knockback, apply_knockback, change_health, check_game_outcome, manage_charge_effect,
-    persist_player_hp, player_boss_collision, player_movement, player_shooting, projectile_boss_collision,
-    projectile_movement, setup_player_hp_bar, spawn_boss, spawn_player_and_level, update_health_bars,
+    BossDamaged, BossDefeated, PlayerDied, ProjectileHit, ProjectileHitSet, animate_charge_effect,
+    apply_boss_knockback, apply_charge_hit_rule, check_game_outcome, despawn_orphaned_health_bars,
+    despawn_spent_projectiles, detect_defeats, emit_footsteps, manage_charge_effect,
+    persist_player_hp, player_boss_collision, player_movement, player_shooting,
+    projectile_hits_enemies, projectile_movement, regenerate_health, setup_lives_display,
+    setup_shared_combat_assets, spawn_boss, spawn_player_and_level, spawn_player_hp_orbs,
+    update_health_bars, use_potion,
+};
+use crate::systems::player_animation::{animate_player, update_player_animation_state};
+use crate::systems::projectile_cap::{
+    ProjectileCapStats, cap_boss_projectiles, reset_projectile_cap_stats,
+    toggle_projectile_cap_overlay, update_projectile_cap_overlay,
+};
+use crate::systems::projectile_styles::{
+    emit_projectile_trails, load_projectile_styles, spawn_projectile_impacts,
+};
+use crate::systems::stage_modifier::{
+    StageModifier, follow_player_with_darkness, roll_stage_modifier, spawn_curse_effects,
+    update_curse_banner,
+};
+use crate::systems::weapons::{
+    LifestealTracker, WeaponWheel, apply_lifesteal, close_weapon_wheel, fire_weapon_combo,
+    recolor_player_for_weapon, reset_lifesteal_tracker, setup_fusion_materials,
+    weapon_wheel_closed, weapon_wheel_input,
 };
 use bevy::prelude::*;
+use bevy::sprite_render::Material2dPlugin;
 
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<BossRegistry>()
+        // Shader material for the player HP orb
+        app.add_plugins(Material2dPlugin::<HpOrbMaterial>::default())
+            .init_resource::<BossRegistry>()
             .init_resource::<BossPatternRegistry>()
-            .init_resource::<CurrentStage>()
-            .init_resource::<PlayerUpgrades>()
+            .init_resource::<PatternDiagnostics>()
+            .init_resource::<HitStop>()
+            .init_resource::<KillCam>()
+            .init_resource::<ProjectileCapStats>()
+            .init_resource::<ScreenShake>()
+            .init_resource::<CameraZoom>()
+            .init_resource::<WeaponWheel>()
+            .init_resource::<GameConfig>()
+            .init_resource::<GameConfigWatcher>()
+            .add_message::<PlayerDied>()
+            .add_message::<BossDefeated>()
+            .add_message::<BossDamaged>()
+            .add_message::<ProjectileHit>()
+            .add_message::<FeedbackEvent>()
+            .add_message::<HealthChanged>()
+            .add_message::<EntityDied>()
+            .init_resource::<LifestealTracker>()
+            .init_resource::<StageModifier>()
+            .init_resource::<ArenaBounds>()
+            .init_resource::<StagePlatforms>()
+            .init_resource::<ArenaDoors>()
+            .init_resource::<Corridor>()
+            .init_resource::<CameraScroll>()
+            .add_systems(
+                Startup,
+                (
+                    load_game_config,
+                    setup_shared_combat_assets,
+                    load_projectile_styles,
+                    setup_fusion_materials.after(load_projectile_styles),
+                ),
+            )
             .add_systems(
                 OnEnter(GameState::InGame),
                 (
-                    // Initialize stage to 1 only if starting fresh (stage is 0)
-                    |mut stage: ResMut<CurrentStage>| {
-                        if stage.0 == 0 {
-                            stage.0 = 1;
-                        }
-                    },
                     // Load boss pattern for current stage
                     load_stage_boss_pattern,
+                    // The stage's pattern can set its own arena walls and ceiling
+                    apply_stage_arena_bounds,
+                    // The stage's layout sets where the player and the boss spawn
+                    spawn_stage_platforms,
                     // Spawn player, boss, and boundaries
                     spawn_player_and_level,
-                    spawn_boss,
+                    // The tutorial spawns its practice boss once the obstacle course is done
+                    spawn_boss.run_if(|stage: Res<CurrentStage>| stage.0 != TUTORIAL_STAGE),
                     spawn_boundaries,
                 )
                     .chain(),
             )
             .add_systems(
                 OnEnter(GameState::InGame),
-                (setup_player_hp_bar, setup_boss_hp_bar)
-                    .after(spawn_player_and_level)
-                    .after(spawn_boss),
+                (setup_lives_display.after(spawn_player_and_level), reset_lifesteal_tracker),
+            )
+            .add_systems(
+                OnEnter(GameState::InGame),
+                // Roguelike mode: roll the stage's curse and announce it before the fight starts
+                (roll_stage_modifier, spawn_curse_effects).chain(),
+            )
+            .add_systems(
+                OnEnter(GameState::InGame),
+                spawn_pattern_diagnostics_panel.after(load_stage_boss_pattern),
+            )
+            .add_systems(
+                OnEnter(GameState::InGame),
+                // Stages with arena doors start with the player walking in through the entrance,
+                // or with a corridor leading up to it
+                (
+                    setup_arena_doors.after(spawn_player_and_level),
+                    spawn_corridor
+                        .after(setup_arena_doors)
+                        .after(spawn_stage_platforms),
+                )
+                    .run_if(not(in_pattern_preview))
+                    .run_if(not(in_endless_mode)),
             )
             .add_systems(
                 Update,
                 (
-                    player_movement,
-                    apply_knockback.after(player_movement), // Apply knockback after normal movement
-                    player_shooting,
+                    player_movement
+                        .run_if(weapon_wheel_closed) // No movement while picking a weapon
+                        .run_if(game_unpaused)
+                        .run_if(player_in_control),
+                    // Dash + shoot with two boss weapons fires a fusion attack instead of a shot
+                    fire_weapon_combo
+                        .before(player_shooting)
+                        .run_if(weapon_wheel_closed)
+                        .run_if(game_unpaused)
+                        .run_if(player_in_control),
+                    player_shooting
+                        .run_if(weapon_wheel_closed)
+                        .run_if(game_unpaused)
+                        .run_if(player_in_control),
                     manage_charge_effect.after(player_shooting), // Manage charge effect spawn/despawn
                     animate_charge_effect.after(manage_charge_effect), // Animate charge effect
                     projectile_movement,
-                    boss_movement,            // Boss movement system
+                    boss_behavior
+                        .before(boss_movement)
+                        .before(boss_attacks)
+                        .run_if(fight_started), // Behavior tree picks the active patterns
+                    boss_movement.run_if(fight_started), // Boss movement system
                     apply_boss_knockback.after(boss_movement), // Apply boss knockback after boss movement
-                    boss_attacks,             // Boss attack system
+                    boss_attacks.run_if(fight_started), // Boss attack system
                     boss_projectile_movement, // Boss projectile movement
-                    boss_projectile_player_collision.after(boss_projectile_movement), // Boss projectile hits player (after movement)
-                    player_boss_collision,
-                    projectile_boss_collision,
-                    persist_player_hp, // Persist player HP to upgrades resource
-                    check_game_outcome, // Check for win/lose conditions
-                    update_health_bars,
-                    change_health,
+                    boss_projectile_player_collision
+                        .after(boss_projectile_movement)
+                        .before(emit_health_events)
+                        .in_set(ProjectileHitSet), // Boss projectile hits player (after movement)
+                    player_boss_collision.before(emit_health_events),
+                    projectile_hits_enemies.in_set(ProjectileHitSet),
+                    update_boss_phase.after(projectile_hits_enemies), // Phase transitions trigger hit-stop
+                    persist_player_hp.after(detect_defeats), // Persist player HP when the fight ends
+                    check_game_outcome
+                        .after(detect_defeats)
+                        .run_if(not(in_pattern_preview))
+                        .run_if(not(in_endless_mode)), // React to wins/losses
+                    // HP bars follow whichever players/bosses exist, including ones spawned mid-fight
+                    (spawn_player_hp_orbs, spawn_boss_hp_bars, despawn_orphaned_health_bars)
+                        .before(update_health_bars),
+                    update_health_bars.after(emit_health_events),
+                    regenerate_health.before(emit_health_events), // Out-of-combat regeneration upgrade
                 )
                     .run_if(in_state(GameState::InGame)),
             )
+            .add_systems(
+                Update,
+                (
+                    apply_screen_shake,
+                    player_hit_flash,
+                    update_curse_banner,
+                    follow_player_with_darkness.after(player_movement),
+                    // The camera scrolls along corridors; the HUD drawn in the world moves with it
+                    (follow_player_with_camera, anchor_hud_to_camera)
+                        .chain()
+                        .after(player_movement)
+                        .before(apply_screen_shake),
+                    // Corridor, walk-in, lock-in and reopening of stages with arena doors
+                    (
+                        enter_arena_from_corridor,
+                        update_arena_doors,
+                        animate_arena_doors,
+                    )
+                        .chain()
+                        .after(player_movement),
+                    reach_checkpoint_flags.after(player_movement), // Retries start from the last flag passed
+                    patrol_minions.before(UpdateColliders),
+                    break_blocks.after(projectile_hits_enemies), // Shot-down blocks burst into debris
+                    minion_player_collision
+                        .after(UpdateColliders)
+                        .before(emit_health_events),
+                )
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                Update,
+                (
+                    boss_shockwave_movement.after(boss_movement), // Landing shockwaves travel along the floor
+                    // Every system changing HP runs before emit_health_events, so hits are
+                    // reported in the frame they land
+                    boss_shockwave_player_collision
+                        .after(boss_shockwave_movement)
+                        .before(emit_health_events),
+                    boss_melee_player_collision
+                        .after(boss_attacks)
+                        .before(emit_health_events), // Active melee hitboxes hurt the player
+                    boss_beam_player_collision
+                        .after(boss_attacks)
+                        .before(emit_health_events), // Firing beams hurt the player
+                    update_hazard_zones,
+                    hazard_player_damage
+                        .after(update_hazard_zones)
+                        .before(emit_health_events), // Standing in hazards hurts the player
+                    update_boss_invulnerability.after(boss_behavior), // Survival phases end on a timer
+                    update_survival_timer_text.after(update_boss_invulnerability),
+                    weapon_wheel_input
+                        .before(player_movement)
+                        .run_if(game_unpaused)
+                        .run_if(player_in_control), // Hold Tab to pick a weapon
+                    recolor_player_for_weapon.after(weapon_wheel_input), // The player takes on the equipped weapon's color
+                    emit_health_events.before(detect_defeats), // Reports every HP change and death
+                    detect_defeats, // Sends PlayerDied / BossDefeated when HP hits 0
+                    apply_lifesteal
+                        .after(projectile_hits_enemies)
+                        .before(emit_health_events), // Boss weapon hits heal with the lifesteal upgrade
+                    use_potion.before(emit_health_events).run_if(game_unpaused), // Q drinks a potion bought in the shop
+                    apply_charge_hit_rule.after(emit_health_events), // Hits may cancel or weaken a charge (GameConfig)
+                    emit_footsteps
+                        .after(player_movement)
+                        .run_if(player_in_control), // Running sends footsteps (sounds and dust by surface)
+                )
+                    .run_if(in_state(GameState::InGame)),
+            )
+            // Spent projectiles go once every collision system of the frame has had its look
+            .configure_sets(
+                Update,
+                ProjectileHitSet
+                    .after(UpdateColliders)
+                    .before(spawn_projectile_impacts)
+                    .before(despawn_spent_projectiles),
+            )
+            .add_systems(
+                Update,
+                (
+                    // Impact bursts need the hit projectiles still around
+                    spawn_projectile_impacts.before(despawn_spent_projectiles),
+                    despawn_spent_projectiles,
+                )
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                Update,
+                (
+                    emit_projectile_trails,
+                    // Past the projectile cap (GameConfig), the oldest boss projectiles are culled
+                    cap_boss_projectiles
+                        .after(boss_projectile_movement)
+                        .before(boss_projectile_player_collision),
+                    // F3 shows the projectile counts against the cap
+                    toggle_projectile_cap_overlay,
+                    update_projectile_cap_overlay
+                        .after(toggle_projectile_cap_overlay)
+                        .after(cap_boss_projectiles),
+                )
+                    .run_if(in_state(GameState::InGame)),
+            )
+            // Camera zoom, and the kill cam on the final blow: zoom, hit-stop and a grayed
+            // background, then the boss explodes and the fight ends
+            .add_systems(
+                Update,
+                (
+                    start_kill_cam
+                        .after(emit_health_events)
+                        .before(check_game_outcome)
+                        .run_if(not(in_pattern_preview))
+                        .run_if(not(in_endless_mode)),
+                    update_kill_cam
+                        .after(start_kill_cam)
+                        .after(apply_screen_shake),
+                    update_camera_zoom.after(player_movement),
+                    apply_camera_zoom
+                        .after(update_camera_zoom)
+                        .after(update_kill_cam),
+                )
+                    .run_if(in_state(GameState::InGame)),
+            )
+            // Idle animation after a few seconds without input (breathing and blinking)
+            .add_systems(
+                Update,
+                (
+                    update_player_animation_state,
+                    animate_player.after(update_player_animation_state),
+                )
+                    .run_if(in_state(GameState::InGame)),
+            )
+            // Hit-stop runs in every state so virtual time always recovers to normal speed
+            .add_systems(Update, update_hit_stop)
+            // Every gameplay entity goes in one sweep; the fight's HUD carries
+            // DespawnOnExit(GameState::InGame) instead
             .add_systems(
                 OnExit(GameState::InGame),
                 (
-                    persist_player_hp, // Save HP before despawning
-                    despawn_screen::<Player>,
-                    despawn_screen::<Boss>,
-                    despawn_screen::<Floor>,
-                    despawn_screen::<Projectile>,
-                    despawn_screen::<HealthBar>,
-                    despawn_screen::<HealthBarBackground>,
-                    despawn_screen::<HealthBarMask>,
-                    despawn_screen::<BossHealthBarContainer>,
-                    despawn_screen::<BackgroundImage>,
-                    despawn_screen::<BossProjectile>,
-                    despawn_screen::<BoundaryWall>,
-                    despawn_screen::<ChargeEffect>,
+                    despawn_screen::<GameplayEntity>,
+                    close_weapon_wheel,
+                    reset_arena_bounds,
+                    clear_stage_platforms,
+                    reset_arena_doors,
+                    reset_corridor,
+                    reset_camera_scroll,
+                    reset_kill_cam,
+                    reset_camera_zoom,
+                    reset_projectile_cap_stats,
                 ),
             );
+
+        // Pick up edits to the config file while the game runs (debug builds only)
+        if cfg!(debug_assertions) {
+            app.add_systems(Update, hot_reload_game_config);
+        }
     }
 }
@@ -0,0 +1,96 @@
+use crate::plugins::run_plugin::RestartFight;
+use crate::stages::endless::in_endless_mode;
+use crate::stages::game_menu::GameState;
+use crate::stages::pattern_preview::in_pattern_preview;
+use crate::stages::pause_menu::game_unpaused;
+use crate::systems::config::RESTART_FIGHT_HOLD_TIME;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+
+const RESTART_KEY: KeyCode = KeyCode::KeyR;
+
+/// Resource to time how long the restart key has been held
+#[derive(Resource, Default)]
+pub struct RestartHold {
+    pub held: f32,
+    pub fired: bool, // The restart was sent; the key has to be let go before it can fire again
+}
+
+/// Marker component for the "Restarting fight" prompt shown while the restart key is held
+#[derive(Component)]
+pub struct RestartPrompt;
+
+/// System to restart the fight once the restart key has been held long enough, showing a prompt
+/// that fills in while it's held (real time, so hit-stop doesn't slow it down)
+pub fn hold_to_restart_fight(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time<Real>>,
+    mut hold: ResMut<RestartHold>,
+    mut restart_fight: MessageWriter<RestartFight>,
+    mut prompt_query: Query<(Entity, &mut Text, &mut TextColor), With<RestartPrompt>>,
+) {
+    if !keyboard_input.pressed(RESTART_KEY) {
+        *hold = RestartHold::default();
+        for (entity, ..) in &prompt_query {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    // Only a press made during the fight counts (not one carried over from the game over screen)
+    if hold.fired || (hold.held == 0.0 && !keyboard_input.just_pressed(RESTART_KEY)) {
+        return;
+    }
+
+    hold.held += time.delta_secs();
+    let progress = (hold.held / RESTART_FIGHT_HOLD_TIME).min(1.0);
+    let text = format!("Restarting fight... {:.0}%", progress * 100.0);
+    match prompt_query.single_mut() {
+        Ok((_, mut prompt_text, mut color)) => {
+            prompt_text.0 = text;
+            color.0 = color.0.with_alpha(0.4 + 0.6 * progress);
+        }
+        Err(_) => {
+            commands.spawn((
+                DespawnOnExit(GameState::InGame),
+                Text::new(text),
+                TextFont {
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(Color::from(WHITE).with_alpha(0.4)),
+                TextLayout::new_with_justify(Justify::Center),
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: percent(100.0),
+                    top: percent(30.0),
+                    ..default()
+                },
+                RestartPrompt,
+            ));
+        }
+    }
+
+    if hold.held >= RESTART_FIGHT_HOLD_TIME {
+        hold.fired = true;
+        restart_fight.write(RestartFight);
+    }
+}
+
+/// Hold R during a fight to restart it from the start of the stage, without going through the
+/// game over screen
+pub struct RestartPlugin;
+
+impl Plugin for RestartPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RestartHold>().add_systems(
+            Update,
+            hold_to_restart_fight
+                .run_if(in_state(GameState::InGame))
+                .run_if(game_unpaused)
+                // Endless waves and the pattern preview aren't stages to restart
+                .run_if(not(in_endless_mode))
+                .run_if(not(in_pattern_preview)),
+        );
+    }
+}
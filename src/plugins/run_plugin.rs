@@ -0,0 +1,459 @@
+use crate::stages::game_menu::{
+    CurrentStage, DefeatedBoss, FightCheckpoint, GameState, Lives, PlayerUpgrades,
+    SelectedCharacter,
+};
+use crate::systems::config::{
+    CRUMB_OF_DEATH_DAMAGE_MULTIPLIER, MAX_STAGES, PATTERN_PREVIEW_STAGE, SAVE_FILE_PATH,
+    SCORE_DEATH_PENALTY, SCORE_PER_BOSS, SECRET_TOKEN_REWARD_POTIONS, TUTORIAL_STAGE,
+};
+use crate::systems::player::{BossDefeated, PlayerDied};
+use crate::systems::rng::GameRng;
+use crate::systems::save_data::{SaveData, SavedRun, current_day, daily_seed};
+use crate::systems::spawn::PLAYER_BASE_MAX_HP;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::window::WindowCloseRequested;
+
+/// Resource with statistics about the current run
+#[derive(Resource, Default, Debug)]
+pub struct RunStats {
+    pub seed: u64, // GameRng seed the run started with (pass it to --seed to replay the run)
+    pub deaths: u32,
+    pub bosses_defeated: u32,
+    pub daily_day: Option<u64>, // Day of the daily challenge this run is for (None = regular run)
+    pub endless_wave: Option<u32>, // Current wave of an endless mode run (None = regular run)
+    pub randomizer: bool, // Randomizer mode run: bosses shuffled and attacks mixed from the seed
+    pub crumb_of_death: bool, // Crumb of Death run: the player dies in one hit but hits harder
+}
+
+impl RunStats {
+    /// Score of the run: points per boss defeated, minus a penalty per death
+    pub fn score(&self) -> u32 {
+        (self.bosses_defeated * SCORE_PER_BOSS).saturating_sub(self.deaths * SCORE_DEATH_PENALTY)
+    }
+
+    /// Multiplier for the damage the player's shots deal
+    pub fn damage_dealt_multiplier(&self) -> f32 {
+        if self.crumb_of_death {
+            CRUMB_OF_DEATH_DAMAGE_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Message to reset all run state and start a fresh run at stage 1 (sent when a character is picked)
+#[derive(Message)]
+pub struct StartNewRun;
+
+/// Message to reset all run state and start today's daily challenge: a run at stage 1 seeded
+/// from the date, with stage curses on and the game speed setting ignored
+#[derive(Message)]
+pub struct StartDailyChallenge;
+
+/// Message to restore the run saved in the save file (from the main menu) and resume it at the
+/// start of the stage it was saved in
+#[derive(Message)]
+pub struct ContinueSavedRun;
+
+/// Message to save the run in progress (if any) and exit the game
+#[derive(Message)]
+pub struct QuitGame;
+
+/// Message to reset all run state and start an endless mode run: waves of bosses with
+/// growing HP and speed until the player dies
+#[derive(Message)]
+pub struct StartEndlessRun;
+
+/// Message to reset all run state and start a randomizer run: a run at stage 1 with the bosses
+/// shuffled and their attacks mixed up from the run's seed
+#[derive(Message)]
+pub struct StartRandomizerRun;
+
+/// Message to reset all run state and start a Crumb of Death run: a run at stage 1 where the
+/// player dies in one hit but deals triple damage (only once a run was won)
+#[derive(Message)]
+pub struct StartCrumbOfDeathRun;
+
+/// Message to reset all run state and start the tutorial stage
+#[derive(Message)]
+pub struct StartTutorial;
+
+/// Message to reset all run state and preview the pattern loaded for `PATTERN_PREVIEW_STAGE`
+#[derive(Message)]
+pub struct StartPatternPreview;
+
+/// Message to spend a life and retry the current stage with upgrades kept
+#[derive(Message)]
+pub struct ContinueRun {
+    pub from_checkpoint: bool, // Resume the lost fight from its phase checkpoint instead of the stage start
+}
+
+/// Message to end the current run (full game over or final win) and return to character selection
+#[derive(Message)]
+pub struct EndRun;
+
+/// Message to restart the current fight on the spot, from the start of the stage (held restart key)
+#[derive(Message)]
+pub struct RestartFight;
+
+/// Run state resources, reset together whenever a run starts or ends
+#[derive(SystemParam)]
+pub struct RunResources<'w> {
+    current_stage: ResMut<'w, CurrentStage>,
+    player_upgrades: ResMut<'w, PlayerUpgrades>,
+    checkpoint: ResMut<'w, FightCheckpoint>,
+    lives: ResMut<'w, Lives>,
+    defeated_boss: ResMut<'w, DefeatedBoss>,
+    rng: ResMut<'w, GameRng>,
+    run_stats: ResMut<'w, RunStats>,
+}
+
+impl RunResources<'_> {
+    /// Resets every piece of run state back to a fresh run
+    fn reset(&mut self) {
+        self.current_stage.0 = 0;
+        *self.player_upgrades = PlayerUpgrades::new();
+        *self.checkpoint = FightCheckpoint::default();
+        *self.lives = Lives::default();
+        *self.defeated_boss = DefeatedBoss::default();
+        *self.run_stats = RunStats {
+            seed: self.rng.reseed(),
+            ..default()
+        };
+    }
+
+    /// Resets every piece of run state and starts a `mode` run at `stage`: `mode` gives the run's
+    /// mode fields (its seed is ignored) and `seed` replays a given seed instead of a fresh one
+    fn start(
+        &mut self,
+        stage: u32,
+        seed: Option<u64>,
+        mode: RunStats,
+        next_state: &mut NextState<GameState>,
+    ) {
+        self.reset();
+        self.current_stage.0 = stage;
+        let seed = match seed {
+            Some(seed) => self.rng.reseed_to(seed),
+            None => self.run_stats.seed,
+        };
+        *self.run_stats = RunStats { seed, ..mode };
+        next_state.set(GameState::InGame);
+    }
+}
+
+/// Tears the fight down and sets the stage up again from its start, the same as leaving and
+/// re-entering `GameState::InGame` (Bevy skips the enter/exit schedules when a state is set to
+/// itself). The player starts again with the HP they started the stage with.
+pub fn reset_fight(world: &mut World) {
+    // Sweeps up every GameplayEntity; the fight's HUD is scoped to the state instead
+    world.run_schedule(OnExit(GameState::InGame));
+
+    let scoped: Vec<Entity> = world
+        .query::<(Entity, &DespawnOnExit<GameState>)>()
+        .iter(world)
+        .filter(|(_, scope)| scope.0 == GameState::InGame)
+        .map(|(entity, _)| entity)
+        .collect();
+    for entity in scoped {
+        // Children go along with their parent, so some may already be gone
+        let _ = world.try_despawn(entity);
+    }
+
+    world.run_schedule(OnEnter(GameState::InGame));
+}
+
+/// System to handle run lifecycle messages. This is the only place run state is reset.
+pub fn handle_run_messages(
+    mut commands: Commands,
+    mut start_messages: MessageReader<StartNewRun>,
+    mut tutorial_messages: MessageReader<StartTutorial>,
+    mut daily_messages: MessageReader<StartDailyChallenge>,
+    mut endless_messages: MessageReader<StartEndlessRun>,
+    mut randomizer_messages: MessageReader<StartRandomizerRun>,
+    mut crumb_of_death_messages: MessageReader<StartCrumbOfDeathRun>,
+    mut preview_messages: MessageReader<StartPatternPreview>,
+    mut continue_messages: MessageReader<ContinueRun>,
+    mut end_messages: MessageReader<EndRun>,
+    mut restart_messages: MessageReader<RestartFight>,
+    mut run: RunResources,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for _ in start_messages.read() {
+        run.start(1, None, RunStats::default(), &mut next_state);
+        // Finding every secret token unlocks bonus potions for regular runs
+        if SaveData::load_or_default().all_secret_tokens_found() {
+            run.player_upgrades.potions += SECRET_TOKEN_REWARD_POTIONS;
+        }
+        info!("Starting new run (seed {})", run.run_stats.seed);
+    }
+
+    for _ in daily_messages.read() {
+        let day = current_day();
+        let mode = RunStats {
+            daily_day: Some(day),
+            ..default()
+        };
+        run.start(1, Some(daily_seed(day)), mode, &mut next_state);
+        info!(
+            "Starting daily challenge for day {} (seed {})",
+            day, run.run_stats.seed
+        );
+    }
+
+    for _ in endless_messages.read() {
+        let mode = RunStats {
+            endless_wave: Some(1),
+            ..default()
+        };
+        run.start(1, None, mode, &mut next_state);
+        info!("Starting endless run (seed {})", run.run_stats.seed);
+    }
+
+    for _ in randomizer_messages.read() {
+        let mode = RunStats {
+            randomizer: true,
+            ..default()
+        };
+        run.start(1, None, mode, &mut next_state);
+        info!("Starting randomizer run (seed {})", run.run_stats.seed);
+    }
+
+    for _ in crumb_of_death_messages.read() {
+        if !SaveData::load_or_default().game_cleared {
+            warn!("StartCrumbOfDeathRun sent before the mode was unlocked, ignoring");
+            continue;
+        }
+        let mode = RunStats {
+            crumb_of_death: true,
+            ..default()
+        };
+        run.start(1, None, mode, &mut next_state);
+        info!("Starting Crumb of Death run (seed {})", run.run_stats.seed);
+    }
+
+    for _ in tutorial_messages.read() {
+        run.start(TUTORIAL_STAGE, None, RunStats::default(), &mut next_state);
+        info!("Starting tutorial");
+    }
+
+    for _ in preview_messages.read() {
+        run.start(
+            PATTERN_PREVIEW_STAGE,
+            None,
+            RunStats::default(),
+            &mut next_state,
+        );
+        info!("Starting pattern preview");
+    }
+
+    for continue_run in continue_messages.read() {
+        if run.lives.0 == 0 {
+            warn!("ContinueRun sent with no lives left, ignoring");
+            continue;
+        }
+        run.lives.0 -= 1;
+
+        if continue_run.from_checkpoint && run.checkpoint.available_for(run.current_stage.0) {
+            // Retry the lost fight from its checkpoint
+            run.checkpoint.resume = true;
+            run.player_upgrades.current_hp = run.checkpoint.player_hp;
+        } else {
+            // Retry the stage from the start with full HP
+            run.player_upgrades.current_hp = PLAYER_BASE_MAX_HP + run.player_upgrades.max_hp_bonus;
+        }
+        info!(
+            "Continuing run at stage {} ({} lives left)",
+            run.current_stage.0, run.lives.0
+        );
+        next_state.set(GameState::InGame);
+    }
+
+    // Several restarts in one frame are still one restart
+    if restart_messages.read().count() > 0 {
+        // A restart counts as a death, so it can't be used to dodge the death penalty
+        run.run_stats.deaths += 1;
+        run.checkpoint.resume = false;
+        info!("Restarting stage {}", run.current_stage.0);
+        commands.run_system_cached(reset_fight);
+    }
+
+    for _ in end_messages.read() {
+        let mut save_data = SaveData::load_or_default();
+        // Runs end from the win screen only once the final stage is beaten
+        let won = *state.get() == GameState::GameWin;
+        if won && !save_data.game_cleared {
+            save_data.game_cleared = true;
+            info!("Crumb of Death mode unlocked");
+        }
+        // Daily challenge results go to that day's leaderboard entry
+        if let Some(day) = run.run_stats.daily_day {
+            let score = run.run_stats.score();
+            save_data.record_daily(day, run.run_stats.seed, score);
+            info!("Daily challenge score: {}", score);
+        }
+        // Crumb of Death results go to the mode's own leaderboard entry
+        if run.run_stats.crumb_of_death {
+            let score = run.run_stats.score();
+            if save_data.record_crumb_of_death(score, won) {
+                info!("New Crumb of Death best score: {}", score);
+            }
+        }
+        // The run is over, so there is nothing left to continue
+        save_data.saved_run = None;
+        if let Err(e) = save_data.save_to_file(SAVE_FILE_PATH) {
+            warn!("Failed to save run results to {}: {}", SAVE_FILE_PATH, e);
+        }
+
+        run.reset();
+        info!("Run ended");
+        next_state.set(GameState::CharacterSelection);
+    }
+}
+
+/// System to restore a saved run when continuing from the main menu
+pub fn handle_continue_saved_run(
+    mut continue_messages: MessageReader<ContinueSavedRun>,
+    mut run: RunResources,
+    mut selected_character: ResMut<SelectedCharacter>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for _ in continue_messages.read() {
+        let Some(saved_run) = SaveData::load_or_default().saved_run else {
+            warn!("ContinueSavedRun sent with no saved run, ignoring");
+            continue;
+        };
+
+        let mode = RunStats {
+            deaths: saved_run.deaths,
+            bosses_defeated: saved_run.bosses_defeated,
+            ..default()
+        };
+        run.start(saved_run.stage, Some(saved_run.seed), mode, &mut next_state);
+        *run.player_upgrades = saved_run.upgrades;
+        run.lives.0 = saved_run.lives;
+        *selected_character = saved_run.character;
+        info!("Continuing saved run at stage {}", run.current_stage.0);
+    }
+}
+
+/// Run state that makes up a saved run
+#[derive(SystemParam)]
+pub struct RunSnapshot<'w> {
+    current_stage: Res<'w, CurrentStage>,
+    player_upgrades: Res<'w, PlayerUpgrades>,
+    lives: Res<'w, Lives>,
+    run_stats: Res<'w, RunStats>,
+    selected_character: Res<'w, SelectedCharacter>,
+}
+
+impl RunSnapshot<'_> {
+    /// Write the run to the save file so the main menu can continue it from the start of its
+    /// current stage. Only regular runs are saved (tutorial, preview, daily challenge, endless,
+    /// randomizer and Crumb of Death runs can't be continued).
+    pub fn save(&self) {
+        let regular_stage = (1..=MAX_STAGES).contains(&self.current_stage.0);
+        if !regular_stage
+            || self.run_stats.daily_day.is_some()
+            || self.run_stats.endless_wave.is_some()
+            || self.run_stats.randomizer
+            || self.run_stats.crumb_of_death
+        {
+            return;
+        }
+
+        let mut save_data = SaveData::load_or_default();
+        save_data.saved_run = Some(SavedRun {
+            stage: self.current_stage.0,
+            character: *self.selected_character,
+            upgrades: self.player_upgrades.clone(),
+            lives: self.lives.0,
+            seed: self.run_stats.seed,
+            deaths: self.run_stats.deaths,
+            bosses_defeated: self.run_stats.bosses_defeated,
+        });
+        match save_data.save_to_file(SAVE_FILE_PATH) {
+            Ok(()) => info!("Saved run progress (stage {})", self.current_stage.0),
+            Err(e) => warn!("Failed to save run progress to {}: {}", SAVE_FILE_PATH, e),
+        }
+    }
+}
+
+/// System to save a snapshot of the run as each of its stages starts
+pub fn save_run_progress(snapshot: RunSnapshot) {
+    snapshot.save();
+}
+
+/// System to quit gracefully: closing the window or picking Quit saves the run in progress
+/// before the app exits
+pub fn handle_quit_requests(
+    mut close_requests: MessageReader<WindowCloseRequested>,
+    mut quit_messages: MessageReader<QuitGame>,
+    state: Res<State<GameState>>,
+    snapshot: RunSnapshot,
+    mut app_exit: MessageWriter<AppExit>,
+) {
+    // Read both readers so neither keeps stale messages around
+    let close_requested = close_requests.read().count() > 0;
+    let quit_requested = quit_messages.read().count() > 0;
+    if !close_requested && !quit_requested {
+        return;
+    }
+
+    // A lost or finished run keeps the snapshot taken when its stage started
+    if !matches!(state.get(), GameState::GameOver | GameState::GameWin) {
+        snapshot.save();
+    }
+    info!("Quitting");
+    app_exit.write(AppExit::Success);
+}
+
+/// System to count deaths and boss defeats in the run stats
+pub fn record_run_stats(
+    mut player_died: MessageReader<PlayerDied>,
+    mut boss_defeated: MessageReader<BossDefeated>,
+    mut run_stats: ResMut<RunStats>,
+) {
+    run_stats.deaths += player_died.read().count() as u32;
+    run_stats.bosses_defeated += boss_defeated.read().count() as u32;
+}
+
+/// Owns the run state resources (stage, upgrades, lives, checkpoint, defeated boss, RNG, stats)
+/// and the messages that start, continue and end a run.
+pub struct RunPlugin;
+
+impl Plugin for RunPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CurrentStage>()
+            .init_resource::<PlayerUpgrades>()
+            .init_resource::<FightCheckpoint>()
+            .init_resource::<Lives>()
+            .init_resource::<DefeatedBoss>()
+            .init_resource::<RunStats>()
+            .insert_resource(GameRng::from_args())
+            .add_message::<StartNewRun>()
+            .add_message::<StartTutorial>()
+            .add_message::<StartDailyChallenge>()
+            .add_message::<StartEndlessRun>()
+            .add_message::<StartRandomizerRun>()
+            .add_message::<StartCrumbOfDeathRun>()
+            .add_message::<StartPatternPreview>()
+            .add_message::<ContinueRun>()
+            .add_message::<EndRun>()
+            .add_message::<RestartFight>()
+            .add_message::<ContinueSavedRun>()
+            .add_message::<QuitGame>()
+            .add_systems(
+                Update,
+                (
+                    handle_run_messages,
+                    handle_continue_saved_run,
+                    record_run_stats,
+                    handle_quit_requests,
+                ),
+            )
+            .add_systems(OnEnter(GameState::InGame), save_run_progress);
+    }
+}
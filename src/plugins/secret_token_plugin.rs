@@ -0,0 +1,167 @@
+use crate::components::gameplay::GameplayEntity;
+use crate::components::player::{Defeated, Player};
+use crate::plugins::camera_plugin::EFFECTS_RENDER_LAYER;
+use crate::plugins::particle_plugin::{FeedbackParticle, FeedbackParticleAssets};
+use crate::stages::game_menu::{CurrentStage, GameState};
+use crate::stages::pattern_preview::in_pattern_preview;
+use crate::stages::pause_menu::game_unpaused;
+use crate::systems::boss::{BossPatternRegistry, load_stage_boss_pattern};
+use crate::systems::collision::PLAYER_HITBOX_SIZE;
+use crate::systems::config::{
+    SAVE_FILE_PATH, SECRET_TOKEN_FOUND_ALPHA, SECRET_TOKEN_SIZE, SECRET_TOKENS_PER_STAGE,
+};
+use crate::systems::player::check_aabb_collision;
+use crate::systems::save_data::SaveData;
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+
+const TOKEN_COLOR: Color = Color::srgb(1.0, 0.85, 0.25);
+const TOKEN_SPARKLE_COUNT: u32 = 8;
+const TOKEN_SPARKLE_SPEED: f32 = 120.0;
+
+/// Component for one of a stage's secret tokens (`index` into its pattern's `secret_tokens`).
+/// Tokens found on an earlier visit are shown faded and can't be picked up again.
+#[derive(Component)]
+#[require(GameplayEntity)]
+pub struct SecretToken {
+    pub index: usize,
+    pub found: bool,
+}
+
+/// Places the current stage's secret tokens, fading out the ones the save file says were found
+pub fn spawn_secret_tokens(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    pattern_registry: Res<BossPatternRegistry>,
+    current_stage: Res<CurrentStage>,
+) {
+    let Some(pattern) = pattern_registry.get_pattern(&format!("stage_{}", current_stage.0)) else {
+        return;
+    };
+    if pattern.secret_tokens.is_empty() {
+        return;
+    }
+
+    let save_data = SaveData::load_or_default();
+    let mesh = meshes.add(Rectangle::new(SECRET_TOKEN_SIZE, SECRET_TOKEN_SIZE));
+    for (index, spot) in pattern
+        .secret_tokens
+        .iter()
+        .take(SECRET_TOKENS_PER_STAGE)
+        .enumerate()
+    {
+        let found = save_data.has_secret_token(current_stage.0, index);
+        let color = if found {
+            TOKEN_COLOR.with_alpha(SECRET_TOKEN_FOUND_ALPHA)
+        } else {
+            TOKEN_COLOR
+        };
+        commands.spawn((
+            Mesh2d(mesh.clone()),
+            MeshMaterial2d(materials.add(color)),
+            Transform::from_xyz(spot.x, spot.y, 0.5)
+                .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+            SecretToken { index, found },
+        ));
+    }
+}
+
+/// System to make the tokens sparkle: a slow spin with a twinkling pulse
+pub fn animate_secret_tokens(
+    time: Res<Time>,
+    mut token_query: Query<(&SecretToken, &mut Transform)>,
+) {
+    let t = time.elapsed_secs();
+    for (token, mut transform) in &mut token_query {
+        // Each token twinkles out of step with the others
+        let phase = t * 4.0 + token.index as f32 * 2.1;
+        transform.rotation = Quat::from_rotation_z(std::f32::consts::FRAC_PI_4 + t * 1.5);
+        transform.scale = Vec3::splat(1.0 + 0.2 * phase.sin());
+    }
+}
+
+/// System to pick up the tokens the player touches, writing each find to the save file straight
+/// away so it counts even if the run is lost
+pub fn collect_secret_tokens(
+    mut commands: Commands,
+    current_stage: Res<CurrentStage>,
+    particle_assets: Res<FeedbackParticleAssets>,
+    token_query: Query<(
+        Entity,
+        &SecretToken,
+        &Transform,
+        &MeshMaterial2d<ColorMaterial>,
+    )>,
+    player_query: Query<&Transform, (With<Player>, Without<Defeated>)>,
+) {
+    for (entity, token, token_transform, material) in &token_query {
+        if token.found {
+            continue;
+        }
+        let touched = player_query.iter().any(|player_transform| {
+            check_aabb_collision(
+                player_transform.translation,
+                PLAYER_HITBOX_SIZE,
+                token_transform.translation,
+                Vec2::splat(SECRET_TOKEN_SIZE),
+            )
+        });
+        if !touched {
+            continue;
+        }
+
+        let mut save_data = SaveData::load_or_default();
+        if save_data.record_secret_token(current_stage.0, token.index)
+            && let Err(e) = save_data.save_to_file(SAVE_FILE_PATH)
+        {
+            warn!("Failed to save secret token to {}: {}", SAVE_FILE_PATH, e);
+        }
+        info!(
+            "Found secret token {} of stage {} ({}/{})",
+            token.index + 1,
+            current_stage.0,
+            save_data.secret_tokens_in(current_stage.0),
+            SECRET_TOKENS_PER_STAGE
+        );
+        commands.entity(entity).despawn();
+
+        let origin = token_transform.translation;
+        for i in 0..TOKEN_SPARKLE_COUNT {
+            let angle = std::f32::consts::TAU * i as f32 / TOKEN_SPARKLE_COUNT as f32;
+            commands.spawn((
+                Mesh2d(particle_assets.mesh.clone()),
+                MeshMaterial2d(material.0.clone()),
+                Transform::from_xyz(origin.x, origin.y, 0.0),
+                RenderLayers::layer(EFFECTS_RENDER_LAYER),
+                FeedbackParticle {
+                    velocity: Vec2::from_angle(angle) * TOKEN_SPARKLE_SPEED,
+                    age: 0.0,
+                },
+            ));
+        }
+    }
+}
+
+/// Secret tokens: three per stage, hidden in hard-to-reach spots. Finds are kept in the save file,
+/// tallied on the character selection screen, and finding them all gives every new run bonus potions.
+pub struct SecretTokenPlugin;
+
+impl Plugin for SecretTokenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameState::InGame),
+            spawn_secret_tokens
+                .after(load_stage_boss_pattern)
+                .run_if(not(in_pattern_preview)),
+        )
+        .add_systems(
+            Update,
+            (
+                animate_secret_tokens,
+                collect_secret_tokens.run_if(game_unpaused),
+            )
+                .run_if(in_state(GameState::InGame)),
+        );
+    }
+}
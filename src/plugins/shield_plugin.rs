@@ -0,0 +1,126 @@
+use crate::components::gameplay::GameplayEntity;
+use crate::components::player::{Defeated, Player};
+use crate::stages::game_menu::GameState;
+use crate::stages::pattern_preview::in_pattern_preview;
+use crate::stages::pause_menu::game_unpaused;
+use crate::systems::boundaries::ArenaBounds;
+use crate::systems::collision::PLAYER_HITBOX_SIZE;
+use crate::systems::config::{
+    FLOOR_TOP, SHIELD_AMOUNT, SHIELD_DURATION, SHIELD_PICKUP_INTERVAL, SHIELD_PICKUP_SIZE,
+};
+use crate::systems::health::{Overshield, emit_health_events, tick_overshields};
+use crate::systems::player::check_aabb_collision;
+use crate::systems::rng::GameRng;
+use bevy::prelude::*;
+
+/// Marker component for a shield pickup lying on the arena floor
+#[derive(Component)]
+#[require(GameplayEntity)]
+pub struct ShieldPickup;
+
+/// Resource with the time until the next shield pickup appears
+#[derive(Resource)]
+pub struct ShieldPickupTimer(pub Timer);
+
+impl Default for ShieldPickupTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(
+            SHIELD_PICKUP_INTERVAL,
+            TimerMode::Repeating,
+        ))
+    }
+}
+
+/// Spawns a shield pickup lying on the floor at `x`
+pub fn spawn_shield_pickup(commands: &mut Commands, x: f32) {
+    spawn_shield_pickup_at(commands, Vec2::new(x, FLOOR_TOP + SHIELD_PICKUP_SIZE * 0.5));
+}
+
+/// Spawns a shield pickup centered at `position` (like inside a broken block)
+pub fn spawn_shield_pickup_at(commands: &mut Commands, position: Vec2) {
+    commands.spawn((
+        Sprite::from_color(Color::srgb(0.3, 0.85, 1.0), Vec2::splat(SHIELD_PICKUP_SIZE)),
+        Transform::from_translation(position.extend(0.5)),
+        ShieldPickup,
+    ));
+}
+
+/// System to drop a shield pickup at a random spot on the floor every `SHIELD_PICKUP_INTERVAL`,
+/// as long as the last one has been picked up
+pub fn spawn_shield_pickups(
+    mut commands: Commands,
+    time: Res<Time>,
+    arena: Res<ArenaBounds>,
+    mut rng: ResMut<GameRng>,
+    mut timer: ResMut<ShieldPickupTimer>,
+    pickup_query: Query<(), With<ShieldPickup>>,
+) {
+    if !timer.0.tick(time.delta()).just_finished() || !pickup_query.is_empty() {
+        return;
+    }
+
+    let x = arena.left
+        + SHIELD_PICKUP_SIZE * 0.5
+        + rng.next_f32() * (arena.width() - SHIELD_PICKUP_SIZE);
+    spawn_shield_pickup(&mut commands, x);
+}
+
+/// System to give the player who touches a shield pickup a fresh overshield
+pub fn collect_shield_pickups(
+    mut commands: Commands,
+    pickup_query: Query<(Entity, &Transform), With<ShieldPickup>>,
+    player_query: Query<(Entity, &Transform), (With<Player>, Without<Defeated>)>,
+) {
+    for (pickup, pickup_transform) in &pickup_query {
+        let collector = player_query.iter().find(|(_, player_transform)| {
+            check_aabb_collision(
+                player_transform.translation,
+                PLAYER_HITBOX_SIZE,
+                pickup_transform.translation,
+                Vec2::splat(SHIELD_PICKUP_SIZE),
+            )
+        });
+        if let Some((player, _)) = collector {
+            commands.entity(player).insert(Overshield {
+                amount: SHIELD_AMOUNT,
+                max: SHIELD_AMOUNT,
+                timer: SHIELD_DURATION,
+            });
+            commands.entity(pickup).despawn();
+        }
+    }
+}
+
+/// System to restart the pickup timer and drop any overshields when the fight ends
+pub fn reset_shield_pickups(
+    mut commands: Commands,
+    mut timer: ResMut<ShieldPickupTimer>,
+    shield_query: Query<Entity, With<Overshield>>,
+) {
+    timer.0.reset();
+    for entity in &shield_query {
+        commands.entity(entity).remove::<Overshield>();
+    }
+}
+
+/// Shield pickups: dropped on the floor during fights, they give an overshield that takes damage
+/// before HP does and shows as a ring around the HP orb
+pub struct ShieldPickupPlugin;
+
+impl Plugin for ShieldPickupPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ShieldPickupTimer>()
+            .add_systems(
+                Update,
+                (
+                    spawn_shield_pickups,
+                    collect_shield_pickups,
+                    tick_overshields.after(emit_health_events),
+                )
+                    .run_if(in_state(GameState::InGame))
+                    .run_if(not(in_pattern_preview))
+                    .run_if(game_unpaused),
+            )
+            .add_systems(OnExit(GameState::InGame), reset_shield_pickups);
+    }
+}
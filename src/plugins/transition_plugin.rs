@@ -0,0 +1,158 @@
+use crate::stages::game_menu::GameState;
+use crate::systems::config::{TRANSITION_COVER_TIME, TRANSITION_REVEAL_TIME};
+use bevy::prelude::*;
+
+/// Where a screen transition is at
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransitionPhase {
+    #[default]
+    Idle,
+    Covering,  // The fade or wipe is covering the old screen; the state change waits for it
+    Switching, // The screen is covered and the held back state change is being applied
+    Revealing, // The new screen is being uncovered
+}
+
+/// Resource with the screen transition in progress
+#[derive(Resource, Default)]
+pub struct ScreenTransition {
+    pub phase: TransitionPhase,
+    pub target: Option<GameState>, // State to switch to once the screen is covered
+    pub coverage: f32,             // 0.0 = screen fully visible, 1.0 = fully covered
+    pub wipe: bool,                // Wipe across the screen instead of fading to black
+}
+
+/// Marker component for the full-screen panel drawn by the transitions
+#[derive(Component)]
+pub struct TransitionCover;
+
+/// Whether switching between these states plays a transition
+fn is_transition_state(state: GameState) -> bool {
+    matches!(
+        state,
+        GameState::CharacterSelection
+            | GameState::InGame
+            | GameState::StageUpgrade
+            | GameState::GameOver
+            | GameState::GameWin
+    )
+}
+
+/// Spawns the transition panel, which lives for the whole app above every other screen
+pub fn spawn_transition_cover(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100.0),
+            height: percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.0)),
+        GlobalZIndex(30), // Above every screen and overlay
+        Visibility::Hidden,
+        TransitionCover,
+    ));
+}
+
+/// System to hold back state changes to or from the covered states until the screen has been
+/// covered. Runs before the change would be applied, so the old screen stays up meanwhile.
+pub fn hold_back_state_changes(
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut transition: ResMut<ScreenTransition>,
+) {
+    let NextState::Pending(target) = *next_state else {
+        return;
+    };
+
+    match transition.phase {
+        // The change the transition itself sets goes through
+        TransitionPhase::Switching => transition.phase = TransitionPhase::Revealing,
+        // A newer change while covering replaces the held back one
+        TransitionPhase::Covering => {
+            transition.target = Some(target);
+            next_state.reset();
+        }
+        TransitionPhase::Idle | TransitionPhase::Revealing => {
+            let current = *state.get();
+            if target == current || !(is_transition_state(current) || is_transition_state(target)) {
+                return;
+            }
+            // Starting over while revealing covers again from where the reveal got to
+            transition.phase = TransitionPhase::Covering;
+            transition.target = Some(target);
+            transition.wipe = current == GameState::InGame || target == GameState::InGame;
+            next_state.reset();
+        }
+    }
+}
+
+/// System to play the transition (in real time, so hit-stop and game speed don't slow it
+/// down), switching to the held back state once the screen is covered
+pub fn animate_transition(
+    time: Res<Time<Real>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut transition: ResMut<ScreenTransition>,
+    mut cover_query: Query<
+        (&mut Node, &mut BackgroundColor, &mut Visibility),
+        With<TransitionCover>,
+    >,
+) {
+    match transition.phase {
+        TransitionPhase::Idle | TransitionPhase::Switching => {}
+        TransitionPhase::Covering => {
+            transition.coverage += time.delta_secs() / TRANSITION_COVER_TIME;
+            if transition.coverage >= 1.0 {
+                transition.coverage = 1.0;
+                if let Some(target) = transition.target.take() {
+                    transition.phase = TransitionPhase::Switching;
+                    next_state.set(target);
+                }
+            }
+        }
+        TransitionPhase::Revealing => {
+            transition.coverage -= time.delta_secs() / TRANSITION_REVEAL_TIME;
+            if transition.coverage <= 0.0 {
+                transition.coverage = 0.0;
+                transition.phase = TransitionPhase::Idle;
+            }
+        }
+    }
+
+    let Ok((mut node, mut color, mut visibility)) = cover_query.single_mut() else {
+        return;
+    };
+    *visibility = if transition.coverage > 0.0 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    if transition.wipe {
+        // The panel sweeps in from the left, then carries on out to the right
+        let revealing = transition.phase == TransitionPhase::Revealing;
+        node.left = percent(if revealing {
+            100.0 * (1.0 - transition.coverage)
+        } else {
+            0.0
+        });
+        node.width = percent(100.0 * transition.coverage);
+        color.0 = Color::BLACK;
+    } else {
+        node.left = percent(0.0);
+        node.width = percent(100.0);
+        color.0 = Color::BLACK.with_alpha(transition.coverage);
+    }
+}
+
+/// Fade-to-black and wipe transitions between character selection, the fights and the screens
+/// after them: state changes wait for the screen to be covered before they're applied
+pub struct TransitionPlugin;
+
+impl Plugin for TransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScreenTransition>()
+            .add_systems(Startup, spawn_transition_cover)
+            // PreUpdate runs before the StateTransition schedule applies NextState
+            .add_systems(PreUpdate, hold_back_state_changes)
+            .add_systems(Update, animate_transition);
+    }
+}
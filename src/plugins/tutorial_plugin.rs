@@ -0,0 +1,179 @@
+use crate::components::player::{ChargeShot, Dash, Player, PlayerVelocity};
+use crate::stages::game_menu::{CurrentStage, GameState, SelectedCharacter};
+use crate::systems::config::{CHARGE_SHOT_MIN_TIME, CONTROL_HINT_FADE_TIME};
+use crate::systems::input::InputAction;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+
+/// Actions the first stage teaches with on-screen control hints
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TutorialAction {
+    Move,
+    Jump,
+    Charge,
+    Dash,
+}
+
+impl TutorialAction {
+    const ALL: [TutorialAction; 4] = [
+        TutorialAction::Move,
+        TutorialAction::Jump,
+        TutorialAction::Charge,
+        TutorialAction::Dash,
+    ];
+
+    fn prompt(self) -> &'static str {
+        match self {
+            TutorialAction::Move => "Left/Right: Move",
+            TutorialAction::Jump => "Space or X: Jump (hold to jump higher)",
+            TutorialAction::Charge => "Hold C, then release: Charge shot",
+            TutorialAction::Dash => "Down + Jump: Dash",
+        }
+    }
+}
+
+/// Resource to track which actions the player has already performed (hints for them aren't shown again)
+#[derive(Resource, Default)]
+pub struct TutorialProgress {
+    pub performed: Vec<TutorialAction>,
+}
+
+impl TutorialProgress {
+    pub fn has_performed(&self, action: TutorialAction) -> bool {
+        self.performed.contains(&action)
+    }
+
+    fn record(&mut self, action: TutorialAction) {
+        if !self.has_performed(action) {
+            self.performed.push(action);
+        }
+    }
+}
+
+/// Marker component for the control hints UI root
+#[derive(Component)]
+pub struct ControlHints;
+
+/// Component for a single control hint; starts fading out once its action is performed
+#[derive(Component)]
+pub struct ControlHint {
+    pub action: TutorialAction,
+    pub fade_timer: Option<f32>, // Remaining fade-out time (None = still waiting for the action)
+}
+
+/// Spawns hints for the actions the player hasn't performed yet (first stage only)
+pub fn spawn_control_hints(
+    mut commands: Commands,
+    current_stage: Res<CurrentStage>,
+    progress: Res<TutorialProgress>,
+    selected_character: Res<SelectedCharacter>,
+) {
+    if current_stage.0 != 1 {
+        return;
+    }
+
+    // Cheeseman can't charge shots, so there's nothing to teach
+    let can_charge = matches!(*selected_character, SelectedCharacter::Breadman);
+
+    commands
+        .spawn((
+            DespawnOnExit(GameState::InGame),
+            Node {
+                position_type: PositionType::Absolute,
+                left: px(20.0),
+                bottom: px(20.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: px(6.0),
+                ..default()
+            },
+            ControlHints,
+        ))
+        .with_children(|parent| {
+            for action in TutorialAction::ALL {
+                if progress.has_performed(action)
+                    || (action == TutorialAction::Charge && !can_charge)
+                {
+                    continue;
+                }
+                parent.spawn((
+                    Text::new(action.prompt()),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(WHITE.into()),
+                    ControlHint {
+                        action,
+                        fade_timer: None,
+                    },
+                ));
+            }
+        });
+}
+
+/// System to record tutorial actions from the player's state
+pub fn track_tutorial_actions(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    player_query: Query<(&PlayerVelocity, &ChargeShot, Has<Dash>), With<Player>>,
+    mut progress: ResMut<TutorialProgress>,
+) {
+    if InputAction::Left.pressed(&keyboard_input) || InputAction::Right.pressed(&keyboard_input) {
+        progress.record(TutorialAction::Move);
+    }
+
+    for (velocity, charge_shot, is_dashing) in &player_query {
+        if velocity.y > 0.0 {
+            progress.record(TutorialAction::Jump);
+        }
+        if charge_shot.is_charging && charge_shot.timer >= CHARGE_SHOT_MIN_TIME {
+            progress.record(TutorialAction::Charge);
+        }
+        if is_dashing {
+            progress.record(TutorialAction::Dash);
+        }
+    }
+}
+
+/// System to fade out hints for performed actions and despawn them once invisible. Runs on real
+/// time, so hit-stop and the game speed setting don't hold the fade.
+pub fn fade_control_hints(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    progress: Res<TutorialProgress>,
+    mut hint_query: Query<(Entity, &mut ControlHint, &mut TextColor)>,
+) {
+    for (entity, mut hint, mut text_color) in &mut hint_query {
+        let Some(fade_timer) = hint.fade_timer.as_mut() else {
+            if progress.has_performed(hint.action) {
+                hint.fade_timer = Some(CONTROL_HINT_FADE_TIME);
+            }
+            continue;
+        };
+
+        *fade_timer -= time.delta_secs();
+        if *fade_timer <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let alpha = *fade_timer / CONTROL_HINT_FADE_TIME;
+        text_color.0 = text_color.0.with_alpha(alpha);
+    }
+}
+
+/// Shows contextual control hints during the first stage
+pub struct TutorialPlugin;
+
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TutorialProgress>()
+            .add_systems(OnEnter(GameState::InGame), spawn_control_hints)
+            .add_systems(
+                Update,
+                (
+                    track_tutorial_actions,
+                    fade_control_hints.after(track_tutorial_actions),
+                )
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
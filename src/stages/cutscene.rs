@@ -0,0 +1,368 @@
+use crate::stages::game_menu::{CurrentStage, GameState, despawn_screen};
+use crate::systems::config::{DIALOGUE_CHARS_PER_SECOND, DIALOGUE_DIR, SAVE_FILE_PATH};
+use crate::systems::save_data::SaveData;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// One line of dialogue, shown with the speaker's name and portrait
+#[derive(Clone, Debug, Deserialize)]
+pub struct DialogueLine {
+    pub speaker: String,
+    pub text: String,
+    #[serde(default)]
+    pub portrait: Option<String>, // Portrait image path (relative to the game directory)
+    #[serde(default)]
+    pub portrait_color: Option<[f32; 3]>, // Flat portrait color (sRGB) used when there is no image
+    #[serde(default)]
+    pub image: Option<String>, // Slide image shown above the dialogue box (story sequences)
+}
+
+/// Dialogue of a cutscene, authored in a JSON file in `DIALOGUE_DIR`
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct DialogueScript {
+    pub lines: Vec<DialogueLine>,
+}
+
+impl DialogueScript {
+    /// Load a dialogue script from a JSON file
+    pub fn load_from_file(file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let json_content = std::fs::read_to_string(file_path)?;
+        Ok(serde_json::from_str(&json_content)?)
+    }
+}
+
+/// Resource to pick which cutscene the `Cutscene` state plays (set before entering it)
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CutsceneKind {
+    #[default]
+    Victory,    // The defeated boss's lines (`stage_N_victory.json`), then the upgrade screen
+    StoryIntro, // Opening story slides (`intro.json`) on a fresh save, then the main menu
+}
+
+impl CutsceneKind {
+    fn file_path(self, stage: u32) -> String {
+        match self {
+            CutsceneKind::Victory => format!("{}/stage_{}_victory.json", DIALOGUE_DIR, stage),
+            CutsceneKind::StoryIntro => format!("{}/intro.json", DIALOGUE_DIR),
+        }
+    }
+
+    /// State the game moves on to once the cutscene is over (or skipped)
+    fn next_state(self) -> GameState {
+        match self {
+            CutsceneKind::Victory => GameState::StageUpgrade,
+            CutsceneKind::StoryIntro => GameState::MainMenu,
+        }
+    }
+}
+
+/// Resource with the dialogue being played and how far along it is
+#[derive(Resource, Default)]
+pub struct ActiveDialogue {
+    pub script: DialogueScript,
+    pub line: usize,
+    pub shown_chars: f32, // Characters of the current line revealed so far (typewriter effect)
+}
+
+impl ActiveDialogue {
+    fn current_line(&self) -> Option<&DialogueLine> {
+        self.script.lines.get(self.line)
+    }
+
+    fn line_finished(&self) -> bool {
+        self.current_line()
+            .is_none_or(|line| self.shown_chars as usize >= line.text.chars().count())
+    }
+}
+
+/// Marker component for the cutscene UI root
+#[derive(Component)]
+pub struct CutsceneScreen;
+
+/// Marker component for the slide image above the dialogue box
+#[derive(Component)]
+pub struct DialogueSlide;
+
+/// Marker component for the speaker's portrait
+#[derive(Component)]
+pub struct DialoguePortrait;
+
+/// Marker component for the speaker's name
+#[derive(Component)]
+pub struct DialogueSpeakerText;
+
+/// Marker component for the dialogue text
+#[derive(Component)]
+pub struct DialogueBodyText;
+
+fn portrait_color(line: &DialogueLine) -> Color {
+    line.portrait_color
+        .map_or(Color::srgb(0.5, 0.5, 0.5), |[r, g, b]| Color::srgb(r, g, b))
+}
+
+/// Shows the image at `path` on a UI node, or removes its image when there is none
+fn set_node_image(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    node: Entity,
+    path: Option<&String>,
+) {
+    match path {
+        Some(path) => {
+            commands
+                .entity(node)
+                .insert(ImageNode::new(asset_server.load(path.clone())));
+        }
+        None => {
+            commands.entity(node).remove::<ImageNode>();
+        }
+    }
+}
+
+/// Queues the story intro on a fresh save, before the main menu is first shown
+pub fn queue_story_intro(
+    mut cutscene_kind: ResMut<CutsceneKind>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !SaveData::load_or_default().intro_seen {
+        *cutscene_kind = CutsceneKind::StoryIntro;
+        next_state.set(GameState::Cutscene);
+    }
+}
+
+/// Loads the cutscene's dialogue, skipping straight to the next screen if there is none
+pub fn start_cutscene(
+    current_stage: Res<CurrentStage>,
+    cutscene_kind: Res<CutsceneKind>,
+    mut dialogue: ResMut<ActiveDialogue>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let file_path = cutscene_kind.file_path(current_stage.0);
+    let script = match DialogueScript::load_from_file(&file_path) {
+        Ok(script) => script,
+        Err(e) => {
+            info!("No cutscene dialogue loaded from {}: {}", file_path, e);
+            DialogueScript::default()
+        }
+    };
+
+    if script.lines.is_empty() {
+        next_state.set(cutscene_kind.next_state());
+    }
+    *dialogue = ActiveDialogue {
+        script,
+        ..default()
+    };
+}
+
+/// Spawns the slide image and, along the bottom of the screen, the dialogue box
+/// (portrait, speaker name and text)
+pub fn spawn_cutscene_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    dialogue: Res<ActiveDialogue>,
+) {
+    let Some(first_line) = dialogue.current_line() else {
+        return;
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: percent(100.0),
+                height: percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::FlexEnd,
+                align_items: AlignItems::Center,
+                padding: UiRect::bottom(px(40.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.05, 0.1)),
+            CutsceneScreen,
+        ))
+        .with_children(|parent| {
+            let mut slide = parent.spawn((
+                Node {
+                    width: px(900.0),
+                    height: px(380.0),
+                    margin: UiRect::bottom(px(20.0)),
+                    ..default()
+                },
+                DialogueSlide,
+            ));
+            if let Some(path) = &first_line.image {
+                slide.insert(ImageNode::new(asset_server.load(path.clone())));
+            }
+
+            parent
+                .spawn((
+                    Node {
+                        width: px(900.0),
+                        height: px(200.0),
+                        align_items: AlignItems::Center,
+                        column_gap: px(24.0),
+                        padding: UiRect::all(px(20.0)),
+                        border: UiRect::all(px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                    BorderColor::all(Color::srgb(1.0, 0.9, 0.0)),
+                ))
+                .with_children(|dialogue_box| {
+                    let mut portrait = dialogue_box.spawn((
+                        Node {
+                            width: px(140.0),
+                            height: px(140.0),
+                            flex_shrink: 0.0,
+                            ..default()
+                        },
+                        BackgroundColor(portrait_color(first_line)),
+                        DialoguePortrait,
+                    ));
+                    if let Some(path) = &first_line.portrait {
+                        portrait.insert(ImageNode::new(asset_server.load(path.clone())));
+                    }
+
+                    dialogue_box
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            flex_grow: 1.0,
+                            row_gap: px(12.0),
+                            ..default()
+                        })
+                        .with_children(|column| {
+                            column.spawn((
+                                Text::new(first_line.speaker.clone()),
+                                TextFont {
+                                    font_size: 28.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.9, 0.0)),
+                                DialogueSpeakerText,
+                            ));
+                            column.spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font_size: 24.0,
+                                    ..default()
+                                },
+                                TextColor(WHITE.into()),
+                                DialogueBodyText,
+                            ));
+                        });
+                });
+
+            parent.spawn((
+                Text::new("Press ENTER to continue, ESC to skip"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+                Node {
+                    margin: UiRect::top(px(12.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+/// Reveals the current line a few characters at a time. Enter shows the rest of the line at once,
+/// or moves on to the next line once it is fully shown; after the last line (or on Esc) the game
+/// moves on to the cutscene's next screen. Runs on real time, so the game speed setting doesn't
+/// change the text speed.
+pub fn advance_dialogue(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time<Real>>,
+    asset_server: Res<AssetServer>,
+    cutscene_kind: Res<CutsceneKind>,
+    mut dialogue: ResMut<ActiveDialogue>,
+    mut body_query: Query<&mut Text, (With<DialogueBodyText>, Without<DialogueSpeakerText>)>,
+    mut speaker_query: Query<&mut Text, (With<DialogueSpeakerText>, Without<DialogueBodyText>)>,
+    mut portrait_query: Query<(Entity, &mut BackgroundColor), With<DialoguePortrait>>,
+    slide_query: Query<Entity, With<DialogueSlide>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if dialogue.current_line().is_none() {
+        return;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        next_state.set(cutscene_kind.next_state());
+        return;
+    }
+
+    let mut next_line = false;
+    if keyboard_input.just_pressed(KeyCode::Enter) {
+        if dialogue.line_finished() {
+            dialogue.line += 1;
+            dialogue.shown_chars = 0.0;
+            next_line = true;
+        } else {
+            dialogue.shown_chars = f32::MAX;
+        }
+    } else {
+        dialogue.shown_chars += DIALOGUE_CHARS_PER_SECOND * time.delta_secs();
+    }
+
+    let Some(line) = dialogue.current_line() else {
+        next_state.set(cutscene_kind.next_state());
+        return;
+    };
+
+    if next_line {
+        for mut text in &mut speaker_query {
+            text.0 = line.speaker.clone();
+        }
+        for (entity, mut background) in &mut portrait_query {
+            background.0 = portrait_color(line);
+            set_node_image(&mut commands, &asset_server, entity, line.portrait.as_ref());
+        }
+        for entity in &slide_query {
+            set_node_image(&mut commands, &asset_server, entity, line.image.as_ref());
+        }
+    }
+
+    let shown: String = line.text.chars().take(dialogue.shown_chars as usize).collect();
+    for mut text in &mut body_query {
+        if text.0 != shown {
+            text.0 = shown.clone();
+        }
+    }
+}
+
+/// System to remember that the story intro was seen (played through or skipped), so it only
+/// plays on a fresh save
+pub fn finish_cutscene(mut cutscene_kind: ResMut<CutsceneKind>) {
+    if *cutscene_kind == CutsceneKind::StoryIntro {
+        let mut save_data = SaveData::load_or_default();
+        save_data.intro_seen = true;
+        if let Err(e) = save_data.save_to_file(SAVE_FILE_PATH) {
+            warn!("Failed to save intro status to {}: {}", SAVE_FILE_PATH, e);
+        }
+    }
+    *cutscene_kind = CutsceneKind::default();
+}
+
+/// Data-driven cutscenes: the story intro on a fresh save, and victory dialogue played between
+/// a won fight and the upgrade screen
+pub struct CutscenePlugin;
+
+impl Plugin for CutscenePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActiveDialogue>()
+            .init_resource::<CutsceneKind>()
+            .add_systems(Startup, queue_story_intro)
+            .add_systems(
+                OnEnter(GameState::Cutscene),
+                (start_cutscene, spawn_cutscene_screen).chain(),
+            )
+            .add_systems(Update, advance_dialogue.run_if(in_state(GameState::Cutscene)))
+            .add_systems(
+                OnExit(GameState::Cutscene),
+                (despawn_screen::<CutsceneScreen>, finish_cutscene),
+            );
+    }
+}
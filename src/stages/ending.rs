@@ -0,0 +1,106 @@
+use crate::plugins::run_plugin::RunStats;
+use crate::stages::game_menu::PlayerUpgrades;
+use crate::stages::settings_menu::{GameSettings, GameSpeed};
+use crate::systems::config::ENDING_ARSENAL_WEAPONS;
+use bevy::prelude::*;
+
+/// How hard the run was made by the settings it was played with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RunDifficulty {
+    Assisted, // Slow game speed or aim assist
+    Normal,
+    Hard, // Stage curses (roguelike mode or a daily challenge) or fast game speed
+    CrumbOfDeath, // Crumb of Death mode (one-hit deaths), whatever the settings
+}
+
+impl RunDifficulty {
+    pub fn of_run(settings: &GameSettings, run_stats: &RunStats) -> Self {
+        if run_stats.crumb_of_death {
+            return RunDifficulty::CrumbOfDeath;
+        }
+
+        let cursed = settings.roguelike_mode || run_stats.daily_day.is_some();
+        // Daily challenges ignore the game speed setting
+        let speed = if run_stats.daily_day.is_some() {
+            GameSpeed::Normal
+        } else {
+            settings.game_speed
+        };
+
+        if cursed || speed == GameSpeed::Fast {
+            RunDifficulty::Hard
+        } else if speed == GameSpeed::Slow || settings.aim_assist {
+            RunDifficulty::Assisted
+        } else {
+            RunDifficulty::Normal
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RunDifficulty::Assisted => "Assisted",
+            RunDifficulty::Normal => "Normal",
+            RunDifficulty::Hard => "Hard",
+            RunDifficulty::CrumbOfDeath => "Crumb of Death",
+        }
+    }
+}
+
+/// Ending shown on the final win screen, picked from how the run went (best ending first)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ending {
+    Legendary, // No deaths on hard or in Crumb of Death mode
+    Flawless,  // No deaths
+    Arsenal,   // Collected enough boss weapons
+    Standard,
+}
+
+impl Ending {
+    pub fn of_run(difficulty: RunDifficulty, deaths: u32, upgrades: &PlayerUpgrades) -> Self {
+        let hard = matches!(difficulty, RunDifficulty::Hard | RunDifficulty::CrumbOfDeath);
+        if deaths == 0 && hard {
+            Ending::Legendary
+        } else if deaths == 0 {
+            Ending::Flawless
+        } else if upgrades.boss_weapons.len() >= ENDING_ARSENAL_WEAPONS {
+            Ending::Arsenal
+        } else {
+            Ending::Standard
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Ending::Legendary => "LEGENDARY VICTORY!",
+            Ending::Flawless => "FLAWLESS VICTORY!",
+            Ending::Arsenal => "MASTER OF ARMS!",
+            Ending::Standard => "VICTORY!",
+        }
+    }
+
+    pub fn epilogue(self) -> &'static str {
+        match self {
+            Ending::Legendary => {
+                "Not a single fall on the hardest road. The kingdom will tell this tale for ages."
+            }
+            Ending::Flawless => {
+                "Not a single crumb was lost. The ovens of the kingdom are warm again."
+            }
+            Ending::Arsenal => {
+                "Armed with the weapons of fallen bosses, you return home as the kingdom's champion."
+            }
+            Ending::Standard => {
+                "It was a rough journey, but the last boss has fallen. The kingdom is safe, for now."
+            }
+        }
+    }
+
+    pub fn background_color(self) -> Color {
+        match self {
+            Ending::Legendary => Color::srgb(0.6, 0.45, 0.1), // Gold
+            Ending::Flawless => Color::srgb(0.3, 0.6, 0.9),
+            Ending::Arsenal => Color::srgb(0.5, 0.3, 0.7),
+            Ending::Standard => Color::srgb(0.4, 0.8, 0.4),
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use crate::components::boss::Boss;
+use crate::components::player::{Defeated, Hp, Player};
+use crate::plugins::camera_plugin::HUD_RENDER_LAYER;
+use crate::plugins::run_plugin::{EndRun, RunStats};
+use crate::stages::game_menu::{CurrentStage, GameState, despawn_screen};
+use crate::systems::boss::{BossPatternRegistry, load_boss_pattern_for_stage};
+use crate::systems::config::{
+    BOUNDARY_TOP, ENDLESS_HP_SCALE_PER_WAVE, ENDLESS_MAX_SPEED_SCALE, ENDLESS_SPEED_SCALE_PER_WAVE,
+    ENDLESS_WAVE_BREAK, ENDLESS_WAVE_HEAL, MAX_STAGES, SAVE_FILE_PATH,
+};
+use crate::systems::pattern_validation::PatternDiagnostics;
+use crate::systems::player::{BossDefeated, PlayerDied, detect_defeats, spawn_boss};
+use crate::systems::save_data::SaveData;
+use bevy::camera::visibility::RenderLayers;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+
+/// Boss HP multiplier for an endless mode wave (wave 1 = the pattern's own HP)
+pub fn wave_hp_scale(wave: u32) -> f32 {
+    1.0 + ENDLESS_HP_SCALE_PER_WAVE * wave.saturating_sub(1) as f32
+}
+
+/// Boss movement speed multiplier for an endless mode wave
+pub fn wave_speed_scale(wave: u32) -> f32 {
+    (1.0 + ENDLESS_SPEED_SCALE_PER_WAVE * wave.saturating_sub(1) as f32)
+        .min(ENDLESS_MAX_SPEED_SCALE)
+}
+
+/// Run condition: true during an endless mode run
+pub fn in_endless_mode(run_stats: Res<RunStats>) -> bool {
+    run_stats.endless_wave.is_some()
+}
+
+/// Resource to time the break between a wave falling and the next one spawning
+#[derive(Resource, Default)]
+pub struct EndlessWaveBreak {
+    pub timer: Option<f32>, // None while a wave is being fought
+}
+
+/// Resource with the result of the last endless run (shown on the results screen)
+#[derive(Resource, Default)]
+pub struct EndlessResult {
+    pub bosses_defeated: u32,
+    pub wave: u32,
+    pub high_score: u32,
+    pub new_high_score: bool,
+}
+
+/// Marker component for the wave counter shown during an endless run
+#[derive(Component)]
+pub struct EndlessWaveText;
+
+/// Marker component for the endless results screen UI root
+#[derive(Component)]
+pub struct EndlessResultsScreen;
+
+fn wave_text(run_stats: &RunStats) -> String {
+    format!(
+        "Wave {}  |  Bosses defeated: {}",
+        run_stats.endless_wave.unwrap_or(1),
+        run_stats.bosses_defeated
+    )
+}
+
+/// Loads every stage's pattern up front, as endless waves cycle through the stages mid-fight
+pub fn load_endless_patterns(
+    mut pattern_registry: ResMut<BossPatternRegistry>,
+    mut diagnostics: ResMut<PatternDiagnostics>,
+) {
+    for stage in 1..=MAX_STAGES {
+        load_boss_pattern_for_stage(&mut pattern_registry, &mut diagnostics, stage);
+    }
+}
+
+/// Sets up the wave counter when an endless run starts
+pub fn spawn_endless_hud(
+    mut commands: Commands,
+    run_stats: Res<RunStats>,
+    mut wave_break: ResMut<EndlessWaveBreak>,
+) {
+    wave_break.timer = None;
+    commands.spawn((
+        DespawnOnExit(GameState::InGame),
+        Text2d::new(wave_text(&run_stats)),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Transform::from_xyz(0.0, BOUNDARY_TOP - 20.0, 0.0),
+        RenderLayers::layer(HUD_RENDER_LAYER),
+        EndlessWaveText,
+    ));
+}
+
+/// System to react to defeats in endless mode: dying ends the run, and clearing a wave
+/// heals the player a little and starts the break before the next, stronger wave
+pub fn check_endless_outcome(
+    mut commands: Commands,
+    mut player_died: MessageReader<PlayerDied>,
+    mut boss_defeated: MessageReader<BossDefeated>,
+    remaining_boss_query: Query<(), (With<Boss>, Without<Defeated>)>,
+    mut player_query: Query<&mut Hp, (With<Player>, Without<Defeated>)>,
+    mut run_stats: ResMut<RunStats>,
+    mut current_stage: ResMut<CurrentStage>,
+    mut wave_break: ResMut<EndlessWaveBreak>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let player_died = player_died.read().count() > 0;
+    let defeated: Vec<Entity> = boss_defeated.read().map(|message| message.entity).collect();
+
+    if player_died {
+        next_state.set(GameState::EndlessResults);
+        return;
+    }
+
+    // Defeated bosses always leave the arena, there is no win screen between waves
+    for &entity in &defeated {
+        commands.entity(entity).despawn();
+    }
+
+    if defeated.is_empty() || !remaining_boss_query.is_empty() {
+        return;
+    }
+
+    let wave = run_stats.endless_wave.unwrap_or(1) + 1;
+    run_stats.endless_wave = Some(wave);
+    // Cycle through the stage patterns, one per wave
+    current_stage.0 = (wave - 1) % MAX_STAGES + 1;
+    wave_break.timer = Some(ENDLESS_WAVE_BREAK);
+
+    for mut hp in &mut player_query {
+        hp.current = (hp.current + ENDLESS_WAVE_HEAL).min(hp.max);
+    }
+    info!("Endless wave {} cleared", wave - 1);
+}
+
+/// System to count down the break between waves and spawn the next wave once it is over
+pub fn spawn_next_endless_wave(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut wave_break: ResMut<EndlessWaveBreak>,
+) {
+    let Some(timer) = wave_break.timer.as_mut() else {
+        return;
+    };
+    *timer -= time.delta_secs();
+    if *timer <= 0.0 {
+        wave_break.timer = None;
+        commands.run_system_cached(spawn_boss);
+    }
+}
+
+/// System to keep the wave counter up to date
+pub fn update_endless_hud(
+    run_stats: Res<RunStats>,
+    mut text_query: Query<&mut Text2d, With<EndlessWaveText>>,
+) {
+    if !run_stats.is_changed() {
+        return;
+    }
+    for mut text in &mut text_query {
+        text.0 = wave_text(&run_stats);
+    }
+}
+
+/// System to record the finished run's high score when the results screen opens
+pub fn record_endless_result(run_stats: Res<RunStats>, mut result: ResMut<EndlessResult>) {
+    let mut save_data = SaveData::load_or_default();
+    let new_high_score = save_data.record_endless(run_stats.bosses_defeated);
+    if new_high_score && let Err(e) = save_data.save_to_file(SAVE_FILE_PATH) {
+        warn!("Failed to save endless high score to {}: {}", SAVE_FILE_PATH, e);
+    }
+
+    *result = EndlessResult {
+        bosses_defeated: run_stats.bosses_defeated,
+        wave: run_stats.endless_wave.unwrap_or(1),
+        high_score: save_data.endless_high_score,
+        new_high_score,
+    };
+    info!(
+        "Endless run over: {} bosses defeated (high score {})",
+        result.bosses_defeated, result.high_score
+    );
+}
+
+/// Spawns the endless results screen
+pub fn spawn_endless_results_screen(mut commands: Commands, result: Res<EndlessResult>) {
+    let high_score_text = if result.new_high_score {
+        format!("NEW HIGH SCORE: {}", result.high_score)
+    } else {
+        format!("High score: {}", result.high_score)
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: percent(100.0),
+                height: percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: px(30.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.1, 0.1, 0.2)),
+            EndlessResultsScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("ENDLESS RUN OVER"),
+                TextFont {
+                    font_size: 64.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+
+            parent.spawn((
+                Text::new(format!(
+                    "Bosses defeated: {}  (reached wave {})",
+                    result.bosses_defeated, result.wave
+                )),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+
+            parent.spawn((
+                Text::new(high_score_text),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.9, 0.0)),
+            ));
+
+            parent.spawn((
+                Text::new("Press SPACE or ENTER to return to character selection"),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+        });
+}
+
+/// Handles keyboard input on the endless results screen
+pub fn handle_endless_results_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut end_run: MessageWriter<EndRun>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Enter) || keyboard_input.just_pressed(KeyCode::Space) {
+        end_run.write(EndRun);
+    }
+}
+
+/// Endless mode: bosses spawn wave after wave with growing HP and speed until the player dies
+pub struct EndlessPlugin;
+
+impl Plugin for EndlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EndlessWaveBreak>()
+            .init_resource::<EndlessResult>()
+            .add_systems(
+                OnEnter(GameState::InGame),
+                (
+                    load_endless_patterns.before(spawn_boss),
+                    spawn_endless_hud,
+                )
+                    .run_if(in_endless_mode),
+            )
+            .add_systems(
+                Update,
+                (
+                    check_endless_outcome.after(detect_defeats),
+                    spawn_next_endless_wave,
+                    update_endless_hud,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::InGame).and(in_endless_mode)),
+            )
+            .add_systems(
+                OnEnter(GameState::EndlessResults),
+                (record_endless_result, spawn_endless_results_screen).chain(),
+            )
+            .add_systems(
+                Update,
+                handle_endless_results_input.run_if(in_state(GameState::EndlessResults)),
+            )
+            .add_systems(
+                OnExit(GameState::EndlessResults),
+                despawn_screen::<EndlessResultsScreen>,
+            );
+    }
+}
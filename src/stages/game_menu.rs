@@ -1,24 +1,46 @@
 use bevy::text::prelude::{TextColor, TextFont};
 use bevy::{
+    camera::visibility::RenderLayers,
     color::palettes::basic::{BLACK, WHITE},
     prelude::*,
     sprite::Anchor,
 };
-use crate::systems::config::{BOUNDARY_LEFT, BOUNDARY_RIGHT, BOUNDARY_TOP, BOUNDARY_BOTTOM, BACKGROUND_PADDING};
+use crate::systems::config::{BREADMAN_KNOCKBACK_SCALE, BREADMAN_MOVE_SPEED_SCALE, CHEESEMAN_KNOCKBACK_SCALE, CHEESEMAN_MOVE_SPEED_SCALE, BOUNDARY_LEFT, BOUNDARY_RIGHT, BOUNDARY_TOP, BOUNDARY_BOTTOM, BACKGROUND_PADDING, MAX_STAGES, SECRET_TOKEN_REWARD_POTIONS, SECRET_TOKENS_PER_STAGE, WEAPON_ENERGY_MAX};
+use crate::plugins::run_plugin::{
+    ContinueRun, EndRun, RunStats, StartDailyChallenge, StartEndlessRun, StartNewRun,
+    StartCrumbOfDeathRun, StartRandomizerRun, StartTutorial,
+};
+use crate::stages::ending::{Ending, RunDifficulty};
+use crate::stages::settings_menu::{GameSettings, SettingsReturnState};
+use crate::components::boss::BossType;
+use crate::components::player::CharacterKit;
+use crate::systems::save_data::{SaveData, current_day};
+use crate::plugins::medal_plugin::StageClock;
+use crate::plugins::camera_plugin::BACKGROUND_RENDER_LAYER;
+use crate::systems::weapons::BossWeapon;
+use serde::{Deserialize, Serialize};
 
 /// Game state to manage transitions between character selection and gameplay
 #[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States, Component)]
 pub enum GameState {
     #[default]
+    MainMenu, // Title screen (new game, continue, options, quit)
     CharacterSelection,
     InGame,
+    Cutscene,     // Victory dialogue of the defeated boss (before the upgrade screen)
     StageUpgrade, // Intermediate stage between bosses for upgrades
+    WeaponGet,    // Splash for a boss weapon just acquired on the upgrade screen
+    Shop,         // Spend crumbs between stages (after the free upgrade pick)
     GameOver,
     GameWin,
+    Settings, // Options screen (game speed, accessibility)
+    PatternSelect,  // Developer screen to pick a boss pattern file to preview
+    StageEditor,    // Developer arena editor (place platforms, hazards and spawn points)
+    EndlessResults, // Endless mode run summary (bosses defeated and high score)
 }
 
 /// Resource to store the currently selected character
-#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SelectedCharacter {
     Breadman,
     Cheeseman,
@@ -30,6 +52,30 @@ impl Default for SelectedCharacter {
     }
 }
 
+impl SelectedCharacter {
+    /// Movement and weight stats of the character
+    pub fn kit(self) -> CharacterKit {
+        match self {
+            SelectedCharacter::Breadman => CharacterKit {
+                move_speed_scale: BREADMAN_MOVE_SPEED_SCALE,
+                knockback_scale: BREADMAN_KNOCKBACK_SCALE,
+            },
+            SelectedCharacter::Cheeseman => CharacterKit {
+                move_speed_scale: CHEESEMAN_MOVE_SPEED_SCALE,
+                knockback_scale: CHEESEMAN_KNOCKBACK_SCALE,
+            },
+        }
+    }
+
+    /// Color of the character's body (before any boss weapon recolors it)
+    pub fn color(self) -> Color {
+        match self {
+            SelectedCharacter::Breadman => Color::srgb(0.2, 0.4, 0.9), // Blue
+            SelectedCharacter::Cheeseman => Color::srgb(0.9, 0.2, 0.2), // Red
+        }
+    }
+}
+
 /// Component to mark character selection buttons
 #[derive(Component)]
 pub enum CharacterButton {
@@ -66,10 +112,12 @@ pub struct CurrentStage(pub u32);
 pub enum UpgradeButton {
     IncreaseHp,
     AcquireWeapon,
+    Regeneration,
+    Lifesteal,
     ImproveDefense,
 }
 
-/// Resource to track which upgrade option is currently selected (0 = HP, 1 = Weapon)
+/// Resource to track which upgrade option is currently selected (0 = HP, 1 = Weapon, 2 = Regeneration, 3 = Lifesteal)
 #[derive(Resource, Default)]
 pub struct SelectedUpgradeIndex(pub usize);
 
@@ -77,10 +125,6 @@ pub struct SelectedUpgradeIndex(pub usize);
 #[derive(Component)]
 pub struct BackgroundImage;
 
-/// Component to identify the UI camera
-#[derive(Component)]
-pub struct UiCamera;
-
 /// Resource to hold background image handles for each stage
 #[derive(Resource, Default)]
 pub struct BackgroundImages {
@@ -106,20 +150,97 @@ pub struct DefeatedBoss {
 #[derive(Resource, Default)]
 pub struct ShowWinScreen(pub bool);
 
-/// Resource to track player upgrades and stats
+/// Resource to store mid-fight progress, so a lost multi-phase fight can be retried from the phase reached
+/// and a stage with a corridor from its last checkpoint flag
+#[derive(Resource, Default, Clone, Debug)]
+pub struct FightCheckpoint {
+    pub stage: u32,                  // Stage the checkpoint was reached in (0 = no checkpoint)
+    pub bosses: Vec<BossCheckpoint>, // HP and phase of each boss, indexed by spawn index
+    pub player_hp: f32,              // Player HP when the phase started
+    pub resume: bool,                // Set when retrying; the next fight starts from the checkpoint
+    pub corridor_flag: Option<f32>,  // Last corridor flag reached (distance along the corridor)
+}
+
+/// One boss's progress at a fight checkpoint
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BossCheckpoint {
+    pub hp: f32,    // Boss HP when the checkpoint was reached (0 = already beaten)
+    pub phase: u32, // Boss phase the checkpoint restores
+}
+
+impl FightCheckpoint {
+    /// Furthest phase any boss of the fight reached at the checkpoint
+    pub fn phase(&self) -> u32 {
+        self.bosses.iter().map(|boss| boss.phase).max().unwrap_or(1)
+    }
+
+    /// Whether a checkpoint past phase 1 exists for the given stage
+    pub fn available_for(&self, stage: u32) -> bool {
+        self.stage != 0 && self.stage == stage && self.phase() > 1
+    }
+
+    /// The corridor checkpoint flag a retry of the given stage starts from, if one was reached
+    pub fn corridor_flag_for(&self, stage: u32) -> Option<f32> {
+        self.corridor_flag.filter(|_| self.stage != 0 && self.stage == stage)
+    }
+}
+
+/// Resource to track how many continues the player has left in the current run
 #[derive(Resource)]
+pub struct Lives(pub u32);
+
+impl Default for Lives {
+    fn default() -> Self {
+        Self(crate::systems::config::STARTING_LIVES)
+    }
+}
+
+/// Resource to track player upgrades and stats (saved with the run so it can be continued)
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PlayerUpgrades {
     pub max_hp_bonus: f32,       // Additional HP added to base max HP
     pub current_hp: f32,         // Current HP that persists between stages
     pub defense_multiplier: f32, // Damage reduction (1.0 = no reduction, 0.5 = 50% less damage)
-    pub has_boss_weapon: bool,   // Whether player has acquired boss weapon
-    pub boss_weapon_type: Option<crate::components::boss::BossType>, // Which boss weapon was acquired
+    pub boss_weapons: Vec<BossWeapon>, // Boss weapons acquired so far (in acquisition order)
+    pub equipped_weapon: Option<BossType>, // Equipped boss weapon (None = default shot)
+    pub regen_per_second: f32, // HP regenerated per second out of combat (0.0 = no regeneration)
+    pub lifesteal_ratio: f32, // Share of boss weapon damage that heals the player (0.0 = no lifesteal)
+    pub crumbs: u32,  // Currency earned in fights and spent in the shop
+    pub potions: u32, // Healing potions carried into fights (drunk with Q)
 }
 
 impl PlayerUpgrades {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Add a boss weapon (or refill it if it was already acquired)
+    pub fn acquire_boss_weapon(&mut self, boss_type: BossType) {
+        match self
+            .boss_weapons
+            .iter_mut()
+            .find(|w| w.boss_type == boss_type)
+        {
+            Some(weapon) => weapon.energy = WEAPON_ENERGY_MAX,
+            None => self.boss_weapons.push(BossWeapon::new(boss_type)),
+        }
+    }
+
+    /// Refill the energy of every acquired boss weapon
+    pub fn refill_weapon_energy(&mut self) {
+        for weapon in &mut self.boss_weapons {
+            weapon.energy = WEAPON_ENERGY_MAX;
+        }
+    }
+
+    /// The equipped boss weapon, if any
+    pub fn equipped_boss_weapon_mut(&mut self) -> Option<&mut BossWeapon> {
+        let equipped = self.equipped_weapon?;
+        self.boss_weapons
+            .iter_mut()
+            .find(|w| w.boss_type == equipped)
+    }
 }
 
 impl Default for PlayerUpgrades {
@@ -128,8 +249,12 @@ impl Default for PlayerUpgrades {
             max_hp_bonus: 0.0,
             current_hp: 100.0, // Start with base max HP
             defense_multiplier: 1.0, // Start with no defense bonus
-            has_boss_weapon: false,
-            boss_weapon_type: None,
+            boss_weapons: Vec::new(),
+            equipped_weapon: None,
+            regen_per_second: 0.0,
+            lifesteal_ratio: 0.0,
+            crumbs: 0,
+            potions: 0,
         }
     }
 }
@@ -161,7 +286,7 @@ pub fn load_background_images(mut background_images: ResMut<BackgroundImages>, a
 pub fn filter_loaded_background_images(
     mut background_images: ResMut<BackgroundImages>,
     mut timer: Local<Option<f32>>,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     asset_server: Res<AssetServer>,
 ) {
     // Wait 0.5 seconds before filtering to give assets time to load/fail
@@ -256,27 +381,60 @@ pub fn animate_background(
     }
 }
 
-/// Despawns the UI camera (used when entering gameplay)
-pub fn despawn_ui_camera(mut commands: Commands, ui_camera_query: Query<Entity, With<UiCamera>>) {
-    for entity in ui_camera_query.iter() {
-        commands.entity(entity).despawn();
-    }
-}
-
-/// Spawns a UI camera for rendering
-pub fn spawn_ui_camera(mut commands: Commands) {
-    commands.spawn((
-        Camera2d,
-        Camera {
-            order: 1, // UI camera renders on top
-            ..default()
-        },
-        UiCamera,
-    ));
-}
-
 /// Spawns the character selection menu UI when entering the CharacterSelection state
 pub fn spawn_character_selection_menu(mut commands: Commands) {
+    let save_data = SaveData::load_or_default();
+    let daily_hint = match save_data.daily_best(current_day()) {
+        Some(best) => format!("Press D for the Daily Challenge (today's best: {})", best),
+        None => "Press D for the Daily Challenge".to_string(),
+    };
+    let endless_hint = format!(
+        "Press N for Endless mode (high score: {} bosses)",
+        save_data.endless_high_score
+    );
+    let crumb_of_death_hint = if !save_data.game_cleared {
+        "Crumb of Death mode: win a run to unlock".to_string()
+    } else if save_data.crumb_of_death.attempts > 0 {
+        format!(
+            "Press C for Crumb of Death: one hit kills, triple damage (best: {})",
+            save_data.crumb_of_death.best_score
+        )
+    } else {
+        "Press C for Crumb of Death: one hit kills, triple damage".to_string()
+    };
+    let token_tally = (1..=MAX_STAGES)
+        .map(|stage| {
+            format!(
+                "Stage {}: {}/{}",
+                stage,
+                save_data.secret_tokens_in(stage),
+                SECRET_TOKENS_PER_STAGE
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("   ");
+    let medal_tally = (1..=MAX_STAGES)
+        .map(|stage| match save_data.clear_record(stage) {
+            Some(record) => format!(
+                "Stage {}: {} ({:.1}s)",
+                stage,
+                record.medal.map_or("No medal", |medal| medal.label()),
+                record.best_time
+            ),
+            None => format!("Stage {}: not cleared", stage),
+        })
+        .collect::<Vec<_>>()
+        .join("   ");
+    let medal_hint = format!("Medals - {}", medal_tally);
+    let token_hint = if save_data.all_secret_tokens_found() {
+        format!(
+            "Secret tokens - {} (all found: new runs start with +{} potion)",
+            token_tally, SECRET_TOKEN_REWARD_POTIONS
+        )
+    } else {
+        format!("Secret tokens - {}", token_tally)
+    };
+
     // Create two character boxes
     let breadman_entity = commands
         .spawn((
@@ -376,6 +534,76 @@ pub fn spawn_character_selection_menu(mut commands: Commands) {
                 })
                 .add_child(breadman_entity)
                 .add_child(cheeseman_entity);
+
+            // Options and tutorial hint
+            parent.spawn((
+                Text::new("Press O for Options, T for the Tutorial, Esc for the Main Menu"),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(BLACK.into()),
+            ));
+
+            // Daily challenge hint with today's best
+            parent.spawn((
+                Text::new(daily_hint),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(BLACK.into()),
+            ));
+
+            // Endless mode hint with the high score
+            parent.spawn((
+                Text::new(endless_hint),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(BLACK.into()),
+            ));
+
+            // Crumb of Death hint with its best score (or how to unlock it)
+            parent.spawn((
+                Text::new(crumb_of_death_hint),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(BLACK.into()),
+            ));
+
+            // Randomizer mode hint (a shared seed is replayed with --seed)
+            parent.spawn((
+                Text::new("Press R for Randomizer mode (replay a seed with --seed <n>)"),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(BLACK.into()),
+            ));
+
+            // Best medal and clear time of each stage
+            parent.spawn((
+                Text::new(medal_hint),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(BLACK.into()),
+            ));
+
+            // Secret tokens found in each stage
+            parent.spawn((
+                Text::new(token_hint),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(BLACK.into()),
+            ));
         });
 }
 
@@ -383,25 +611,9 @@ pub fn spawn_character_selection_menu(mut commands: Commands) {
 pub fn spawn_in_game_screen(
     mut commands: Commands,
     background_images: Res<BackgroundImages>,
-    mut current_stage: ResMut<CurrentStage>,
+    current_stage: Res<CurrentStage>,
     asset_server: Res<AssetServer>,
 ) {
-    // Spawn game camera (separate from UI camera) - use Camera2dBundle as recommended
-    commands.spawn((
-        Camera2d,
-        Camera {
-            order: 0, // Game camera renders first (background)
-            ..default()
-        },
-        Transform::default(),
-        GlobalTransform::default(),
-    ));
-
-    // Ensure the first gameplay entry starts at stage 1
-    if current_stage.0 == 0 {
-        current_stage.0 = 1;
-    }
-
     let stage_number = current_stage.0;
 
     // Spawn background image for current stage if available
@@ -431,13 +643,15 @@ pub fn spawn_in_game_screen(
             
             // Spawn background sprite - ensure all required components are present
             commands.spawn((
+                DespawnOnExit(GameState::InGame),
                 Sprite {
                     image: first_handle.clone(),
                     custom_size: Some(Vec2::new(bg_width, bg_height)),
                     ..default()
                 },
                 Anchor::CENTER,
-                Transform::from_xyz(bg_center_x, bg_center_y, -10.0),
+                Transform::from_xyz(bg_center_x, bg_center_y, 0.0),
+                RenderLayers::layer(BACKGROUND_RENDER_LAYER), // Drawn behind everything else
                 GlobalTransform::default(),
                 Visibility::Visible,
                 InheritedVisibility::default(),
@@ -461,6 +675,13 @@ pub fn handle_keyboard_selection(
     mut border_query: Query<(&CharacterButton, &mut BorderColor)>,
     mut next_state: ResMut<NextState<GameState>>,
     mut selected_character: ResMut<SelectedCharacter>,
+    mut start_new_run: MessageWriter<StartNewRun>,
+    mut start_tutorial: MessageWriter<StartTutorial>,
+    mut start_daily: MessageWriter<StartDailyChallenge>,
+    mut start_endless: MessageWriter<StartEndlessRun>,
+    mut start_randomizer: MessageWriter<StartRandomizerRun>,
+    mut start_crumb_of_death: MessageWriter<StartCrumbOfDeathRun>,
+    mut settings_return: ResMut<SettingsReturnState>,
 ) {
     // Handle left/right arrow keys to navigate
     if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
@@ -498,8 +719,24 @@ pub fn handle_keyboard_selection(
         }
     }
 
-    // Handle Enter or Space to confirm selection
-    if keyboard_input.just_pressed(KeyCode::Enter) || keyboard_input.just_pressed(KeyCode::Space) {
+    // Handle Enter or Space to confirm selection (T starts the tutorial, D today's daily
+    // challenge, N an endless run, R a randomizer run and C a Crumb of Death run with the
+    // selected character)
+    let start_tutorial_pressed = keyboard_input.just_pressed(KeyCode::KeyT);
+    let start_daily_pressed = keyboard_input.just_pressed(KeyCode::KeyD);
+    let start_endless_pressed = keyboard_input.just_pressed(KeyCode::KeyN);
+    let start_randomizer_pressed = keyboard_input.just_pressed(KeyCode::KeyR);
+    // Crumb of Death only starts once unlocked
+    let start_crumb_of_death_pressed = keyboard_input.just_pressed(KeyCode::KeyC)
+        && SaveData::load_or_default().game_cleared;
+    if keyboard_input.just_pressed(KeyCode::Enter)
+        || keyboard_input.just_pressed(KeyCode::Space)
+        || start_tutorial_pressed
+        || start_daily_pressed
+        || start_endless_pressed
+        || start_randomizer_pressed
+        || start_crumb_of_death_pressed
+    {
         match selected_index.0 {
             0 => {
                 *selected_character = SelectedCharacter::Breadman;
@@ -511,7 +748,28 @@ pub fn handle_keyboard_selection(
             }
             _ => {}
         }
-        next_state.set(GameState::InGame);
+        if start_tutorial_pressed {
+            start_tutorial.write(StartTutorial);
+        } else if start_daily_pressed {
+            start_daily.write(StartDailyChallenge);
+        } else if start_endless_pressed {
+            start_endless.write(StartEndlessRun);
+        } else if start_randomizer_pressed {
+            start_randomizer.write(StartRandomizerRun);
+        } else if start_crumb_of_death_pressed {
+            start_crumb_of_death.write(StartCrumbOfDeathRun);
+        } else {
+            start_new_run.write(StartNewRun);
+        }
+    } else if keyboard_input.just_pressed(KeyCode::KeyO) {
+        settings_return.0 = GameState::CharacterSelection;
+        next_state.set(GameState::Settings);
+    } else if keyboard_input.just_pressed(KeyCode::Escape) {
+        next_state.set(GameState::MainMenu);
+    } else if keyboard_input.just_pressed(KeyCode::KeyP) {
+        next_state.set(GameState::PatternSelect);
+    } else if keyboard_input.just_pressed(KeyCode::KeyE) {
+        next_state.set(GameState::StageEditor);
     }
 }
 
@@ -522,7 +780,14 @@ pub fn despawn_screen<T: Component>(to_despawn: Query<Entity, With<T>>, mut comm
 }
 
 /// Spawns the game over screen (dark background, white text)
-pub fn spawn_game_over_screen(mut commands: Commands) {
+pub fn spawn_game_over_screen(
+    mut commands: Commands,
+    checkpoint: Res<FightCheckpoint>,
+    current_stage: Res<CurrentStage>,
+    lives: Res<Lives>,
+) {
+    let can_continue = lives.0 > 0;
+
     commands
         .spawn((
             Node {
@@ -538,9 +803,13 @@ pub fn spawn_game_over_screen(mut commands: Commands) {
             GameOverScreen,
         ))
         .with_children(|parent| {
-            // Game Over text
+            // Game Over text (only a full game over once no lives are left)
             parent.spawn((
-                Text::new("GAME OVER"),
+                Text::new(if can_continue {
+                    "YOU DIED"
+                } else {
+                    "GAME OVER"
+                }),
                 TextFont {
                     font_size: 64.0,
                     ..default()
@@ -548,32 +817,87 @@ pub fn spawn_game_over_screen(mut commands: Commands) {
                 TextColor(WHITE.into()),
             ));
 
-            // Restart instruction
+            // Continue or restart instruction
+            let instruction = if can_continue {
+                let from_flag = if checkpoint.corridor_flag_for(current_stage.0).is_some() {
+                    " from the last checkpoint flag"
+                } else {
+                    ""
+                };
+                format!(
+                    "Press SPACE or ENTER to retry stage {}{} (Lives left: {})",
+                    current_stage.0, from_flag, lives.0
+                )
+            } else {
+                "Press SPACE or ENTER to restart".to_string()
+            };
             parent.spawn((
-                Text::new("Press SPACE or ENTER to restart"),
+                Text::new(instruction),
                 TextFont {
                     font_size: 32.0,
                     ..default()
                 },
                 TextColor(WHITE.into()),
             ));
+
+            // Checkpoint retry (only once the boss was pushed into a later phase)
+            if can_continue && checkpoint.available_for(current_stage.0) {
+                parent.spawn((
+                    Text::new(format!("Press R to retry from phase {}", checkpoint.phase())),
+                    TextFont {
+                        font_size: 32.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 0.9, 0.0)),
+                ));
+            }
         });
 }
 
-/// Spawns the game win screen (bright background, extensible for different bosses)
-pub fn spawn_game_win_screen(mut commands: Commands, defeated_boss: Res<DefeatedBoss>) {
-    // Determine background color and text based on defeated boss
-    let (bg_color, win_text) = match defeated_boss.boss_type {
-        Some(crate::components::boss::BossType::Default) => {
-            (Color::srgb(0.3, 0.6, 0.9), "VICTORY!")
-        }
+/// Spawns the game win screen. The ending (title, epilogue and background) depends on how the run
+/// went: deaths, difficulty and the boss weapons collected.
+pub fn spawn_game_win_screen(
+    mut commands: Commands,
+    defeated_boss: Res<DefeatedBoss>,
+    run_stats: Res<RunStats>,
+    player_upgrades: Res<PlayerUpgrades>,
+    settings: Res<GameSettings>,
+    stage_clock: Res<StageClock>,
+) {
+    let difficulty = RunDifficulty::of_run(&settings, &run_stats);
+    let ending = Ending::of_run(difficulty, run_stats.deaths, &player_upgrades);
+    info!("Run finished with the {:?} ending", ending);
+
+    // Line about the final boss, extensible for different bosses
+    let boss_text = match defeated_boss.boss_type {
+        Some(BossType::Default) => "The final boss has been defeated.",
         // Add more boss types here as you create them
-        // Some(crate::components::boss::BossType::FireMan) => {
-        //     (Color::srgb(0.9, 0.4, 0.2), "FIRE MAN DEFEATED!")
-        // }
-        None => (Color::srgb(0.4, 0.8, 0.4), "VICTORY!"),
+        // Some(BossType::FireMan) => "Fire Man has been extinguished.",
+        None => "The final boss has been defeated.",
     };
 
+    let stats_text = format!(
+        "Difficulty: {}  |  Deaths: {}  |  Boss weapons: {}",
+        difficulty.label(),
+        run_stats.deaths,
+        player_upgrades.boss_weapons.len()
+    );
+    // Final stage's clear time and medal (none after a checkpoint retry)
+    let clear_text = stage_clock.last_clear.map(|clear| clear.summary());
+    let mut summary_lines = vec![
+        (boss_text, 24.0),
+        (ending.epilogue(), 24.0),
+        (stats_text.as_str(), 20.0),
+    ];
+    if let Some(clear_text) = &clear_text {
+        summary_lines.push((clear_text.as_str(), 20.0));
+    }
+    // Randomizer runs show their seed so they can be shared
+    let seed_text = format!("Randomizer seed: {}", run_stats.seed);
+    if run_stats.randomizer {
+        summary_lines.push((seed_text.as_str(), 20.0));
+    }
+
     commands
         .spawn((
             Node {
@@ -585,13 +909,13 @@ pub fn spawn_game_win_screen(mut commands: Commands, defeated_boss: Res<Defeated
                 row_gap: px(40.0),
                 ..default()
             },
-            BackgroundColor(bg_color),
+            BackgroundColor(ending.background_color()),
             GameWinScreen,
         ))
         .with_children(|parent| {
             // Victory text
             parent.spawn((
-                Text::new(win_text),
+                Text::new(ending.title()),
                 TextFont {
                     font_size: 72.0,
                     ..default()
@@ -599,6 +923,33 @@ pub fn spawn_game_win_screen(mut commands: Commands, defeated_boss: Res<Defeated
                 TextColor(WHITE.into()),
             ));
 
+            // Ending text and run summary
+            parent
+                .spawn((
+                    Node {
+                        width: px(800.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: px(16.0),
+                        padding: UiRect::all(px(20.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.4)),
+                ))
+                .with_children(|content_parent| {
+                    for (text, font_size) in summary_lines {
+                        content_parent.spawn((
+                            Text::new(text),
+                            TextFont {
+                                font_size,
+                                ..default()
+                            },
+                            TextColor(WHITE.into()),
+                            TextLayout::new_with_justify(Justify::Center),
+                        ));
+                    }
+                });
+
             // Restart instruction
             parent.spawn((
                 Text::new("Press SPACE or ENTER to play again"),
@@ -608,29 +959,6 @@ pub fn spawn_game_win_screen(mut commands: Commands, defeated_boss: Res<Defeated
                 },
                 TextColor(WHITE.into()),
             ));
-
-            // Placeholder for boss-specific content (images, text, etc.)
-            // This can be extended later to show different content based on boss type
-            parent
-                .spawn((
-                    Node {
-                        width: px(400.0),
-                        height: px(200.0),
-                        margin: UiRect::all(px(20.0)),
-                        ..default()
-                    },
-                    BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
-                ))
-                .with_children(|content_parent| {
-                    content_parent.spawn((
-                        Text::new("Boss-specific content area\n(Add images/text here)"),
-                        TextFont {
-                            font_size: 24.0,
-                            ..default()
-                        },
-                        TextColor(WHITE.into()),
-                    ));
-                });
         });
 }
 
@@ -717,6 +1045,89 @@ pub fn spawn_stage_upgrade_screen(
         })
         .id();
 
+    let regen_button_entity = commands
+        .spawn((
+            Button,
+            Node {
+                width: px(400.0),
+                height: px(120.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: px(10.0),
+                padding: UiRect::all(px(20.0)),
+                border: UiRect::all(px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.3, 0.5, 0.5)), // Teal for regeneration
+            BorderColor::all(Color::srgb(0.2, 0.4, 0.4)), // Not selected
+            UpgradeButton::Regeneration,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Regeneration"),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+            parent.spawn((
+                Text::new(format!(
+                    "+{} HP/s after {}s without damage",
+                    crate::systems::config::REGEN_UPGRADE_AMOUNT,
+                    crate::systems::config::REGEN_DELAY
+                )),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+        })
+        .id();
+
+    let lifesteal_button_entity = commands
+        .spawn((
+            Button,
+            Node {
+                width: px(400.0),
+                height: px(120.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: px(10.0),
+                padding: UiRect::all(px(20.0)),
+                border: UiRect::all(px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.5, 0.25, 0.25)), // Red for lifesteal
+            BorderColor::all(Color::srgb(0.4, 0.2, 0.2)), // Not selected
+            UpgradeButton::Lifesteal,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Lifesteal"),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+            parent.spawn((
+                Text::new(format!(
+                    "Boss weapon hits heal {:.0}% of damage (max {} HP per stage)",
+                    crate::systems::config::LIFESTEAL_UPGRADE_RATIO * 100.0,
+                    crate::systems::config::LIFESTEAL_STAGE_CAP
+                )),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+        })
+        .id();
 
     // Create the root menu container
     commands
@@ -754,16 +1165,18 @@ pub fn spawn_stage_upgrade_screen(
                 TextColor(WHITE.into()),
             ));
 
-            // Button container with the two upgrade options
+            // Button container with the upgrade options
             parent
                 .spawn(Node {
                     flex_direction: FlexDirection::Column,
-                    row_gap: px(20.0),
+                    row_gap: px(12.0), // Tighter so all four options fit the reference height
                     align_items: AlignItems::Center,
                     ..default()
                 })
                 .add_child(hp_button_entity)
-                .add_child(weapon_button_entity);
+                .add_child(weapon_button_entity)
+                .add_child(regen_button_entity)
+                .add_child(lifesteal_button_entity);
         });
 }
 
@@ -785,7 +1198,7 @@ pub fn handle_upgrade_input(
     }
 
     if keyboard_input.just_pressed(KeyCode::ArrowDown) {
-        if selected_index.0 < 1 {
+        if selected_index.0 < 3 {
             selected_index.0 += 1;
         }
     }
@@ -795,6 +1208,8 @@ pub fn handle_upgrade_input(
         let is_selected = match button {
             UpgradeButton::IncreaseHp => selected_index.0 == 0,
             UpgradeButton::AcquireWeapon => selected_index.0 == 1,
+            UpgradeButton::Regeneration => selected_index.0 == 2,
+            UpgradeButton::Lifesteal => selected_index.0 == 3,
             UpgradeButton::ImproveDefense => false, // Not used anymore
         };
 
@@ -810,6 +1225,12 @@ pub fn handle_upgrade_input(
                 UpgradeButton::AcquireWeapon => {
                     *border_color = BorderColor::all(Color::srgb(0.4, 0.2, 0.4));
                 }
+                UpgradeButton::Regeneration => {
+                    *border_color = BorderColor::all(Color::srgb(0.2, 0.4, 0.4));
+                }
+                UpgradeButton::Lifesteal => {
+                    *border_color = BorderColor::all(Color::srgb(0.4, 0.2, 0.2));
+                }
                 UpgradeButton::ImproveDefense => {
                     *border_color = BorderColor::all(Color::srgb(0.4, 0.4, 0.2));
                 }
@@ -829,32 +1250,70 @@ pub fn handle_upgrade_input(
             1 => {
                 // Acquire boss weapon
                 if let Some(boss_type) = defeated_boss.boss_type {
-                    player_upgrades.has_boss_weapon = true;
-                    player_upgrades.boss_weapon_type = Some(boss_type);
+                    player_upgrades.acquire_boss_weapon(boss_type);
                 }
                 info!("Selected upgrade: Acquire Boss Weapon");
             }
+            2 => {
+                // Regenerate HP out of combat (stacks with earlier picks)
+                player_upgrades.regen_per_second += crate::systems::config::REGEN_UPGRADE_AMOUNT;
+                info!(
+                    "Selected upgrade: Regeneration ({} HP/s)",
+                    player_upgrades.regen_per_second
+                );
+            }
+            3 => {
+                // Boss weapon lifesteal (stacks with earlier picks)
+                player_upgrades.lifesteal_ratio += crate::systems::config::LIFESTEAL_UPGRADE_RATIO;
+                info!(
+                    "Selected upgrade: Lifesteal ({:.0}%)",
+                    player_upgrades.lifesteal_ratio * 100.0
+                );
+            }
             _ => {}
         }
-        // Move to next stage
+        // Move to next stage (boss weapons start it fully charged), visiting the shop first.
+        // A new boss weapon gets its splash screen on the way.
+        player_upgrades.refill_weapon_energy();
         current_stage.0 += 1;
-        next_state.set(GameState::InGame);
+        next_state.set(if selected_index.0 == 1 && defeated_boss.boss_type.is_some() {
+            GameState::WeaponGet
+        } else {
+            GameState::Shop
+        });
     }
 }
 
-/// Handles input for game over and win screens (restart functionality)
+/// Handles input for game over and win screens (continue and restart functionality)
 pub fn handle_game_end_input(
     keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut next_state: ResMut<NextState<GameState>>,
-    mut current_stage: ResMut<CurrentStage>,
-    mut player_upgrades: ResMut<PlayerUpgrades>,
+    current_stage: Res<CurrentStage>,
+    checkpoint: Res<FightCheckpoint>,
+    lives: Res<Lives>,
+    state: Res<State<GameState>>,
+    mut continue_run: MessageWriter<ContinueRun>,
+    mut end_run: MessageWriter<EndRun>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Enter) || keyboard_input.just_pressed(KeyCode::Space) {
-        // Reset stage counter and upgrades when restarting
-        current_stage.0 = 0;
-        *player_upgrades = PlayerUpgrades::new();
-        // Restart game by going back to character selection
-        next_state.set(GameState::CharacterSelection);
+    let confirm_pressed =
+        keyboard_input.just_pressed(KeyCode::Enter) || keyboard_input.just_pressed(KeyCode::Space);
+
+    // Spend a life to retry the current stage, keeping stage and upgrades
+    if *state.get() == GameState::GameOver && lives.0 > 0 {
+        if keyboard_input.just_pressed(KeyCode::KeyR) && checkpoint.available_for(current_stage.0) {
+            continue_run.write(ContinueRun {
+                from_checkpoint: true,
+            });
+        } else if confirm_pressed {
+            continue_run.write(ContinueRun {
+                from_checkpoint: false,
+            });
+        }
+        return;
+    }
+
+    if confirm_pressed {
+        // Run is over; restart by going back to character selection
+        end_run.write(EndRun);
     }
 }
 
@@ -863,18 +1322,26 @@ pub fn handle_stage_progression(
     current_stage: Res<CurrentStage>,
     mut next_state: ResMut<NextState<GameState>>,
     mut show_win_screen: ResMut<ShowWinScreen>,
+    mut checkpoint: ResMut<FightCheckpoint>,
+    mut start_new_run: MessageWriter<StartNewRun>,
 ) {
-    use crate::systems::config::MAX_STAGES;
+    use crate::systems::config::TUTORIAL_STAGE;
+
+    // The fight is won, so its checkpoint no longer applies
+    *checkpoint = FightCheckpoint::default();
 
     // Check current stage BEFORE incrementing
     let current_stage_num = current_stage.0;
 
-    // If we're not at the final stage, go to upgrade screen
-    if current_stage_num < MAX_STAGES {
+    if current_stage_num == TUTORIAL_STAGE {
+        // Tutorial finished - go straight into a fresh run
+        show_win_screen.0 = false;
+        start_new_run.write(StartNewRun);
+    } else if current_stage_num < MAX_STAGES {
+        // If we're not at the final stage, go to upgrade screen (after the boss's victory dialogue)
         // Don't show win screen - we're going to upgrade screen
         show_win_screen.0 = false;
-        // Transition to upgrade screen
-        next_state.set(GameState::StageUpgrade);
+        next_state.set(GameState::Cutscene);
     } else {
         // Final stage completed - show win screen
         show_win_screen.0 = true;
@@ -887,11 +1354,9 @@ impl Plugin for GameMenuPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SelectedCharacterIndex>()
             .init_resource::<SelectedUpgradeIndex>()
-            .init_resource::<DefeatedBoss>()
             .init_resource::<ShowWinScreen>()
-            .init_resource::<PlayerUpgrades>()
             .init_resource::<BackgroundImages>()
-            .add_systems(Startup, (spawn_ui_camera, load_background_images))
+            .add_systems(Startup, load_background_images)
             .add_systems(
                 Update,
                 filter_loaded_background_images.run_if(resource_exists::<BackgroundImages>),
@@ -908,12 +1373,11 @@ impl Plugin for GameMenuPlugin {
                 OnExit(GameState::CharacterSelection),
                 despawn_screen::<CharacterSelectionMenu>,
             )
-            .add_systems(OnEnter(GameState::InGame), (despawn_ui_camera, spawn_in_game_screen))
+            .add_systems(OnEnter(GameState::InGame), spawn_in_game_screen)
             .add_systems(
                 Update,
                 (animate_background).run_if(in_state(GameState::InGame)),
             )
-            .add_systems(OnExit(GameState::InGame), spawn_ui_camera)
             .add_systems(OnEnter(GameState::GameOver), spawn_game_over_screen)
             .add_systems(
                 OnEnter(GameState::StageUpgrade),
@@ -0,0 +1,207 @@
+use crate::plugins::run_plugin::{ContinueSavedRun, QuitGame};
+use crate::stages::game_menu::{GameState, despawn_screen};
+use crate::stages::settings_menu::SettingsReturnState;
+use crate::systems::save_data::SaveData;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+
+/// Component to identify main menu entries (also defines their display order)
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum MainMenuEntry {
+    NewGame,
+    Continue,
+    Options,
+    Quit,
+}
+
+impl MainMenuEntry {
+    const ALL: [MainMenuEntry; 4] = [
+        MainMenuEntry::NewGame,
+        MainMenuEntry::Continue,
+        MainMenuEntry::Options,
+        MainMenuEntry::Quit,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            MainMenuEntry::NewGame => "New Game",
+            MainMenuEntry::Continue => "Continue",
+            MainMenuEntry::Options => "Options",
+            MainMenuEntry::Quit => "Quit",
+        }
+    }
+}
+
+/// Resource to track the selected main menu entry, and whether there is a saved run to continue
+#[derive(Resource, Default)]
+pub struct MainMenuSelection {
+    pub index: usize,
+    pub can_continue: bool,
+}
+
+impl MainMenuSelection {
+    fn enabled(&self, entry: MainMenuEntry) -> bool {
+        entry != MainMenuEntry::Continue || self.can_continue
+    }
+
+    /// Move the selection by `step`, skipping disabled entries
+    fn step(&mut self, step: i32) {
+        let mut index = self.index as i32;
+        loop {
+            index += step;
+            if index < 0 || index >= MainMenuEntry::ALL.len() as i32 {
+                return;
+            }
+            if self.enabled(MainMenuEntry::ALL[index as usize]) {
+                self.index = index as usize;
+                return;
+            }
+        }
+    }
+}
+
+/// Marker component for the main menu UI root
+#[derive(Component)]
+pub struct MainMenuScreen;
+
+fn entry_border_color(is_selected: bool) -> Color {
+    if is_selected {
+        Color::srgb(1.0, 0.9, 0.0) // Glowing border (bright yellow/gold)
+    } else {
+        Color::srgb(0.2, 0.2, 0.3)
+    }
+}
+
+fn entry_text_color(is_enabled: bool) -> Color {
+    if is_enabled {
+        WHITE.into()
+    } else {
+        Color::srgb(0.45, 0.45, 0.5)
+    }
+}
+
+/// Spawns the main menu. Continue is selected when there is a saved run, New Game otherwise.
+pub fn spawn_main_menu(mut commands: Commands, mut selection: ResMut<MainMenuSelection>) {
+    selection.can_continue = SaveData::load_or_default().saved_run.is_some();
+    selection.index = if selection.can_continue { 1 } else { 0 };
+
+    commands
+        .spawn((
+            Node {
+                width: percent(100.0),
+                height: percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: px(20.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.15, 0.12, 0.1)),
+            MainMenuScreen,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new("BREADMAN & CHEESEMAN"),
+                TextFont {
+                    font_size: 64.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+                Node {
+                    margin: UiRect::bottom(px(30.0)),
+                    ..default()
+                },
+            ));
+
+            for (index, entry) in MainMenuEntry::ALL.into_iter().enumerate() {
+                let enabled = selection.enabled(entry);
+                parent
+                    .spawn((
+                        Node {
+                            width: px(400.0),
+                            height: px(70.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(px(4.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.3, 0.25, 0.2)),
+                        BorderColor::all(entry_border_color(index == selection.index)),
+                        entry,
+                    ))
+                    .with_child((
+                        Text::new(entry.label()),
+                        TextFont {
+                            font_size: 32.0,
+                            ..default()
+                        },
+                        TextColor(entry_text_color(enabled)),
+                    ));
+            }
+
+            // Instructions
+            parent.spawn((
+                Text::new("Up/Down to select, Enter to confirm"),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+        });
+}
+
+/// Handles keyboard input on the main menu
+pub fn handle_main_menu_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut selection: ResMut<MainMenuSelection>,
+    mut entry_query: Query<(&MainMenuEntry, &mut BorderColor)>,
+    mut settings_return: ResMut<SettingsReturnState>,
+    mut continue_saved_run: MessageWriter<ContinueSavedRun>,
+    mut quit_game: MessageWriter<QuitGame>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::ArrowUp) {
+        selection.step(-1);
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowDown) {
+        selection.step(1);
+    }
+
+    let selected_entry = MainMenuEntry::ALL[selection.index];
+    for (entry, mut border_color) in &mut entry_query {
+        *border_color = BorderColor::all(entry_border_color(*entry == selected_entry));
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Enter) || keyboard_input.just_pressed(KeyCode::Space) {
+        match selected_entry {
+            MainMenuEntry::NewGame => next_state.set(GameState::CharacterSelection),
+            MainMenuEntry::Continue => {
+                continue_saved_run.write(ContinueSavedRun);
+            }
+            MainMenuEntry::Options => {
+                settings_return.0 = GameState::MainMenu;
+                next_state.set(GameState::Settings);
+            }
+            MainMenuEntry::Quit => {
+                quit_game.write(QuitGame);
+            }
+        }
+    }
+}
+
+/// Title screen shown at startup: start a new game, continue the saved run, options or quit
+pub struct MainMenuPlugin;
+
+impl Plugin for MainMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MainMenuSelection>()
+            .add_systems(OnEnter(GameState::MainMenu), spawn_main_menu)
+            .add_systems(
+                Update,
+                handle_main_menu_input.run_if(in_state(GameState::MainMenu)),
+            )
+            .add_systems(OnExit(GameState::MainMenu), despawn_screen::<MainMenuScreen>);
+    }
+}
@@ -1 +1,14 @@
+pub mod cutscene;
+pub mod endless;
+pub mod ending;
 pub mod game_menu;
+pub mod main_menu;
+pub mod pattern_preview;
+pub mod pause_menu;
+pub mod randomizer;
+pub mod settings_menu;
+pub mod shop;
+pub mod stage_editor;
+pub mod stage_title;
+pub mod tutorial_stage;
+pub mod weapon_get;
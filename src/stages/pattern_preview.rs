@@ -0,0 +1,386 @@
+use crate::components::boss::{Boss, BossAttackState, BossData, BossPhaseState};
+use crate::components::player::{Hp, Player};
+use crate::plugins::run_plugin::StartPatternPreview;
+use crate::stages::game_menu::{CurrentStage, GameState, despawn_screen};
+use crate::systems::boss::BossPatternRegistry;
+use crate::systems::config::{PATTERN_PREVIEW_STAGE, PATTERN_PREVIEW_TIMELINE_LENGTH};
+use crate::systems::game_config::GameConfig;
+use crate::systems::health::emit_health_events;
+use crate::systems::pattern_validation::{PatternDiagnostics, pattern_file_paths};
+use crate::systems::player::spawn_boss;
+use crate::systems::projectile_cap::ProjectileCapStats;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+
+/// Resource for the pattern select screen: the available files and which one is highlighted
+#[derive(Resource, Default)]
+pub struct PatternPreviewSelection {
+    pub file_paths: Vec<String>,
+    pub index: usize,
+    pub error: Option<String>, // Shown when the picked file failed to load
+}
+
+/// Resource for the running preview: the previewed file and a timeline of recent boss events
+#[derive(Resource, Default)]
+pub struct PatternPreview {
+    pub file_path: String,
+    pub elapsed: f32,
+    pub events: Vec<(f32, String)>, // (time, description), newest last
+    last_attack: String,
+    last_movement: String,
+    last_attack_timer: f32,
+}
+
+impl PatternPreview {
+    /// Fresh preview of the pattern file at `file_path`, with an empty timeline
+    pub fn new(file_path: String) -> Self {
+        Self {
+            file_path,
+            ..default()
+        }
+    }
+
+    fn push_event(&mut self, description: String) {
+        self.events.push((self.elapsed, description));
+        if self.events.len() > PATTERN_PREVIEW_TIMELINE_LENGTH {
+            self.events.remove(0);
+        }
+    }
+}
+
+/// Marker component for the pattern select screen UI root
+#[derive(Component)]
+pub struct PatternSelectScreen;
+
+/// Component for a file row on the pattern select screen
+#[derive(Component)]
+pub struct PatternFileRow(pub usize);
+
+/// Marker component for the load error text on the pattern select screen
+#[derive(Component)]
+pub struct PatternSelectErrorText;
+
+/// Marker component for the preview timeline UI root
+#[derive(Component)]
+pub struct PatternTimeline;
+
+/// Marker component for the preview status line (time, patterns, HP)
+#[derive(Component)]
+pub struct PatternTimelineStatus;
+
+/// Marker component for the preview event list
+#[derive(Component)]
+pub struct PatternTimelineEvents;
+
+/// Run condition: true while previewing a pattern
+pub fn in_pattern_preview(current_stage: Res<CurrentStage>) -> bool {
+    current_stage.0 == PATTERN_PREVIEW_STAGE
+}
+
+/// Variant name of a pattern (e.g. "Ring" for `Ring { .. }`)
+pub fn pattern_name<T: std::fmt::Debug>(pattern: &T) -> String {
+    let debug = format!("{:?}", pattern);
+    debug
+        .split([' ', '{', '('])
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn row_color(is_selected: bool) -> Color {
+    if is_selected {
+        Color::srgb(1.0, 0.9, 0.0)
+    } else {
+        Color::WHITE
+    }
+}
+
+/// Spawns the pattern select screen listing every file in the pattern directory
+pub fn spawn_pattern_select_screen(
+    mut commands: Commands,
+    mut selection: ResMut<PatternPreviewSelection>,
+) {
+    selection.error = None;
+    match pattern_file_paths() {
+        Ok(file_paths) => selection.file_paths = file_paths,
+        Err(e) => {
+            selection.file_paths.clear();
+            selection.error = Some(format!("Failed to read pattern directory: {}", e));
+        }
+    }
+    selection.index = selection.index.min(selection.file_paths.len().saturating_sub(1));
+
+    commands
+        .spawn((
+            Node {
+                width: percent(100.0),
+                height: percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: px(10.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.1, 0.1, 0.15)),
+            PatternSelectScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("PATTERN PREVIEW"),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+            parent.spawn((
+                Text::new("Up/Down to select, Enter to preview, Esc to go back"),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+
+            for (index, file_path) in selection.file_paths.iter().enumerate() {
+                parent.spawn((
+                    Text::new(file_path.clone()),
+                    TextFont {
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(row_color(index == selection.index)),
+                    PatternFileRow(index),
+                ));
+            }
+
+            parent.spawn((
+                Text::new(selection.error.clone().unwrap_or_default()),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.4, 0.4)),
+                PatternSelectErrorText,
+            ));
+        });
+}
+
+/// Handles keyboard input on the pattern select screen.
+/// The picked file is (re)loaded every time, so edits to the JSON show up on the next preview.
+pub fn handle_pattern_select_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut selection: ResMut<PatternPreviewSelection>,
+    mut preview: ResMut<PatternPreview>,
+    mut pattern_registry: ResMut<BossPatternRegistry>,
+    mut diagnostics: ResMut<PatternDiagnostics>,
+    mut row_query: Query<(&PatternFileRow, &mut TextColor)>,
+    mut error_query: Query<&mut Text, With<PatternSelectErrorText>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut start_preview: MessageWriter<StartPatternPreview>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        next_state.set(GameState::CharacterSelection);
+        return;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::ArrowUp) && selection.index > 0 {
+        selection.index -= 1;
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowDown)
+        && selection.index + 1 < selection.file_paths.len()
+    {
+        selection.index += 1;
+    }
+
+    for (row, mut text_color) in &mut row_query {
+        text_color.0 = row_color(row.0 == selection.index);
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Enter)
+        && let Some(file_path) = selection.file_paths.get(selection.index).cloned()
+    {
+        let pattern_name = format!("stage_{}", PATTERN_PREVIEW_STAGE);
+        pattern_registry.patterns.remove(&pattern_name);
+        let report = pattern_registry.load_from_file(pattern_name, &file_path);
+        let rejected = report.has_errors();
+        let problem_count = report.diagnostics.len();
+        diagnostics.record(report);
+
+        if rejected {
+            selection.error = Some(format!(
+                "{} has {} problem(s), see the log or run --validate-patterns",
+                file_path, problem_count
+            ));
+        } else {
+            selection.error = None;
+            *preview = PatternPreview::new(file_path);
+            start_preview.write(StartPatternPreview);
+        }
+
+        for mut text in &mut error_query {
+            text.0 = selection.error.clone().unwrap_or_default();
+        }
+    }
+}
+
+/// Spawns the preview timeline (status line plus recent boss events) at the top of the screen
+pub fn spawn_pattern_timeline(mut commands: Commands) {
+    commands
+        .spawn((
+            DespawnOnExit(GameState::InGame),
+            Node {
+                position_type: PositionType::Absolute,
+                left: px(10.0),
+                top: px(110.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: px(2.0),
+                padding: UiRect::all(px(6.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            PatternTimeline,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+                PatternTimelineStatus,
+            ));
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.9, 1.0)),
+                PatternTimelineEvents,
+            ));
+        });
+}
+
+/// System to keep the stand-in player alive and restart the boss's HP when it runs out,
+/// so the pattern keeps running for as long as the designer watches
+pub fn keep_preview_fighters_alive(
+    mut preview: ResMut<PatternPreview>,
+    mut player_query: Query<&mut Hp, (With<Player>, Without<Boss>)>,
+    mut boss_query: Query<&mut Hp, (With<Boss>, Without<Player>)>,
+) {
+    for mut hp in &mut player_query {
+        if hp.current < hp.max {
+            hp.current = hp.max;
+        }
+    }
+    for mut hp in &mut boss_query {
+        if hp.current <= 0.0 {
+            hp.current = hp.max;
+            preview.push_event("boss HP reset".to_string());
+        }
+    }
+}
+
+/// System to record pattern switches and attacks in the timeline and refresh its text (the
+/// status line also counts the boss projectiles against the projectile cap)
+pub fn update_pattern_timeline(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    projectile_stats: Res<ProjectileCapStats>,
+    mut preview: ResMut<PatternPreview>,
+    boss_query: Query<(&BossData, &BossAttackState, &BossPhaseState, &Hp), With<Boss>>,
+    mut status_query: Query<&mut Text, (With<PatternTimelineStatus>, Without<PatternTimelineEvents>)>,
+    mut events_query: Query<&mut Text, (With<PatternTimelineEvents>, Without<PatternTimelineStatus>)>,
+) {
+    preview.elapsed += time.delta_secs();
+
+    // Patterns with several bosses run them all; the timeline follows the first one
+    let Some((boss_data, attack_state, phase_state, hp)) = boss_query.iter().next() else {
+        return;
+    };
+
+    let attack = pattern_name(&boss_data.attack_pattern);
+    if attack != preview.last_attack {
+        preview.push_event(format!("attack -> {}", attack));
+        preview.last_attack = attack.clone();
+    }
+    let movement = pattern_name(&boss_data.movement_pattern);
+    if movement != preview.last_movement {
+        preview.push_event(format!("movement -> {}", movement));
+        preview.last_movement = movement.clone();
+    }
+    // The attack timer jumps back up to the cooldown whenever an attack starts
+    if attack_state.timer > preview.last_attack_timer {
+        preview.push_event(format!("{} fired", attack));
+    }
+    preview.last_attack_timer = attack_state.timer;
+
+    for mut text in &mut status_query {
+        text.0 = format!(
+            "{}  |  {:.1}s  |  phase {}  |  boss HP {:.0}/{:.0}  |  next attack {:.1}s  |  \
+             {}  |  Esc: back",
+            preview.file_path,
+            preview.elapsed,
+            phase_state.phase,
+            hp.current,
+            hp.max,
+            attack_state.timer.max(0.0),
+            projectile_stats.summary(config.boss_projectile_cap),
+        );
+    }
+    for mut text in &mut events_query {
+        text.0 = preview
+            .events
+            .iter()
+            .map(|(time, description)| format!("{:>6.1}s  {}", time, description))
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+}
+
+/// System to leave the preview back to the pattern select screen
+pub fn exit_pattern_preview(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        next_state.set(GameState::PatternSelect);
+    }
+}
+
+/// Developer mode: runs a chosen boss pattern against an invincible player in an empty arena
+pub struct PatternPreviewPlugin;
+
+impl Plugin for PatternPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PatternPreviewSelection>()
+            .init_resource::<PatternPreview>()
+            .add_systems(OnEnter(GameState::PatternSelect), spawn_pattern_select_screen)
+            .add_systems(
+                Update,
+                handle_pattern_select_input.run_if(in_state(GameState::PatternSelect)),
+            )
+            .add_systems(
+                OnExit(GameState::PatternSelect),
+                despawn_screen::<PatternSelectScreen>,
+            )
+            .add_systems(
+                OnEnter(GameState::InGame),
+                spawn_pattern_timeline
+                    .after(spawn_boss)
+                    .run_if(in_pattern_preview),
+            )
+            .add_systems(
+                Update,
+                (
+                    // Refilled before HP changes are reported, so the boss is never defeated
+                    keep_preview_fighters_alive.before(emit_health_events),
+                    update_pattern_timeline,
+                    exit_pattern_preview,
+                )
+                    .run_if(in_state(GameState::InGame).and(in_pattern_preview)),
+            );
+    }
+}
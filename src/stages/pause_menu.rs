@@ -0,0 +1,207 @@
+use crate::plugins::run_plugin::{QuitGame, RunSnapshot};
+use crate::stages::game_menu::GameState;
+use crate::stages::pattern_preview::in_pattern_preview;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+
+/// Component to identify pause menu entries (also defines their display order)
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum PauseMenuEntry {
+    Resume,
+    QuitToMainMenu,
+    QuitGame,
+}
+
+impl PauseMenuEntry {
+    const ALL: [PauseMenuEntry; 3] = [
+        PauseMenuEntry::Resume,
+        PauseMenuEntry::QuitToMainMenu,
+        PauseMenuEntry::QuitGame,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            PauseMenuEntry::Resume => "Resume",
+            PauseMenuEntry::QuitToMainMenu => "Save & Quit to Main Menu",
+            PauseMenuEntry::QuitGame => "Save & Quit Game",
+        }
+    }
+}
+
+/// Resource to track whether the fight is paused, and the selected pause menu entry
+#[derive(Resource, Default)]
+pub struct PauseMenu {
+    pub open: bool,
+    pub index: usize,
+}
+
+/// Marker component for the pause menu overlay
+#[derive(Component)]
+pub struct PauseMenuScreen;
+
+/// Run condition: true while the pause menu is closed (gates the player's controls)
+pub fn game_unpaused(pause_menu: Res<PauseMenu>) -> bool {
+    !pause_menu.open
+}
+
+fn entry_border_color(is_selected: bool) -> Color {
+    if is_selected {
+        Color::srgb(1.0, 0.9, 0.0) // Glowing border (bright yellow/gold)
+    } else {
+        Color::srgb(0.2, 0.2, 0.3)
+    }
+}
+
+fn spawn_pause_menu(commands: &mut Commands) {
+    commands
+        .spawn((
+            DespawnOnExit(GameState::InGame),
+            Node {
+                position_type: PositionType::Absolute,
+                width: percent(100.0),
+                height: percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: px(20.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            GlobalZIndex(10), // Above the fight's HUD
+            PauseMenuScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("PAUSED"),
+                TextFont {
+                    font_size: 56.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+
+            for (index, entry) in PauseMenuEntry::ALL.into_iter().enumerate() {
+                parent
+                    .spawn((
+                        Node {
+                            width: px(420.0),
+                            height: px(60.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(px(4.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.25, 0.25, 0.35)),
+                        BorderColor::all(entry_border_color(index == 0)),
+                        entry,
+                    ))
+                    .with_child((
+                        Text::new(entry.label()),
+                        TextFont {
+                            font_size: 28.0,
+                            ..default()
+                        },
+                        TextColor(WHITE.into()),
+                    ));
+            }
+        });
+}
+
+fn close_pause_menu(
+    commands: &mut Commands,
+    pause_menu: &mut PauseMenu,
+    virtual_time: &mut Time<Virtual>,
+    screen_query: &Query<Entity, With<PauseMenuScreen>>,
+) {
+    pause_menu.open = false;
+    virtual_time.unpause();
+    for entity in screen_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Handles the pause menu: Esc pauses the fight (freezing virtual time) and resumes it again.
+/// Quitting from the menu saves the run, so it can be continued from the current stage's start.
+pub fn handle_pause_menu(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut pause_menu: ResMut<PauseMenu>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    screen_query: Query<Entity, With<PauseMenuScreen>>,
+    mut entry_query: Query<(&PauseMenuEntry, &mut BorderColor)>,
+    snapshot: RunSnapshot,
+    mut quit_game: MessageWriter<QuitGame>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !pause_menu.open {
+        if keyboard_input.just_pressed(KeyCode::Escape) {
+            pause_menu.open = true;
+            pause_menu.index = 0;
+            virtual_time.pause();
+            spawn_pause_menu(&mut commands);
+        }
+        return;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        close_pause_menu(&mut commands, &mut pause_menu, &mut virtual_time, &screen_query);
+        return;
+    }
+
+    // Handle up/down arrow keys to navigate
+    if keyboard_input.just_pressed(KeyCode::ArrowUp) && pause_menu.index > 0 {
+        pause_menu.index -= 1;
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowDown)
+        && pause_menu.index < PauseMenuEntry::ALL.len() - 1
+    {
+        pause_menu.index += 1;
+    }
+
+    let selected_entry = PauseMenuEntry::ALL[pause_menu.index];
+    for (entry, mut border_color) in &mut entry_query {
+        *border_color = BorderColor::all(entry_border_color(*entry == selected_entry));
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Enter) || keyboard_input.just_pressed(KeyCode::Space) {
+        match selected_entry {
+            PauseMenuEntry::Resume => {
+                close_pause_menu(&mut commands, &mut pause_menu, &mut virtual_time, &screen_query);
+            }
+            PauseMenuEntry::QuitToMainMenu => {
+                snapshot.save();
+                next_state.set(GameState::MainMenu);
+            }
+            PauseMenuEntry::QuitGame => {
+                // The quit handler saves the run before exiting
+                quit_game.write(QuitGame);
+            }
+        }
+    }
+}
+
+/// System to make sure the game isn't left paused when the fight ends
+pub fn reset_pause_menu(
+    mut pause_menu: ResMut<PauseMenu>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    pause_menu.open = false;
+    virtual_time.unpause();
+}
+
+/// Pause menu for fights (Esc), with options to save and quit
+pub struct PauseMenuPlugin;
+
+impl Plugin for PauseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PauseMenu>()
+            .add_systems(
+                Update,
+                // The pattern preview uses Esc to go back to its pattern list
+                handle_pause_menu
+                    .run_if(in_state(GameState::InGame))
+                    .run_if(not(in_pattern_preview)),
+            )
+            .add_systems(OnExit(GameState::InGame), reset_pause_menu);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::plugins::camera_plugin::HUD_RENDER_LAYER;
+use crate::plugins::run_plugin::RunStats;
+use crate::stages::game_menu::{CurrentStage, GameState};
+use crate::systems::boss::{
+    AttackPatternConfig, BossPatternConfig, BossPatternRegistry, load_boss_pattern_for_stage,
+};
+use crate::systems::boss_ai::BehaviorNode;
+use crate::systems::config::{BOUNDARY_TOP, MAX_STAGES};
+use crate::systems::pattern_validation::PatternDiagnostics;
+use crate::systems::player::spawn_boss;
+use crate::systems::rng::GameRng;
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+
+/// Run condition: true during a randomizer run
+pub fn in_randomizer_mode(run_stats: Res<RunStats>) -> bool {
+    run_stats.randomizer
+}
+
+/// Resource with the boss the current stage of a randomizer run throws at the player, used by
+/// `spawn_boss` instead of the stage's own pattern (None outside of randomizer runs)
+#[derive(Resource, Default)]
+pub struct RandomizedBoss(pub Option<BossPatternConfig>);
+
+/// Marker component for the seed shown during a randomizer run
+#[derive(Component)]
+pub struct RandomizerSeedText;
+
+/// Random index in 0..len
+fn random_index(rng: &mut GameRng, len: usize) -> usize {
+    (rng.next_u64() % len as u64) as usize
+}
+
+/// Every attack a behavior tree can switch to (survive attacks stay with their boss)
+fn collect_tree_attacks(node: &BehaviorNode, attacks: &mut Vec<AttackPatternConfig>) {
+    match node {
+        BehaviorNode::Sequence { children }
+        | BehaviorNode::Selector { children }
+        | BehaviorNode::RandomSelector { children, .. } => {
+            for child in children {
+                collect_tree_attacks(child, attacks);
+            }
+        }
+        BehaviorNode::Attack { attack } => attacks.push(attack.clone()),
+        _ => {}
+    }
+}
+
+/// Swaps every attack of a behavior tree for one picked from `pool`
+fn remix_tree_attacks(node: &mut BehaviorNode, pool: &[AttackPatternConfig], rng: &mut GameRng) {
+    match node {
+        BehaviorNode::Sequence { children }
+        | BehaviorNode::Selector { children }
+        | BehaviorNode::RandomSelector { children, .. } => {
+            for child in children {
+                remix_tree_attacks(child, pool, rng);
+            }
+        }
+        BehaviorNode::Attack { attack } => *attack = pool[random_index(rng, pool.len())].clone(),
+        _ => {}
+    }
+}
+
+/// Shuffles the bosses into a new stage order and deals their attacks out among them at random
+/// (a boss that doesn't attack keeps not attacking). The lineup only depends on `seed`, so it's
+/// the same on every stage and retry of the run, and for anyone playing the same seed.
+pub fn randomize_bosses(seed: u64, mut bosses: Vec<BossPatternConfig>) -> Vec<BossPatternConfig> {
+    // A generator of its own: the run's GameRng moves on as the run is played
+    let mut rng = GameRng::new(Some(seed));
+
+    for i in (1..bosses.len()).rev() {
+        let j = random_index(&mut rng, i + 1);
+        bosses.swap(i, j);
+    }
+
+    let mut pool = Vec::new();
+    for boss in &bosses {
+        pool.push(boss.attack.clone());
+        if let Some(tree) = &boss.behavior {
+            collect_tree_attacks(tree, &mut pool);
+        }
+    }
+    pool.retain(|attack| !matches!(attack, AttackPatternConfig::None));
+    if pool.is_empty() {
+        return bosses;
+    }
+
+    for boss in &mut bosses {
+        if !matches!(boss.attack, AttackPatternConfig::None) {
+            boss.attack = pool[random_index(&mut rng, pool.len())].clone();
+        }
+        if let Some(tree) = &mut boss.behavior {
+            remix_tree_attacks(tree, &pool, &mut rng);
+        }
+    }
+    bosses
+}
+
+/// Picks the randomized boss of the current stage, loading every stage's pattern to draw from
+pub fn pick_randomized_boss(
+    mut pattern_registry: ResMut<BossPatternRegistry>,
+    mut diagnostics: ResMut<PatternDiagnostics>,
+    current_stage: Res<CurrentStage>,
+    run_stats: Res<RunStats>,
+    mut randomized_boss: ResMut<RandomizedBoss>,
+) {
+    let mut bosses = Vec::new();
+    for stage in 1..=MAX_STAGES {
+        load_boss_pattern_for_stage(&mut pattern_registry, &mut diagnostics, stage);
+        if let Some(pattern) = pattern_registry.get_pattern(&format!("stage_{}", stage)) {
+            bosses.push(pattern.clone());
+        }
+    }
+
+    let bosses = randomize_bosses(run_stats.seed, bosses);
+    randomized_boss.0 = current_stage
+        .0
+        .checked_sub(1)
+        .and_then(|index| bosses.get(index as usize))
+        .cloned();
+    if let Some(boss) = &randomized_boss.0 {
+        info!(
+            "Randomizer stage {}: {} (seed {})",
+            current_stage.0,
+            boss.name.as_deref().unwrap_or("unnamed boss"),
+            run_stats.seed
+        );
+    }
+}
+
+/// Shows the run's seed during a randomizer run, so the run can be shared and replayed
+pub fn spawn_randomizer_hud(mut commands: Commands, run_stats: Res<RunStats>) {
+    commands.spawn((
+        DespawnOnExit(GameState::InGame),
+        Text2d::new(format!("Randomizer  |  Seed {}", run_stats.seed)),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Transform::from_xyz(0.0, BOUNDARY_TOP - 20.0, 0.0),
+        RenderLayers::layer(HUD_RENDER_LAYER),
+        RandomizerSeedText,
+    ));
+}
+
+/// System to forget the randomized boss once the fight is over
+pub fn reset_randomized_boss(mut randomized_boss: ResMut<RandomizedBoss>) {
+    randomized_boss.0 = None;
+}
+
+/// Randomizer mode: a run through the stages with the bosses in a shuffled order and their
+/// attacks mixed between them, all worked out from the run's seed
+pub struct RandomizerPlugin;
+
+impl Plugin for RandomizerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RandomizedBoss>()
+            .add_systems(
+                OnEnter(GameState::InGame),
+                (
+                    pick_randomized_boss.before(spawn_boss),
+                    spawn_randomizer_hud,
+                )
+                    .run_if(in_randomizer_mode),
+            )
+            .add_systems(OnExit(GameState::InGame), reset_randomized_boss);
+    }
+}
@@ -0,0 +1,383 @@
+use crate::stages::game_menu::{GameState, despawn_screen};
+use crate::systems::config::SETTINGS_FILE_PATH;
+use crate::systems::display::{apply_window_settings, scale_ui_to_window};
+use crate::systems::palette::ColorPalette;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+use bevy::text::prelude::{TextColor, TextFont};
+use serde::{Deserialize, Serialize};
+
+/// Global gameplay speed options (applied through `Time<Virtual>`, so menus stay real-time)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GameSpeed {
+    Slow,
+    #[default]
+    Normal,
+    Fast,
+}
+
+impl GameSpeed {
+    /// Relative speed of virtual time for this option
+    pub fn multiplier(self) -> f32 {
+        match self {
+            GameSpeed::Slow => 0.75,
+            GameSpeed::Normal => 1.0,
+            GameSpeed::Fast => 1.25,
+        }
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        const ORDER: [GameSpeed; 3] = [GameSpeed::Slow, GameSpeed::Normal, GameSpeed::Fast];
+        let index = ORDER.iter().position(|speed| *speed == self).unwrap_or(1) as i32;
+        ORDER[(index + step).clamp(0, ORDER.len() as i32 - 1) as usize]
+    }
+}
+
+/// Windowed resolution options (ignored while fullscreen)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DisplayResolution {
+    #[default]
+    Hd,
+    HdPlus,
+    FullHd,
+}
+
+impl DisplayResolution {
+    /// Logical window size for this option
+    pub fn size(self) -> (u32, u32) {
+        match self {
+            DisplayResolution::Hd => (1280, 720),
+            DisplayResolution::HdPlus => (1600, 900),
+            DisplayResolution::FullHd => (1920, 1080),
+        }
+    }
+
+    fn cycle(self, step: i32) -> Self {
+        const ORDER: [DisplayResolution; 3] = [
+            DisplayResolution::Hd,
+            DisplayResolution::HdPlus,
+            DisplayResolution::FullHd,
+        ];
+        let index = ORDER.iter().position(|resolution| *resolution == self).unwrap_or(0) as i32;
+        ORDER[(index + step).clamp(0, ORDER.len() as i32 - 1) as usize]
+    }
+}
+
+/// Resource to store player-facing settings (accessibility, practice and display options).
+/// Saved to `SETTINGS_FILE_PATH` when leaving the settings screen and loaded at startup.
+#[derive(Resource, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameSettings {
+    pub game_speed: GameSpeed,
+    pub palette: ColorPalette,
+    pub reduced_flashing: bool, // Disables pulsing/blinking/shaking effects in favor of steady indicators
+    pub resolution: DisplayResolution,
+    pub fullscreen: bool, // Borderless fullscreen on the current monitor
+    pub vsync: bool,
+    pub aim_assist: bool, // Nudges shots toward a boss that is almost in line (helps with gamepads)
+    pub roguelike_mode: bool, // Every stage gets a random curse (low gravity, mirrored controls, ...)
+    pub show_ghost: bool, // Replays the fastest clear of the stage as a translucent ghost
+    pub input_display: bool, // Shows the held gameplay inputs on screen (also toggled with F9)
+    pub mute_announcer: bool, // Silences the announcer's voice lines
+}
+
+impl GameSettings {
+    /// Load settings from a JSON file
+    pub fn load_from_file(file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let json_content = std::fs::read_to_string(file_path)?;
+        Ok(serde_json::from_str(&json_content)?)
+    }
+
+    /// Save settings to a JSON file
+    pub fn save_to_file(&self, file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        std::fs::write(file_path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// Component to identify settings rows (also defines their display order)
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsOption {
+    GameSpeed,
+    Palette,
+    ReducedFlashing,
+    Resolution,
+    Fullscreen,
+    VSync,
+    AimAssist,
+    RoguelikeMode,
+    Ghost,
+    InputDisplay,
+    Announcer,
+}
+
+impl SettingsOption {
+    const ALL: [SettingsOption; 11] = [
+        SettingsOption::GameSpeed,
+        SettingsOption::Palette,
+        SettingsOption::ReducedFlashing,
+        SettingsOption::Resolution,
+        SettingsOption::Fullscreen,
+        SettingsOption::VSync,
+        SettingsOption::AimAssist,
+        SettingsOption::RoguelikeMode,
+        SettingsOption::Ghost,
+        SettingsOption::InputDisplay,
+        SettingsOption::Announcer,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            SettingsOption::GameSpeed => "Game Speed",
+            SettingsOption::Palette => "Color Palette",
+            SettingsOption::ReducedFlashing => "Reduced Flashing",
+            SettingsOption::Resolution => "Resolution",
+            SettingsOption::Fullscreen => "Fullscreen",
+            SettingsOption::VSync => "VSync",
+            SettingsOption::AimAssist => "Aim Assist",
+            SettingsOption::RoguelikeMode => "Roguelike Mode",
+            SettingsOption::Ghost => "Best Clear Ghost",
+            SettingsOption::InputDisplay => "Input Display",
+            SettingsOption::Announcer => "Announcer",
+        }
+    }
+}
+
+impl GameSettings {
+    /// Human-readable value shown next to a settings row
+    fn value_text(&self, option: SettingsOption) -> String {
+        match option {
+            SettingsOption::GameSpeed => format!("{:.2}x", self.game_speed.multiplier()),
+            SettingsOption::Palette => self.palette.label().to_string(),
+            SettingsOption::ReducedFlashing => on_off(self.reduced_flashing).to_string(),
+            SettingsOption::Resolution => {
+                let (width, height) = self.resolution.size();
+                format!("{}x{}", width, height)
+            }
+            SettingsOption::Fullscreen => on_off(self.fullscreen).to_string(),
+            SettingsOption::VSync => on_off(self.vsync).to_string(),
+            SettingsOption::AimAssist => on_off(self.aim_assist).to_string(),
+            SettingsOption::RoguelikeMode => on_off(self.roguelike_mode).to_string(),
+            SettingsOption::Ghost => on_off(self.show_ghost).to_string(),
+            SettingsOption::InputDisplay => on_off(self.input_display).to_string(),
+            SettingsOption::Announcer => on_off(!self.mute_announcer).to_string(),
+        }
+    }
+
+    /// Change a setting one step to the left (-1) or right (+1)
+    fn change(&mut self, option: SettingsOption, step: i32) {
+        match option {
+            SettingsOption::GameSpeed => self.game_speed = self.game_speed.cycle(step),
+            SettingsOption::Palette => self.palette = self.palette.toggled(),
+            SettingsOption::ReducedFlashing => self.reduced_flashing = !self.reduced_flashing,
+            SettingsOption::Resolution => self.resolution = self.resolution.cycle(step),
+            SettingsOption::Fullscreen => self.fullscreen = !self.fullscreen,
+            SettingsOption::VSync => self.vsync = !self.vsync,
+            SettingsOption::AimAssist => self.aim_assist = !self.aim_assist,
+            SettingsOption::RoguelikeMode => self.roguelike_mode = !self.roguelike_mode,
+            SettingsOption::Ghost => self.show_ghost = !self.show_ghost,
+            SettingsOption::InputDisplay => self.input_display = !self.input_display,
+            SettingsOption::Announcer => self.mute_announcer = !self.mute_announcer,
+        }
+    }
+}
+
+fn on_off(value: bool) -> &'static str {
+    if value { "On" } else { "Off" }
+}
+
+/// Resource to track which settings row is currently selected
+#[derive(Resource, Default)]
+pub struct SelectedSettingsIndex(pub usize);
+
+/// Resource with the screen the settings screen goes back to (set when opening it)
+#[derive(Resource, Default)]
+pub struct SettingsReturnState(pub GameState);
+
+/// Marker component for the settings screen UI root
+#[derive(Component)]
+pub struct SettingsScreen;
+
+/// Marker component for the value text of a settings row
+#[derive(Component)]
+pub struct SettingsValueText(pub SettingsOption);
+
+/// Spawns the settings screen
+pub fn spawn_settings_screen(mut commands: Commands, settings: Res<GameSettings>) {
+    commands
+        .spawn((
+            Node {
+                width: percent(100.0),
+                height: percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: px(10.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+            SettingsScreen,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new("OPTIONS"),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+
+            // Instructions
+            parent.spawn((
+                Text::new("Up/Down to select, Left/Right to change, Esc or Enter to go back"),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+
+            for (index, option) in SettingsOption::ALL.into_iter().enumerate() {
+                parent
+                    .spawn((
+                        Node {
+                            width: px(500.0),
+                            height: px(50.0),
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::horizontal(px(20.0)),
+                            border: UiRect::all(px(4.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.25, 0.25, 0.35)),
+                        BorderColor::all(settings_border_color(index == 0)),
+                        option,
+                    ))
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(option.label()),
+                            TextFont {
+                                font_size: 28.0,
+                                ..default()
+                            },
+                            TextColor(WHITE.into()),
+                        ));
+                        row.spawn((
+                            Text::new(format!("< {} >", settings.value_text(option))),
+                            TextFont {
+                                font_size: 28.0,
+                                ..default()
+                            },
+                            TextColor(WHITE.into()),
+                            SettingsValueText(option),
+                        ));
+                    });
+            }
+        });
+}
+
+fn settings_border_color(is_selected: bool) -> Color {
+    if is_selected {
+        Color::srgb(1.0, 0.9, 0.0) // Glowing border (bright yellow/gold)
+    } else {
+        Color::srgb(0.2, 0.2, 0.3)
+    }
+}
+
+/// Handles keyboard input on the settings screen
+pub fn handle_settings_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut selected_index: ResMut<SelectedSettingsIndex>,
+    mut settings: ResMut<GameSettings>,
+    mut row_query: Query<(&SettingsOption, &mut BorderColor)>,
+    mut value_query: Query<(&SettingsValueText, &mut Text)>,
+    settings_return: Res<SettingsReturnState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    // Handle up/down arrow keys to navigate
+    if keyboard_input.just_pressed(KeyCode::ArrowUp) && selected_index.0 > 0 {
+        selected_index.0 -= 1;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::ArrowDown)
+        && selected_index.0 < SettingsOption::ALL.len() - 1
+    {
+        selected_index.0 += 1;
+    }
+
+    // Handle left/right arrow keys to change the selected value
+    let selected_option = SettingsOption::ALL[selected_index.0];
+    if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+        settings.change(selected_option, -1);
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+        settings.change(selected_option, 1);
+    }
+
+    // Update border colors based on selection
+    for (option, mut border_color) in &mut row_query {
+        *border_color = BorderColor::all(settings_border_color(*option == selected_option));
+    }
+
+    // Refresh displayed values
+    if settings.is_changed() {
+        for (value_text, mut text) in &mut value_query {
+            text.0 = format!("< {} >", settings.value_text(value_text.0));
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Escape) || keyboard_input.just_pressed(KeyCode::Enter) {
+        next_state.set(settings_return.0);
+    }
+}
+
+/// Loads saved settings at startup (falls back to defaults if the file is missing or invalid)
+pub fn load_settings(mut commands: Commands) {
+    match GameSettings::load_from_file(SETTINGS_FILE_PATH) {
+        Ok(settings) => commands.insert_resource(settings),
+        Err(e) => info!("No saved settings loaded from {}: {}", SETTINGS_FILE_PATH, e),
+    }
+}
+
+/// Saves the settings when leaving the settings screen
+pub fn save_settings(settings: Res<GameSettings>) {
+    if let Err(e) = settings.save_to_file(SETTINGS_FILE_PATH) {
+        warn!("Failed to save settings to {}: {}", SETTINGS_FILE_PATH, e);
+    }
+}
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameSettings>()
+            .init_resource::<SelectedSettingsIndex>()
+            .init_resource::<SettingsReturnState>()
+            .add_systems(Startup, load_settings)
+            .add_systems(
+                OnEnter(GameState::Settings),
+                (
+                    |mut selected_index: ResMut<SelectedSettingsIndex>| {
+                        // Reset to first option when entering settings screen
+                        selected_index.0 = 0;
+                    },
+                    spawn_settings_screen,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    handle_settings_input.run_if(in_state(GameState::Settings)),
+                    apply_window_settings.run_if(resource_changed::<GameSettings>),
+                    scale_ui_to_window,
+                ),
+            )
+            .add_systems(
+                OnExit(GameState::Settings),
+                (despawn_screen::<SettingsScreen>, save_settings),
+            );
+    }
+}
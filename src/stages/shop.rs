@@ -0,0 +1,352 @@
+use crate::plugins::medal_plugin::StageClock;
+use crate::stages::game_menu::{GameState, PlayerUpgrades, despawn_screen};
+use crate::systems::config::{
+    CRUMBS_PER_DAMAGE, FAST_CLEAR_BONUS, FAST_CLEAR_TIME, MAX_POTIONS, SHOP_ARMOR_MIN_MULTIPLIER,
+    SHOP_ARMOR_MULTIPLIER, SHOP_ARMOR_PRICE, SHOP_HEAL_AMOUNT, SHOP_HEAL_PRICE, SHOP_MAX_HP_AMOUNT,
+    SHOP_MAX_HP_PRICE, SHOP_POTION_PRICE,
+};
+use crate::systems::player::BossDamaged;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+
+/// Component to identify shop rows (also defines their display order)
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum ShopItem {
+    MaxHp,
+    Armor,
+    Heal,
+    Potion,
+}
+
+impl ShopItem {
+    const ALL: [ShopItem; 4] = [ShopItem::MaxHp, ShopItem::Armor, ShopItem::Heal, ShopItem::Potion];
+
+    fn label(self) -> String {
+        match self {
+            ShopItem::MaxHp => format!("Max HP +{}", SHOP_MAX_HP_AMOUNT),
+            ShopItem::Armor => format!(
+                "Armor (-{:.0}% damage taken)",
+                (1.0 - SHOP_ARMOR_MULTIPLIER) * 100.0
+            ),
+            ShopItem::Heal => format!("Heal {} HP", SHOP_HEAL_AMOUNT),
+            ShopItem::Potion => format!("Potion (Q in a fight, max {})", MAX_POTIONS),
+        }
+    }
+
+    fn price(self) -> u32 {
+        match self {
+            ShopItem::MaxHp => SHOP_MAX_HP_PRICE,
+            ShopItem::Armor => SHOP_ARMOR_PRICE,
+            ShopItem::Heal => SHOP_HEAL_PRICE,
+            ShopItem::Potion => SHOP_POTION_PRICE,
+        }
+    }
+
+    /// Whether buying the item would do anything (e.g. no healing at full HP)
+    fn available(self, upgrades: &PlayerUpgrades) -> bool {
+        match self {
+            ShopItem::MaxHp => true,
+            ShopItem::Armor => upgrades.defense_multiplier > SHOP_ARMOR_MIN_MULTIPLIER,
+            ShopItem::Heal => upgrades.current_hp < 100.0 + upgrades.max_hp_bonus,
+            ShopItem::Potion => upgrades.potions < MAX_POTIONS,
+        }
+    }
+
+    fn can_buy(self, upgrades: &PlayerUpgrades) -> bool {
+        self.available(upgrades) && upgrades.crumbs >= self.price()
+    }
+
+    fn apply(self, upgrades: &mut PlayerUpgrades) {
+        match self {
+            ShopItem::MaxHp => {
+                upgrades.max_hp_bonus += SHOP_MAX_HP_AMOUNT;
+                upgrades.current_hp += SHOP_MAX_HP_AMOUNT;
+            }
+            ShopItem::Armor => {
+                upgrades.defense_multiplier = (upgrades.defense_multiplier * SHOP_ARMOR_MULTIPLIER)
+                    .max(SHOP_ARMOR_MIN_MULTIPLIER);
+            }
+            ShopItem::Heal => {
+                let max_hp = 100.0 + upgrades.max_hp_bonus;
+                upgrades.current_hp = (upgrades.current_hp + SHOP_HEAL_AMOUNT).min(max_hp);
+            }
+            ShopItem::Potion => upgrades.potions += 1,
+        }
+    }
+}
+
+/// Resource to track what the current fight is worth in crumbs (reset on every stage start)
+#[derive(Resource, Default)]
+pub struct StageEarnings {
+    pub damage_dealt: f32,
+    pub fight_time: f32,
+    pub last_award: u32, // Crumbs awarded for the last won fight (shown in the shop)
+    pub last_fast_clear: bool,
+}
+
+/// Resource to track which shop row is currently selected
+#[derive(Resource, Default)]
+pub struct SelectedShopIndex(pub usize);
+
+/// Marker component for the shop screen UI root
+#[derive(Component)]
+pub struct ShopScreen;
+
+/// Marker component for the shop's crumbs and inventory line
+#[derive(Component)]
+pub struct ShopStatusText;
+
+/// System to start tracking earnings when a fight starts
+pub fn reset_stage_earnings(mut earnings: ResMut<StageEarnings>) {
+    earnings.damage_dealt = 0.0;
+    earnings.fight_time = 0.0;
+}
+
+/// System to add up the fight's duration and the damage dealt to bosses
+pub fn track_stage_earnings(
+    time: Res<Time>,
+    mut earnings: ResMut<StageEarnings>,
+    mut boss_damaged: MessageReader<BossDamaged>,
+) {
+    earnings.fight_time += time.delta_secs();
+    for damaged in boss_damaged.read() {
+        earnings.damage_dealt += damaged.amount;
+    }
+}
+
+/// System to pay out crumbs for a won fight: a share of the damage dealt plus a fast clear bonus
+pub fn award_stage_crumbs(
+    mut earnings: ResMut<StageEarnings>,
+    mut player_upgrades: ResMut<PlayerUpgrades>,
+) {
+    let fast_clear = earnings.fight_time <= FAST_CLEAR_TIME;
+    let mut award = (earnings.damage_dealt * CRUMBS_PER_DAMAGE).round() as u32;
+    if fast_clear {
+        award += FAST_CLEAR_BONUS;
+    }
+
+    player_upgrades.crumbs += award;
+    earnings.last_award = award;
+    earnings.last_fast_clear = fast_clear;
+    info!(
+        "Earned {} crumbs in {:.1}s (total {})",
+        award, earnings.fight_time, player_upgrades.crumbs
+    );
+}
+
+fn shop_row_color(is_selected: bool) -> Color {
+    if is_selected {
+        Color::srgb(1.0, 0.9, 0.0) // Glowing border (bright yellow/gold)
+    } else {
+        Color::srgb(0.2, 0.2, 0.3)
+    }
+}
+
+fn shop_status_text(upgrades: &PlayerUpgrades) -> String {
+    format!(
+        "Crumbs: {}  |  HP {:.0}/{:.0}  |  Potions {}/{}",
+        upgrades.crumbs,
+        upgrades.current_hp,
+        100.0 + upgrades.max_hp_bonus,
+        upgrades.potions,
+        MAX_POTIONS
+    )
+}
+
+/// Spawns the shop screen
+pub fn spawn_shop_screen(
+    mut commands: Commands,
+    player_upgrades: Res<PlayerUpgrades>,
+    earnings: Res<StageEarnings>,
+    stage_clock: Res<StageClock>,
+) {
+    let earned_text = if earnings.last_fast_clear {
+        format!("+{} crumbs (fast clear bonus!)", earnings.last_award)
+    } else {
+        format!("+{} crumbs", earnings.last_award)
+    };
+    // Clear time and medal of the stage just won (none after a checkpoint retry)
+    let clear_text = stage_clock.last_clear.map(|clear| clear.summary());
+
+    commands
+        .spawn((
+            Node {
+                width: percent(100.0),
+                height: percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.2, 0.15, 0.1)),
+            ShopScreen,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new("SHOP"),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+
+            parent.spawn((
+                Text::new(earned_text),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.85, 0.4)),
+            ));
+
+            if let Some(clear_text) = clear_text {
+                parent.spawn((
+                    Text::new(clear_text),
+                    TextFont {
+                        font_size: 22.0,
+                        ..default()
+                    },
+                    TextColor(WHITE.into()),
+                ));
+            }
+
+            parent.spawn((
+                Text::new(shop_status_text(&player_upgrades)),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+                ShopStatusText,
+            ));
+
+            // Instructions
+            parent.spawn((
+                Text::new("Up/Down to select, Enter to buy, Esc to start the next stage"),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+
+            for (index, item) in ShopItem::ALL.into_iter().enumerate() {
+                parent
+                    .spawn((
+                        Node {
+                            width: px(600.0),
+                            height: px(60.0),
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::horizontal(px(20.0)),
+                            border: UiRect::all(px(4.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.3, 0.25, 0.2)),
+                        BorderColor::all(shop_row_color(index == 0)),
+                        item,
+                    ))
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(item.label()),
+                            TextFont {
+                                font_size: 26.0,
+                                ..default()
+                            },
+                            TextColor(WHITE.into()),
+                        ));
+                        row.spawn((
+                            Text::new(format!("{} crumbs", item.price())),
+                            TextFont {
+                                font_size: 26.0,
+                                ..default()
+                            },
+                            TextColor(WHITE.into()),
+                        ));
+                    });
+            }
+        });
+}
+
+/// Handles keyboard input in the shop: buying items and leaving for the next stage
+pub fn handle_shop_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut selected_index: ResMut<SelectedShopIndex>,
+    mut player_upgrades: ResMut<PlayerUpgrades>,
+    mut row_query: Query<(&ShopItem, &mut BorderColor, &mut BackgroundColor)>,
+    mut status_query: Query<&mut Text, With<ShopStatusText>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    // Handle up/down arrow keys to navigate
+    if keyboard_input.just_pressed(KeyCode::ArrowUp) && selected_index.0 > 0 {
+        selected_index.0 -= 1;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::ArrowDown) && selected_index.0 < ShopItem::ALL.len() - 1
+    {
+        selected_index.0 += 1;
+    }
+
+    let selected_item = ShopItem::ALL[selected_index.0];
+    if keyboard_input.just_pressed(KeyCode::Enter) {
+        if selected_item.can_buy(&player_upgrades) {
+            player_upgrades.crumbs -= selected_item.price();
+            selected_item.apply(&mut player_upgrades);
+            info!("Bought {} ({} crumbs left)", selected_item.label(), player_upgrades.crumbs);
+        } else {
+            info!("Can't buy {}", selected_item.label());
+        }
+    }
+
+    // Highlight the selection and grey out what can't be bought
+    for (item, mut border_color, mut background_color) in &mut row_query {
+        *border_color = BorderColor::all(shop_row_color(*item == selected_item));
+        background_color.0 = if item.can_buy(&player_upgrades) {
+            Color::srgb(0.3, 0.25, 0.2)
+        } else {
+            Color::srgb(0.18, 0.16, 0.15)
+        };
+    }
+
+    if player_upgrades.is_changed() {
+        for mut text in &mut status_query {
+            text.0 = shop_status_text(&player_upgrades);
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        next_state.set(GameState::InGame);
+    }
+}
+
+/// Crumbs currency earned in fights and the shop visited between stages
+pub struct ShopPlugin;
+
+impl Plugin for ShopPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StageEarnings>()
+            .init_resource::<SelectedShopIndex>()
+            .add_systems(OnEnter(GameState::InGame), reset_stage_earnings)
+            .add_systems(
+                Update,
+                track_stage_earnings.run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(OnEnter(GameState::GameWin), award_stage_crumbs)
+            .add_systems(
+                OnEnter(GameState::Shop),
+                (
+                    |mut selected_index: ResMut<SelectedShopIndex>| {
+                        // Reset to first item when entering the shop
+                        selected_index.0 = 0;
+                    },
+                    spawn_shop_screen,
+                )
+                    .chain(),
+            )
+            .add_systems(Update, handle_shop_input.run_if(in_state(GameState::Shop)))
+            .add_systems(OnExit(GameState::Shop), despawn_screen::<ShopScreen>);
+    }
+}
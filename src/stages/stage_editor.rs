@@ -0,0 +1,360 @@
+use crate::components::player::BoundaryWall;
+use crate::plugins::camera_plugin::GameCamera;
+use crate::stages::game_menu::{GameState, despawn_screen};
+use crate::systems::boss::{BossPatternRegistry, Vec2Config, load_boss_pattern_for_stage};
+use crate::systems::boundaries::{ArenaBounds, reset_arena_bounds, spawn_boundaries};
+use crate::systems::config::{
+    MAX_STAGES, STAGE_EDITOR_FLOOR_HEIGHT, STAGE_EDITOR_GRID, STAGE_EDITOR_HAZARD_DAMAGE,
+    STAGE_EDITOR_HAZARD_MARKER_HEIGHT, STAGE_EDITOR_MAX_WIDTH, STAGE_EDITOR_MIN_WIDTH,
+    STAGE_EDITOR_PLATFORM_HEIGHT, STAGE_EDITOR_SPAWN_MARKER_SIZE,
+};
+use crate::systems::hazards::HazardKind;
+use crate::systems::pattern_validation::PatternDiagnostics;
+use crate::systems::platforms::SurfaceMaterial;
+use crate::systems::stage_layout::{
+    PlacedHazardConfig, PlatformConfig, StageLayout, stage_layout_path,
+};
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// What a left click places in the stage editor
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum EditorTool {
+    #[default]
+    Platform,
+    Hazard,
+    PlayerSpawn,
+    BossSpawn,
+}
+
+impl EditorTool {
+    fn label(self) -> &'static str {
+        match self {
+            EditorTool::Platform => "Platform",
+            EditorTool::Hazard => "Hazard",
+            EditorTool::PlayerSpawn => "Player spawn",
+            EditorTool::BossSpawn => "Boss spawn",
+        }
+    }
+}
+
+/// Resource holding the layout being edited, the stage slot it belongs to and the current tool
+#[derive(Resource)]
+pub struct StageEditor {
+    pub layout: StageLayout,
+    pub tool: EditorTool,
+    pub stage: u32,     // Stage whose layout file is edited and exported
+    pub width: f32,     // Width of the next platform/hazard
+    pub status: String, // Last save/load message
+}
+
+impl Default for StageEditor {
+    fn default() -> Self {
+        Self {
+            layout: StageLayout::default(),
+            tool: EditorTool::default(),
+            stage: 1,
+            width: 120.0,
+            status: String::new(),
+        }
+    }
+}
+
+/// Marker component for editor-only entities (floor and help text)
+#[derive(Component)]
+pub struct EditorEntity;
+
+/// Marker component for the editor's floor (respawned to fit the arena of each stage slot)
+#[derive(Component)]
+pub struct EditorFloor;
+
+/// Marker component for the editor help/status text
+#[derive(Component)]
+pub struct EditorStatusText;
+
+fn snap(value: f32) -> f32 {
+    (value / STAGE_EDITOR_GRID).round() * STAGE_EDITOR_GRID
+}
+
+/// Sets up the editor's help text (the arena is set up by `load_editor_stage`)
+pub fn setup_stage_editor(mut commands: Commands) {
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(WHITE.into()),
+        Node {
+            position_type: PositionType::Absolute,
+            left: px(10.0),
+            top: px(10.0),
+            ..default()
+        },
+        EditorStatusText,
+        EditorEntity,
+    ));
+}
+
+/// Loads the layout of the stage slot being edited (a new layout when it has none yet) and sets
+/// up that stage's arena: the walls and ceiling of its boss pattern and a floor spanning them
+pub fn load_editor_stage(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut editor: ResMut<StageEditor>,
+    mut arena: ResMut<ArenaBounds>,
+    mut pattern_registry: ResMut<BossPatternRegistry>,
+    mut diagnostics: ResMut<PatternDiagnostics>,
+    arena_query: Query<Entity, Or<(With<EditorFloor>, With<BoundaryWall>)>>,
+) {
+    let file_path = stage_layout_path(editor.stage);
+    editor.status = match StageLayout::load_from_file(&file_path) {
+        Ok(layout) => {
+            editor.layout = layout;
+            format!("Loaded {}", file_path)
+        }
+        Err(_) => {
+            editor.layout = StageLayout::default();
+            format!("New layout for stage {}", editor.stage)
+        }
+    };
+
+    load_boss_pattern_for_stage(&mut pattern_registry, &mut diagnostics, editor.stage);
+    *arena = pattern_registry
+        .get_pattern(&format!("stage_{}", editor.stage))
+        .and_then(|pattern| pattern.arena.as_ref())
+        .map_or_else(ArenaBounds::default, ArenaBounds::from_config);
+
+    for entity in &arena_query {
+        commands.entity(entity).despawn();
+    }
+    commands.spawn((
+        Mesh2d(meshes.add(Rectangle::new(arena.width(), STAGE_EDITOR_FLOOR_HEIGHT))),
+        MeshMaterial2d(materials.add(Color::srgb(0.3, 0.3, 0.3))),
+        Transform::from_xyz(
+            (arena.left + arena.right) / 2.0,
+            arena.floor_top() - STAGE_EDITOR_FLOOR_HEIGHT / 2.0,
+            0.0,
+        ),
+        EditorFloor,
+        EditorEntity,
+    ));
+    commands.run_system_cached(spawn_boundaries);
+}
+
+/// Handles editor input: tool and stage slot selection, placing/removing items with the mouse,
+/// export and exit
+pub fn handle_stage_editor_input(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    arena: Res<ArenaBounds>,
+    mut editor: ResMut<StageEditor>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        next_state.set(GameState::CharacterSelection);
+        return;
+    }
+
+    let tool_keys = [
+        (KeyCode::Digit1, EditorTool::Platform),
+        (KeyCode::Digit2, EditorTool::Hazard),
+        (KeyCode::Digit3, EditorTool::PlayerSpawn),
+        (KeyCode::Digit4, EditorTool::BossSpawn),
+    ];
+    for (key, tool) in tool_keys {
+        if keyboard_input.just_pressed(key) {
+            editor.tool = tool;
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+        editor.width = (editor.width - STAGE_EDITOR_GRID * 2.0).max(STAGE_EDITOR_MIN_WIDTH);
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+        editor.width = (editor.width + STAGE_EDITOR_GRID * 2.0).min(STAGE_EDITOR_MAX_WIDTH);
+    }
+
+    // Switching the stage slot loads that stage's layout and arena (unexported edits are lost)
+    let stage = if keyboard_input.just_pressed(KeyCode::BracketLeft) {
+        editor.stage.saturating_sub(1).max(1)
+    } else if keyboard_input.just_pressed(KeyCode::BracketRight) {
+        (editor.stage + 1).min(MAX_STAGES)
+    } else {
+        editor.stage
+    };
+    if stage != editor.stage {
+        editor.stage = stage;
+        commands.run_system_cached(load_editor_stage);
+        return;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::KeyS) {
+        let file_path = stage_layout_path(editor.stage);
+        editor.status = match editor.layout.save_to_file(&file_path) {
+            Ok(()) => format!("Exported to {}", file_path),
+            Err(e) => format!("Export failed: {}", e),
+        };
+        info!("{}", editor.status);
+    }
+
+    // Mouse position in world space
+    let (Ok(window), Ok((camera, camera_transform))) =
+        (window_query.single(), camera_query.single())
+    else {
+        return;
+    };
+    let Some(cursor) = window
+        .cursor_position()
+        .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok())
+    else {
+        return;
+    };
+    let x = arena.clamp_x(snap(cursor.x));
+    let y = arena.clamp_y(snap(cursor.y));
+
+    if mouse_input.just_pressed(MouseButton::Left) {
+        let width = editor.width;
+        match editor.tool {
+            EditorTool::Platform => editor.layout.platforms.push(PlatformConfig {
+                x,
+                y,
+                width,
+                height: STAGE_EDITOR_PLATFORM_HEIGHT,
+                material: SurfaceMaterial::default(),
+            }),
+            EditorTool::Hazard => editor.layout.hazards.push(PlacedHazardConfig {
+                x,
+                kind: HazardKind::Fire,
+                width,
+                damage_per_second: STAGE_EDITOR_HAZARD_DAMAGE,
+            }),
+            EditorTool::PlayerSpawn => editor.layout.player_spawn = Vec2Config { x, y },
+            EditorTool::BossSpawn => editor.layout.boss_spawn = Vec2Config { x, y },
+        }
+    }
+
+    // Right click removes the platform or hazard under the cursor
+    if mouse_input.just_pressed(MouseButton::Right) {
+        let layout = &mut editor.layout;
+        if let Some(index) = layout.platforms.iter().position(|platform| {
+            (cursor.x - platform.x).abs() <= platform.width / 2.0
+                && (cursor.y - platform.y).abs() <= platform.height / 2.0 + STAGE_EDITOR_GRID
+        }) {
+            layout.platforms.remove(index);
+        } else if let Some(index) = layout.hazards.iter().position(|placed| {
+            (cursor.x - placed.x).abs() <= placed.width / 2.0
+                && cursor.y
+                    <= arena.floor_top() + STAGE_EDITOR_HAZARD_MARKER_HEIGHT + STAGE_EDITOR_GRID
+        }) {
+            layout.hazards.remove(index);
+        }
+    }
+}
+
+/// Draws the layout, a preview of the current tool at the cursor and the help text
+pub fn draw_stage_editor(
+    mut gizmos: Gizmos,
+    editor: Res<StageEditor>,
+    arena: Res<ArenaBounds>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    mut text_query: Query<&mut Text, With<EditorStatusText>>,
+) {
+    let layout = &editor.layout;
+    let hazard_y = arena.floor_top() + STAGE_EDITOR_HAZARD_MARKER_HEIGHT / 2.0;
+    for platform in &layout.platforms {
+        gizmos.rect_2d(
+            Vec2::new(platform.x, platform.y),
+            Vec2::new(platform.width, platform.height),
+            Color::srgb(0.8, 0.8, 0.8),
+        );
+    }
+    for placed in &layout.hazards {
+        gizmos.rect_2d(
+            Vec2::new(placed.x, hazard_y),
+            Vec2::new(placed.width, STAGE_EDITOR_HAZARD_MARKER_HEIGHT),
+            Color::srgb(1.0, 0.4, 0.0),
+        );
+    }
+    gizmos.rect_2d(
+        Vec2::new(layout.player_spawn.x, layout.player_spawn.y),
+        STAGE_EDITOR_SPAWN_MARKER_SIZE,
+        Color::srgb(0.2, 0.4, 1.0),
+    );
+    gizmos.rect_2d(
+        Vec2::new(layout.boss_spawn.x, layout.boss_spawn.y),
+        STAGE_EDITOR_SPAWN_MARKER_SIZE,
+        Color::srgb(1.0, 0.2, 0.2),
+    );
+
+    // Ghost of the item the next click would place
+    if let (Ok(window), Ok((camera, camera_transform))) =
+        (window_query.single(), camera_query.single())
+        && let Some(cursor) = window
+            .cursor_position()
+            .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok())
+    {
+        let x = arena.clamp_x(snap(cursor.x));
+        let y = arena.clamp_y(snap(cursor.y));
+        let (position, size) = match editor.tool {
+            EditorTool::Platform => (
+                Vec2::new(x, y),
+                Vec2::new(editor.width, STAGE_EDITOR_PLATFORM_HEIGHT),
+            ),
+            EditorTool::Hazard => (
+                Vec2::new(x, hazard_y),
+                Vec2::new(editor.width, STAGE_EDITOR_HAZARD_MARKER_HEIGHT),
+            ),
+            EditorTool::PlayerSpawn | EditorTool::BossSpawn => {
+                (Vec2::new(x, y), STAGE_EDITOR_SPAWN_MARKER_SIZE)
+            }
+        };
+        gizmos.rect_2d(position, size, Color::srgba(1.0, 1.0, 0.0, 0.5));
+    }
+
+    if editor.is_changed() {
+        for mut text in &mut text_query {
+            text.0 = format!(
+                "STAGE EDITOR  |  Stage: {} ([ ])  |  Tool: {} (1-4)  |  Width: {:.0} (Left/Right)\n\
+                 Left click: place  |  Right click: remove  |  S: export to {}  |  Esc: back\n\
+                 {} platform(s), {} hazard(s)  |  {}",
+                editor.stage,
+                editor.tool.label(),
+                editor.width,
+                stage_layout_path(editor.stage),
+                layout.platforms.len(),
+                layout.hazards.len(),
+                editor.status,
+            );
+        }
+    }
+}
+
+/// Developer editor for placing platforms, hazards and spawn points and exporting a stage layout
+pub struct StageEditorPlugin;
+
+impl Plugin for StageEditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StageEditor>()
+            .add_systems(
+                OnEnter(GameState::StageEditor),
+                (setup_stage_editor, load_editor_stage).chain(),
+            )
+            .add_systems(
+                Update,
+                (handle_stage_editor_input, draw_stage_editor)
+                    .chain()
+                    .run_if(in_state(GameState::StageEditor)),
+            )
+            .add_systems(
+                OnExit(GameState::StageEditor),
+                (despawn_screen::<EditorEntity>, reset_arena_bounds),
+            );
+    }
+}
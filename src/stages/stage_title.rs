@@ -0,0 +1,96 @@
+use crate::stages::endless::in_endless_mode;
+use crate::stages::game_menu::{CurrentStage, GameState};
+use crate::stages::pattern_preview::in_pattern_preview;
+use crate::systems::boss::{BossPatternRegistry, load_stage_boss_pattern};
+use crate::systems::config::{STAGE_TITLE_CARD_TIME, TUTORIAL_STAGE};
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+
+/// Component for the stage title card shown as a fight starts (fades out, then is removed)
+#[derive(Component)]
+pub struct StageTitleCard {
+    pub timer: f32,
+}
+
+/// Title card text, e.g. "Stage 2 — Fortress of Cheese" (just "Stage 2" if the stage has no title)
+fn stage_title_text(stage: u32, title: Option<&String>) -> String {
+    let stage_label = if stage == TUTORIAL_STAGE {
+        "Tutorial".to_string()
+    } else {
+        format!("Stage {}", stage)
+    };
+    match title {
+        Some(title) => format!("{} — {}", stage_label, title),
+        None => stage_label,
+    }
+}
+
+/// Spawns the stage title card, with the stage name from its boss pattern file
+pub fn spawn_stage_title_card(
+    mut commands: Commands,
+    current_stage: Res<CurrentStage>,
+    pattern_registry: Res<BossPatternRegistry>,
+) {
+    let pattern_name = format!("stage_{}", current_stage.0);
+    let title = pattern_registry
+        .get_pattern(&pattern_name)
+        .and_then(|pattern| pattern.stage_title.as_ref());
+
+    commands.spawn((
+        DespawnOnExit(GameState::InGame),
+        Text::new(stage_title_text(current_stage.0, title)),
+        TextFont {
+            font_size: 48.0,
+            ..default()
+        },
+        TextColor(WHITE.into()),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100.0),
+            top: percent(18.0), // Above the curse banner
+            ..default()
+        },
+        StageTitleCard {
+            timer: STAGE_TITLE_CARD_TIME,
+        },
+    ));
+}
+
+/// System to fade out and remove the stage title card (in real time, so hit-stop and the game
+/// speed setting don't stretch it)
+pub fn update_stage_title_card(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut card_query: Query<(Entity, &mut StageTitleCard, &mut TextColor)>,
+) {
+    for (entity, mut card, mut text_color) in &mut card_query {
+        card.timer -= time.delta_secs();
+        if card.timer <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        // Fade out over the last second
+        text_color.0 = text_color.0.with_alpha(card.timer.min(1.0));
+    }
+}
+
+/// Stage title card ("Stage 2 — Fortress of Cheese") shown when a fight starts
+pub struct StageTitlePlugin;
+
+impl Plugin for StageTitlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameState::InGame),
+            spawn_stage_title_card
+                .after(load_stage_boss_pattern)
+                // The pattern preview and endless waves aren't numbered stages
+                .run_if(not(in_pattern_preview))
+                .run_if(not(in_endless_mode)),
+        )
+        .add_systems(
+            Update,
+            update_stage_title_card.run_if(in_state(GameState::InGame)),
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use crate::components::gameplay::GameplayEntity;
+use crate::components::player::{Dash, Player, Projectile, ProjectileHasHit};
+use crate::stages::game_menu::{CurrentStage, GameState, SelectedCharacter};
+use crate::systems::collision::{ColliderKind, CollisionLookup};
+use crate::systems::config::{
+    BOUNDARY_BOTTOM, CHARGE_SHOT_MAX_TIME, CHARGE_SHOT_MIN_TIME, FLOOR_TOP, TUTORIAL_STAGE,
+};
+use crate::systems::player::{
+    ProjectileHit, ProjectileHitSet, player_movement, projectile_hits_enemies, spawn_boss,
+    spawn_player_and_level,
+};
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+
+// Tutorial course layout (left to right)
+const TUTORIAL_START_X: f32 = -300.0; // Player start (and respawn point after falling into the gap)
+const GAP_LEFT: f32 = -220.0;
+const GAP_RIGHT: f32 = -140.0;
+const TARGET_X: f32 = 0.0;
+const TARGET_SIZE: Vec2 = Vec2::new(32.0, 64.0);
+const GATE_X: f32 = 150.0;
+const GATE_WIDTH: f32 = 12.0;
+const PLAYER_HALF_WIDTH: f32 = 16.0;
+
+/// Scripted steps of the tutorial stage, completed in order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TutorialStep {
+    #[default]
+    JumpGap,
+    ChargeShot,
+    DashGate,
+    PracticeBoss,
+}
+
+impl TutorialStep {
+    fn prompt(self, can_charge: bool) -> &'static str {
+        match self {
+            TutorialStep::JumpGap => "Jump over the gap! (Space or X, hold to jump higher)",
+            TutorialStep::ChargeShot if can_charge => "Hold C and release to break the target with a charged shot!",
+            TutorialStep::ChargeShot => "Press C to shoot the target!",
+            TutorialStep::DashGate => "Dash through the gate! (Down + Jump)",
+            TutorialStep::PracticeBoss => "Defeat the practice boss!",
+        }
+    }
+}
+
+/// Resource to track the current tutorial step
+#[derive(Resource, Default)]
+pub struct TutorialStage {
+    pub step: TutorialStep,
+}
+
+/// Marker component for the tutorial prompt text
+#[derive(Component)]
+pub struct TutorialPrompt;
+
+/// Marker component for the gap the player must jump over
+#[derive(Component)]
+pub struct TutorialGap;
+
+/// Marker component for the target that must be shot (charged shots only, if the character can charge)
+#[derive(Component)]
+pub struct TutorialTarget;
+
+/// Marker component for the gate that can only be passed while dashing
+#[derive(Component)]
+pub struct TutorialGate;
+
+/// Run condition: true while playing the tutorial stage
+pub fn in_tutorial_stage(current_stage: Res<CurrentStage>) -> bool {
+    current_stage.0 == TUTORIAL_STAGE
+}
+
+/// Spawns the tutorial course (gap, target, gate and prompt) and moves the player to the start
+pub fn setup_tutorial_stage(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut tutorial: ResMut<TutorialStage>,
+    selected_character: Res<SelectedCharacter>,
+    mut player_query: Query<&mut Transform, With<Player>>,
+) {
+    tutorial.step = TutorialStep::default();
+
+    for mut transform in &mut player_query {
+        transform.translation.x = TUTORIAL_START_X;
+    }
+
+    // Gap: a pit drawn over the floor
+    let gap_width = GAP_RIGHT - GAP_LEFT;
+    commands.spawn((
+        Mesh2d(meshes.add(Rectangle::new(gap_width, 40.0))),
+        MeshMaterial2d(materials.add(Color::BLACK)),
+        Transform::from_xyz((GAP_LEFT + GAP_RIGHT) / 2.0, FLOOR_TOP - 20.0, 0.1),
+        TutorialGap,
+        GameplayEntity,
+    ));
+
+    // Target blocking the path
+    commands.spawn((
+        Mesh2d(meshes.add(Rectangle::new(TARGET_SIZE.x, TARGET_SIZE.y))),
+        MeshMaterial2d(materials.add(Color::srgb(0.9, 0.8, 0.2))),
+        Transform::from_xyz(TARGET_X, BOUNDARY_BOTTOM, 0.5),
+        TutorialTarget,
+        GameplayEntity,
+    ));
+
+    // Gate that only lets a dashing player through
+    commands.spawn((
+        Mesh2d(meshes.add(Rectangle::new(GATE_WIDTH, 120.0))),
+        MeshMaterial2d(materials.add(Color::srgba(0.3, 0.8, 1.0, 0.6))),
+        Transform::from_xyz(GATE_X, FLOOR_TOP + 60.0, 0.5),
+        TutorialGate,
+        GameplayEntity,
+    ));
+
+    let can_charge = matches!(*selected_character, SelectedCharacter::Breadman);
+    commands.spawn((
+        Text::new(tutorial.step.prompt(can_charge)),
+        TextFont {
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(WHITE.into()),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(110.0),
+            width: percent(100.0),
+            ..default()
+        },
+        TutorialPrompt,
+        DespawnOnExit(GameState::InGame),
+    ));
+}
+
+/// System to apply the course obstacles to the player and advance the tutorial when a step is cleared
+pub fn tutorial_course(
+    mut commands: Commands,
+    mut tutorial: ResMut<TutorialStage>,
+    selected_character: Res<SelectedCharacter>,
+    mut player_query: Query<(&mut Transform, Has<Dash>), With<Player>>,
+    target_query: Query<(Entity, &Transform), (With<TutorialTarget>, Without<Player>)>,
+    gate_query: Query<Entity, With<TutorialGate>>,
+    projectile_query: Query<&Projectile, Without<ProjectileHasHit>>,
+    collisions: CollisionLookup,
+    mut projectile_hit: MessageWriter<ProjectileHit>,
+    mut prompt_query: Query<&mut Text, With<TutorialPrompt>>,
+) {
+    let Ok((mut player_transform, is_dashing)) = player_query.single_mut() else {
+        return;
+    };
+    let can_charge = matches!(*selected_character, SelectedCharacter::Breadman);
+    let step = tutorial.step;
+
+    // Falling into the gap sends the player back to the start
+    let is_grounded = player_transform.translation.y <= BOUNDARY_BOTTOM + 1.0;
+    let x = player_transform.translation.x;
+    if is_grounded && x > GAP_LEFT + PLAYER_HALF_WIDTH && x < GAP_RIGHT - PLAYER_HALF_WIDTH {
+        player_transform.translation.x = TUTORIAL_START_X;
+    }
+
+    // The target blocks the path until it is destroyed
+    if let Ok((target_entity, target_transform)) = target_query.single() {
+        let wall_x = target_transform.translation.x - (TARGET_SIZE.x / 2.0 + PLAYER_HALF_WIDTH);
+        player_transform.translation.x = player_transform.translation.x.min(wall_x);
+
+        let hits = collisions.overlapping(
+            target_transform.translation.truncate(),
+            TARGET_SIZE,
+            ColliderKind::PlayerProjectile,
+        );
+        for projectile_entity in hits {
+            let Ok(projectile) = projectile_query.get(projectile_entity) else {
+                continue;
+            };
+            commands.entity(projectile_entity).insert(ProjectileHasHit);
+            projectile_hit.write(ProjectileHit(projectile_entity));
+
+            let is_charged_shot =
+                projectile.charge_level >= CHARGE_SHOT_MIN_TIME / CHARGE_SHOT_MAX_TIME;
+            if step == TutorialStep::ChargeShot && (is_charged_shot || !can_charge) {
+                commands.entity(target_entity).despawn();
+            }
+        }
+    }
+
+    // The gate blocks the path unless the player is dashing
+    if let Ok(gate_entity) = gate_query.single() {
+        let wall_x = GATE_X - (GATE_WIDTH / 2.0 + PLAYER_HALF_WIDTH);
+        let dash_allowed = step == TutorialStep::DashGate && is_dashing;
+        if !dash_allowed && player_transform.translation.x > wall_x {
+            player_transform.translation.x = wall_x;
+        }
+        if step == TutorialStep::DashGate
+            && player_transform.translation.x > GATE_X + GATE_WIDTH / 2.0 + PLAYER_HALF_WIDTH
+        {
+            commands.entity(gate_entity).despawn();
+        }
+    }
+
+    // Completion sensors
+    let x = player_transform.translation.x;
+    let next_step = match step {
+        TutorialStep::JumpGap if x > GAP_RIGHT + PLAYER_HALF_WIDTH => Some(TutorialStep::ChargeShot),
+        TutorialStep::ChargeShot if target_query.is_empty() => Some(TutorialStep::DashGate),
+        TutorialStep::DashGate if x > GATE_X + GATE_WIDTH / 2.0 + PLAYER_HALF_WIDTH => {
+            Some(TutorialStep::PracticeBoss)
+        }
+        _ => None,
+    };
+
+    if let Some(next_step) = next_step {
+        tutorial.step = next_step;
+        for mut text in &mut prompt_query {
+            text.0 = next_step.prompt(can_charge).to_string();
+        }
+
+        if next_step == TutorialStep::PracticeBoss {
+            // The practice boss uses the regular spawn system (its HP bar appears on its own)
+            commands.run_system_cached(spawn_boss);
+        }
+    }
+}
+
+/// Scripted tutorial course (stage 0): gap, charge target and dash gate before a weak practice boss
+pub struct TutorialStagePlugin;
+
+impl Plugin for TutorialStagePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TutorialStage>()
+            .add_systems(
+                OnEnter(GameState::InGame),
+                setup_tutorial_stage
+                    .after(spawn_player_and_level)
+                    .run_if(in_tutorial_stage),
+            )
+            .add_systems(
+                Update,
+                // Shots already spent on an enemy this frame can't also hit the target
+                tutorial_course
+                    .after(player_movement)
+                    .after(projectile_hits_enemies)
+                    .in_set(ProjectileHitSet)
+                    .run_if(in_state(GameState::InGame).and(in_tutorial_stage)),
+            );
+    }
+}
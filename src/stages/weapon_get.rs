@@ -0,0 +1,177 @@
+use crate::components::boss::BossRegistry;
+use crate::stages::game_menu::{DefeatedBoss, GameState, SelectedCharacter, despawn_screen};
+use crate::systems::config::{
+    BOSS_WEAPON_DAMAGE_MULTIPLIER, WEAPON_ENERGY_MAX, WEAPON_ENERGY_PER_SHOT,
+};
+use crate::systems::projectile_styles::ProjectileStyles;
+use crate::systems::weapons::weapon_recolor;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+
+/// Marker component for the weapon get screen UI root
+#[derive(Component)]
+pub struct WeaponGetScreen;
+
+/// Player-shaped swatch of `color`, for the recolor preview
+fn player_swatch(parent: &mut ChildSpawnerCommands, color: Color) {
+    parent.spawn((
+        Node {
+            width: px(48.0),
+            height: px(96.0),
+            ..default()
+        },
+        BackgroundColor(color),
+    ));
+}
+
+/// Spawns the weapon get screen for the boss weapon just acquired: its name and icon, what it
+/// does, and how the player looks with it equipped
+pub fn spawn_weapon_get_screen(
+    mut commands: Commands,
+    defeated_boss: Res<DefeatedBoss>,
+    boss_registry: Res<BossRegistry>,
+    projectile_styles: Res<ProjectileStyles>,
+    selected_character: Res<SelectedCharacter>,
+) {
+    let boss_data = defeated_boss
+        .boss_type
+        .and_then(|boss_type| boss_registry.get_boss_data(boss_type));
+    let name = boss_data.map_or_else(|| "Boss".to_string(), |data| data.name.clone());
+    let weapon_color = boss_data.map_or(Color::WHITE, |data| data.color);
+    let description = format!(
+        "Shots hit {}x harder than the buster.\n\
+         {} shots per stage, refilled after every stage.\n\
+         Hold Tab in a fight to equip it.",
+        BOSS_WEAPON_DAMAGE_MULTIPLIER,
+        (WEAPON_ENERGY_MAX / WEAPON_ENERGY_PER_SHOT) as u32
+    );
+
+    commands
+        .spawn((
+            Node {
+                width: percent(100.0),
+                height: percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: px(30.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.1, 0.1, 0.2)),
+            WeaponGetScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("WEAPON GET!"),
+                TextFont {
+                    font_size: 56.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.9, 0.0)),
+            ));
+
+            // Icon and name
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: px(20.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Node {
+                            width: px(64.0),
+                            height: px(64.0),
+                            border: UiRect::all(px(4.0)),
+                            ..default()
+                        },
+                        BackgroundColor(weapon_color),
+                        BorderColor::all(WHITE),
+                    ));
+                    row.spawn((
+                        Text::new(format!("{} Weapon", name)),
+                        TextFont {
+                            font_size: 40.0,
+                            ..default()
+                        },
+                        TextColor(WHITE.into()),
+                    ));
+                });
+
+            parent.spawn((
+                Text::new(description),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+
+            // Recolor preview: the player now, and with the weapon equipped
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: px(30.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    player_swatch(row, selected_character.color());
+                    row.spawn((
+                        Text::new("->"),
+                        TextFont {
+                            font_size: 40.0,
+                            ..default()
+                        },
+                        TextColor(WHITE.into()),
+                    ));
+                    player_swatch(
+                        row,
+                        weapon_recolor(
+                            *selected_character,
+                            defeated_boss.boss_type,
+                            &boss_registry,
+                            &projectile_styles,
+                        ),
+                    );
+                });
+
+            parent.spawn((
+                Text::new("Press Enter to continue"),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+/// System to move on to the shop once the player has seen the new weapon
+pub fn handle_weapon_get_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Enter) || keyboard_input.just_pressed(KeyCode::Space) {
+        next_state.set(GameState::Shop);
+    }
+}
+
+/// Weapon get screen shown after acquiring a boss weapon on the upgrade screen, before the shop
+pub struct WeaponGetPlugin;
+
+impl Plugin for WeaponGetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::WeaponGet), spawn_weapon_get_screen)
+            .add_systems(
+                Update,
+                handle_weapon_get_input.run_if(in_state(GameState::WeaponGet)),
+            )
+            .add_systems(
+                OnExit(GameState::WeaponGet),
+                despawn_screen::<WeaponGetScreen>,
+            );
+    }
+}
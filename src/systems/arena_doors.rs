@@ -0,0 +1,182 @@
+use crate::components::gameplay::GameplayEntity;
+use crate::components::player::{Player, PlayerVelocity};
+use crate::stages::game_menu::{CurrentStage, GameState};
+use crate::systems::boss::BossPatternRegistry;
+use crate::systems::boundaries::ArenaBounds;
+use crate::systems::collision::PLAYER_HITBOX_SIZE;
+use crate::systems::config::{
+    ARENA_DOOR_HEIGHT, ARENA_DOOR_OPEN_TIME, ARENA_DOOR_WIDTH, ARENA_WALK_IN_TIME,
+};
+use crate::systems::game_config::GameConfig;
+use bevy::prelude::*;
+
+/// Where a stage with arena doors is in its fight
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DoorPhase {
+    #[default]
+    NoDoors, // Stage without doors: the fight starts right away
+    Corridor,     // Player making their way along the approach corridor to the entrance
+    WalkIn(f32),  // Player walking in through the entrance (seconds left)
+    Locked,       // Doors shut until the boss is beaten
+    Opening(f32), // Boss beaten, doors sliding open before the win screen (seconds left)
+    Open,
+}
+
+/// Resource with the state of the current stage's arena doors (Mega Man-style boss gates),
+/// set up from the `arena_doors` entry of the stage's boss pattern file (always on with a corridor)
+#[derive(Resource, Default)]
+pub struct ArenaDoors {
+    pub phase: DoorPhase,
+}
+
+impl ArenaDoors {
+    pub fn is_locked(&self) -> bool {
+        self.phase == DoorPhase::Locked
+    }
+
+    /// Start opening the doors; the win screen follows once they are open
+    pub fn open(&mut self) {
+        self.phase = DoorPhase::Opening(ARENA_DOOR_OPEN_TIME);
+    }
+
+    /// Whether a door should be open: only the entrance is open during the walk-in
+    fn door_open(&self, door: &ArenaDoor) -> bool {
+        match self.phase {
+            DoorPhase::Corridor | DoorPhase::WalkIn(_) => door.entrance,
+            DoorPhase::Locked => false,
+            DoorPhase::NoDoors | DoorPhase::Opening(_) | DoorPhase::Open => true,
+        }
+    }
+}
+
+/// Component for an arena door, sitting in the left (entrance) or right wall
+#[derive(Component)]
+pub struct ArenaDoor {
+    pub closed_y: f32,
+    pub entrance: bool,
+}
+
+/// Run condition: false until the doors of a doored stage have shut (gates the boss AI)
+pub fn fight_started(doors: Res<ArenaDoors>) -> bool {
+    !matches!(doors.phase, DoorPhase::Corridor | DoorPhase::WalkIn(_))
+}
+
+/// Run condition: false during the scripted walk-in (gates the player's controls)
+pub fn player_in_control(doors: Res<ArenaDoors>) -> bool {
+    !matches!(doors.phase, DoorPhase::WalkIn(_))
+}
+
+/// Spawns the doors of stages whose pattern asks for them, with the entrance open and the player
+/// standing in it, ready for the walk-in (stages with a corridor move the player back to its
+/// start, see `spawn_corridor`)
+pub fn setup_arena_doors(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    pattern_registry: Res<BossPatternRegistry>,
+    current_stage: Res<CurrentStage>,
+    arena: Res<ArenaBounds>,
+    mut doors: ResMut<ArenaDoors>,
+    mut player_query: Query<&mut Transform, With<Player>>,
+) {
+    let pattern = pattern_registry.get_pattern(&format!("stage_{}", current_stage.0));
+    doors.phase = match pattern {
+        Some(pattern) if pattern.corridor.is_some() => DoorPhase::Corridor,
+        Some(pattern) if pattern.arena_doors => DoorPhase::WalkIn(ARENA_WALK_IN_TIME),
+        _ => DoorPhase::NoDoors,
+    };
+    if doors.phase == DoorPhase::NoDoors {
+        return;
+    }
+
+    for mut transform in &mut player_query {
+        transform.translation.x = arena.left + PLAYER_HITBOX_SIZE.x * 0.5;
+    }
+
+    // Doors stand on the floor; an open door is slid up out of the way
+    let closed_y = arena.bottom - PLAYER_HITBOX_SIZE.y * 0.5 + ARENA_DOOR_HEIGHT * 0.5;
+    let mesh = meshes.add(Rectangle::new(ARENA_DOOR_WIDTH, ARENA_DOOR_HEIGHT));
+    let material = materials.add(Color::srgb(0.55, 0.5, 0.6));
+    for (x, entrance) in [(arena.left, true), (arena.right, false)] {
+        let door = ArenaDoor { closed_y, entrance };
+        let y = if doors.door_open(&door) {
+            closed_y + ARENA_DOOR_HEIGHT
+        } else {
+            closed_y
+        };
+        commands.spawn((
+            GameplayEntity,
+            Mesh2d(mesh.clone()),
+            MeshMaterial2d(material.clone()),
+            Transform::from_xyz(x, y, 0.6), // In front of the walls and the player
+            door,
+        ));
+    }
+}
+
+/// System to run the door script: walk the player in, shut the doors behind them, and once
+/// the boss is beaten (see `check_game_outcome`) open them again before the win screen
+pub fn update_arena_doors(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    arena: Res<ArenaBounds>,
+    mut doors: ResMut<ArenaDoors>,
+    mut player_query: Query<(&mut Transform, &mut PlayerVelocity), With<Player>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let dt = time.delta_secs();
+    match doors.phase {
+        DoorPhase::WalkIn(timer) => {
+            for (mut transform, mut velocity) in &mut player_query {
+                velocity.facing_direction = Vec2::X;
+                let x = transform.translation.x + config.move_speed * dt;
+                transform.translation.x = arena.clamp_x(x);
+            }
+
+            let timer = timer - dt;
+            doors.phase = if timer > 0.0 {
+                DoorPhase::WalkIn(timer)
+            } else {
+                DoorPhase::Locked // Shut behind the player
+            };
+        }
+        DoorPhase::Opening(timer) => {
+            let timer = timer - dt;
+            if timer > 0.0 {
+                doors.phase = DoorPhase::Opening(timer);
+            } else {
+                doors.phase = DoorPhase::Open;
+                next_state.set(GameState::GameWin);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// System to slide the doors toward their open or closed height
+pub fn animate_arena_doors(
+    time: Res<Time>,
+    doors: Res<ArenaDoors>,
+    mut door_query: Query<(&ArenaDoor, &mut Transform)>,
+) {
+    let speed = ARENA_DOOR_HEIGHT / ARENA_DOOR_OPEN_TIME;
+    for (door, mut transform) in &mut door_query {
+        let target = if doors.door_open(door) {
+            door.closed_y + ARENA_DOOR_HEIGHT
+        } else {
+            door.closed_y
+        };
+        let step = speed * time.delta_secs();
+        let y = transform.translation.y;
+        transform.translation.y = if (target - y).abs() <= step {
+            target
+        } else {
+            y + step * (target - y).signum()
+        };
+    }
+}
+
+/// System to forget the doors once the fight is over
+pub fn reset_arena_doors(mut doors: ResMut<ArenaDoors>) {
+    *doors = ArenaDoors::default();
+}
@@ -0,0 +1,87 @@
+use crate::components::gameplay::GameplayEntity;
+use crate::components::player::Hp;
+use crate::plugins::camera_plugin::EFFECTS_RENDER_LAYER;
+use crate::plugins::particle_plugin::{FeedbackParticle, FeedbackParticleAssets};
+use crate::plugins::shield_plugin::spawn_shield_pickup_at;
+use crate::systems::collision::{Enemy, Hitbox};
+use crate::systems::config::{BLOCK_DEBRIS_COUNT, BLOCK_DEBRIS_SPEED, SHIELD_PICKUP_SIZE};
+use crate::systems::platforms::StagePlatforms;
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+
+pub const BLOCK_COLOR: Color = Color::srgb(0.6, 0.45, 0.3);
+
+/// Component for a breakable block: solid ground for as long as it stands (see
+/// `StagePlatforms::blocks`). Player shots wear it down and a charged shot smashes it outright.
+#[derive(Component)]
+pub struct BreakableBlock {
+    pub rect: Rect,
+    pub pickup: bool, // Leaves a shield pickup where it stood when broken
+}
+
+/// Spawns a breakable block covering `rect`
+pub fn spawn_breakable_block(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    material: Handle<ColorMaterial>,
+    rect: Rect,
+    hp: f32,
+    pickup: bool,
+) -> Entity {
+    commands
+        .spawn((
+            GameplayEntity,
+            Mesh2d(meshes.add(Rectangle::new(rect.width(), rect.height()))),
+            MeshMaterial2d(material),
+            Transform::from_translation(rect.center().extend(0.2)),
+            BreakableBlock { rect, pickup },
+            Enemy,
+            Hitbox(rect.size()),
+            Hp {
+                current: hp,
+                max: hp,
+            },
+        ))
+        .id()
+}
+
+/// System to break apart blocks shot down to 0 HP: they burst into debris, stop being solid and
+/// leave behind the pickup hidden inside, if any
+pub fn break_blocks(
+    mut commands: Commands,
+    mut platforms: ResMut<StagePlatforms>,
+    particle_assets: Res<FeedbackParticleAssets>,
+    block_query: Query<(Entity, &BreakableBlock, &Hp, &MeshMaterial2d<ColorMaterial>), Changed<Hp>>,
+) {
+    for (entity, block, hp, material) in &block_query {
+        if hp.current > 0.0 {
+            continue;
+        }
+
+        platforms.blocks.retain(|(solid, _)| *solid != entity);
+        commands.entity(entity).despawn();
+
+        // Debris flies out evenly all around, in the block's own color
+        let origin = block.rect.center();
+        for i in 0..BLOCK_DEBRIS_COUNT {
+            let angle = std::f32::consts::TAU * i as f32 / BLOCK_DEBRIS_COUNT as f32;
+            commands.spawn((
+                Mesh2d(particle_assets.mesh.clone()),
+                MeshMaterial2d(material.0.clone()),
+                Transform::from_xyz(origin.x, origin.y, 0.0),
+                RenderLayers::layer(EFFECTS_RENDER_LAYER),
+                FeedbackParticle {
+                    velocity: Vec2::from_angle(angle) * BLOCK_DEBRIS_SPEED,
+                    age: 0.0,
+                },
+            ));
+        }
+
+        if block.pickup {
+            spawn_shield_pickup_at(
+                &mut commands,
+                Vec2::new(origin.x, block.rect.min.y + SHIELD_PICKUP_SIZE * 0.5),
+            );
+        }
+    }
+}
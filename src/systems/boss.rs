@@ -1,11 +1,32 @@
 use crate::components::boss::*;
+use crate::components::gameplay::GameplayEntity;
 use crate::components::player::*;
+use crate::plugins::medal_plugin::Medal;
+use crate::stages::game_menu::{BossCheckpoint, CurrentStage, FightCheckpoint, GameState};
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::boss_ai::BehaviorNode;
+use crate::systems::boundaries::ArenaBounds;
+use crate::systems::collision::{ColliderKind, CollisionLookup, PLAYER_HITBOX_SIZE};
 use crate::systems::config::{
-    BOSS_HP_BAR_HEIGHT, BOSS_HP_BAR_MARGIN_BOTTOM, BOSS_HP_BAR_MARGIN_LEFT,
-    BOSS_HP_BAR_MARGIN_RIGHT, BOSS_HP_BAR_MARGIN_TOP, BOSS_HP_BAR_USE_CENTER, BOSS_HP_BAR_WIDTH,
-    BOUNDARY_BOTTOM, BOUNDARY_LEFT, BOUNDARY_RIGHT, BOUNDARY_TOP, KNOCKBACK_DURATION,
-    KNOCKBACK_FORCE,
+    BOSS_BEAM_ACTIVE_ALPHA, BOSS_BEAM_TELEGRAPH_ALPHA, BOSS_BEAM_TELEGRAPH_WIDTH,
+    BOSS_DESPERATION_HP_FRACTION, BOSS_HP_BAR_HEIGHT, BOSS_HP_BAR_MARGIN_BOTTOM, BOSS_HP_BAR_MARGIN_LEFT,
+    BOSS_HP_BAR_MARGIN_RIGHT, BOSS_HP_BAR_MARGIN_TOP, BOSS_HP_BAR_SPACING, BOSS_HP_BAR_USE_CENTER,
+    BOSS_HP_BAR_WIDTH, BOSS_MELEE_ACTIVE_ALPHA, BOSS_MELEE_TELEGRAPH_ALPHA, BOSS_PATTERNS_DIR,
+    BOSS_PHASE_TWO_HP_FRACTION, KNOCKBACK_SIDE_VERTICAL_COMPONENT, SHOCKWAVE_HEIGHT, SHOCKWAVE_WIDTH,
+    WATER_GRAVITY_SCALE, WATER_PROJECTILE_SCALE,
 };
+use crate::systems::feedback::FeedbackEvent;
+use crate::systems::game_config::GameConfig;
+use crate::systems::hazards::{HazardConfig, spawn_hazard};
+use crate::systems::health::{Overshield, take_damage};
+use crate::systems::hit_stop::HitStop;
+use crate::systems::palette::ColorPalette;
+use crate::systems::pattern_validation::{PatternDiagnostics, PatternReport, check_pattern_file};
+use crate::systems::platforms::Ground;
+use crate::systems::player::{ProjectileAssets, ProjectileHit};
+use crate::systems::spawn::ProjectileBundle;
+use crate::systems::stage_layout::PlatformConfig;
+use crate::systems::stage_modifier::StageModifier;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +35,100 @@ use serde::{Deserialize, Serialize};
 pub struct BossPatternConfig {
     pub attack: AttackPatternConfig,
     pub movement: MovementPatternConfig,
+    /// Optional behavior tree that switches between attack/movement patterns at runtime
+    #[serde(default)]
+    pub behavior: Option<BehaviorNode>,
+    /// Optional boss max HP (defaults to BOSS_DEFAULT_HP)
+    #[serde(default)]
+    pub max_hp: Option<f32>,
+    /// Number of copies of this boss fought at once (e.g. 2 for a duo fight, defaults to 1)
+    #[serde(default = "default_boss_count")]
+    pub count: u32,
+    /// Optional boss name shown on the nameplate above its HP bar
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Optional stage name shown on the title card as the fight starts (e.g. "Fortress of Cheese")
+    #[serde(default)]
+    pub stage_title: Option<String>,
+    /// Optional arena walls and ceiling for the stage (defaults to the BOUNDARY_* constants)
+    #[serde(default)]
+    pub arena: Option<ArenaConfig>,
+    /// Keep the boss on the floor and platforms, falling when it walks off a ledge
+    /// (always on for the jumping movement patterns)
+    #[serde(default)]
+    pub grounded: bool,
+    /// Close doors behind the player after a short walk-in, reopening them when the boss is beaten
+    #[serde(default)]
+    pub arena_doors: bool,
+    /// Optional side-scrolling approach to the arena, fought through before the doors shut
+    #[serde(default)]
+    pub corridor: Option<CorridorConfig>,
+    /// Hard-to-reach spots of the stage's secret tokens (world coordinates)
+    #[serde(default)]
+    pub secret_tokens: Vec<Vec2Config>,
+    /// Optional clear times (seconds) for the stage's bronze, silver and gold medals
+    #[serde(default)]
+    pub medals: Option<MedalTimes>,
+}
+
+/// JSON structure for a stage's arena bounds (the floor is the same in every stage)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArenaConfig {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+}
+
+/// JSON structure for a stage's approach corridor, running left from the arena's left wall.
+/// Positions along it are measured from its far (left) end, where the player starts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorridorConfig {
+    pub length: f32,
+    #[serde(default)]
+    pub platforms: Vec<PlatformConfig>,
+    #[serde(default)]
+    pub minions: Vec<MinionConfig>,
+    #[serde(default)]
+    pub pickups: Vec<f32>, // Positions of shield pickups on the floor
+    #[serde(default)]
+    pub checkpoints: Vec<f32>, // Positions of checkpoint flags a retry can start from
+}
+
+/// JSON structure for a corridor minion
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinionConfig {
+    pub x: f32,
+    #[serde(default)]
+    pub patrol: f32, // Distance it walks either side of `x`
+    #[serde(default)]
+    pub hp: Option<f32>, // Defaults to MINION_DEFAULT_HP
+}
+
+/// JSON structure for a stage's medal times: clearing it within a time earns that medal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MedalTimes {
+    pub gold: f32,
+    pub silver: f32,
+    pub bronze: f32,
+}
+
+impl MedalTimes {
+    /// Best medal a clear in `time` seconds earns, if any
+    pub fn medal_for(&self, time: f32) -> Option<Medal> {
+        if time <= self.gold {
+            Some(Medal::Gold)
+        } else if time <= self.silver {
+            Some(Medal::Silver)
+        } else if time <= self.bronze {
+            Some(Medal::Bronze)
+        } else {
+            None
+        }
+    }
+}
+
+fn default_boss_count() -> u32 {
+    1
 }
 
 /// JSON structure for attack patterns
@@ -25,19 +140,91 @@ pub enum AttackPatternConfig {
         cooldown: f32,
         projectile_speed: f32,
         #[serde(default)]
+        gravity: f32,
+        #[serde(default)]
         cardinal_only: bool,
     },
     TripleShot {
         cooldown: f32,
         projectile_speed: f32,
+        #[serde(default)]
+        gravity: f32,
         spread_angle: f32,
     },
     RapidFire {
         cooldown: f32,
         projectile_speed: f32,
+        #[serde(default)]
+        gravity: f32,
         burst_count: u32,
         burst_delay: f32,
     },
+    /// Melee swing toward the player: `windup` telegraph, `active` damaging window, then `recovery`
+    Melee {
+        cooldown: f32,
+        windup: f32,
+        active: f32,
+        recovery: f32,
+        reach: f32,
+        height: f32,
+    },
+    /// Ring of `count` projectiles in every direction.
+    /// `angular_velocity` (degrees/second) curves each projectile's path after it is fired.
+    Ring {
+        cooldown: f32,
+        projectile_speed: f32,
+        #[serde(default)]
+        gravity: f32,
+        count: u32,
+        #[serde(default)]
+        angular_velocity: f32,
+    },
+    /// Rotating emitter firing `arms` evenly spaced streams every `cooldown` seconds,
+    /// turning `rotation_speed` degrees/second between shots
+    Spiral {
+        cooldown: f32,
+        projectile_speed: f32,
+        #[serde(default)]
+        gravity: f32,
+        arms: u32,
+        rotation_speed: f32,
+        #[serde(default)]
+        angular_velocity: f32,
+    },
+    /// Fan of `count` projectiles spread evenly over `spread_angle` degrees, centered on the player
+    Fan {
+        cooldown: f32,
+        projectile_speed: f32,
+        #[serde(default)]
+        gravity: f32,
+        count: u32,
+        spread_angle: f32,
+        #[serde(default)]
+        angular_velocity: f32,
+    },
+    /// Arcing shells that land on the player after `flight_time` seconds.
+    /// `count` shells land `spacing` pixels apart, centered on the player.
+    Mortar {
+        cooldown: f32,
+        flight_time: f32,
+        gravity: f32,
+        #[serde(default = "default_mortar_count")]
+        count: u32,
+        #[serde(default)]
+        spacing: f32,
+        /// Ground hazard left where each shell lands
+        #[serde(default)]
+        hazard: Option<HazardConfig>,
+    },
+    /// Laser: a `telegraph` line, then a beam sweeping `sweep_angle` degrees across the player for `duration`
+    Beam {
+        cooldown: f32,
+        telegraph: f32,
+        duration: f32,
+        sweep_angle: f32,
+        length: f32,
+        width: f32,
+    },
     /// Pattern with multiple actions in sequence
     Sequence {
         actions: Vec<AttackAction>,
@@ -45,6 +232,10 @@ pub enum AttackPatternConfig {
     },
 }
 
+fn default_mortar_count() -> u32 {
+    1
+}
+
 /// Individual attack action in a sequence
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttackAction {
@@ -94,6 +285,25 @@ pub enum MovementPatternConfig {
         speed: f32,
         loop_path: bool,
     },
+    /// Walk toward the player, stopping at `stop_distance`
+    Chase {
+        speed: f32,
+        stop_distance: f32,
+    },
+    /// Back away from the player until hitting a wall
+    Retreat {
+        speed: f32,
+    },
+    /// Jump in an arc toward the player every `interval` seconds (landing sends out shockwaves)
+    JumpTowardPlayer {
+        interval: f32,
+        arc_height: f32,
+    },
+    /// Leap `height` pixels above the player, hang, then slam down (landing sends out shockwaves)
+    GroundSlam {
+        interval: f32,
+        height: f32,
+    },
 }
 
 /// Resource to store loaded boss patterns from JSON
@@ -103,22 +313,14 @@ pub struct BossPatternRegistry {
 }
 
 impl BossPatternRegistry {
-    /// Load a pattern from a JSON string
-    pub fn load_from_json(&mut self, name: String, json: &str) -> Result<(), serde_json::Error> {
-        let pattern: BossPatternConfig = serde_json::from_str(json)?;
-        self.patterns.insert(name, pattern);
-        Ok(())
-    }
-
-    /// Load a pattern from a JSON file path
-    pub fn load_from_file(
-        &mut self,
-        name: String,
-        file_path: &str,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        let json = std::fs::read_to_string(file_path)?;
-        self.load_from_json(name, &json)?;
-        Ok(())
+    /// Load and validate a pattern from a JSON file path.
+    /// The pattern is only registered if it has no errors; the report lists every problem found.
+    pub fn load_from_file(&mut self, name: String, file_path: &str) -> PatternReport {
+        let (pattern, report) = check_pattern_file(file_path);
+        if let Some(pattern) = pattern {
+            self.patterns.insert(name, pattern);
+        }
+        report
     }
 
     /// Get a pattern by name
@@ -134,32 +336,126 @@ pub fn convert_attack_pattern(config: &AttackPatternConfig) -> AttackPattern {
         AttackPatternConfig::SingleShot {
             cooldown,
             projectile_speed,
+            gravity,
             cardinal_only,
         } => AttackPattern::SingleShot {
             cooldown: *cooldown,
             projectile_speed: *projectile_speed,
+            gravity: *gravity,
             cardinal_only: *cardinal_only,
         },
         AttackPatternConfig::TripleShot {
             cooldown,
             projectile_speed,
+            gravity,
             spread_angle,
         } => AttackPattern::TripleShot {
             cooldown: *cooldown,
             projectile_speed: *projectile_speed,
+            gravity: *gravity,
             spread_angle: *spread_angle,
         },
         AttackPatternConfig::RapidFire {
             cooldown,
             projectile_speed,
+            gravity,
             burst_count,
             burst_delay,
         } => AttackPattern::RapidFire {
             cooldown: *cooldown,
             projectile_speed: *projectile_speed,
+            gravity: *gravity,
             burst_count: *burst_count,
             burst_delay: *burst_delay,
         },
+        AttackPatternConfig::Melee {
+            cooldown,
+            windup,
+            active,
+            recovery,
+            reach,
+            height,
+        } => AttackPattern::Melee {
+            cooldown: *cooldown,
+            windup: *windup,
+            active: *active,
+            recovery: *recovery,
+            reach: *reach,
+            height: *height,
+        },
+        AttackPatternConfig::Ring {
+            cooldown,
+            projectile_speed,
+            gravity,
+            count,
+            angular_velocity,
+        } => AttackPattern::Ring {
+            cooldown: *cooldown,
+            projectile_speed: *projectile_speed,
+            gravity: *gravity,
+            count: *count,
+            angular_velocity: *angular_velocity,
+        },
+        AttackPatternConfig::Spiral {
+            cooldown,
+            projectile_speed,
+            gravity,
+            arms,
+            rotation_speed,
+            angular_velocity,
+        } => AttackPattern::Spiral {
+            cooldown: *cooldown,
+            projectile_speed: *projectile_speed,
+            gravity: *gravity,
+            arms: *arms,
+            rotation_speed: *rotation_speed,
+            angular_velocity: *angular_velocity,
+        },
+        AttackPatternConfig::Fan {
+            cooldown,
+            projectile_speed,
+            gravity,
+            count,
+            spread_angle,
+            angular_velocity,
+        } => AttackPattern::Fan {
+            cooldown: *cooldown,
+            projectile_speed: *projectile_speed,
+            gravity: *gravity,
+            count: *count,
+            spread_angle: *spread_angle,
+            angular_velocity: *angular_velocity,
+        },
+        AttackPatternConfig::Mortar {
+            cooldown,
+            flight_time,
+            gravity,
+            count,
+            spacing,
+            hazard,
+        } => AttackPattern::Mortar {
+            cooldown: *cooldown,
+            flight_time: *flight_time,
+            gravity: *gravity,
+            count: *count,
+            spacing: *spacing,
+            hazard: hazard.clone(),
+        },
+        AttackPatternConfig::Beam {
+            cooldown,
+            telegraph,
+            duration,
+            sweep_angle,
+            length,
+            width,
+        } => AttackPattern::Beam {
+            cooldown: *cooldown,
+            telegraph: *telegraph,
+            duration: *duration,
+            sweep_angle: *sweep_angle,
+            length: *length,
+            width: *width,
+        },
         AttackPatternConfig::Sequence { .. } => {
             // For now, treat sequence as None - can be extended later
             AttackPattern::None
@@ -202,36 +498,199 @@ pub fn convert_movement_pattern(config: &MovementPatternConfig) -> MovementPatte
             // For now, treat waypoint as Stationary - can be extended later
             MovementPattern::Stationary
         }
+        MovementPatternConfig::Chase {
+            speed,
+            stop_distance,
+        } => MovementPattern::Chase {
+            speed: *speed,
+            stop_distance: *stop_distance,
+        },
+        MovementPatternConfig::Retreat { speed } => MovementPattern::Retreat { speed: *speed },
+        MovementPatternConfig::JumpTowardPlayer {
+            interval,
+            arc_height,
+        } => MovementPattern::JumpTowardPlayer {
+            interval: *interval,
+            arc_height: *arc_height,
+        },
+        MovementPatternConfig::GroundSlam { interval, height } => MovementPattern::GroundSlam {
+            interval: *interval,
+            height: *height,
+        },
     }
 }
 
-/// System to load boss pattern for the current stage
+/// System to load boss pattern for the current stage.
+/// Invalid files are rejected with diagnostics (logged and shown in the debug panel) and the default pattern is used.
 pub fn load_stage_boss_pattern(
     mut pattern_registry: ResMut<BossPatternRegistry>,
+    mut diagnostics: ResMut<PatternDiagnostics>,
     current_stage: Res<crate::stages::game_menu::CurrentStage>,
 ) {
-    let stage_num = current_stage.0;
+    load_boss_pattern_for_stage(&mut pattern_registry, &mut diagnostics, current_stage.0);
+}
+
+/// Loads the boss pattern file of a stage into the registry (once), recording its diagnostics
+pub fn load_boss_pattern_for_stage(
+    pattern_registry: &mut BossPatternRegistry,
+    diagnostics: &mut PatternDiagnostics,
+    stage_num: u32,
+) {
     let pattern_name = format!("stage_{}", stage_num);
-    let file_path = format!("boss_patterns/stage_{}_boss.json", stage_num);
+    let file_path = format!("{}/stage_{}_boss.json", BOSS_PATTERNS_DIR, stage_num);
 
     // Only load if not already loaded
-    if pattern_registry.get_pattern(&pattern_name).is_none() {
-        if let Err(e) = pattern_registry.load_from_file(pattern_name.clone(), &file_path) {
-            eprintln!(
-                "Warning: Failed to load boss pattern from {}: {}",
-                file_path, e
-            );
-            eprintln!("Using default boss pattern instead");
+    if pattern_registry.get_pattern(&pattern_name).is_some() {
+        return;
+    }
+
+    let report = pattern_registry.load_from_file(pattern_name, &file_path);
+    let rejected = report.has_errors();
+    diagnostics.record(report);
+    if rejected {
+        warn!("Using default boss pattern for stage {}", stage_num);
+    }
+}
+
+/// System to advance the boss fight phase based on remaining HP.
+/// Triggers hit-stop on transition and records a checkpoint the fight can be retried from, with
+/// the HP and phase of every boss of the fight.
+pub fn update_boss_phase(
+    mut boss_query: Query<(&Hp, &mut BossPhaseState, &BossSpawnIndex), With<Boss>>,
+    player_query: Query<&Hp, (With<Player>, Without<Boss>)>,
+    mut hit_stop: ResMut<HitStop>,
+    mut checkpoint: ResMut<FightCheckpoint>,
+    current_stage: Res<CurrentStage>,
+) {
+    let mut phase_reached = false;
+    for (hp, mut phase_state, _) in &mut boss_query {
+        let health_fraction = (hp.current / hp.max).clamp(0.0, 1.0);
+        let phase = if health_fraction <= BOSS_PHASE_TWO_HP_FRACTION {
+            2
+        } else {
+            1
+        };
+        // Like phases, desperation doesn't wear off if the boss heals
+        if health_fraction <= BOSS_DESPERATION_HP_FRACTION {
+            phase_state.desperate = true;
+        }
+
+        if phase > phase_state.phase {
+            phase_state.phase = phase;
+            hit_stop.trigger();
+            phase_reached = true;
         }
     }
+
+    if !phase_reached {
+        return;
+    }
+    let Ok(player_hp) = player_query.single() else {
+        return;
+    };
+    // A boss missing from the fight keeps the default entry, which counts as already beaten
+    let mut bosses = Vec::new();
+    for (hp, phase_state, index) in &boss_query {
+        if bosses.len() <= index.0 {
+            bosses.resize(index.0 + 1, BossCheckpoint::default());
+        }
+        bosses[index.0] = BossCheckpoint {
+            hp: hp.current,
+            phase: phase_state.phase,
+        };
+    }
+    *checkpoint = FightCheckpoint {
+        stage: current_stage.0,
+        bosses,
+        player_hp: player_hp.current,
+        resume: false,
+        corridor_flag: checkpoint.corridor_flag_for(current_stage.0),
+    };
 }
 
 /// System to handle boss movement based on pattern
 pub fn boss_movement(
     time: Res<Time>,
-    mut boss_query: Query<(&mut Transform, &BossData, &mut BossMovementState), With<Boss>>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut boss_query: Query<
+        (
+            &mut Transform,
+            &BossData,
+            &mut BossMovementState,
+            Option<&BossSpeedMultiplier>,
+            Has<GroundedBoss>,
+        ),
+        With<Boss>,
+    >,
+    player_query: Query<&Transform, (With<Player>, Without<Boss>)>,
+    settings: Res<GameSettings>,
+    mut screen_shake: ResMut<crate::systems::effects::ScreenShake>,
+    config: Res<GameConfig>,
+    stage_modifier: Res<StageModifier>,
+    arena: Res<ArenaBounds>,
+    ground: Ground,
 ) {
-    for (mut transform, boss_data, mut movement_state) in &mut boss_query {
+    // Bosses move (and count down to their next jump) faster under the double boss speed curse
+    let stage_delta = time.delta_secs() * stage_modifier.boss_speed_scale();
+
+    let player_x = player_query
+        .single()
+        .ok()
+        .map(|player_transform| player_transform.translation.x);
+
+    for (mut transform, boss_data, mut movement_state, speed_multiplier, grounded) in
+        &mut boss_query
+    {
+        let delta = stage_delta * speed_multiplier.map_or(1.0, |multiplier| multiplier.0);
+        let half_size = boss_data.size / 2.0;
+        let previous_x = transform.translation.x;
+
+        // Grounded bosses that walk off a platform fall until they land again
+        if grounded
+            && !movement_state.airborne
+            && !ground.is_supported(transform.translation.x, transform.translation.y, half_size)
+        {
+            movement_state.airborne = true;
+            movement_state.velocity = Vec2::ZERO;
+        }
+
+        // Wind zones and conveyor floors carry grounded bosses along, like the player
+        if grounded {
+            let push = ground.push_speed(
+                transform.translation.truncate(),
+                half_size,
+                !movement_state.airborne,
+            );
+            transform.translation.x = arena.clamp_x(transform.translation.x + push * delta);
+        }
+
+        // A boss in mid-jump finishes its arc before following its movement pattern again
+        if movement_state.airborne {
+            if update_boss_airborne(
+                &mut transform,
+                &mut movement_state,
+                &config,
+                &arena,
+                &ground,
+                half_size,
+                delta,
+            ) {
+                spawn_shockwaves(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    settings.palette,
+                    &config,
+                    &arena,
+                    transform.translation.x,
+                );
+                screen_shake.trigger();
+            }
+            continue;
+        }
+
         match &boss_data.movement_pattern {
             MovementPattern::Stationary => {
                 // Boss doesn't move
@@ -242,17 +701,15 @@ pub fn boss_movement(
                 speed,
             } => {
                 // Move horizontally between bounds
-                transform.translation.x += movement_state.direction * speed * time.delta_secs();
+                transform.translation.x += movement_state.direction * speed * delta;
 
                 // Clamp to game boundaries first
-                transform.translation.x =
-                    transform.translation.x.clamp(BOUNDARY_LEFT, BOUNDARY_RIGHT);
-                transform.translation.y =
-                    transform.translation.y.clamp(BOUNDARY_BOTTOM, BOUNDARY_TOP);
+                transform.translation.x = arena.clamp_x(transform.translation.x);
+                transform.translation.y = arena.clamp_y(transform.translation.y);
 
                 // Reverse direction at bounds
-                let effective_left = left_bound.max(BOUNDARY_LEFT);
-                let effective_right = right_bound.min(BOUNDARY_RIGHT);
+                let effective_left = left_bound.max(arena.left);
+                let effective_right = right_bound.min(arena.right);
                 if transform.translation.x <= effective_left {
                     transform.translation.x = effective_left;
                     movement_state.direction = 1.0;
@@ -267,17 +724,15 @@ pub fn boss_movement(
                 speed,
             } => {
                 // Move vertically between bounds
-                transform.translation.y += movement_state.direction * speed * time.delta_secs();
+                transform.translation.y += movement_state.direction * speed * delta;
 
                 // Clamp to game boundaries first
-                transform.translation.x =
-                    transform.translation.x.clamp(BOUNDARY_LEFT, BOUNDARY_RIGHT);
-                transform.translation.y =
-                    transform.translation.y.clamp(BOUNDARY_BOTTOM, BOUNDARY_TOP);
+                transform.translation.x = arena.clamp_x(transform.translation.x);
+                transform.translation.y = arena.clamp_y(transform.translation.y);
 
                 // Reverse direction at bounds
-                let effective_bottom = bottom_bound.max(BOUNDARY_BOTTOM);
-                let effective_top = top_bound.min(BOUNDARY_TOP);
+                let effective_bottom = bottom_bound.max(arena.bottom);
+                let effective_top = top_bound.min(arena.top);
                 if transform.translation.y <= effective_bottom {
                     transform.translation.y = effective_bottom;
                     movement_state.direction = 1.0;
@@ -292,20 +747,274 @@ pub fn boss_movement(
                 speed,
             } => {
                 // Circular movement
-                movement_state.current_angle += speed * time.delta_secs();
+                movement_state.current_angle += speed * delta;
                 transform.translation.x = center.x + radius * movement_state.current_angle.cos();
                 transform.translation.y = center.y + radius * movement_state.current_angle.sin();
 
                 // Clamp to game boundaries
-                transform.translation.x =
-                    transform.translation.x.clamp(BOUNDARY_LEFT, BOUNDARY_RIGHT);
-                transform.translation.y =
-                    transform.translation.y.clamp(BOUNDARY_BOTTOM, BOUNDARY_TOP);
+                transform.translation.x = arena.clamp_x(transform.translation.x);
+                transform.translation.y = arena.clamp_y(transform.translation.y);
+            }
+            MovementPattern::Chase {
+                speed,
+                stop_distance,
+            } => {
+                // Walk horizontally toward the player until close enough
+                if let Some(player_x) = player_x {
+                    let offset = player_x - transform.translation.x;
+                    if offset.abs() > *stop_distance {
+                        transform.translation.x += offset.signum() * speed * delta;
+                    }
+                }
+                transform.translation.x = arena.clamp_x(transform.translation.x);
+            }
+            MovementPattern::Retreat { speed } => {
+                // Back away from the player; the arena walls stop the retreat
+                if let Some(player_x) = player_x {
+                    let away = if transform.translation.x >= player_x {
+                        1.0
+                    } else {
+                        -1.0
+                    };
+                    transform.translation.x += away * speed * delta;
+                }
+                transform.translation.x = arena.clamp_x(transform.translation.x);
+            }
+            MovementPattern::JumpTowardPlayer {
+                interval,
+                arc_height,
+            } => {
+                movement_state.jump_timer -= delta;
+                if movement_state.jump_timer <= 0.0
+                    && let Some(player_x) = player_x
+                {
+                    movement_state.jump_timer = *interval;
+
+                    // Launch speed to reach arc_height, with horizontal speed chosen to land on the player
+                    let launch_speed = (2.0 * config.boss_gravity * arc_height.max(1.0)).sqrt();
+                    let flight_time = 2.0 * launch_speed / config.boss_gravity;
+                    movement_state.velocity = Vec2::new(
+                        (player_x - transform.translation.x) / flight_time,
+                        launch_speed,
+                    );
+                    movement_state.airborne = true;
+                }
+            }
+            MovementPattern::GroundSlam { interval, height } => {
+                movement_state.jump_timer -= delta;
+                if movement_state.jump_timer <= 0.0
+                    && let Some(player_x) = player_x
+                {
+                    movement_state.jump_timer = *interval;
+
+                    // Reach the top of the leap right above the player, then hang and slam down
+                    let launch_speed = (2.0 * config.boss_gravity * height.max(1.0)).sqrt();
+                    let rise_time = launch_speed / config.boss_gravity;
+                    movement_state.velocity = Vec2::new(
+                        (player_x - transform.translation.x) / rise_time,
+                        launch_speed,
+                    );
+                    movement_state.airborne = true;
+                    movement_state.slam_pending = true;
+                }
             }
             MovementPattern::Custom => {
                 // Custom movement - can be extended
             }
         }
+
+        // Grounded bosses walk up against breakable blocks instead of through them
+        if grounded {
+            transform.translation.x = ground.blocked_x(
+                previous_x,
+                transform.translation.x,
+                transform.translation.y,
+                half_size,
+            );
+        }
+    }
+}
+
+/// Advances a jumping or falling boss under gravity. Returns true on the frame it lands
+/// (on the floor, or on a platform it comes down onto).
+fn update_boss_airborne(
+    transform: &mut Transform,
+    movement_state: &mut BossMovementState,
+    config: &GameConfig,
+    arena: &ArenaBounds,
+    ground: &Ground,
+    half_size: Vec2,
+    delta: f32,
+) -> bool {
+    // Hang at the top of a slam before dropping
+    if movement_state.hover_timer > 0.0 {
+        movement_state.hover_timer -= delta;
+        if movement_state.hover_timer <= 0.0 {
+            movement_state.velocity = Vec2::new(0.0, -config.boss_slam_speed);
+        }
+        return false;
+    }
+
+    movement_state.velocity.y -= config.boss_gravity
+        * ground.water_scale(transform.translation.truncate(), WATER_GRAVITY_SCALE)
+        * delta;
+
+    if movement_state.slam_pending && movement_state.velocity.y <= 0.0 {
+        movement_state.slam_pending = false;
+        movement_state.velocity = Vec2::ZERO;
+        movement_state.hover_timer = config.boss_slam_hover_time;
+        return false;
+    }
+
+    let previous_x = transform.translation.x;
+    let previous_y = transform.translation.y;
+    transform.translation += movement_state.velocity.extend(0.0) * delta;
+    transform.translation.x = ground.blocked_x(
+        previous_x,
+        arena.clamp_x(transform.translation.x),
+        transform.translation.y,
+        half_size,
+    );
+    transform.translation.y = transform.translation.y.min(arena.top);
+
+    if movement_state.velocity.y <= 0.0
+        && let Some(landing_y) = ground.landing_y(
+            transform.translation.x,
+            previous_y,
+            transform.translation.y,
+            half_size,
+        )
+    {
+        transform.translation.y = landing_y;
+        movement_state.velocity = Vec2::ZERO;
+        movement_state.airborne = false;
+        return true;
+    }
+
+    false
+}
+
+/// Component for shockwaves that travel along the floor after a boss lands
+#[derive(Component)]
+#[require(GameplayEntity)]
+pub struct BossShockwave {
+    pub direction: f32, // -1.0 for left, 1.0 for right
+    pub timer: f32,     // Remaining lifetime
+}
+
+/// Spawns a pair of shockwaves travelling left and right from a landing point
+fn spawn_shockwaves(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    palette: ColorPalette,
+    config: &GameConfig,
+    arena: &ArenaBounds,
+    x: f32,
+) {
+    // Sit on the floor surface
+    let y = arena.floor_top() + SHOCKWAVE_HEIGHT / 2.0;
+    let mesh = meshes.add(Rectangle::new(SHOCKWAVE_WIDTH, SHOCKWAVE_HEIGHT));
+    let material = materials.add(palette.boss_projectile_color());
+
+    for direction in [-1.0, 1.0] {
+        commands.spawn((
+            Mesh2d(mesh.clone()),
+            MeshMaterial2d(material.clone()),
+            Transform::from_xyz(x, y, 1.0),
+            BossShockwave {
+                direction,
+                timer: config.shockwave_lifetime,
+            },
+        ));
+    }
+}
+
+/// System to move shockwaves along the floor and despawn them when they expire
+pub fn boss_shockwave_movement(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut shockwave_query: Query<(Entity, &mut Transform, &mut BossShockwave)>,
+    config: Res<GameConfig>,
+    arena: Res<ArenaBounds>,
+) {
+    for (entity, mut transform, mut shockwave) in &mut shockwave_query {
+        transform.translation.x += shockwave.direction * config.shockwave_speed * time.delta_secs();
+        shockwave.timer -= time.delta_secs();
+
+        if shockwave.timer <= 0.0 || arena.outside_x(transform.translation.x) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// System to damage a grounded player touched by a shockwave (jumping over it avoids the hit)
+pub fn boss_shockwave_player_collision(
+    mut commands: Commands,
+    shockwave_query: Query<&BossShockwave>,
+    mut player_query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Hp,
+            Option<&Invincibility>,
+            Option<&mut Overshield>,
+        ),
+        With<Player>,
+    >,
+    collisions: CollisionLookup,
+    player_upgrades: Option<Res<crate::stages::game_menu::PlayerUpgrades>>,
+    mut screen_shake: ResMut<crate::systems::effects::ScreenShake>,
+    mut feedback: MessageWriter<FeedbackEvent>,
+    config: Res<GameConfig>,
+    arena: Res<ArenaBounds>,
+) {
+    let defense_multiplier = player_upgrades
+        .as_ref()
+        .map(|u| u.defense_multiplier)
+        .unwrap_or(1.0);
+    let damage = config.shockwave_damage * defense_multiplier;
+
+    for (player_entity, player_transform, mut player_hp, invincibility, mut overshield) in
+        &mut player_query
+    {
+        // Invincibility frames are ticked by the other collision systems
+        if invincibility.is_some_and(|inv| inv.timer > 0.0) {
+            continue;
+        }
+
+        let is_grounded = player_transform.translation.y <= arena.bottom + 1.0;
+        if !is_grounded {
+            continue;
+        }
+
+        // Shockwaves hug the floor, so any one overlapping a grounded player hits it
+        let touching_shockwaves = collisions.overlapping(
+            player_transform.translation.truncate(),
+            PLAYER_HITBOX_SIZE,
+            ColliderKind::Shockwave,
+        );
+        for shockwave_entity in touching_shockwaves {
+            let Ok(shockwave) = shockwave_query.get(shockwave_entity) else {
+                continue;
+            };
+
+            take_damage(&mut player_hp, overshield.as_deref_mut(), damage);
+            screen_shake.trigger();
+            feedback.write(FeedbackEvent::Hurt);
+
+            commands.entity(player_entity).insert(Invincibility {
+                timer: config.invincibility_duration,
+            });
+
+            // Push the player along the shockwave's direction and slightly upward
+            commands.entity(player_entity).insert(Knockback {
+                velocity: Vec2::new(shockwave.direction, 0.3).normalize() * config.knockback_force,
+                timer: config.knockback_duration,
+            });
+
+            break;
+        }
     }
 }
 
@@ -336,12 +1045,30 @@ pub fn boss_attacks(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
-    mut boss_query: Query<(&Transform, &BossData, &mut BossAttackState), With<Boss>>,
+    mut boss_query: Query<(Entity, &Transform, &BossData, &mut BossAttackState), With<Boss>>,
     player_query: Query<&Transform, (With<Player>, Without<Boss>)>,
+    mut hitbox_query: Query<(&mut BossMeleeHitbox, &MeshMaterial2d<ColorMaterial>)>,
+    mut beam_query: Query<
+        (
+            &mut BossBeam,
+            &mut Transform,
+            &MeshMaterial2d<ColorMaterial>,
+        ),
+        (Without<Boss>, Without<Player>),
+    >,
+    settings: Res<GameSettings>,
+    projectile_assets: Res<ProjectileAssets>,
 ) {
-    for (boss_transform, boss_data, mut attack_state) in &mut boss_query {
+    for (boss_entity, boss_transform, boss_data, mut attack_state) in &mut boss_query {
         attack_state.timer -= time.delta_secs();
 
+        // Clean up the hitbox/beam of an attack the behavior tree interrupted
+        if attack_state.attack_phase == AttackPhase::Idle
+            && let Some(attack_entity) = attack_state.attack_entity.take()
+        {
+            commands.entity(attack_entity).despawn();
+        }
+
         match &boss_data.attack_pattern {
             AttackPattern::None => {
                 // Boss doesn't attack
@@ -349,6 +1076,7 @@ pub fn boss_attacks(
             AttackPattern::SingleShot {
                 cooldown,
                 projectile_speed,
+                gravity,
                 cardinal_only,
             } => {
                 if attack_state.timer <= 0.0 {
@@ -367,8 +1095,15 @@ pub fn boss_attacks(
                             &mut commands,
                             &mut meshes,
                             &mut materials,
+                            &projectile_assets,
+                            settings.palette,
                             boss_transform.translation,
-                            direction * *projectile_speed,
+                            BossProjectile {
+                                velocity: direction * *projectile_speed,
+                                gravity: *gravity,
+                                angular_velocity: 0.0,
+                                hazard: None,
+                            },
                         );
 
                         attack_state.timer = *cooldown;
@@ -378,6 +1113,7 @@ pub fn boss_attacks(
             AttackPattern::TripleShot {
                 cooldown,
                 projectile_speed,
+                gravity,
                 spread_angle,
             } => {
                 if attack_state.timer <= 0.0 {
@@ -402,8 +1138,15 @@ pub fn boss_attacks(
                                 &mut commands,
                                 &mut meshes,
                                 &mut materials,
+                                &projectile_assets,
+                                settings.palette,
                                 boss_transform.translation,
-                                direction * *projectile_speed,
+                                BossProjectile {
+                                    velocity: direction * *projectile_speed,
+                                    gravity: *gravity,
+                                    angular_velocity: 0.0,
+                                    hazard: None,
+                                },
                             );
                         }
 
@@ -414,6 +1157,7 @@ pub fn boss_attacks(
             AttackPattern::RapidFire {
                 cooldown,
                 projectile_speed,
+                gravity,
                 burst_count,
                 burst_delay,
             } => {
@@ -431,8 +1175,15 @@ pub fn boss_attacks(
                                 &mut commands,
                                 &mut meshes,
                                 &mut materials,
+                                &projectile_assets,
+                                settings.palette,
                                 boss_transform.translation,
-                                direction * *projectile_speed,
+                                BossProjectile {
+                                    velocity: direction * *projectile_speed,
+                                    gravity: *gravity,
+                                    angular_velocity: 0.0,
+                                    hazard: None,
+                                },
                             );
 
                             attack_state.burst_count -= 1;
@@ -449,6 +1200,343 @@ pub fn boss_attacks(
                     attack_state.burst_timer = *burst_delay;
                 }
             }
+            AttackPattern::Melee {
+                cooldown,
+                windup,
+                active,
+                recovery,
+                reach,
+                height,
+            } => {
+                attack_state.phase_timer -= time.delta_secs();
+
+                match attack_state.attack_phase {
+                    AttackPhase::Idle => {
+                        if attack_state.timer <= 0.0
+                            && let Ok(player_transform) = player_query.single()
+                        {
+                            // Swing toward whichever side the player is on, telegraphing the hitbox first
+                            let facing =
+                                if player_transform.translation.x >= boss_transform.translation.x {
+                                    1.0
+                                } else {
+                                    -1.0
+                                };
+                            let size = Vec2::new(*reach, *height);
+                            let hitbox = commands
+                                .spawn((
+                                    Mesh2d(meshes.add(Rectangle::new(size.x, size.y))),
+                                    MeshMaterial2d(
+                                        materials.add(
+                                            settings
+                                                .palette
+                                                .boss_projectile_color()
+                                                .with_alpha(BOSS_MELEE_TELEGRAPH_ALPHA),
+                                        ),
+                                    ),
+                                    Transform::from_xyz(
+                                        facing * (boss_data.size.x + size.x) / 2.0,
+                                        0.0,
+                                        0.1,
+                                    ),
+                                    BossMeleeHitbox {
+                                        size,
+                                        facing,
+                                        active: false,
+                                    },
+                                    ChildOf(boss_entity),
+                                ))
+                                .id();
+
+                            attack_state.attack_entity = Some(hitbox);
+                            attack_state.attack_phase = AttackPhase::Windup;
+                            attack_state.phase_timer = *windup;
+                        }
+                    }
+                    AttackPhase::Windup => {
+                        if attack_state.phase_timer <= 0.0 {
+                            // Hitbox becomes solid and starts dealing damage
+                            if let Some(hitbox) = attack_state.attack_entity
+                                && let Ok((mut melee_hitbox, material)) =
+                                    hitbox_query.get_mut(hitbox)
+                            {
+                                melee_hitbox.active = true;
+                                if let Some(material) = materials.get_mut(&material.0) {
+                                    material.color =
+                                        material.color.with_alpha(BOSS_MELEE_ACTIVE_ALPHA);
+                                }
+                            }
+                            attack_state.attack_phase = AttackPhase::Active;
+                            attack_state.phase_timer = *active;
+                        }
+                    }
+                    AttackPhase::Active => {
+                        if attack_state.phase_timer <= 0.0 {
+                            if let Some(hitbox) = attack_state.attack_entity.take() {
+                                commands.entity(hitbox).despawn();
+                            }
+                            attack_state.attack_phase = AttackPhase::Recovery;
+                            attack_state.phase_timer = *recovery;
+                        }
+                    }
+                    AttackPhase::Recovery => {
+                        if attack_state.phase_timer <= 0.0 {
+                            attack_state.attack_phase = AttackPhase::Idle;
+                            attack_state.timer = *cooldown;
+                        }
+                    }
+                }
+            }
+            AttackPattern::Ring {
+                cooldown,
+                projectile_speed,
+                gravity,
+                count,
+                angular_velocity,
+            } => {
+                if attack_state.timer <= 0.0 {
+                    let count = (*count).max(1);
+                    for i in 0..count {
+                        let angle = std::f32::consts::TAU * i as f32 / count as f32;
+                        spawn_boss_projectile(
+                            &mut commands,
+                            &mut meshes,
+                            &mut materials,
+                            &projectile_assets,
+                            settings.palette,
+                            boss_transform.translation,
+                            BossProjectile {
+                                velocity: Vec2::from_angle(angle) * *projectile_speed,
+                                gravity: *gravity,
+                                angular_velocity: angular_velocity.to_radians(),
+                                hazard: None,
+                            },
+                        );
+                    }
+                    attack_state.timer = *cooldown;
+                }
+            }
+            AttackPattern::Spiral {
+                cooldown,
+                projectile_speed,
+                gravity,
+                arms,
+                rotation_speed,
+                angular_velocity,
+            } => {
+                // The emitter keeps turning between shots, so consecutive volleys trace spiral arms
+                attack_state.spiral_angle = (attack_state.spiral_angle
+                    + rotation_speed.to_radians() * time.delta_secs())
+                .rem_euclid(std::f32::consts::TAU);
+
+                if attack_state.timer <= 0.0 {
+                    let arms = (*arms).max(1);
+                    for i in 0..arms {
+                        let angle = attack_state.spiral_angle
+                            + std::f32::consts::TAU * i as f32 / arms as f32;
+                        spawn_boss_projectile(
+                            &mut commands,
+                            &mut meshes,
+                            &mut materials,
+                            &projectile_assets,
+                            settings.palette,
+                            boss_transform.translation,
+                            BossProjectile {
+                                velocity: Vec2::from_angle(angle) * *projectile_speed,
+                                gravity: *gravity,
+                                angular_velocity: angular_velocity.to_radians(),
+                                hazard: None,
+                            },
+                        );
+                    }
+                    attack_state.timer = *cooldown;
+                }
+            }
+            AttackPattern::Fan {
+                cooldown,
+                projectile_speed,
+                gravity,
+                count,
+                spread_angle,
+                angular_velocity,
+            } => {
+                if attack_state.timer <= 0.0
+                    && let Ok(player_transform) = player_query.single()
+                {
+                    let base_direction = (player_transform.translation
+                        - boss_transform.translation)
+                        .truncate()
+                        .normalize_or_zero();
+
+                    // Spread evenly across the fan, centered on the player
+                    let count = (*count).max(1);
+                    let spread = spread_angle.to_radians();
+                    for i in 0..count {
+                        let offset = if count == 1 {
+                            0.0
+                        } else {
+                            -spread / 2.0 + spread * i as f32 / (count - 1) as f32
+                        };
+                        spawn_boss_projectile(
+                            &mut commands,
+                            &mut meshes,
+                            &mut materials,
+                            &projectile_assets,
+                            settings.palette,
+                            boss_transform.translation,
+                            BossProjectile {
+                                velocity: Vec2::from_angle(offset).rotate(base_direction)
+                                    * *projectile_speed,
+                                gravity: *gravity,
+                                angular_velocity: angular_velocity.to_radians(),
+                                hazard: None,
+                            },
+                        );
+                    }
+                    attack_state.timer = *cooldown;
+                }
+            }
+            AttackPattern::Mortar {
+                cooldown,
+                flight_time,
+                gravity,
+                count,
+                spacing,
+                hazard,
+            } => {
+                if attack_state.timer <= 0.0
+                    && let Ok(player_transform) = player_query.single()
+                {
+                    let origin = boss_transform.translation.truncate();
+                    let flight_time = flight_time.max(0.1);
+                    let count = (*count).max(1);
+                    for i in 0..count {
+                        // Spread landing points evenly around the player
+                        let offset = (i as f32 - (count - 1) as f32 / 2.0) * spacing;
+                        let target =
+                            player_transform.translation.truncate() + Vec2::new(offset, 0.0);
+
+                        // Solve target = origin + v*t - (0, g*t²/2) for the launch velocity
+                        let velocity = (target - origin) / flight_time
+                            + Vec2::new(0.0, 0.5 * gravity * flight_time);
+
+                        spawn_boss_projectile(
+                            &mut commands,
+                            &mut meshes,
+                            &mut materials,
+                            &projectile_assets,
+                            settings.palette,
+                            boss_transform.translation,
+                            BossProjectile {
+                                velocity,
+                                gravity: *gravity,
+                                angular_velocity: 0.0,
+                                hazard: hazard.clone(),
+                            },
+                        );
+                    }
+                    attack_state.timer = *cooldown;
+                }
+            }
+            AttackPattern::Beam {
+                cooldown,
+                telegraph,
+                duration,
+                sweep_angle,
+                length,
+                width,
+            } => {
+                attack_state.phase_timer -= time.delta_secs();
+
+                match attack_state.attack_phase {
+                    AttackPhase::Idle => {
+                        if attack_state.timer <= 0.0
+                            && let Ok(player_transform) = player_query.single()
+                        {
+                            // Sweep across the player's current position, starting from one side
+                            let to_player = (player_transform.translation
+                                - boss_transform.translation)
+                                .truncate();
+                            let aim_angle = to_player.y.atan2(to_player.x);
+                            let half_sweep = sweep_angle.to_radians() / 2.0;
+                            let start_angle = aim_angle - half_sweep;
+
+                            // Thin telegraph line along the starting angle
+                            let mut transform = beam_transform(start_angle, *length);
+                            transform.scale.y = BOSS_BEAM_TELEGRAPH_WIDTH / width.max(0.01);
+                            let beam = commands
+                                .spawn((
+                                    Mesh2d(meshes.add(Rectangle::new(*length, *width))),
+                                    MeshMaterial2d(
+                                        materials.add(
+                                            settings
+                                                .palette
+                                                .boss_projectile_color()
+                                                .with_alpha(BOSS_BEAM_TELEGRAPH_ALPHA),
+                                        ),
+                                    ),
+                                    transform,
+                                    BossBeam {
+                                        angle: start_angle,
+                                        start_angle,
+                                        end_angle: aim_angle + half_sweep,
+                                        length: *length,
+                                        width: *width,
+                                        firing: false,
+                                    },
+                                    ChildOf(boss_entity),
+                                ))
+                                .id();
+
+                            attack_state.attack_entity = Some(beam);
+                            attack_state.attack_phase = AttackPhase::Windup;
+                            attack_state.phase_timer = *telegraph;
+                        }
+                    }
+                    AttackPhase::Windup => {
+                        if attack_state.phase_timer <= 0.0 {
+                            // Telegraph widens into the damaging beam
+                            if let Some(beam) = attack_state.attack_entity
+                                && let Ok((mut boss_beam, mut transform, material)) =
+                                    beam_query.get_mut(beam)
+                            {
+                                boss_beam.firing = true;
+                                transform.scale.y = 1.0;
+                                if let Some(material) = materials.get_mut(&material.0) {
+                                    material.color =
+                                        material.color.with_alpha(BOSS_BEAM_ACTIVE_ALPHA);
+                                }
+                            }
+                            attack_state.attack_phase = AttackPhase::Active;
+                            attack_state.phase_timer = *duration;
+                        }
+                    }
+                    AttackPhase::Active => {
+                        // Sweep from the start angle to the end angle over the beam's duration
+                        if let Some(beam) = attack_state.attack_entity
+                            && let Ok((mut boss_beam, mut transform, _)) = beam_query.get_mut(beam)
+                        {
+                            let progress = (1.0 - attack_state.phase_timer / duration.max(0.01))
+                                .clamp(0.0, 1.0);
+                            boss_beam.angle = boss_beam.start_angle
+                                + (boss_beam.end_angle - boss_beam.start_angle) * progress;
+                            *transform = beam_transform(boss_beam.angle, boss_beam.length);
+                        }
+
+                        if attack_state.phase_timer <= 0.0 {
+                            if let Some(beam) = attack_state.attack_entity.take() {
+                                commands.entity(beam).despawn();
+                            }
+                            attack_state.attack_phase = AttackPhase::Idle;
+                            attack_state.timer = *cooldown;
+                        }
+                    }
+                    AttackPhase::Recovery => {
+                        // Beams go straight back to their cooldown
+                        attack_state.attack_phase = AttackPhase::Idle;
+                    }
+                }
+            }
             AttackPattern::Custom { cooldown: _ } => {
                 // Custom attack pattern - can be extended
             }
@@ -456,207 +1544,481 @@ pub fn boss_attacks(
     }
 }
 
-/// Helper function to spawn a boss projectile
-fn spawn_boss_projectile(
+/// Local transform of a beam pivoting around the boss center at `angle` radians
+fn beam_transform(angle: f32, length: f32) -> Transform {
+    let direction = Vec2::from_angle(angle);
+    Transform::from_translation((direction * length / 2.0).extend(0.2))
+        .with_rotation(Quat::from_rotation_z(angle))
+}
+
+/// Helper function to spawn a boss projectile, returning its entity
+pub fn spawn_boss_projectile(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<ColorMaterial>>,
+    projectile_assets: &ProjectileAssets,
+    palette: ColorPalette,
     position: Vec3,
-    velocity: Vec2,
-) {
-    commands.spawn((
-        Mesh2d(meshes.add(Rectangle::new(10.0, 10.0))),
-        MeshMaterial2d(materials.add(Color::srgb(1.0, 0.5, 0.0))), // Orange boss projectiles
-        Transform::from_xyz(position.x, position.y, 0.0),
-        Projectile {
-            direction: velocity.normalize_or_zero(),
-            charge_level: 0.0, // Boss projectiles are always uncharged
-        },
-        BossProjectile {
-            speed: velocity.length(),
-        },
+    boss_projectile: BossProjectile,
+) -> Entity {
+    // Colorblind palette: boss shots are outlined circles so they differ from the player's squares by shape too
+    let mesh = if palette.uses_shape_cues() {
+        meshes.add(Circle::new(5.0))
+    } else {
+        projectile_assets.square.clone()
+    };
+
+    let mut projectile = commands.spawn((
+        ProjectileBundle::boss(
+            mesh,
+            materials.add(palette.boss_projectile_color()),
+            position.truncate(),
+            boss_projectile.velocity,
+        ),
+        boss_projectile,
     ));
+
+    if palette.uses_shape_cues() {
+        projectile.with_child((
+            Mesh2d(meshes.add(Circle::new(7.5))),
+            MeshMaterial2d(materials.add(Color::BLACK)),
+            Transform::from_xyz(0.0, 0.0, -0.01), // Dark outline just behind the fill
+        ));
+    }
+    projectile.id()
 }
 
-/// Marker component for boss projectiles (to distinguish from player projectiles)
+/// Component for boss projectiles (also distinguishes them from player projectiles)
 #[derive(Component)]
 pub struct BossProjectile {
-    pub speed: f32,
+    pub velocity: Vec2,
+    pub gravity: f32, // Downward acceleration (pixels per second squared, 0.0 = straight shot)
+    pub angular_velocity: f32, // Turn rate of the flight direction (radians per second)
+    pub hazard: Option<HazardConfig>, // Ground hazard left behind when the projectile hits the floor
 }
 
 /// System to move boss projectiles
 pub fn boss_projectile_movement(
     time: Res<Time>,
     mut commands: Commands,
-    mut projectile_query: Query<(Entity, &mut Transform, &Projectile, &BossProjectile)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut projectile_query: Query<(Entity, &mut Transform, &mut Projectile, &mut BossProjectile)>,
+    settings: Res<GameSettings>,
+    arena: Res<ArenaBounds>,
+    ground: Ground,
 ) {
-    for (entity, mut transform, projectile, boss_projectile) in &mut projectile_query {
-        transform.translation.x +=
-            projectile.direction.x * boss_projectile.speed * time.delta_secs();
-        transform.translation.y +=
-            projectile.direction.y * boss_projectile.speed * time.delta_secs();
-
-        // Despawn projectile after it goes outside boundaries
-        if transform.translation.x < BOUNDARY_LEFT
-            || transform.translation.x > BOUNDARY_RIGHT
-            || transform.translation.y < BOUNDARY_BOTTOM
-            || transform.translation.y > BOUNDARY_TOP
+    for (entity, mut transform, mut projectile, mut boss_projectile) in &mut projectile_query {
+        // Shots slow down while passing through water
+        let delta = time.delta_secs()
+            * ground.water_scale(transform.translation.truncate(), WATER_PROJECTILE_SCALE);
+
+        // Curving projectiles turn their velocity over time
+        if boss_projectile.angular_velocity != 0.0 {
+            boss_projectile.velocity = Vec2::from_angle(boss_projectile.angular_velocity * delta)
+                .rotate(boss_projectile.velocity);
+        }
+        boss_projectile.velocity.y -= boss_projectile.gravity * delta;
+
+        transform.translation += boss_projectile.velocity.extend(0.0) * delta;
+        // Keep the direction in sync for knockback on hit
+        projectile.direction = boss_projectile.velocity.normalize_or_zero();
+
+        // Despawn projectile after it leaves the arena or hits the floor
+        // (arcing shots may rise above the top boundary and fall back in)
+        let hit_floor = transform.translation.y < arena.floor_top();
+        if arena.outside_x(transform.translation.x)
+            || hit_floor
+            || (transform.translation.y > arena.top && boss_projectile.gravity <= 0.0)
         {
+            // Shells carrying a hazard leave it where they hit the floor
+            if hit_floor && let Some(hazard) = &boss_projectile.hazard {
+                spawn_hazard(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    settings.palette,
+                    hazard,
+                    &arena,
+                    transform.translation.x,
+                );
+            }
             commands.entity(entity).despawn();
         }
     }
 }
 
-/// System to handle boss projectile collision with player
+/// System to handle boss projectile collision with player.
+/// Only the projectiles found around each player are checked, not every projectile in the arena.
 pub fn boss_projectile_player_collision(
     mut commands: Commands,
     projectile_query: Query<
-        (Entity, &Transform, &Projectile),
-        (With<BossProjectile>, Without<Player>),
+        &Projectile,
+        (With<BossProjectile>, Without<Player>, Without<ProjectileHasHit>),
     >,
     mut player_query: Query<
-        (Entity, &Transform, &mut Hp, Option<&mut Invincibility>),
+        (
+            Entity,
+            &Transform,
+            &mut Hp,
+            Option<&Invincibility>,
+            Option<&mut Overshield>,
+        ),
+        With<Player>,
+    >,
+    collisions: CollisionLookup,
+    player_upgrades: Option<Res<crate::stages::game_menu::PlayerUpgrades>>,
+    mut screen_shake: ResMut<crate::systems::effects::ScreenShake>,
+    mut feedback: MessageWriter<FeedbackEvent>,
+    mut projectile_hit: MessageWriter<ProjectileHit>,
+    config: Res<GameConfig>,
+) {
+    // Apply defense multiplier to damage
+    let defense_multiplier = player_upgrades
+        .as_ref()
+        .map(|u| u.defense_multiplier)
+        .unwrap_or(1.0);
+    let damage = config.boss_projectile_damage * defense_multiplier;
+
+    for (player_entity, player_transform, mut player_hp, invincibility, mut overshield) in
+        &mut player_query
+    {
+        // Invincibility frames are ticked by the other collision systems;
+        // projectiles pass through an invincible player
+        if invincibility.is_some_and(|inv| inv.timer > 0.0) {
+            continue;
+        }
+
+        let hits = collisions.overlapping(
+            player_transform.translation.truncate(),
+            PLAYER_HITBOX_SIZE,
+            ColliderKind::BossProjectile,
+        );
+        for projectile_entity in hits {
+            let Ok(projectile) = projectile_query.get(projectile_entity) else {
+                continue;
+            };
+
+            // Calculate knockback direction: push player away from the boss (same direction as projectile was traveling)
+            // The projectile direction points from boss toward player, so we use the same direction
+            // to push the player further away from the boss
+            let knockback_direction = projectile.direction.normalize_or_zero();
+
+            // Player takes damage
+            take_damage(&mut player_hp, overshield.as_deref_mut(), damage);
+            screen_shake.trigger();
+            feedback.write(FeedbackEvent::Hurt);
+
+            // Add invincibility frames
+            commands.entity(player_entity).insert(Invincibility {
+                timer: config.invincibility_duration,
+            });
+
+            // Add knockback effect
+            commands.entity(player_entity).insert(Knockback {
+                velocity: knockback_direction * config.knockback_force,
+                timer: config.knockback_duration,
+            });
+
+            // Spent: despawned after the collisions
+            commands.entity(projectile_entity).insert(ProjectileHasHit);
+            projectile_hit.write(ProjectileHit(projectile_entity));
+
+            // The new invincibility frames cover any other projectile touching the player
+            break;
+        }
+    }
+}
+
+/// System to damage the player when touching an active boss melee hitbox
+pub fn boss_melee_player_collision(
+    mut commands: Commands,
+    hitbox_query: Query<(&GlobalTransform, &BossMeleeHitbox)>,
+    mut player_query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Hp,
+            Option<&Invincibility>,
+            Option<&mut Overshield>,
+        ),
         With<Player>,
     >,
-    time: Res<Time>,
     player_upgrades: Option<Res<crate::stages::game_menu::PlayerUpgrades>>,
+    mut screen_shake: ResMut<crate::systems::effects::ScreenShake>,
+    mut feedback: MessageWriter<FeedbackEvent>,
+    config: Res<GameConfig>,
 ) {
-    use crate::systems::config::INVINCIBILITY_DURATION;
     use crate::systems::player::check_aabb_collision;
 
-    const PROJECTILE_SIZE: Vec2 = Vec2::new(10.0, 10.0);
-    const PLAYER_SIZE: Vec2 = Vec2::new(32.0, 64.0);
+    let defense_multiplier = player_upgrades
+        .as_ref()
+        .map(|u| u.defense_multiplier)
+        .unwrap_or(1.0);
+    let damage = config.boss_melee_damage * defense_multiplier;
+
+    for (player_entity, player_transform, mut player_hp, invincibility, mut overshield) in
+        &mut player_query
+    {
+        // Invincibility frames are ticked by the other collision systems
+        if invincibility.is_some_and(|inv| inv.timer > 0.0) {
+            continue;
+        }
+
+        for (hitbox_transform, hitbox) in &hitbox_query {
+            if !hitbox.active
+                || !check_aabb_collision(
+                    hitbox_transform.translation(),
+                    hitbox.size,
+                    player_transform.translation,
+                    PLAYER_HITBOX_SIZE,
+                )
+            {
+                continue;
+            }
+
+            take_damage(&mut player_hp, overshield.as_deref_mut(), damage);
+            screen_shake.trigger();
+            feedback.write(FeedbackEvent::Hurt);
+
+            commands.entity(player_entity).insert(Invincibility {
+                timer: config.invincibility_duration,
+            });
+
+            // Knock the player away in the direction of the swing
+            commands.entity(player_entity).insert(Knockback {
+                velocity: Vec2::new(hitbox.facing, KNOCKBACK_SIDE_VERTICAL_COMPONENT).normalize()
+                    * config.knockback_force,
+                timer: config.knockback_duration,
+            });
+
+            break;
+        }
+    }
+}
+
+/// System to damage the player while touching a firing beam.
+/// Damage repeats each time the player's invincibility frames run out; beams don't knock back.
+pub fn boss_beam_player_collision(
+    mut commands: Commands,
+    beam_query: Query<(&GlobalTransform, &BossBeam)>,
+    mut player_query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Hp,
+            Option<&Invincibility>,
+            Option<&mut Overshield>,
+        ),
+        With<Player>,
+    >,
+    player_upgrades: Option<Res<crate::stages::game_menu::PlayerUpgrades>>,
+    mut screen_shake: ResMut<crate::systems::effects::ScreenShake>,
+    mut feedback: MessageWriter<FeedbackEvent>,
+    config: Res<GameConfig>,
+) {
+    let half_size = PLAYER_HITBOX_SIZE / 2.0;
 
-    // Apply defense multiplier to damage
     let defense_multiplier = player_upgrades
         .as_ref()
         .map(|u| u.defense_multiplier)
         .unwrap_or(1.0);
-    let DAMAGE = crate::systems::config::BOSS_PROJECTILE_DAMAGE * defense_multiplier;
-
-    for (projectile_entity, projectile_transform, projectile) in &projectile_query {
-        for (player_entity, player_transform, mut player_hp, invincibility) in &mut player_query {
-            // Check if player is invincible
-            let is_invincible = if let Some(mut inv) = invincibility {
-                inv.timer -= time.delta_secs();
-                if inv.timer > 0.0 {
-                    true
-                } else {
-                    commands.entity(player_entity).remove::<Invincibility>();
-                    false
-                }
-            } else {
-                false
-            };
+    let damage = config.boss_beam_damage * defense_multiplier;
+
+    for (player_entity, player_transform, mut player_hp, invincibility, mut overshield) in
+        &mut player_query
+    {
+        // Invincibility frames are ticked by the other collision systems
+        if invincibility.is_some_and(|inv| inv.timer > 0.0) {
+            continue;
+        }
 
-            if is_invincible {
+        let player_position = player_transform.translation.truncate();
+        for (beam_transform, beam) in &beam_query {
+            if !beam.firing {
                 continue;
             }
 
-            // Check collision using the same AABB function as other collisions
-            if check_aabb_collision(
-                projectile_transform.translation,
-                PROJECTILE_SIZE,
-                player_transform.translation,
-                PLAYER_SIZE,
-            ) {
-                // Calculate knockback direction: push player away from the boss (same direction as projectile was traveling)
-                // The projectile direction points from boss toward player, so we use the same direction
-                // to push the player further away from the boss
-                let knockback_direction = projectile.direction.normalize_or_zero();
-
-                // Player takes damage
-                player_hp.current = (player_hp.current - DAMAGE).max(0.0);
-
-                // Add invincibility frames
-                commands.entity(player_entity).insert(Invincibility {
-                    timer: INVINCIBILITY_DURATION,
-                });
-
-                // Add knockback effect
-                commands.entity(player_entity).insert(Knockback {
-                    velocity: knockback_direction * KNOCKBACK_FORCE,
-                    timer: KNOCKBACK_DURATION,
-                });
-
-                // Despawn projectile
-                commands.entity(projectile_entity).despawn();
-
-                // Only process one collision per projectile
-                break;
+            // Closest point on the beam's center line to the player, checked against the player's box
+            let direction = Vec2::from_angle(beam.angle);
+            let beam_start =
+                beam_transform.translation().truncate() - direction * beam.length / 2.0;
+            let along = (player_position - beam_start)
+                .dot(direction)
+                .clamp(0.0, beam.length);
+            let offset = (beam_start + direction * along - player_position).abs();
+            let half_width = beam.width / 2.0;
+            if offset.x > half_size.x + half_width || offset.y > half_size.y + half_width {
+                continue;
             }
+
+            take_damage(&mut player_hp, overshield.as_deref_mut(), damage);
+            screen_shake.trigger();
+            feedback.write(FeedbackEvent::Hurt);
+
+            commands.entity(player_entity).insert(Invincibility {
+                timer: config.invincibility_duration,
+            });
+
+            break;
         }
     }
 }
 
-/// Spawns the boss's HP bar.
-pub fn setup_boss_hp_bar(mut commands: Commands, boss_query: Query<Entity, With<Boss>>) {
-    let Ok(boss) = boss_query.single() else {
-        // Boss doesn't exist yet, skip creating HP bar
-        return;
-    };
+/// System to count down a survival phase and make the boss vulnerable again when it ends
+pub fn update_boss_invulnerability(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut boss_query: Query<(Entity, &mut BossInvulnerable)>,
+) {
+    for (entity, mut invulnerable) in &mut boss_query {
+        invulnerable.timer -= time.delta_secs();
+        if invulnerable.timer <= 0.0 {
+            commands.entity(entity).remove::<BossInvulnerable>();
+        }
+    }
+}
+
+/// Marker component for the on-screen survival phase countdown
+#[derive(Component)]
+pub struct SurvivalTimerText;
+
+/// System to show a countdown while the boss is invulnerable during a survival phase
+pub fn update_survival_timer_text(
+    mut commands: Commands,
+    boss_query: Query<&BossInvulnerable, With<Boss>>,
+    mut text_query: Query<(Entity, &mut Text), With<SurvivalTimerText>>,
+) {
+    let remaining = boss_query
+        .iter()
+        .map(|invulnerable| invulnerable.timer)
+        .reduce(f32::max);
 
-    // --- Boss HP Bar ---
-    // Create a completely separate root container for the boss HP bar
-    let root_node = if BOSS_HP_BAR_USE_CENTER {
-        // Use center alignment
-        Node {
-            width: percent(100.0),
-            height: percent(100.0),
-            justify_content: JustifyContent::Center,
-            align_items: AlignItems::Center,
-            ..default()
+    match (remaining, text_query.single_mut()) {
+        (Some(remaining), Ok((_, mut text))) => {
+            text.0 = format!("SURVIVE! {:.1}", remaining.max(0.0));
         }
-    } else {
-        // Use margin-based positioning with horizontal centering
-        Node {
-            width: percent(100.0),
-            height: percent(100.0),
-            justify_content: JustifyContent::Center, // Center horizontally
-            align_items: AlignItems::FlexStart, // Align to top for margin-based vertical positioning
-            ..default()
+        (Some(remaining), Err(_)) => {
+            commands.spawn((
+                DespawnOnExit(GameState::InGame),
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: percent(100.0),
+                    top: px(100.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                Text::new(format!("SURVIVE! {:.1}", remaining.max(0.0))),
+                TextFont {
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.9, 0.0)),
+                TextLayout::new_with_justify(Justify::Center),
+                SurvivalTimerText,
+            ));
         }
-    };
+        (None, Ok((entity, _))) => {
+            commands.entity(entity).despawn();
+        }
+        (None, Err(_)) => {}
+    }
+}
+
+/// System to give every newly spawned boss an HP bar.
+/// The bars stack in one shared column at the top of the screen, so any number of bosses can be tracked;
+/// `despawn_orphaned_health_bars` removes a bar once its boss is gone.
+pub fn spawn_boss_hp_bars(
+    mut commands: Commands,
+    boss_query: Query<(Entity, &BossData, &Hp), Added<Boss>>,
+    container_query: Query<Entity, With<BossHealthBarContainer>>,
+    settings: Res<GameSettings>,
+) {
+    if boss_query.is_empty() {
+        return;
+    }
 
-    commands.spawn((root_node, BossHealthBarContainer)).with_children(|parent| {
-        // HP bar container with configurable positioning
-        let hp_bar_node = if BOSS_HP_BAR_USE_CENTER {
-            // Centered - no margins needed
+    // Create the shared column on the first boss of the fight
+    let container = container_query.iter().next().unwrap_or_else(|| {
+        let root_node = if BOSS_HP_BAR_USE_CENTER {
+            // Use center alignment
             Node {
-                width: px(BOSS_HP_BAR_WIDTH),
-                height: px(BOSS_HP_BAR_HEIGHT),
-                border: UiRect::all(px(2.0)),
+                width: percent(100.0),
+                height: percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: px(BOSS_HP_BAR_SPACING),
                 ..default()
             }
         } else {
-            // Margin-based positioning
+            // Use margin-based positioning with horizontal centering
             Node {
-                width: px(BOSS_HP_BAR_WIDTH),
-                height: px(BOSS_HP_BAR_HEIGHT),
-                margin: UiRect {
+                width: percent(100.0),
+                height: percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::FlexStart, // Align to top for margin-based vertical positioning
+                align_items: AlignItems::Center,            // Center horizontally
+                padding: UiRect {
                     left: px(BOSS_HP_BAR_MARGIN_LEFT),
                     top: px(BOSS_HP_BAR_MARGIN_TOP),
                     right: px(BOSS_HP_BAR_MARGIN_RIGHT),
                     bottom: px(BOSS_HP_BAR_MARGIN_BOTTOM),
                 },
-                border: UiRect::all(px(2.0)),
+                row_gap: px(BOSS_HP_BAR_SPACING),
                 ..default()
             }
         };
+        commands
+            .spawn((root_node, BossHealthBarContainer, DespawnOnExit(GameState::InGame)))
+            .id()
+    });
 
-        parent
-            .spawn((hp_bar_node, BackgroundColor(Color::BLACK.into())))
-            .with_children(|hp_parent| {
-                // HP bar fill
-                hp_parent.spawn((
-                    Node {
-                        width: percent(100.0),
-                        height: percent(100.0),
+    for (boss, boss_data, hp) in &boss_query {
+        // Nameplate above the bar; the whole column is removed with the boss
+        let hp_bar = commands
+            .spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: px(2.0),
+                    ..default()
+                },
+                HealthBarRoot { entity: boss },
+            ))
+            .with_children(|column| {
+                column.spawn((
+                    Text::new(boss_data.name.clone()),
+                    TextFont {
+                        font_size: 20.0,
                         ..default()
                     },
-                    BackgroundColor(Color::srgb(1.0, 0.0, 0.0).into()), // Red for boss
-                    HealthBar { entity: boss },
+                    TextColor(Color::WHITE),
                 ));
-            });
-    });
+                column
+                    .spawn((
+                        Node {
+                            width: px(BOSS_HP_BAR_WIDTH),
+                            height: px(BOSS_HP_BAR_HEIGHT),
+                            border: UiRect::all(px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::BLACK),
+                    ))
+                    .with_children(|hp_parent| {
+                        // HP bar fill (a boss resumed from a checkpoint starts hurt)
+                        hp_parent.spawn((
+                            Node {
+                                width: percent((hp.current / hp.max).clamp(0.0, 1.0) * 100.0),
+                                height: percent(100.0),
+                                ..default()
+                            },
+                            BackgroundColor(settings.palette.boss_hp_bar_color()),
+                            HealthBar { entity: boss },
+                        ));
+                    });
+            })
+            .id();
+        commands.entity(container).add_child(hp_bar);
+    }
 }
@@ -0,0 +1,631 @@
+use crate::components::boss::*;
+use crate::components::player::*;
+use crate::systems::boss::{
+    AttackPatternConfig, MovementPatternConfig, convert_attack_pattern, convert_movement_pattern,
+};
+use crate::systems::boundaries::ArenaBounds;
+use crate::systems::collision::PLAYER_HITBOX_SIZE;
+use crate::systems::platforms::Ground;
+use crate::systems::rng::GameRng;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// JSON structure for a boss behavior tree node.
+///
+/// Composite nodes (`Sequence`, `Selector`, `RandomSelector`) decide which leaves run;
+/// leaves either check a condition or switch the boss's active attack/movement pattern.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum BehaviorNode {
+    /// Runs children in order, stopping at the first one that fails
+    Sequence { children: Vec<BehaviorNode> },
+    /// Runs children in order, stopping at the first one that succeeds
+    Selector { children: Vec<BehaviorNode> },
+    /// Runs one randomly picked child (optionally weighted)
+    RandomSelector {
+        children: Vec<BehaviorNode>,
+        #[serde(default)]
+        weights: Vec<f32>,
+    },
+    /// Succeeds if the condition holds, fails otherwise
+    Condition { condition: BehaviorCondition },
+    /// Switches the boss's attack pattern
+    Attack { attack: AttackPatternConfig },
+    /// Switches the boss's movement pattern
+    Move { movement: MovementPatternConfig },
+    /// Keeps the current patterns running for a while
+    Wait { duration: f32 },
+    /// Boss turns invulnerable and runs `attack` for `duration` seconds; the player has to survive it
+    Survive {
+        duration: f32,
+        attack: AttackPatternConfig,
+    },
+}
+
+/// Conditions a behavior tree can check
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum BehaviorCondition {
+    /// Boss HP fraction (0.0-1.0) is below the threshold
+    HpBelow { fraction: f32 },
+    /// Boss HP fraction (0.0-1.0) is at or above the threshold
+    HpAbove { fraction: f32 },
+    /// Player is within `distance` pixels of the boss
+    PlayerCloserThan { distance: f32 },
+    /// Player is further than `distance` pixels from the boss
+    PlayerFartherThan { distance: f32 },
+    /// Player is in the air (jumping or knocked up)
+    PlayerAirborne,
+    /// Player is standing on the ground
+    PlayerGrounded,
+    /// Boss is in the given fight phase (see `BossPhaseState`)
+    InPhase { phase: u32 },
+    /// Player is more than `height` pixels above the boss
+    PlayerAbove { height: f32 },
+    /// Boss is within `margin` pixels of an arena wall with the player on the open side
+    Cornered { margin: f32 },
+}
+
+/// Result of ticking a behavior tree node
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Status {
+    Success,
+    Failure,
+    Running,
+}
+
+/// Snapshot of the world a behavior tree is evaluated against
+struct BehaviorContext {
+    hp_fraction: f32,
+    phase: u32,
+    boss_position: Vec2,
+    player_position: Option<Vec2>,
+    player_grounded: Option<bool>, // Standing on the floor, a platform or a ladder
+    arena: ArenaBounds,
+}
+
+impl BehaviorContext {
+    fn distance_to_player(&self) -> Option<f32> {
+        self.player_position
+            .map(|player_position| player_position.distance(self.boss_position))
+    }
+
+    fn player_airborne(&self) -> Option<bool> {
+        self.player_grounded.map(|grounded| !grounded)
+    }
+}
+
+impl BehaviorCondition {
+    fn evaluate(&self, context: &BehaviorContext) -> bool {
+        match self {
+            BehaviorCondition::HpBelow { fraction } => context.hp_fraction < *fraction,
+            BehaviorCondition::HpAbove { fraction } => context.hp_fraction >= *fraction,
+            BehaviorCondition::PlayerCloserThan { distance } => context
+                .distance_to_player()
+                .is_some_and(|player_distance| player_distance < *distance),
+            BehaviorCondition::PlayerFartherThan { distance } => context
+                .distance_to_player()
+                .is_some_and(|player_distance| player_distance > *distance),
+            BehaviorCondition::PlayerAirborne => context.player_airborne() == Some(true),
+            BehaviorCondition::PlayerGrounded => context.player_airborne() == Some(false),
+            BehaviorCondition::InPhase { phase } => context.phase == *phase,
+            BehaviorCondition::PlayerAbove { height } => {
+                context.player_position.is_some_and(|player_position| {
+                    player_position.y - context.boss_position.y > *height
+                })
+            }
+            BehaviorCondition::Cornered { margin } => {
+                let Some(player_position) = context.player_position else {
+                    return false;
+                };
+                let boss_x = context.boss_position.x;
+                // Near the left wall with the player to the right, or near the right wall with the player to the left
+                (boss_x - context.arena.left <= *margin && player_position.x > boss_x)
+                    || (context.arena.right - boss_x <= *margin && player_position.x < boss_x)
+            }
+        }
+    }
+}
+
+impl BehaviorNode {
+    fn children(&self) -> &[BehaviorNode] {
+        match self {
+            BehaviorNode::Sequence { children }
+            | BehaviorNode::Selector { children }
+            | BehaviorNode::RandomSelector { children, .. } => children,
+            _ => &[],
+        }
+    }
+
+    /// Number of nodes in this subtree (used to give every node a stable preorder id)
+    fn subtree_size(&self) -> usize {
+        1 + self
+            .children()
+            .iter()
+            .map(BehaviorNode::subtree_size)
+            .sum::<usize>()
+    }
+}
+
+/// Component that drives a boss with a behavior tree loaded from its pattern JSON
+#[derive(Component)]
+pub struct BossBrain {
+    pub tree: BehaviorNode,
+    state: BrainState,
+}
+
+/// Execution state of a behavior tree, kept separate from the tree so both can be borrowed at once
+struct BrainState {
+    /// Per-node memory, indexed by preorder node id (resume index for composites, started flag for waits)
+    memory: Vec<usize>,
+    /// Remaining time of the currently running `Wait` node
+    wait_timer: f32,
+}
+
+impl BossBrain {
+    pub fn new(tree: BehaviorNode) -> Self {
+        let node_count = tree.subtree_size();
+        Self {
+            tree,
+            state: BrainState {
+                memory: vec![0; node_count],
+                wait_timer: 0.0,
+            },
+        }
+    }
+}
+
+/// Mutable state a behavior tree tick can change
+struct BehaviorOutput<'a> {
+    boss_data: &'a mut BossData,
+    attack_state: &'a mut BossAttackState,
+    movement_state: &'a mut BossMovementState,
+    /// Set when a survival phase starts (invulnerability duration)
+    survival_duration: Option<f32>,
+    /// Shared run RNG, so random picks replay from the run seed
+    rng: &'a mut GameRng,
+}
+
+fn tick_node(
+    node: &BehaviorNode,
+    id: usize,
+    state: &mut BrainState,
+    context: &BehaviorContext,
+    output: &mut BehaviorOutput,
+) -> Status {
+    // Preorder id of each child: parent id + 1 + sizes of earlier siblings
+    let child_ids = |children: &[BehaviorNode]| -> Vec<usize> {
+        let mut next_id = id + 1;
+        children
+            .iter()
+            .map(|child| {
+                let child_id = next_id;
+                next_id += child.subtree_size();
+                child_id
+            })
+            .collect()
+    };
+
+    match node {
+        BehaviorNode::Sequence { children } => {
+            let ids = child_ids(children);
+            let mut index = state.memory[id];
+            while index < children.len() {
+                match tick_node(&children[index], ids[index], state, context, output) {
+                    Status::Success => index += 1,
+                    Status::Running => {
+                        state.memory[id] = index;
+                        return Status::Running;
+                    }
+                    Status::Failure => {
+                        state.memory[id] = 0;
+                        return Status::Failure;
+                    }
+                }
+            }
+            state.memory[id] = 0;
+            Status::Success
+        }
+        BehaviorNode::Selector { children } => {
+            let ids = child_ids(children);
+            let mut index = state.memory[id];
+            while index < children.len() {
+                match tick_node(&children[index], ids[index], state, context, output) {
+                    Status::Failure => index += 1,
+                    Status::Running => {
+                        state.memory[id] = index;
+                        return Status::Running;
+                    }
+                    Status::Success => {
+                        state.memory[id] = 0;
+                        return Status::Success;
+                    }
+                }
+            }
+            state.memory[id] = 0;
+            Status::Failure
+        }
+        BehaviorNode::RandomSelector { children, weights } => {
+            if children.is_empty() {
+                return Status::Failure;
+            }
+            let ids = child_ids(children);
+
+            // Memory stores the picked child + 1 while it is running (0 = nothing picked yet)
+            let index = if state.memory[id] > 0 {
+                state.memory[id] - 1
+            } else {
+                // Missing weights count as 1.0
+                let child_weights: Vec<f32> = (0..children.len())
+                    .map(|i| weights.get(i).copied().unwrap_or(1.0))
+                    .collect();
+                output
+                    .rng
+                    .weighted_index(&child_weights)
+                    .unwrap_or(children.len() - 1)
+            };
+
+            let status = tick_node(&children[index], ids[index], state, context, output);
+            state.memory[id] = if status == Status::Running {
+                index + 1
+            } else {
+                0
+            };
+            status
+        }
+        BehaviorNode::Condition { condition } => {
+            if condition.evaluate(context) {
+                Status::Success
+            } else {
+                Status::Failure
+            }
+        }
+        BehaviorNode::Attack { attack } => {
+            set_attack_pattern(attack, output);
+            Status::Success
+        }
+        BehaviorNode::Move { movement } => {
+            let pattern = convert_movement_pattern(movement);
+            if output.boss_data.movement_pattern != pattern {
+                output.boss_data.movement_pattern = pattern;
+                // Keep any jump in progress so the boss still lands
+                let jump_state = &*output.movement_state;
+                *output.movement_state = BossMovementState {
+                    velocity: jump_state.velocity,
+                    airborne: jump_state.airborne,
+                    slam_pending: jump_state.slam_pending,
+                    hover_timer: jump_state.hover_timer,
+                    ..default()
+                };
+            }
+            Status::Success
+        }
+        BehaviorNode::Wait { duration } => {
+            if state.memory[id] == 0 {
+                // Start waiting; the executor stops ticking the tree until the timer runs out
+                state.memory[id] = 1;
+                state.wait_timer = *duration;
+                Status::Running
+            } else {
+                state.memory[id] = 0;
+                Status::Success
+            }
+        }
+        BehaviorNode::Survive { duration, attack } => {
+            if state.memory[id] == 0 {
+                // Start the barrage and wait it out like a Wait node
+                state.memory[id] = 1;
+                state.wait_timer = *duration;
+                set_attack_pattern(attack, output);
+                output.survival_duration = Some(*duration);
+                Status::Running
+            } else {
+                state.memory[id] = 0;
+                Status::Success
+            }
+        }
+    }
+}
+
+/// Switches the boss's attack pattern, dropping any half-finished burst/swing/beam of the previous one
+fn set_attack_pattern(attack: &AttackPatternConfig, output: &mut BehaviorOutput) {
+    let pattern = convert_attack_pattern(attack);
+    if output.boss_data.attack_pattern != pattern {
+        output.boss_data.attack_pattern = pattern;
+        // boss_attacks despawns the leftover attack entity
+        output.attack_state.burst_count = 0;
+        output.attack_state.attack_phase = AttackPhase::Idle;
+    }
+}
+
+/// System to run boss behavior trees.
+/// Runs before the movement/attack systems, which execute whichever patterns the tree selected.
+pub fn boss_behavior(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut boss_query: Query<
+        (
+            Entity,
+            &Transform,
+            &Hp,
+            &BossPhaseState,
+            &mut BossBrain,
+            &mut BossData,
+            &mut BossAttackState,
+            &mut BossMovementState,
+        ),
+        With<Boss>,
+    >,
+    player_query: Query<(&Transform, Has<Climbing>), (With<Player>, Without<Boss>)>,
+    mut rng: ResMut<GameRng>,
+    arena: Res<ArenaBounds>,
+    ground: Ground,
+) {
+    let player = player_query.single().ok();
+    let player_position = player.map(|(transform, _)| transform.translation.truncate());
+    // Grounded the same way the player's own movement sees it
+    let player_grounded = player.map(|(transform, climbing)| {
+        climbing
+            || ground.is_supported(
+                transform.translation.x,
+                transform.translation.y,
+                PLAYER_HITBOX_SIZE / 2.0,
+            )
+    });
+
+    for (
+        boss_entity,
+        boss_transform,
+        hp,
+        phase_state,
+        mut brain,
+        mut boss_data,
+        mut attack_state,
+        mut movement_state,
+    ) in &mut boss_query
+    {
+        if brain.state.wait_timer > 0.0 {
+            brain.state.wait_timer -= time.delta_secs();
+            if brain.state.wait_timer > 0.0 {
+                continue;
+            }
+        }
+
+        let context = BehaviorContext {
+            hp_fraction: (hp.current / hp.max).clamp(0.0, 1.0),
+            phase: phase_state.phase,
+            boss_position: boss_transform.translation.truncate(),
+            player_position,
+            player_grounded,
+            arena: *arena,
+        };
+
+        let mut output = BehaviorOutput {
+            boss_data: &mut boss_data,
+            attack_state: &mut attack_state,
+            movement_state: &mut movement_state,
+            survival_duration: None,
+            rng: &mut rng,
+        };
+
+        let BossBrain { tree, state } = &mut *brain;
+        tick_node(tree, 0, state, &context, &mut output);
+
+        if let Some(duration) = output.survival_duration {
+            commands
+                .entity(boss_entity)
+                .insert(BossInvulnerable { timer: duration });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Single shot whose cooldown tells the tests which leaf switched the attack
+    fn shot(cooldown: f32) -> BehaviorNode {
+        BehaviorNode::Attack {
+            attack: AttackPatternConfig::SingleShot {
+                cooldown,
+                projectile_speed: 300.0,
+                gravity: 0.0,
+                cardinal_only: false,
+            },
+        }
+    }
+
+    fn hp_below(fraction: f32) -> BehaviorNode {
+        BehaviorNode::Condition {
+            condition: BehaviorCondition::HpBelow { fraction },
+        }
+    }
+
+    /// Boss at full HP on the right of the default arena, with the player grounded on the left
+    fn context() -> BehaviorContext {
+        BehaviorContext {
+            hp_fraction: 1.0,
+            phase: 1,
+            boss_position: Vec2::new(300.0, -198.0),
+            player_position: Some(Vec2::new(-300.0, -198.0)),
+            player_grounded: Some(true),
+            arena: ArenaBounds::default(),
+        }
+    }
+
+    /// Boss state a tree is ticked against, starting without an attack
+    struct TestBoss {
+        data: BossData,
+        attack_state: BossAttackState,
+        movement_state: BossMovementState,
+        rng: GameRng,
+    }
+
+    impl TestBoss {
+        fn new() -> Self {
+            Self {
+                data: BossData {
+                    attack_pattern: AttackPattern::None,
+                    ..default()
+                },
+                attack_state: BossAttackState::default(),
+                movement_state: BossMovementState::default(),
+                rng: GameRng::new(Some(7)),
+            }
+        }
+
+        fn tick(&mut self, brain: &mut BossBrain, context: &BehaviorContext) -> Status {
+            let mut output = BehaviorOutput {
+                boss_data: &mut self.data,
+                attack_state: &mut self.attack_state,
+                movement_state: &mut self.movement_state,
+                survival_duration: None,
+                rng: &mut self.rng,
+            };
+            let BossBrain { tree, state } = brain;
+            tick_node(tree, 0, state, context, &mut output)
+        }
+
+        /// Cooldown of the attack a `shot` leaf switched to (None if none ran)
+        fn shot_cooldown(&self) -> Option<f32> {
+            match self.data.attack_pattern {
+                AttackPattern::SingleShot { cooldown, .. } => Some(cooldown),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn sequence_stops_at_the_first_failing_child() {
+        let mut brain = BossBrain::new(BehaviorNode::Sequence {
+            children: vec![shot(1.0), hp_below(0.5), shot(2.0)],
+        });
+        let mut boss = TestBoss::new();
+        assert_eq!(boss.tick(&mut brain, &context()), Status::Failure);
+        assert_eq!(boss.shot_cooldown(), Some(1.0));
+    }
+
+    #[test]
+    fn sequence_succeeds_once_every_child_has() {
+        let mut brain = BossBrain::new(BehaviorNode::Sequence {
+            children: vec![shot(1.0), hp_below(1.5), shot(2.0)],
+        });
+        let mut boss = TestBoss::new();
+        assert_eq!(boss.tick(&mut brain, &context()), Status::Success);
+        assert_eq!(boss.shot_cooldown(), Some(2.0));
+    }
+
+    #[test]
+    fn sequence_resumes_after_a_running_child() {
+        let mut brain = BossBrain::new(BehaviorNode::Sequence {
+            children: vec![shot(1.0), BehaviorNode::Wait { duration: 1.0 }, shot(2.0)],
+        });
+        let mut boss = TestBoss::new();
+        assert_eq!(boss.tick(&mut brain, &context()), Status::Running);
+        assert_eq!(boss.shot_cooldown(), Some(1.0));
+
+        // The next tick picks up at the wait instead of starting over
+        boss.data.attack_pattern = AttackPattern::None;
+        assert_eq!(boss.tick(&mut brain, &context()), Status::Success);
+        assert_eq!(boss.shot_cooldown(), Some(2.0));
+    }
+
+    #[test]
+    fn selector_stops_at_the_first_succeeding_child() {
+        let mut brain = BossBrain::new(BehaviorNode::Selector {
+            children: vec![hp_below(0.5), shot(1.0), shot(2.0)],
+        });
+        let mut boss = TestBoss::new();
+        assert_eq!(boss.tick(&mut brain, &context()), Status::Success);
+        assert_eq!(boss.shot_cooldown(), Some(1.0));
+    }
+
+    #[test]
+    fn selector_fails_when_every_child_fails() {
+        let mut brain = BossBrain::new(BehaviorNode::Selector {
+            children: vec![hp_below(0.5), hp_below(0.25)],
+        });
+        let mut boss = TestBoss::new();
+        assert_eq!(boss.tick(&mut brain, &context()), Status::Failure);
+        assert_eq!(boss.shot_cooldown(), None);
+    }
+
+    #[test]
+    fn random_selector_counts_missing_weights_as_one() {
+        // Only the second child, whose weight is missing, can be picked
+        let mut brain = BossBrain::new(BehaviorNode::RandomSelector {
+            children: vec![shot(1.0), shot(2.0)],
+            weights: vec![0.0],
+        });
+        let mut boss = TestBoss::new();
+        for _ in 0..20 {
+            assert_eq!(boss.tick(&mut brain, &context()), Status::Success);
+            assert_eq!(boss.shot_cooldown(), Some(2.0));
+        }
+    }
+
+    #[test]
+    fn random_selector_keeps_its_running_pick() {
+        let mut brain = BossBrain::new(BehaviorNode::RandomSelector {
+            children: vec![
+                BehaviorNode::Sequence {
+                    children: vec![BehaviorNode::Wait { duration: 1.0 }, shot(1.0)],
+                },
+                shot(2.0),
+            ],
+            weights: vec![1.0, 0.0],
+        });
+        let mut boss = TestBoss::new();
+        assert_eq!(boss.tick(&mut brain, &context()), Status::Running);
+        // Resuming the picked child doesn't roll again, even if the weights would pick another
+        brain.tree = BehaviorNode::RandomSelector {
+            children: vec![
+                BehaviorNode::Sequence {
+                    children: vec![BehaviorNode::Wait { duration: 1.0 }, shot(1.0)],
+                },
+                shot(2.0),
+            ],
+            weights: vec![0.0, 1.0],
+        };
+        assert_eq!(boss.tick(&mut brain, &context()), Status::Success);
+        assert_eq!(boss.shot_cooldown(), Some(1.0));
+    }
+
+    #[test]
+    fn condition_node_reports_whether_its_condition_holds() {
+        let mut boss = TestBoss::new();
+        let mut low_hp = context();
+        low_hp.hp_fraction = 0.25;
+        let mut brain = BossBrain::new(hp_below(0.5));
+        assert_eq!(boss.tick(&mut brain, &context()), Status::Failure);
+        assert_eq!(boss.tick(&mut brain, &low_hp), Status::Success);
+    }
+
+    #[test]
+    fn conditions_on_the_player_fail_without_a_player() {
+        let mut no_player = context();
+        no_player.player_position = None;
+        no_player.player_grounded = None;
+        for condition in [
+            BehaviorCondition::PlayerCloserThan { distance: 10_000.0 },
+            BehaviorCondition::PlayerFartherThan { distance: 0.0 },
+            BehaviorCondition::PlayerAirborne,
+            BehaviorCondition::PlayerGrounded,
+            BehaviorCondition::PlayerAbove { height: -10_000.0 },
+            BehaviorCondition::Cornered { margin: 10_000.0 },
+        ] {
+            assert!(!condition.evaluate(&no_player), "{:?}", condition);
+        }
+    }
+
+    #[test]
+    fn cornered_needs_the_player_on_the_open_side() {
+        let cornered = BehaviorCondition::Cornered { margin: 60.0 };
+        // Boss 50 from the right wall (350) with the player to its left
+        assert!(cornered.evaluate(&context()));
+
+        let mut player_behind = context();
+        player_behind.player_position = Some(Vec2::new(340.0, -198.0));
+        assert!(!cornered.evaluate(&player_behind));
+    }
+}
@@ -1,45 +1,129 @@
 use crate::components::player::BoundaryWall;
+use crate::stages::game_menu::{CurrentStage, GameState};
+use crate::systems::boss::{ArenaConfig, BossPatternRegistry};
+use crate::systems::collision::PLAYER_HITBOX_SIZE;
 use crate::systems::config::{
     BOUNDARY_BOTTOM, BOUNDARY_LEFT, BOUNDARY_RIGHT, BOUNDARY_TOP, BOUNDARY_WALL_THICKNESS,
 };
+use crate::systems::physics::kinematics::clamp_to_bounds;
 use bevy::prelude::*;
 
+/// Resource with the current arena's walls and ceiling. Stages can narrow or widen the arena
+/// through the `arena` entry of their boss pattern file; the floor is shared by every stage.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct ArenaBounds {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32, // Player ground level
+}
+
+impl Default for ArenaBounds {
+    fn default() -> Self {
+        Self {
+            left: BOUNDARY_LEFT,
+            right: BOUNDARY_RIGHT,
+            top: BOUNDARY_TOP,
+            bottom: BOUNDARY_BOTTOM,
+        }
+    }
+}
+
+impl ArenaBounds {
+    /// Bounds of the arena set by a stage's `arena` entry (the floor stays the shared one)
+    pub fn from_config(config: &ArenaConfig) -> Self {
+        Self {
+            left: config.left,
+            right: config.right,
+            top: config.top,
+            ..default()
+        }
+    }
+
+    pub fn width(&self) -> f32 {
+        self.right - self.left
+    }
+
+    pub fn clamp_x(&self, x: f32) -> f32 {
+        clamp_to_bounds(x, self.left, self.right)
+    }
+
+    pub fn clamp_y(&self, y: f32) -> f32 {
+        clamp_to_bounds(y, self.bottom, self.top)
+    }
+
+    /// Whether `x` is outside the arena walls
+    pub fn outside_x(&self, x: f32) -> bool {
+        x < self.left || x > self.right
+    }
+
+    /// Top surface of the floor (the player stands centered at `bottom`)
+    pub fn floor_top(&self) -> f32 {
+        self.bottom - PLAYER_HITBOX_SIZE.y / 2.0
+    }
+}
+
+/// System to set the arena bounds from the current stage's pattern (defaults when it sets none)
+pub fn apply_stage_arena_bounds(
+    mut arena: ResMut<ArenaBounds>,
+    pattern_registry: Res<BossPatternRegistry>,
+    current_stage: Res<CurrentStage>,
+) {
+    let pattern = pattern_registry.get_pattern(&format!("stage_{}", current_stage.0));
+    *arena = pattern
+        .and_then(|pattern| pattern.arena.as_ref())
+        .map_or_else(ArenaBounds::default, ArenaBounds::from_config);
+}
+
+/// System to go back to the default arena once the fight is over (e.g. for the stage editor)
+pub fn reset_arena_bounds(mut arena: ResMut<ArenaBounds>) {
+    *arena = ArenaBounds::default();
+}
+
 /// Spawns the visual boundary walls (red walls on left/right, green line on top)
 pub fn spawn_boundaries(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    state: Res<State<GameState>>,
+    arena: Res<ArenaBounds>,
 ) {
+    // Walls belong to whichever state spawned them (the fight or the stage editor)
+    let scope = DespawnOnExit(*state.get());
+
     // Left wall (red)
     commands.spawn((
         Mesh2d(meshes.add(Rectangle::new(
             BOUNDARY_WALL_THICKNESS,
-            BOUNDARY_TOP - BOUNDARY_BOTTOM,
+            arena.top - arena.bottom,
         ))),
         MeshMaterial2d(materials.add(Color::srgb(1.0, 0.0, 0.0))), // Red
-        Transform::from_xyz(BOUNDARY_LEFT, (BOUNDARY_TOP + BOUNDARY_BOTTOM) / 2.0, 0.0),
+        Transform::from_xyz(arena.left, (arena.top + arena.bottom) / 2.0, 0.0),
         BoundaryWall,
+        scope.clone(),
     ));
 
     // Right wall (red)
     commands.spawn((
         Mesh2d(meshes.add(Rectangle::new(
             BOUNDARY_WALL_THICKNESS,
-            BOUNDARY_TOP - BOUNDARY_BOTTOM,
+            arena.top - arena.bottom,
         ))),
         MeshMaterial2d(materials.add(Color::srgb(1.0, 0.0, 0.0))), // Red
-        Transform::from_xyz(BOUNDARY_RIGHT, (BOUNDARY_TOP + BOUNDARY_BOTTOM) / 2.0, 0.0),
+        Transform::from_xyz(arena.right, (arena.top + arena.bottom) / 2.0, 0.0),
         BoundaryWall,
+        scope.clone(),
     ));
 
     // Top boundary line (green)
     commands.spawn((
         Mesh2d(meshes.add(Rectangle::new(
-            BOUNDARY_RIGHT - BOUNDARY_LEFT,
+            arena.width(),
             BOUNDARY_WALL_THICKNESS,
         ))),
         MeshMaterial2d(materials.add(Color::srgb(0.0, 1.0, 0.0))), // Green
-        Transform::from_xyz((BOUNDARY_LEFT + BOUNDARY_RIGHT) / 2.0, BOUNDARY_TOP, 0.0),
+        Transform::from_xyz((arena.left + arena.right) / 2.0, arena.top, 0.0),
         BoundaryWall,
+        scope,
     ));
 }
\ No newline at end of file
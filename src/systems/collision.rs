@@ -0,0 +1,68 @@
+use crate::components::player::Projectile;
+use crate::systems::config::{SHOCKWAVE_HEIGHT, SHOCKWAVE_WIDTH};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+#[cfg(not(feature = "avian"))]
+use crate::systems::collision_grid::CollisionGrid;
+
+pub const PLAYER_HITBOX_SIZE: Vec2 = Vec2::new(32.0, 64.0);
+pub const BOSS_HITBOX_SIZE: Vec2 = Vec2::new(32.0, 64.0);
+pub const PROJECTILE_HITBOX_SIZE: Vec2 = Vec2::new(10.0, 10.0); // Uncharged shot; charged shots grow with charge level
+pub const SHOCKWAVE_HITBOX_SIZE: Vec2 = Vec2::new(SHOCKWAVE_WIDTH, SHOCKWAVE_HEIGHT);
+
+/// Hitbox of a projectile, which grows with its charge level (1.0x to 2.5x)
+pub fn projectile_hitbox_size(projectile: &Projectile) -> Vec2 {
+    PROJECTILE_HITBOX_SIZE * (1.0 + projectile.charge_level * 1.5)
+}
+
+/// What a collider belongs to (collision systems only ever look for one kind at a time)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColliderKind {
+    Player,
+    Boss,
+    PlayerProjectile,
+    BossProjectile,
+    Shockwave,
+    Enemy, // Enemies other than bosses that player shots can hit (minions, destructible hazards)
+}
+
+/// Marker component for enemies other than bosses that player shots damage. They need a `Hitbox`
+/// and an `Hp`, and are destroyed when their HP runs out.
+#[derive(Component)]
+pub struct Enemy;
+
+/// Component for the size of an entity's hitbox, for colliders without a fixed size
+#[derive(Component, Clone, Copy)]
+pub struct Hitbox(pub Vec2);
+
+/// System set keeping the collision backend current (rebuilding the grid, or attaching avian colliders).
+/// Runs after everything has moved and before the collision systems.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UpdateColliders;
+
+/// Collision lookups for the collision systems. Backed by the built-in `CollisionGrid`,
+/// or by avian2d's spatial queries when built with the `avian` feature.
+#[derive(SystemParam)]
+pub struct CollisionLookup<'w, 's> {
+    #[cfg(not(feature = "avian"))]
+    grid: Res<'w, CollisionGrid>,
+    #[cfg(not(feature = "avian"))]
+    _marker: std::marker::PhantomData<&'s ()>,
+    #[cfg(feature = "avian")]
+    spatial_query: avian2d::prelude::SpatialQuery<'w, 's>,
+}
+
+impl CollisionLookup<'_, '_> {
+    /// Entities of the given kind whose hitboxes overlap the box at `center` with `size`
+    pub fn overlapping(&self, center: Vec2, size: Vec2, kind: ColliderKind) -> Vec<Entity> {
+        #[cfg(not(feature = "avian"))]
+        {
+            self.grid.query(center, size, kind)
+        }
+        #[cfg(feature = "avian")]
+        {
+            crate::systems::physics::overlapping(&self.spatial_query, center, size, kind)
+        }
+    }
+}
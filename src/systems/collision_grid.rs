@@ -0,0 +1,137 @@
+use crate::components::boss::Boss;
+use crate::components::player::{Player, Projectile, ProjectileHasHit};
+use crate::systems::boss::{BossProjectile, BossShockwave};
+use crate::systems::collision::{
+    BOSS_HITBOX_SIZE, ColliderKind, Enemy, Hitbox, PLAYER_HITBOX_SIZE, SHOCKWAVE_HITBOX_SIZE,
+    projectile_hitbox_size,
+};
+use crate::systems::config::COLLISION_GRID_CELL_SIZE;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy)]
+struct GridCollider {
+    entity: Entity,
+    kind: ColliderKind,
+    min: Vec2,
+    max: Vec2,
+}
+
+/// Uniform-grid broadphase for the collision systems, rebuilt every frame from the colliders' positions.
+/// Collision systems ask it (through `CollisionLookup`) for what overlaps a box instead of testing
+/// every projectile against every target.
+#[derive(Resource, Default)]
+pub struct CollisionGrid {
+    colliders: Vec<GridCollider>,
+    cells: HashMap<IVec2, Vec<usize>>, // Cell -> indices into `colliders`
+}
+
+fn cell_of(point: Vec2) -> IVec2 {
+    (point / COLLISION_GRID_CELL_SIZE).floor().as_ivec2()
+}
+
+impl CollisionGrid {
+    /// Remove every collider (cell vectors are kept to avoid reallocating each frame)
+    pub fn clear(&mut self) {
+        self.colliders.clear();
+        for indices in self.cells.values_mut() {
+            indices.clear();
+        }
+    }
+
+    /// Add an axis-aligned box collider centered at `center`
+    pub fn insert(&mut self, entity: Entity, kind: ColliderKind, center: Vec2, size: Vec2) {
+        let index = self.colliders.len();
+        let half_size = size * 0.5;
+        let collider = GridCollider {
+            entity,
+            kind,
+            min: center - half_size,
+            max: center + half_size,
+        };
+        self.colliders.push(collider);
+
+        let (min_cell, max_cell) = (cell_of(collider.min), cell_of(collider.max));
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(index);
+            }
+        }
+    }
+
+    /// Entities of the given kind whose boxes overlap the box at `center` with `size`,
+    /// in the order they were added to the grid
+    pub fn query(&self, center: Vec2, size: Vec2, kind: ColliderKind) -> Vec<Entity> {
+        let half_size = size * 0.5;
+        let (min, max) = (center - half_size, center + half_size);
+        let (min_cell, max_cell) = (cell_of(min), cell_of(max));
+
+        let mut hits = Vec::new();
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                let Some(indices) = self.cells.get(&IVec2::new(x, y)) else {
+                    continue;
+                };
+                for &index in indices {
+                    let collider = &self.colliders[index];
+                    if collider.kind == kind
+                        && collider.min.x < max.x
+                        && collider.max.x > min.x
+                        && collider.min.y < max.y
+                        && collider.max.y > min.y
+                    {
+                        hits.push(index);
+                    }
+                }
+            }
+        }
+
+        // Colliders spanning several cells are found once per cell
+        hits.sort_unstable();
+        hits.dedup();
+        hits.into_iter()
+            .map(|index| self.colliders[index].entity)
+            .collect()
+    }
+}
+
+/// System to rebuild the collision grid after everything has moved this frame
+pub fn update_collision_grid(
+    mut grid: ResMut<CollisionGrid>,
+    player_query: Query<(Entity, &Transform), With<Player>>,
+    boss_query: Query<(Entity, &Transform), With<Boss>>,
+    projectile_query: Query<
+        (Entity, &Transform, &Projectile, Has<BossProjectile>),
+        Without<ProjectileHasHit>,
+    >,
+    shockwave_query: Query<(Entity, &Transform), With<BossShockwave>>,
+    enemy_query: Query<(Entity, &Transform, &Hitbox), With<Enemy>>,
+) {
+    grid.clear();
+
+    for (entity, transform) in &player_query {
+        let center = transform.translation.truncate();
+        grid.insert(entity, ColliderKind::Player, center, PLAYER_HITBOX_SIZE);
+    }
+    for (entity, transform) in &boss_query {
+        let center = transform.translation.truncate();
+        grid.insert(entity, ColliderKind::Boss, center, BOSS_HITBOX_SIZE);
+    }
+    for (entity, transform, projectile, is_boss_projectile) in &projectile_query {
+        let kind = if is_boss_projectile {
+            ColliderKind::BossProjectile
+        } else {
+            ColliderKind::PlayerProjectile
+        };
+        let center = transform.translation.truncate();
+        grid.insert(entity, kind, center, projectile_hitbox_size(projectile));
+    }
+    for (entity, transform) in &shockwave_query {
+        let center = transform.translation.truncate();
+        grid.insert(entity, ColliderKind::Shockwave, center, SHOCKWAVE_HITBOX_SIZE);
+    }
+    for (entity, transform, hitbox) in &enemy_query {
+        let center = transform.translation.truncate();
+        grid.insert(entity, ColliderKind::Enemy, center, hitbox.0);
+    }
+}
@@ -1,28 +1,65 @@
-pub const SMALL_JUMP_CHARGE_RATIO: f32 = 0.43;
+// Player movement (defaults for GameConfig, tunable at runtime through GAME_CONFIG_PATH)
+pub const PLAYER_MOVE_SPEED: f32 = 200.0; // Pixels per second
+pub const PLAYER_DASH_SPEED: f32 = 400.0; // Pixels per second
+pub const PLAYER_DASH_DURATION: f32 = 0.2; // Seconds
+pub const PLAYER_DASH_COOLDOWN: f32 = 0.4; // Seconds after a dash ends before the next one can start
+pub const PLAYER_BASE_GRAVITY: f32 = 800.0; // Base gravity acceleration in pixels per second squared
+pub const PLAYER_HIGH_JUMP_STRENGTH: f32 = 620.0; // Launch speed of a fully charged jump
+pub const PLAYER_HIGH_JUMP_GRAVITY_SCALE: f32 = 1.5; // Base gravity multiplier during a high jump
+pub const PLAYER_SMALL_JUMP_STRENGTH: f32 = 350.5; // Launch speed of a quick tap jump
+pub const PLAYER_SMALL_JUMP_GRAVITY_SCALE: f32 = 1.2; // Base gravity multiplier during a small jump
+pub const PLAYER_JUMP_MAX_CHARGE_TIME: f32 = 0.2; // Maximum charge time for high jump (seconds)
+pub const PLAYER_SMALL_JUMP_CHARGE_RATIO: f32 = 0.43; // Share of the max charge time below which a jump is a small jump
+pub const GAME_CONFIG_PATH: &str = "game_config.ron"; // Optional RON overrides for GameConfig (hot-reloaded in debug builds)
 
-// Jump mechanics
-pub const BASE_JUMP_STRENGTH: f32 = 400.0;
-pub const BASE_GRAVITY: f32 = 800.0;
+// The jump defaults must keep a tap jump lower than a held one, and both falling at least as fast as walking off a ledge
+const _: () = assert!(PLAYER_SMALL_JUMP_STRENGTH < PLAYER_HIGH_JUMP_STRENGTH);
+const _: () = assert!(PLAYER_HIGH_JUMP_GRAVITY_SCALE >= 1.0 && PLAYER_SMALL_JUMP_GRAVITY_SCALE >= 1.0);
+const _: () = assert!(PLAYER_SMALL_JUMP_CHARGE_RATIO > 0.0 && PLAYER_SMALL_JUMP_CHARGE_RATIO < 1.0);
 
-pub const HIGH_JUMP_STRENGTH_MULTIPLIER: f32 = 1.1;
-pub const HIGH_JUMP_GRAVITY_MULTIPLIER: f32 = 1.1;
+// Ladders (placed in stage layout files)
+pub const LADDER_WIDTH: f32 = 24.0; // Also how close to its middle the player has to be to grab it
+pub const LADDER_CLIMB_SPEED: f32 = 120.0; // Pixels per second
+pub const LADDER_RUNG_SPACING: f32 = 16.0;
 
-pub const SMALL_JUMP_STRENGTH_MULTIPLIER: f32 = 0.4;
-pub const SMALL_JUMP_GRAVITY_MULTIPLIER: f32 = 1.2;
+// Water zones (placed in stage layout files)
+pub const WATER_GRAVITY_SCALE: f32 = 0.45; // Gravity multiplier in water (jumps go higher and fall slower)
+pub const WATER_MOVE_SCALE: f32 = 0.6; // Walking speed multiplier in water
+pub const WATER_PROJECTILE_SCALE: f32 = 0.5; // Projectile speed multiplier in water
 
-pub const MAX_CHARGE_TIME: f32 = 0.2;
+// Breakable blocks (placed in stage layout files)
+pub const BLOCK_DEFAULT_HP: f32 = 30.0; // Blocks without their own `hp` take this much damage to break (charged shots break any block)
+pub const BLOCK_DEBRIS_COUNT: u32 = 10;
+pub const BLOCK_DEBRIS_SPEED: f32 = 150.0; // Pixels per second the debris flies out at
+
+// Wind zones and conveyor floors (placed in stage layout files)
+pub const CONVEYOR_HEIGHT: f32 = 8.0; // Thickness of a conveyor strip drawn along the floor
+pub const CONVEYOR_CHEVRON_SPACING: f32 = 32.0; // Distance between the arrows showing which way a wind zone or conveyor pushes
 
 // Knockback mechanics
 pub const KNOCKBACK_FORCE: f32 = 700.0; // Initial force of knockback push (pixels per second)
 pub const KNOCKBACK_DURATION: f32 = 0.7; // Duration of knockback effect (seconds)
-pub const KNOCKBACK_DECAY_RATE: f32 = 0.9; // Velocity decay per frame (0.0-1.0, higher = slower decay)
+pub const KNOCKBACK_DECAY_RATE: f32 = 6.3; // Exponential decay rate of knockback velocity (per second, higher = faster decay)
 pub const KNOCKBACK_MOVEMENT_REDUCTION: f32 = 0.3; // Player movement speed multiplier during knockback (0.0-1.0)
+pub const KNOCKBACK_LAUNCH_SCALE: f32 = 0.7; // Share of a knockback's upward push turned into launch speed (the arc then falls with gravity)
+
+// Character kits (Breadman is heavy and steady, Cheeseman quick but light)
+pub const BREADMAN_MOVE_SPEED_SCALE: f32 = 0.9; // Walking and dash speed multiplier
+pub const BREADMAN_KNOCKBACK_SCALE: f32 = 0.7; // Knockback taken multiplier (lower = heavier)
+pub const CHEESEMAN_MOVE_SPEED_SCALE: f32 = 1.15; // Walking and dash speed multiplier
+pub const CHEESEMAN_KNOCKBACK_SCALE: f32 = 1.3; // Knockback taken multiplier (higher = flies further)
 
 // Invincibility mechanics (damage immunity after taking damage)
 pub const INVINCIBILITY_DURATION: f32 = 0.7; // Duration of invincibility after taking damage (seconds)
 
 // Stage progression
 pub const MAX_STAGES: u32 = 2; // Maximum number of stages in the game
+pub const TUTORIAL_STAGE: u32 = 0; // Stage number of the optional tutorial (selectable from the character menu)
+pub const PATTERN_PREVIEW_STAGE: u32 = 99; // Stage number used by the developer pattern preview (empty arena, invincible player)
+pub const PATTERN_PREVIEW_TIMELINE_LENGTH: usize = 8; // Number of recent boss events listed in the preview timeline
+pub const STAGE_TITLE_CARD_TIME: f32 = 2.5; // Seconds the stage title card is shown as the fight starts
+pub const STARTING_LIVES: u32 = 3; // Continues available before a full game over (retry the current stage with upgrades kept)
+pub const RESTART_FIGHT_HOLD_TIME: f32 = 1.0; // Seconds R must be held mid-fight to restart it from the stage start
 
 // Knockback direction modifiers for different collision angles
 pub const KNOCKBACK_TOP_HORIZONTAL_COMPONENT: f32 = 0.6; // Horizontal component when hitting from top (0.0-1.0)
@@ -37,30 +74,281 @@ pub const BOSS_HP_BAR_MARGIN_BOTTOM: f32 = 0.0; // Bottom margin in pixels (0.0
 pub const BOSS_HP_BAR_MARGIN_LEFT: f32 = 0.0; // Left margin in pixels (0.0 = use center alignment)
 pub const BOSS_HP_BAR_MARGIN_RIGHT: f32 = 0.0; // Right margin in pixels (0.0 = use center alignment)
 pub const BOSS_HP_BAR_USE_CENTER: bool = false; // If true, centers the HP bar; if false, uses margins for positioning
+pub const BOSS_HP_BAR_SPACING: f32 = 8.0; // Vertical gap between stacked boss HP bars (one per boss)
 
 // Game boundaries (where entities can move)
 pub const BOUNDARY_LEFT: f32 = -350.0; // Left boundary X position
 pub const BOUNDARY_RIGHT: f32 = 350.0; // Right boundary X position
 pub const BOUNDARY_TOP: f32 = 200.0; // Top boundary Y position
 pub const BOUNDARY_BOTTOM: f32 = -198.0; // Bottom boundary Y position (player ground level)
+pub const FLOOR_TOP: f32 = -230.0; // Top surface of the floor (64px tall characters stand centered at BOUNDARY_BOTTOM)
 pub const BOUNDARY_WALL_THICKNESS: f32 = 4.0; // Thickness of boundary wall lines
 pub const BACKGROUND_PADDING: f32 = 50.0; // Padding around boundaries for background image (makes background slightly larger than boundaries)
 
+// Minimap (arena strip along the bottom of the HUD)
+pub const MINIMAP_WIDTH: f32 = 240.0; // Width of the strip in pixels (spans the arena wall to wall)
+pub const MINIMAP_HEIGHT: f32 = 10.0; // Height of the strip in pixels
+
+// Off-screen warnings (arrows at the screen edge for bosses and boss projectiles outside the view)
+pub const OFFSCREEN_WARNING_SIZE: f32 = 28.0; // Font size of the warning arrows in pixels
+pub const OFFSCREEN_WARNING_MARGIN: f32 = 6.0; // Gap between a warning and the screen edge in pixels
+
 // Player HP Bar UI positioning
 pub const PLAYER_HP_BAR_RADIUS: f32 = 30.0; // Radius of the circular HP bar in pixels
 pub const PLAYER_HP_BAR_MARGIN_LEFT: f32 = 20.0; // Left margin from screen edge
+pub const HP_ORB_SHADER_PATH: &str = "shaders/hp_orb.wgsl"; // Fragment shader for the player HP orb (liquid fill)
+
+// Low-HP warning
+pub const LOW_HP_WARNING_FRACTION: f32 = 0.25; // HP share below which the low-HP warning plays
+pub const LOW_HP_WARNING_PULSE_RATE: f32 = 1.2; // Pulses per second of the HP orb's red tint (about a heartbeat)
+pub const LOW_HP_WARNING_COLOR: bevy::color::Color = bevy::color::Color::srgb(1.0, 0.0, 0.0); // Color the HP orb pulses toward
+pub const LOW_HP_VIGNETTE_ALPHA: f32 = 0.35; // Opacity of the red vignette at the screen corners
 
 // Charge shot mechanics
 pub const CHARGE_SHOT_MAX_TIME: f32 = 1.0; // Maximum charge time in seconds
 pub const CHARGE_SHOT_MIN_TIME: f32 = 0.1; // Minimum charge time to fire a charged shot (prevents accidental charges)
 pub const CHARGE_SHOT_COOLDOWN: f32 = 0.3; // Cooldown after firing a charged shot
 pub const NORMAL_SHOT_COOLDOWN: f32 = 0.5; // Cooldown for normal (quick tap) shots
+pub const CHARGE_MOVE_SPEED_SCALE: f32 = 1.0; // Walk speed multiplier while charging (default for GameConfig, 1.0 = no slowdown)
+pub const CHARGE_READY_FLASH_DURATION: f32 = 0.15; // Seconds the charge effect flashes when the charge is full
+pub const CHARGE_READY_FLASH_SCALE: f32 = 1.4; // Size of the charge effect during the ready flash, relative to its ready size
+
+// Aim assist (optional, toggled in the settings menu)
+pub const AIM_ASSIST_CONE_ANGLE: f32 = 0.26; // Half-angle in radians (~15 degrees) around the shot in which a boss attracts it
+pub const AIM_ASSIST_STRENGTH: f32 = 0.5; // Share of the angle to the boss the shot is turned by (0.0-1.0)
+
+// Boss stats
+pub const BOSS_PATTERNS_DIR: &str = "boss_patterns"; // Directory of boss pattern JSON files (checked by --validate-patterns)
+pub const BOSS_DEFAULT_HP: f32 = 200.0; // Boss max HP when its pattern file doesn't set one
+pub const BOSS_SPAWN_SPACING: f32 = 150.0; // Horizontal gap between bosses when a stage spawns several at once
+pub const BOSS_ATTACK_STAGGER: f32 = 0.75; // Extra delay before each additional boss's first attack, so a duo doesn't fire in sync
 
 // Damage values for collisions
 pub const BOSS_COLLISION_DAMAGE: f32 = 10.0; // Damage dealt by boss body collision with player
 pub const PLAYER_PROJECTILE_DAMAGE: f32 = 20.0; // Base damage dealt by player projectiles to boss
 pub const CHARGE_SHOT_DAMAGE_MULTIPLIER: f32 = 3.0; // Fully charged shot deals 3x base damage
 pub const BOSS_PROJECTILE_DAMAGE: f32 = 15.0; // Damage dealt by boss projectiles to player
+pub const PROJECTILE_LIFETIME: f32 = 8.0; // Seconds before any projectile still flying is despawned
+pub const BOSS_PROJECTILE_CAP: usize = 250; // Most boss projectiles in play at once; past it the oldest are culled (default for GameConfig, 0 = no cap)
+pub const PROJECTILE_CAP_LEAVING_TIME: f32 = 0.5; // Boss projectiles about to leave the camera view within this many seconds are culled first
+
+// Tutorial
+pub const CONTROL_HINT_FADE_TIME: f32 = 0.6; // Seconds a control hint takes to fade out once its action is performed
 
 // Upgrade values
 pub const HP_RESTORATION_AMOUNT: f32 = 25.0; // Amount of HP restored when choosing HP upgrade
+pub const REGEN_UPGRADE_AMOUNT: f32 = 1.0; // HP per second added by each regeneration upgrade
+pub const REGEN_DELAY: f32 = 3.0; // Seconds without taking damage before regeneration kicks in
+
+// Boss weapons
+pub const WEAPON_ENERGY_MAX: f32 = 28.0; // Energy of a full boss weapon (refilled after every stage)
+pub const WEAPON_ENERGY_PER_SHOT: f32 = 2.0; // Energy spent per boss weapon shot
+pub const BOSS_WEAPON_DAMAGE_MULTIPLIER: f32 = 1.5; // Boss weapon shots hit harder than the default shot
+pub const WEAPON_RECOLOR_BLEND: f32 = 0.6; // How far the player's color shifts toward the equipped boss weapon's color
+pub const WEAPON_COMBO_ENERGY_COST: f32 = 4.0; // Energy a fusion attack spends from each of its two weapons
+pub const WEAPON_COMBO_COOLDOWN: f32 = 0.6; // Seconds before the player can shoot again after a fusion attack
+pub const PROJECTILE_STYLES_PATH: &str = "weapons/projectile_styles.json"; // Look of the default shot and each boss weapon's shots
+pub const LIFESTEAL_UPGRADE_RATIO: f32 = 0.1; // Share of boss weapon damage returned as HP, per lifesteal upgrade
+pub const LIFESTEAL_STAGE_CAP: f32 = 30.0; // Most HP lifesteal can restore in a single stage
+
+// Crumbs (currency) and the between-stage shop
+pub const CRUMBS_PER_DAMAGE: f32 = 0.1; // Crumbs earned per point of damage dealt to bosses
+pub const FAST_CLEAR_TIME: f32 = 60.0; // Clearing a stage within this many seconds earns the fast clear bonus
+pub const FAST_CLEAR_BONUS: u32 = 25; // Extra crumbs for a fast clear
+pub const SHOP_MAX_HP_PRICE: u32 = 40;
+pub const SHOP_MAX_HP_AMOUNT: f32 = 10.0; // Max HP (and HP) added per purchase
+pub const SHOP_ARMOR_PRICE: u32 = 50;
+pub const SHOP_ARMOR_MULTIPLIER: f32 = 0.9; // Each armor purchase multiplies damage taken by this
+pub const SHOP_ARMOR_MIN_MULTIPLIER: f32 = 0.5; // Armor stops being sold once damage taken is down to this
+pub const SHOP_HEAL_PRICE: u32 = 15;
+pub const SHOP_HEAL_AMOUNT: f32 = 30.0; // HP restored per purchase
+pub const SHOP_POTION_PRICE: u32 = 25;
+pub const POTION_HEAL_AMOUNT: f32 = 40.0; // HP restored by drinking a potion mid-fight (Q)
+pub const MAX_POTIONS: u32 = 3; // Potions the player can carry
+
+// Roguelike mode stage curses
+pub const CURSE_LOW_GRAVITY_SCALE: f32 = 0.5; // Player gravity multiplier under the low gravity curse
+pub const CURSE_BOSS_SPEED_SCALE: f32 = 2.0; // Boss movement speed multiplier under the double boss speed curse
+pub const CURSE_DARKNESS_RADIUS: f32 = 140.0; // Radius of the light around the player under the darkness curse
+pub const CURSE_DARKNESS_ALPHA: f32 = 0.92; // Opacity of the darkness outside that radius
+pub const CURSE_BANNER_TIME: f32 = 3.0; // Seconds the curse name is shown as the fight starts
+pub const WEAPON_WHEEL_TIME_SCALE: f32 = 0.2; // Virtual time speed while the weapon wheel is held open
+
+// Hit-stop (brief slow-motion on heavy impacts)
+pub const HIT_STOP_ENABLED: bool = true; // Default for GameConfig::hit_stop_enabled (false disables hit-stop entirely)
+pub const HIT_STOP_TIME_SCALE: f32 = 0.1; // Virtual time speed while hit-stop is active (0.0-1.0)
+pub const HIT_STOP_DURATION: f32 = 0.05; // Duration of hit-stop in real-time seconds
+
+// Kill cam (the blow that beats the last boss of a fight)
+pub const KILL_CAM_DURATION: f32 = 1.2; // Real-time seconds the kill cam holds (in hit-stop) before the boss explodes
+pub const KILL_CAM_EASE_TIME: f32 = 0.2; // Real-time seconds for the zoom and desaturation to come in
+pub const KILL_CAM_ZOOM: f32 = 0.3; // Share of the view the camera zooms in by, toward the boss
+pub const KILL_CAM_DESATURATION: f32 = 0.7; // Opacity of the gray laid over the background (0.0 = full color)
+pub const KILL_CAM_EXPLOSION_PARTICLES: u32 = 24; // Particles in the boss's death explosion
+pub const KILL_CAM_EXPLOSION_SPEED: f32 = 260.0; // Speed of the explosion particles (pixels per second)
+
+// Boss phases
+pub const BOSS_PHASE_TWO_HP_FRACTION: f32 = 0.5; // Boss enters phase 2 at or below this HP fraction
+pub const BOSS_DESPERATION_HP_FRACTION: f32 = 0.2; // Boss turns desperate at or below this HP fraction (final music layer)
+
+// Hit feedback effects (disabled or made steady by the reduced flashing setting)
+pub const SCREEN_SHAKE_DURATION: f32 = 0.25; // Duration of screen shake when the player is hurt (seconds)
+pub const SCREEN_SHAKE_INTENSITY: f32 = 6.0; // Maximum camera offset during screen shake (pixels)
+pub const HIT_FLASH_INTERVAL: f32 = 0.08; // Blink interval of the player while invincible (seconds)
+pub const HIT_STEADY_ALPHA: f32 = 0.5; // Player opacity while invincible in reduced flashing mode
+
+// Animated materials
+pub const MATERIAL_GRADIENT_STEPS: usize = 32; // Pre-built materials per animated color ramp (charge effect, HP orb)
+
+// Collision broadphase
+pub const COLLISION_GRID_CELL_SIZE: f32 = 64.0; // Cell size of the uniform grid the collision systems use to find nearby colliders (pixels)
+
+// Boss jumps, ground slams and landing shockwaves
+pub const BOSS_GRAVITY: f32 = 1200.0; // Gravity applied to jumping bosses (pixels per second squared)
+pub const BOSS_SLAM_HOVER_TIME: f32 = 0.35; // Time a slamming boss hangs at the top before dropping (seconds)
+pub const BOSS_SLAM_SPEED: f32 = 900.0; // Initial downward speed of a ground slam (pixels per second)
+pub const SHOCKWAVE_SPEED: f32 = 350.0; // Speed of the shockwaves sent out on landing (pixels per second)
+pub const SHOCKWAVE_LIFETIME: f32 = 1.2; // How long a shockwave travels before fading (seconds)
+pub const SHOCKWAVE_WIDTH: f32 = 24.0; // Width of a shockwave hitbox
+pub const SHOCKWAVE_HEIGHT: f32 = 16.0; // Height of a shockwave hitbox (only hits grounded players)
+pub const SHOCKWAVE_DAMAGE: f32 = 12.0; // Damage dealt by a landing shockwave
+
+// Boss melee attacks
+pub const BOSS_MELEE_DAMAGE: f32 = 20.0; // Damage dealt by an active boss melee hitbox
+pub const BOSS_MELEE_TELEGRAPH_ALPHA: f32 = 0.25; // Hitbox opacity during windup
+pub const BOSS_MELEE_ACTIVE_ALPHA: f32 = 0.8; // Hitbox opacity while it can deal damage
+
+// Boss beam attacks
+pub const BOSS_BEAM_DAMAGE: f32 = 8.0; // Damage per hit while touching a beam (repeats each time invincibility wears off)
+pub const BOSS_BEAM_TELEGRAPH_WIDTH: f32 = 2.0; // Width of the telegraph line shown before the beam fires
+pub const BOSS_BEAM_TELEGRAPH_ALPHA: f32 = 0.5; // Opacity of the telegraph line
+pub const BOSS_BEAM_ACTIVE_ALPHA: f32 = 0.9; // Opacity of the firing beam
+
+// Lingering ground hazards left by attacks (fire patches, acid pools)
+pub const HAZARD_HEIGHT: f32 = 10.0; // Visual height of a hazard on the floor
+pub const HAZARD_ALPHA: f32 = 0.6; // Opacity of a fresh hazard (fades out over its lifetime)
+pub const DESTRUCTIBLE_HAZARD_HITBOX_HEIGHT: f32 = 80.0; // Hitbox height of hazards with HP (tall enough for level shots to hit)
+
+// Arena doors (Mega Man-style boss gates, for stages with "arena_doors" in their pattern)
+pub const ARENA_WALK_IN_TIME: f32 = 0.8; // Seconds the player walks in on their own before the doors shut
+pub const ARENA_DOOR_OPEN_TIME: f32 = 1.0; // Seconds for a door to slide open or shut
+pub const ARENA_DOOR_WIDTH: f32 = 24.0;
+pub const ARENA_DOOR_HEIGHT: f32 = 120.0;
+
+// Pre-boss corridors (side-scrolling approach to stages with a "corridor" in their pattern)
+pub const CAMERA_FOLLOW_RATE: f32 = 6.0; // How quickly the scrolling camera catches up with the player (per second)
+
+// Camera zoom (eased toward the zoom the moment calls for; below 1.0 zooms in)
+pub const CAMERA_ZOOM_RATE: f32 = 2.5; // How quickly the zoom catches up with its target (per second)
+pub const CAMERA_ZOOM_OUT_MAX: f32 = 1.12; // Zoom with the player and the nearest boss as far apart as CAMERA_ZOOM_OUT_FULL_DISTANCE
+pub const CAMERA_ZOOM_OUT_START_DISTANCE: f32 = 350.0; // Player-boss distance the zoom-out starts at (pixels)
+pub const CAMERA_ZOOM_OUT_FULL_DISTANCE: f32 = 650.0; // Player-boss distance the zoom-out is at its widest (pixels)
+pub const CAMERA_INTRO_ZOOM: f32 = 0.85; // Zoom during a boss's intro (the walk-in, then the start of the fight)
+pub const CAMERA_INTRO_TIME: f32 = 1.5; // Seconds of the fight the intro zoom holds for after it starts
+pub const CAMERA_DESPERATION_ZOOM: f32 = 0.92; // Zoom while a boss is desperate (see BOSS_DESPERATION_HP_FRACTION)
+pub const CORRIDOR_FLOOR_HEIGHT: f32 = 40.0; // Thickness of the floor laid along a corridor (same as the arena floor)
+pub const CHECKPOINT_FLAG_HEIGHT: f32 = 70.0; // Height of a checkpoint flag's pole
+pub const MINION_WIDTH: f32 = 28.0;
+pub const MINION_HEIGHT: f32 = 36.0;
+pub const MINION_DEFAULT_HP: f32 = 40.0; // Minion HP when the corridor doesn't set one
+pub const MINION_SPEED: f32 = 60.0; // Patrol speed of corridor minions (pixels per second)
+pub const MINION_CONTACT_DAMAGE: f32 = 8.0; // Damage dealt to the player on touching a minion
+
+// Shield pickups (overshield shown as a ring on the HP orb)
+pub const SHIELD_PICKUP_INTERVAL: f32 = 12.0; // Seconds between shield pickups appearing (while none is on the floor)
+pub const SHIELD_PICKUP_SIZE: f32 = 20.0; // Width and height of a shield pickup
+pub const SHIELD_AMOUNT: f32 = 30.0; // Damage an overshield absorbs before HP is lost
+pub const SHIELD_DURATION: f32 = 15.0; // Seconds before an unused overshield fades
+
+// Secret tokens (hidden in each stage's pattern file, found once per save)
+pub const SECRET_TOKENS_PER_STAGE: usize = 3; // Tokens hidden in every regular stage (extras in a pattern are ignored)
+pub const SECRET_TOKEN_SIZE: f32 = 16.0; // Width and height of a token
+pub const SECRET_TOKEN_FOUND_ALPHA: f32 = 0.25; // Tokens found on an earlier visit stay as faint outlines
+pub const SECRET_TOKEN_REWARD_POTIONS: u32 = 1; // Extra potions every new run starts with once all tokens are found
+
+// Display and settings persistence
+pub const SETTINGS_FILE_PATH: &str = "settings.json"; // Saved player settings (written when leaving the options screen)
+pub const REFERENCE_WINDOW_WIDTH: f32 = 1280.0; // Window size the layout was designed for; the game camera always shows at least this much world
+pub const REFERENCE_WINDOW_HEIGHT: f32 = 720.0; // UI is scaled by window height relative to this
+
+// Boss victory cutscenes
+pub const DIALOGUE_DIR: &str = "dialogue"; // Directory of per-stage victory dialogue files (stage_N_victory.json)
+pub const DIALOGUE_CHARS_PER_SECOND: f32 = 40.0; // Typewriter speed of dialogue text
+
+// Endings (final win screen)
+pub const ENDING_ARSENAL_WEAPONS: usize = 1; // Boss weapons a run needs to collect for the arsenal ending
+
+// Daily challenge and scoring
+pub const SAVE_FILE_PATH: &str = "save.json"; // Persistent progress (daily challenge leaderboard)
+pub const SCORE_PER_BOSS: u32 = 1000; // Score for each boss defeated in a run
+pub const SCORE_DEATH_PENALTY: u32 = 250; // Score lost for each death in a run
+
+// Best clear ghost
+pub const GHOST_FILE_PATH: &str = "ghosts.json"; // Fastest clear trace of every stage (replayed as the ghost)
+pub const GHOST_SAMPLE_INTERVAL: f32 = 0.1; // Seconds between recorded player positions
+pub const GHOST_ALPHA: f32 = 0.3; // Opacity of the ghost
+
+// Endless mode
+pub const ENDLESS_HP_SCALE_PER_WAVE: f32 = 0.15; // Extra boss HP per wave (fraction of the pattern's HP)
+pub const ENDLESS_SPEED_SCALE_PER_WAVE: f32 = 0.05; // Extra boss movement speed per wave
+pub const ENDLESS_MAX_SPEED_SCALE: f32 = 2.0; // Cap on the boss movement speed multiplier
+pub const ENDLESS_WAVE_BREAK: f32 = 2.0; // Seconds between a wave falling and the next one spawning
+pub const ENDLESS_WAVE_HEAL: f32 = 15.0; // HP restored to the player when a wave is cleared
+
+// Crumb of Death mode (unlocked by beating the game: the player dies in one hit)
+pub const CRUMB_OF_DEATH_DAMAGE_MULTIPLIER: f32 = 3.0; // Damage multiplier for the player's shots
+
+// Screenshot and clip capture (F12 / hold F11)
+pub const CAPTURE_DIR: &str = "captures"; // Screenshots and clip frame sequences are saved here
+pub const CAPTURE_FRAME_INTERVAL: f32 = 0.1; // Real-time seconds between clip frames (10 fps)
+pub const CAPTURE_MAX_FRAMES: u32 = 50; // Longest clip in frames (stops recording even if F11 is still held)
+
+// Fight analytics (enabled with the --analytics launch option)
+pub const ANALYTICS_DIR: &str = "analytics"; // Per-fight hit logs (JSON and CSV) are written here
+
+// Benchmark (the --bench launch option)
+pub const BENCH_PATTERN_PATH: &str = "boss_patterns/bullet_hell_example.json"; // Pattern the benchmark bosses run
+pub const BENCH_BOSS_COUNT: u32 = 4; // Bosses fought at once in the benchmark
+pub const BENCH_PROJECTILE_COUNT: usize = 3000; // Extra boss projectiles kept in the arena
+pub const BENCH_SPAWN_PER_FRAME: usize = 500; // Most extra projectiles spawned in one frame while filling up
+pub const BENCH_REPORT_INTERVAL: f32 = 2.0; // Real-time seconds between frame time reports
+pub const BENCH_DURATION: f32 = 30.0; // Real-time seconds the benchmark runs before exiting
+
+// Gameplay feedback (sounds and particles)
+pub const FEEDBACK_SOUND_DIR: &str = "sounds"; // Feedback sounds are `<dir>/<event>.ogg` (missing files are skipped)
+pub const FEEDBACK_PARTICLE_SIZE: f32 = 6.0; // Side of a square feedback particle in world units
+pub const FEEDBACK_PARTICLE_LIFETIME: f32 = 0.35; // Seconds a feedback particle lives while shrinking away
+pub const PLAYER_FOOTSTEP_INTERVAL: f32 = 0.3; // Seconds between footsteps while the player runs on the ground
+pub const SOUND_FULL_VOLUME_DISTANCE: f32 = 300.0; // Positional sounds closer than this to the camera play at full volume, and fade with distance past it
+pub const SOUND_EAR_GAP: f32 = 200.0; // Distance between the listener's ears (wider = sounds to the side pan harder)
+
+// Announcer voice lines
+pub const ANNOUNCER_LINES_PATH: &str = "voice/announcer.json"; // Which clip plays for which trigger, and their cooldowns
+pub const ANNOUNCER_LINE_GAP: f32 = 2.5; // Seconds after a voice line starts before another one can (so lines never overlap)
+
+// Fight music (layers stacked as the boss weakens)
+pub const MUSIC_DIR: &str = "music"; // Fight music layers are `<dir>/fight_<layer>.ogg` (missing files are skipped)
+pub const MUSIC_VOLUME: f32 = 0.6; // Volume of a fully faded-in music layer
+pub const MUSIC_CROSSFADE_TIME: f32 = 2.0; // Seconds a music layer takes to fade fully in or out
+
+// Developer stage editor
+pub const STAGE_LAYOUT_DIR: &str = "stage_layouts"; // Fights load their platforms from `stage_N.json` in here when it exists
+pub const STAGE_EDITOR_GRID: f32 = 10.0; // Placement grid size in world units
+pub const STAGE_EDITOR_PLATFORM_HEIGHT: f32 = 16.0; // Thickness of platforms placed with the editor
+pub const STAGE_EDITOR_MIN_WIDTH: f32 = 20.0; // Narrowest platform or hazard the editor places
+pub const STAGE_EDITOR_MAX_WIDTH: f32 = 400.0; // Widest platform or hazard the editor places
+pub const STAGE_EDITOR_HAZARD_DAMAGE: f32 = 10.0; // Damage per second of hazards placed with the editor
+pub const STAGE_EDITOR_HAZARD_MARKER_HEIGHT: f32 = 10.0; // Height of the marker drawn for a placed hazard
+pub const STAGE_EDITOR_SPAWN_MARKER_SIZE: bevy::math::Vec2 = bevy::math::Vec2::new(32.0, 64.0); // Size of the player/boss spawn markers
+pub const STAGE_EDITOR_FLOOR_HEIGHT: f32 = 40.0; // Thickness of the floor drawn under the edited arena
+
+// Screen transitions (between menus and fights)
+pub const TRANSITION_COVER_TIME: f32 = 0.25; // Real-time seconds for the fade or wipe to cover the screen
+pub const TRANSITION_REVEAL_TIME: f32 = 0.25; // Real-time seconds for it to uncover the next screen
+
+// Player idle animation (after a while without input the player breathes and blinks)
+pub const PLAYER_IDLE_DELAY: f32 = 3.0; // Seconds without input before the player goes idle
+pub const PLAYER_BREATH_PERIOD: f32 = 2.4; // Seconds per breath while idle
+pub const PLAYER_BREATH_SQUASH: f32 = 0.04; // Height the player loses at the bottom of a breath (fraction of its height)
+pub const PLAYER_BLINK_INTERVAL: f32 = 2.8; // Seconds between blinks while idle
+pub const PLAYER_BLINK_DURATION: f32 = 0.15; // Seconds the eyes stay shut per blink
@@ -0,0 +1,228 @@
+use crate::components::gameplay::GameplayEntity;
+use crate::components::player::Player;
+use crate::plugins::shield_plugin::spawn_shield_pickup;
+use crate::stages::game_menu::{CurrentStage, FightCheckpoint};
+use crate::systems::arena_doors::{ArenaDoors, DoorPhase};
+use crate::systems::boss::BossPatternRegistry;
+use crate::systems::boundaries::ArenaBounds;
+use crate::systems::collision::PLAYER_HITBOX_SIZE;
+use crate::systems::config::{
+    ARENA_WALK_IN_TIME, CHECKPOINT_FLAG_HEIGHT, CORRIDOR_FLOOR_HEIGHT, FLOOR_TOP,
+    MINION_DEFAULT_HP, REFERENCE_WINDOW_WIDTH,
+};
+use crate::systems::effects::CameraScroll;
+use crate::systems::minions::{Minion, spawn_minion};
+use crate::systems::platforms::{StagePlatforms, spawn_platform};
+use bevy::prelude::*;
+
+/// Resource with the span of the current stage's approach corridor, from its far end to the
+/// arena's left wall (both 0.0 when the stage has none)
+#[derive(Resource, Default)]
+pub struct Corridor {
+    pub left: f32,
+    pub arena_left: f32,
+}
+
+/// Component for a corridor checkpoint flag, `distance` along the corridor.
+/// Passing it makes retries of the stage start there (see `FightCheckpoint::corridor_flag`).
+#[derive(Component)]
+pub struct CheckpointFlag {
+    pub distance: f32,
+    pub reached: bool,
+}
+
+const FLAG_COLOR: Color = Color::srgb(0.8, 0.2, 0.2);
+const REACHED_FLAG_COLOR: Color = Color::srgb(0.2, 0.85, 0.3);
+
+/// Lays out the stage's approach corridor left of the arena: floor, platforms, minions, pickups
+/// and checkpoint flags. The arena's left bound is moved out to the corridor's end, where the
+/// player starts (or at the last flag reached, when retrying), and the camera scrolls along with
+/// them until they reach the entrance door.
+pub fn spawn_corridor(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    pattern_registry: Res<BossPatternRegistry>,
+    current_stage: Res<CurrentStage>,
+    mut arena: ResMut<ArenaBounds>,
+    mut corridor: ResMut<Corridor>,
+    mut platforms: ResMut<StagePlatforms>,
+    mut scroll: ResMut<CameraScroll>,
+    checkpoint: Res<FightCheckpoint>,
+    mut player_query: Query<&mut Transform, With<Player>>,
+) {
+    let Some(config) = pattern_registry
+        .get_pattern(&format!("stage_{}", current_stage.0))
+        .and_then(|pattern| pattern.corridor.as_ref())
+    else {
+        return;
+    };
+
+    *corridor = Corridor {
+        left: arena.left - config.length,
+        arena_left: arena.left,
+    };
+    arena.left = corridor.left;
+
+    let start = checkpoint.corridor_flag_for(current_stage.0).unwrap_or(0.0);
+    let start_x = corridor.left + start.max(PLAYER_HITBOX_SIZE.x * 0.5);
+    for mut transform in &mut player_query {
+        transform.translation.x = start_x;
+    }
+
+    scroll.min_x = (corridor.left + REFERENCE_WINDOW_WIDTH / 2.0).min(0.0);
+    scroll.max_x = 0.0;
+    scroll.x = start_x.clamp(scroll.min_x, scroll.max_x);
+
+    commands.spawn((
+        GameplayEntity,
+        Mesh2d(meshes.add(Rectangle::new(config.length, CORRIDOR_FLOOR_HEIGHT))),
+        MeshMaterial2d(materials.add(Color::srgb(0.3, 0.3, 0.3))), // Same gray as the arena floor
+        Transform::from_xyz(
+            corridor.left + config.length / 2.0,
+            FLOOR_TOP - CORRIDOR_FLOOR_HEIGHT / 2.0,
+            0.0,
+        ),
+    ));
+
+    for platform in &config.platforms {
+        let rect = Rect::from_center_size(
+            Vec2::new(corridor.left + platform.x, platform.y),
+            Vec2::new(platform.width, platform.height),
+        );
+        platforms.rects.push(rect);
+        platforms.surfaces.push(platform.material);
+        let material = materials.add(platform.material.platform_color());
+        spawn_platform(&mut commands, &mut meshes, material, rect);
+    }
+
+    for minion in &config.minions {
+        spawn_minion(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            corridor.left + minion.x,
+            minion.patrol,
+            minion.hp.unwrap_or(MINION_DEFAULT_HP),
+        );
+    }
+
+    for &x in &config.pickups {
+        spawn_shield_pickup(&mut commands, corridor.left + x);
+    }
+
+    let pole_mesh = meshes.add(Rectangle::new(4.0, CHECKPOINT_FLAG_HEIGHT));
+    let pole_material = materials.add(Color::srgb(0.8, 0.8, 0.8));
+    let flag_mesh = meshes.add(Triangle2d::new(
+        Vec2::new(0.0, 10.0),
+        Vec2::new(0.0, -10.0),
+        Vec2::new(26.0, 0.0),
+    ));
+    for &distance in &config.checkpoints {
+        let reached = distance <= start;
+        let color = if reached {
+            REACHED_FLAG_COLOR
+        } else {
+            FLAG_COLOR
+        };
+        commands
+            .spawn((
+                GameplayEntity,
+                Mesh2d(pole_mesh.clone()),
+                MeshMaterial2d(pole_material.clone()),
+                Transform::from_xyz(
+                    corridor.left + distance,
+                    FLOOR_TOP + CHECKPOINT_FLAG_HEIGHT / 2.0,
+                    0.3,
+                ),
+                CheckpointFlag { distance, reached },
+            ))
+            .with_child((
+                Mesh2d(flag_mesh.clone()),
+                MeshMaterial2d(materials.add(color)),
+                Transform::from_xyz(2.0, CHECKPOINT_FLAG_HEIGHT / 2.0 - 10.0, 0.0),
+            ));
+    }
+}
+
+/// System to raise the checkpoint flags the player passes, recording the furthest one in the
+/// fight checkpoint so that spending a life restarts the stage from it
+pub fn reach_checkpoint_flags(
+    current_stage: Res<CurrentStage>,
+    mut checkpoint: ResMut<FightCheckpoint>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    player_query: Query<&Transform, With<Player>>,
+    mut flag_query: Query<(&mut CheckpointFlag, &Transform, &Children), Without<Player>>,
+    flag_material_query: Query<&MeshMaterial2d<ColorMaterial>>,
+) {
+    let Some(player_x) = player_query
+        .iter()
+        .map(|t| t.translation.x)
+        .reduce(f32::max)
+    else {
+        return;
+    };
+
+    for (mut flag, transform, children) in &mut flag_query {
+        if flag.reached || player_x < transform.translation.x {
+            continue;
+        }
+        flag.reached = true;
+
+        if checkpoint.stage != current_stage.0 {
+            *checkpoint = FightCheckpoint {
+                stage: current_stage.0,
+                ..default()
+            };
+        }
+        if checkpoint
+            .corridor_flag
+            .is_none_or(|reached| reached < flag.distance)
+        {
+            checkpoint.corridor_flag = Some(flag.distance);
+        }
+        for child in children {
+            if let Ok(material) = flag_material_query.get(*child)
+                && let Some(material) = materials.get_mut(&material.0)
+            {
+                material.color = REACHED_FLAG_COLOR;
+            }
+        }
+    }
+}
+
+/// System to close the corridor behind the player once they step through the entrance door:
+/// the arena gets its left wall back, the camera returns to it and the remaining minions are
+/// cleared out, and the usual walk-in and lock-in follow
+pub fn enter_arena_from_corridor(
+    mut commands: Commands,
+    corridor: Res<Corridor>,
+    mut doors: ResMut<ArenaDoors>,
+    mut arena: ResMut<ArenaBounds>,
+    mut scroll: ResMut<CameraScroll>,
+    player_query: Query<&Transform, With<Player>>,
+    minion_query: Query<Entity, With<Minion>>,
+) {
+    if doors.phase != DoorPhase::Corridor {
+        return;
+    }
+    let entered = player_query.iter().any(|transform| {
+        transform.translation.x - PLAYER_HITBOX_SIZE.x * 0.5 >= corridor.arena_left
+    });
+    if !entered {
+        return;
+    }
+
+    doors.phase = DoorPhase::WalkIn(ARENA_WALK_IN_TIME);
+    arena.left = corridor.arena_left;
+    scroll.min_x = 0.0;
+    scroll.max_x = 0.0;
+    for entity in &minion_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// System to forget the corridor once the fight is over
+pub fn reset_corridor(mut corridor: ResMut<Corridor>) {
+    *corridor = Corridor::default();
+}
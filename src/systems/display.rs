@@ -0,0 +1,68 @@
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::config::{REFERENCE_WINDOW_HEIGHT, REFERENCE_WINDOW_WIDTH};
+use bevy::camera::ScalingMode;
+use bevy::prelude::*;
+use bevy::window::{PresentMode, PrimaryWindow, WindowMode};
+
+/// Orthographic projection for the game camera that keeps the whole arena visible at any
+/// aspect ratio (extra space is added on the longer axis instead of cropping or stretching)
+pub fn game_camera_projection() -> Projection {
+    Projection::Orthographic(OrthographicProjection {
+        scaling_mode: ScalingMode::AutoMin {
+            min_width: REFERENCE_WINDOW_WIDTH,
+            min_height: REFERENCE_WINDOW_HEIGHT,
+        },
+        ..OrthographicProjection::default_2d()
+    })
+}
+
+/// System to apply the display settings (resolution, fullscreen, vsync) to the primary window
+pub fn apply_window_settings(
+    settings: Res<GameSettings>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Ok(mut window) = window_query.single_mut() else {
+        return;
+    };
+
+    let mode = if settings.fullscreen {
+        WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+    } else {
+        WindowMode::Windowed
+    };
+    if window.mode != mode {
+        window.mode = mode;
+    }
+
+    // Resolution only applies to windowed mode (fullscreen uses the monitor size)
+    let (width, height) = settings.resolution.size();
+    if !settings.fullscreen
+        && (window.resolution.width() != width as f32 || window.resolution.height() != height as f32)
+    {
+        window.resolution.set(width as f32, height as f32);
+    }
+
+    let present_mode = if settings.vsync {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+}
+
+/// System to scale fixed-size UI (HP bars, menus, hints) with the window height
+pub fn scale_ui_to_window(
+    window_query: Query<&Window, (With<PrimaryWindow>, Changed<Window>)>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+
+    let scale = window.resolution.height() / REFERENCE_WINDOW_HEIGHT;
+    if scale > 0.0 && ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+}
@@ -0,0 +1,275 @@
+use crate::components::boss::{Boss, BossPhaseState};
+use crate::components::player::{Defeated, Invincibility, Player};
+use crate::plugins::camera_plugin::{ArenaCamera, GameCamera, HUD_RENDER_LAYER};
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::arena_doors::{ArenaDoors, DoorPhase};
+use crate::systems::config::{
+    CAMERA_DESPERATION_ZOOM, CAMERA_FOLLOW_RATE, CAMERA_INTRO_TIME, CAMERA_INTRO_ZOOM,
+    CAMERA_ZOOM_OUT_FULL_DISTANCE, CAMERA_ZOOM_OUT_MAX, CAMERA_ZOOM_OUT_START_DISTANCE,
+    CAMERA_ZOOM_RATE, HIT_FLASH_INTERVAL, HIT_STEADY_ALPHA, MATERIAL_GRADIENT_STEPS,
+    SCREEN_SHAKE_DURATION, SCREEN_SHAKE_INTENSITY,
+};
+use crate::systems::kill_cam::KillCam;
+use crate::systems::physics::kinematics::smoothing_blend;
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+
+/// A fixed set of materials sampled along a color ramp.
+/// Animated colors swap between these handles instead of editing a material every frame.
+pub struct MaterialGradient {
+    materials: Vec<Handle<ColorMaterial>>,
+}
+
+impl MaterialGradient {
+    /// Builds `MATERIAL_GRADIENT_STEPS` materials from `color_at(t)` for t from 0.0 to 1.0
+    pub fn new(materials: &mut Assets<ColorMaterial>, color_at: impl Fn(f32) -> Color) -> Self {
+        let last_step = (MATERIAL_GRADIENT_STEPS - 1) as f32;
+        Self {
+            materials: (0..MATERIAL_GRADIENT_STEPS)
+                .map(|step| materials.add(color_at(step as f32 / last_step)))
+                .collect(),
+        }
+    }
+
+    /// Material closest to `t` (clamped to 0.0..=1.0)
+    pub fn get(&self, t: f32) -> &Handle<ColorMaterial> {
+        let last_step = self.materials.len() - 1;
+        &self.materials[(t.clamp(0.0, 1.0) * last_step as f32).round() as usize]
+    }
+
+    /// Points `mesh_material` at the material for `t`, leaving it untouched (and unchanged) if it already is
+    pub fn apply(&self, t: f32, mesh_material: &mut Mut<MeshMaterial2d<ColorMaterial>>) {
+        let handle = self.get(t);
+        if mesh_material.0 != *handle {
+            mesh_material.0 = handle.clone();
+        }
+    }
+}
+
+/// Resource to track the remaining screen shake time
+#[derive(Resource, Default)]
+pub struct ScreenShake {
+    pub timer: f32,
+}
+
+impl ScreenShake {
+    /// Start (or restart) a screen shake
+    pub fn trigger(&mut self) {
+        self.timer = SCREEN_SHAKE_DURATION;
+    }
+}
+
+/// Resource with the game camera's horizontal scroll. The camera follows the player between
+/// `min_x` and `max_x`, which are both 0.0 (the arena fixed in view) unless a stage scrolls,
+/// like along its approach corridor.
+#[derive(Resource, Default)]
+pub struct CameraScroll {
+    pub x: f32,
+    pub min_x: f32,
+    pub max_x: f32,
+}
+
+/// Resource for the camera's zoom controller: the projection scale of the arena (1.0 = the usual
+/// view, below zooms in), eased toward the zoom the fight calls for
+#[derive(Resource)]
+pub struct CameraZoom {
+    pub scale: f32,
+    pub fight_time: f32, // Seconds since the fight started (for the intro zoom)
+}
+
+impl Default for CameraZoom {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            fight_time: 0.0,
+        }
+    }
+}
+
+/// Component for HUD elements drawn in the world (like the HP orb), kept at this position
+/// relative to the scrolled camera
+#[derive(Component)]
+pub struct ScreenAnchored(pub Vec2);
+
+/// System to ease the camera scroll toward the player, within the scroll limits
+pub fn follow_player_with_camera(
+    time: Res<Time>,
+    mut scroll: ResMut<CameraScroll>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    let target = player_query
+        .iter()
+        .next()
+        .map_or(0.0, |transform| transform.translation.x)
+        .clamp(scroll.min_x, scroll.max_x.max(scroll.min_x));
+    let blend = smoothing_blend(CAMERA_FOLLOW_RATE, time.delta_secs());
+    scroll.x += (target - scroll.x) * blend;
+}
+
+/// System to keep world-space HUD elements in place on screen as the camera scrolls
+pub fn anchor_hud_to_camera(
+    scroll: Res<CameraScroll>,
+    mut anchored_query: Query<(&ScreenAnchored, &mut Transform)>,
+) {
+    for (anchor, mut transform) in &mut anchored_query {
+        transform.translation.x = anchor.0.x + scroll.x;
+        transform.translation.y = anchor.0.y;
+    }
+}
+
+/// System to forget the scroll once the fight is over
+pub fn reset_camera_scroll(mut scroll: ResMut<CameraScroll>) {
+    *scroll = CameraScroll::default();
+}
+
+/// System to ease the camera zoom toward what the fight calls for: zoomed in on the boss's
+/// intro (the walk-in through the arena doors, then the first seconds of the fight) and while a
+/// boss is desperate, otherwise zoomed out as the player and the nearest boss get far apart
+pub fn update_camera_zoom(
+    time: Res<Time>,
+    arena_doors: Res<ArenaDoors>,
+    mut zoom: ResMut<CameraZoom>,
+    player_query: Query<&Transform, With<Player>>,
+    boss_query: Query<(&Transform, &BossPhaseState), (With<Boss>, Without<Defeated>)>,
+) {
+    let walking_in = matches!(arena_doors.phase, DoorPhase::WalkIn(_));
+    if !walking_in && arena_doors.phase != DoorPhase::Corridor && !boss_query.is_empty() {
+        zoom.fight_time += time.delta_secs();
+    }
+
+    let target = if walking_in || (zoom.fight_time > 0.0 && zoom.fight_time < CAMERA_INTRO_TIME) {
+        CAMERA_INTRO_ZOOM
+    } else if boss_query.iter().any(|(_, phase)| phase.desperate) {
+        CAMERA_DESPERATION_ZOOM
+    } else {
+        let distance = player_query.iter().next().and_then(|player| {
+            boss_query
+                .iter()
+                .map(|(boss, _)| {
+                    player
+                        .translation
+                        .truncate()
+                        .distance(boss.translation.truncate())
+                })
+                .reduce(f32::min)
+        });
+        let spread = distance.map_or(0.0, |distance| {
+            ((distance - CAMERA_ZOOM_OUT_START_DISTANCE)
+                / (CAMERA_ZOOM_OUT_FULL_DISTANCE - CAMERA_ZOOM_OUT_START_DISTANCE))
+                .clamp(0.0, 1.0)
+        });
+        1.0 + (CAMERA_ZOOM_OUT_MAX - 1.0) * spread
+    };
+    let blend = smoothing_blend(CAMERA_ZOOM_RATE, time.delta_secs());
+    zoom.scale += (target - zoom.scale) * blend;
+}
+
+/// System to apply the camera zoom, with the kill cam's on top, to the arena cameras. The HUD
+/// camera keeps its scale and stays put when the kill cam moves the camera, so the HUD drawn in
+/// the world stays where it is on screen.
+pub fn apply_camera_zoom(
+    zoom: Res<CameraZoom>,
+    kill_cam: Res<KillCam>,
+    mut camera_query: Query<(&mut Projection, &mut Transform, &RenderLayers), With<ArenaCamera>>,
+) {
+    let scale = zoom.scale * kill_cam.zoom();
+    let hud_layers = RenderLayers::layer(HUD_RENDER_LAYER);
+    for (mut projection, mut transform, layers) in &mut camera_query {
+        let is_hud = *layers == hud_layers;
+        let camera_scale = if is_hud { 1.0 } else { scale };
+        if let Projection::Orthographic(orthographic) = &mut *projection
+            && orthographic.scale != camera_scale
+        {
+            orthographic.scale = camera_scale;
+        }
+        if is_hud {
+            let offset = -kill_cam.offset.extend(0.0);
+            if transform.translation != offset {
+                transform.translation = offset;
+            }
+        }
+    }
+}
+
+/// System to zoom back out once the fight is over
+pub fn reset_camera_zoom(
+    mut zoom: ResMut<CameraZoom>,
+    mut projection_query: Query<&mut Projection, With<ArenaCamera>>,
+) {
+    *zoom = CameraZoom::default();
+    for mut projection in &mut projection_query {
+        if let Projection::Orthographic(orthographic) = &mut *projection {
+            orthographic.scale = 1.0;
+        }
+    }
+}
+
+/// System to place the game camera at its scroll, offset while a screen shake is active.
+/// Reduced flashing mode skips the shake entirely and keeps the camera steady.
+pub fn apply_screen_shake(
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    scroll: Res<CameraScroll>,
+    mut screen_shake: ResMut<ScreenShake>,
+    mut camera_query: Query<&mut Transform, With<GameCamera>>,
+) {
+    if screen_shake.timer > 0.0 {
+        screen_shake.timer -= time.delta_secs();
+    }
+
+    let offset = if screen_shake.timer > 0.0 && !settings.reduced_flashing {
+        // Fade the shake out over its duration
+        let strength = SCREEN_SHAKE_INTENSITY * (screen_shake.timer / SCREEN_SHAKE_DURATION);
+        let t = time.elapsed_secs();
+        Vec2::new((t * 90.0).sin(), (t * 73.0).cos()) * strength
+    } else {
+        Vec2::ZERO
+    };
+
+    for mut transform in &mut camera_query {
+        transform.translation.x = scroll.x + offset.x;
+        transform.translation.y = offset.y;
+    }
+}
+
+/// System to show the player's invincibility frames.
+/// Normally the player blinks; reduced flashing mode uses a steady translucent tint instead.
+pub fn player_hit_flash(
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    mut player_query: Query<
+        (
+            &mut Visibility,
+            &MeshMaterial2d<ColorMaterial>,
+            Has<Invincibility>,
+        ),
+        With<Player>,
+    >,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (mut visibility, mesh_material, is_invincible) in &mut player_query {
+        let (visible, alpha) = if !is_invincible {
+            (true, 1.0)
+        } else if settings.reduced_flashing {
+            (true, HIT_STEADY_ALPHA)
+        } else {
+            let blink_on = ((time.elapsed_secs() / HIT_FLASH_INTERVAL) as u32).is_multiple_of(2);
+            (blink_on, 1.0)
+        };
+
+        let target_visibility = if visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        visibility.set_if_neq(target_visibility);
+
+        // Only touch the material when the alpha actually changes
+        if let Some(material) = materials.get(&mesh_material.0)
+            && material.color.alpha() != alpha
+            && let Some(material) = materials.get_mut(&mesh_material.0)
+        {
+            material.color.set_alpha(alpha);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::systems::collision::PLAYER_HITBOX_SIZE;
+use crate::systems::platforms::{Ground, SurfaceMaterial};
+use bevy::prelude::*;
+
+/// Message sent by gameplay systems when something the player should see or hear happens.
+/// Sounds and particles react to these instead of being triggered from gameplay code.
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeedbackEvent {
+    Jumped,
+    Landed,
+    Footstep, // The player took a step while running on the ground
+    Dashed,
+    Hurt,
+    ShotFired,
+    ChargedShotFired,
+    ChargeReady, // The charge shot reached full charge
+}
+
+impl FeedbackEvent {
+    pub const ALL: [FeedbackEvent; 8] = [
+        FeedbackEvent::Jumped,
+        FeedbackEvent::Landed,
+        FeedbackEvent::Footstep,
+        FeedbackEvent::Dashed,
+        FeedbackEvent::Hurt,
+        FeedbackEvent::ShotFired,
+        FeedbackEvent::ChargedShotFired,
+        FeedbackEvent::ChargeReady,
+    ];
+
+    /// Name used for the event's asset files (e.g. `sounds/charged_shot_fired.ogg`)
+    pub fn name(self) -> &'static str {
+        match self {
+            FeedbackEvent::Jumped => "jumped",
+            FeedbackEvent::Landed => "landed",
+            FeedbackEvent::Footstep => "footstep",
+            FeedbackEvent::Dashed => "dashed",
+            FeedbackEvent::Hurt => "hurt",
+            FeedbackEvent::ShotFired => "shot_fired",
+            FeedbackEvent::ChargedShotFired => "charged_shot_fired",
+            FeedbackEvent::ChargeReady => "charge_ready",
+        }
+    }
+
+    /// Whether the event's sound and particles depend on the surface the player stands on
+    /// (see `SurfaceMaterial`)
+    pub fn on_surface(self) -> bool {
+        matches!(self, FeedbackEvent::Landed | FeedbackEvent::Footstep)
+    }
+}
+
+/// Material of the surface the player at `transform` stands on, for the events that depend on it
+pub fn player_surface(ground: &Ground, transform: &Transform) -> SurfaceMaterial {
+    ground.surface_at(
+        transform.translation.x,
+        transform.translation.y,
+        PLAYER_HITBOX_SIZE / 2.0,
+    )
+}
@@ -0,0 +1,327 @@
+use crate::components::player::JumpType;
+use crate::systems::config::{
+    BOSS_BEAM_DAMAGE, BOSS_COLLISION_DAMAGE, BOSS_GRAVITY, BOSS_MELEE_DAMAGE, BOSS_PROJECTILE_CAP,
+    BOSS_PROJECTILE_DAMAGE, BOSS_SLAM_HOVER_TIME, BOSS_SLAM_SPEED, CHARGE_MOVE_SPEED_SCALE,
+    CHARGE_SHOT_MAX_TIME, CHARGE_SHOT_MIN_TIME, GAME_CONFIG_PATH, HIT_STOP_ENABLED,
+    INVINCIBILITY_DURATION, KNOCKBACK_DECAY_RATE, KNOCKBACK_DURATION, KNOCKBACK_FORCE,
+    KNOCKBACK_LAUNCH_SCALE, KNOCKBACK_MOVEMENT_REDUCTION, PLAYER_BASE_GRAVITY,
+    PLAYER_DASH_COOLDOWN, PLAYER_DASH_DURATION, PLAYER_DASH_SPEED, PLAYER_HIGH_JUMP_GRAVITY_SCALE,
+    PLAYER_HIGH_JUMP_STRENGTH, PLAYER_JUMP_MAX_CHARGE_TIME, PLAYER_MOVE_SPEED,
+    PLAYER_PROJECTILE_DAMAGE, PLAYER_SMALL_JUMP_CHARGE_RATIO, PLAYER_SMALL_JUMP_GRAVITY_SCALE,
+    PLAYER_SMALL_JUMP_STRENGTH, SHOCKWAVE_DAMAGE, SHOCKWAVE_LIFETIME, SHOCKWAVE_SPEED,
+};
+use crate::systems::physics::kinematics::{resolve_jump_charge, select_gravity};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// What happens to a charge shot being charged when the player takes damage
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChargeHitRule {
+    #[default]
+    Keep, // The charge carries on untouched
+    Cancel,   // The charge is lost and the shot button has to be pressed again
+    LoseTier, // The charge keeps going one tier lower (full to barely charged, anything else to none)
+}
+
+/// Runtime-tunable gameplay values, loaded from `GAME_CONFIG_PATH` (RON) at startup.
+/// Every field defaults to its constant in `config.rs`, so the file only needs the values being tuned.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameConfig {
+    // Player movement
+    pub move_speed: f32,
+    pub dash_speed: f32,
+    pub dash_duration: f32,
+    pub dash_cooldown: f32,
+    pub base_gravity: f32,
+    pub high_jump_strength: f32,
+    pub high_jump_gravity_scale: f32,
+    pub small_jump_strength: f32,
+    pub small_jump_gravity_scale: f32,
+    pub jump_max_charge_time: f32,
+    pub small_jump_charge_ratio: f32,
+
+    // Charge shot rules
+    pub charge_move_speed_scale: f32,
+    pub charge_hit_rule: ChargeHitRule,
+
+    // Knockback and invincibility
+    pub knockback_force: f32,
+    pub knockback_duration: f32,
+    pub knockback_decay_rate: f32,
+    pub knockback_movement_reduction: f32,
+    pub knockback_launch_scale: f32,
+    pub invincibility_duration: f32,
+
+    // Boss movement and damage
+    pub boss_gravity: f32,
+    pub boss_slam_hover_time: f32,
+    pub boss_slam_speed: f32,
+    pub shockwave_speed: f32,
+    pub shockwave_lifetime: f32,
+    pub shockwave_damage: f32,
+    pub boss_collision_damage: f32,
+    pub boss_projectile_damage: f32,
+    pub boss_melee_damage: f32,
+    pub boss_beam_damage: f32,
+    pub player_projectile_damage: f32,
+
+    // Boss projectiles
+    pub boss_projectile_cap: usize, // 0 = no cap
+
+    // Impact effects
+    pub hit_stop_enabled: bool, // false = impacts and the kill cam never slow the game down
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            move_speed: PLAYER_MOVE_SPEED,
+            dash_speed: PLAYER_DASH_SPEED,
+            dash_duration: PLAYER_DASH_DURATION,
+            dash_cooldown: PLAYER_DASH_COOLDOWN,
+            base_gravity: PLAYER_BASE_GRAVITY,
+            high_jump_strength: PLAYER_HIGH_JUMP_STRENGTH,
+            high_jump_gravity_scale: PLAYER_HIGH_JUMP_GRAVITY_SCALE,
+            small_jump_strength: PLAYER_SMALL_JUMP_STRENGTH,
+            small_jump_gravity_scale: PLAYER_SMALL_JUMP_GRAVITY_SCALE,
+            jump_max_charge_time: PLAYER_JUMP_MAX_CHARGE_TIME,
+            small_jump_charge_ratio: PLAYER_SMALL_JUMP_CHARGE_RATIO,
+            charge_move_speed_scale: CHARGE_MOVE_SPEED_SCALE,
+            charge_hit_rule: ChargeHitRule::default(),
+            knockback_force: KNOCKBACK_FORCE,
+            knockback_duration: KNOCKBACK_DURATION,
+            knockback_decay_rate: KNOCKBACK_DECAY_RATE,
+            knockback_movement_reduction: KNOCKBACK_MOVEMENT_REDUCTION,
+            knockback_launch_scale: KNOCKBACK_LAUNCH_SCALE,
+            invincibility_duration: INVINCIBILITY_DURATION,
+            boss_gravity: BOSS_GRAVITY,
+            boss_slam_hover_time: BOSS_SLAM_HOVER_TIME,
+            boss_slam_speed: BOSS_SLAM_SPEED,
+            shockwave_speed: SHOCKWAVE_SPEED,
+            shockwave_lifetime: SHOCKWAVE_LIFETIME,
+            shockwave_damage: SHOCKWAVE_DAMAGE,
+            boss_collision_damage: BOSS_COLLISION_DAMAGE,
+            boss_projectile_damage: BOSS_PROJECTILE_DAMAGE,
+            boss_melee_damage: BOSS_MELEE_DAMAGE,
+            boss_beam_damage: BOSS_BEAM_DAMAGE,
+            player_projectile_damage: PLAYER_PROJECTILE_DAMAGE,
+            boss_projectile_cap: BOSS_PROJECTILE_CAP,
+            hit_stop_enabled: HIT_STOP_ENABLED,
+        }
+    }
+}
+
+impl GameConfig {
+    /// Load a config from a RON file path
+    pub fn load_from_file(file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = std::fs::read_to_string(file_path)?;
+        Ok(ron::from_str(&content)?)
+    }
+
+    /// Jump performed when the jump button is released after charging for `charge_time` seconds
+    pub fn jump_for_charge(&self, charge_time: f32) -> JumpType {
+        resolve_jump_charge(
+            charge_time,
+            self.jump_max_charge_time,
+            self.small_jump_charge_ratio,
+        )
+    }
+
+    /// Charge time left on a charge shot of `charge_time` seconds after the player takes a hit
+    /// (None if the hit cancels the charge)
+    pub fn charge_after_hit(&self, charge_time: f32) -> Option<f32> {
+        match self.charge_hit_rule {
+            ChargeHitRule::Keep => Some(charge_time),
+            ChargeHitRule::Cancel => None,
+            ChargeHitRule::LoseTier if charge_time >= CHARGE_SHOT_MAX_TIME => {
+                Some(CHARGE_SHOT_MIN_TIME)
+            }
+            ChargeHitRule::LoseTier => Some(0.0),
+        }
+    }
+
+    /// Launch speed of a jump
+    pub fn jump_strength(&self, jump_type: JumpType) -> f32 {
+        match jump_type {
+            JumpType::High => self.high_jump_strength,
+            JumpType::Small => self.small_jump_strength,
+            JumpType::None => 0.0,
+        }
+    }
+
+    /// Gravity while airborne, scaled from `base_gravity` so tuning it changes every jump's arc
+    pub fn jump_gravity(&self, jump_type: JumpType) -> f32 {
+        select_gravity(
+            jump_type,
+            self.base_gravity,
+            self.high_jump_gravity_scale,
+            self.small_jump_gravity_scale,
+        )
+    }
+}
+
+/// Resource to track the config file's last modification time (for hot reloading in debug builds)
+#[derive(Resource, Default)]
+pub struct GameConfigWatcher {
+    pub last_modified: Option<std::time::SystemTime>,
+    pub poll_timer: f32,
+}
+
+fn config_modified_time() -> Option<std::time::SystemTime> {
+    std::fs::metadata(GAME_CONFIG_PATH)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Loads the game config at startup (falls back to the defaults if the file is missing or invalid)
+pub fn load_game_config(mut commands: Commands, mut watcher: ResMut<GameConfigWatcher>) {
+    watcher.last_modified = config_modified_time();
+    match GameConfig::load_from_file(GAME_CONFIG_PATH) {
+        Ok(config) => {
+            info!("Loaded game config from {}", GAME_CONFIG_PATH);
+            commands.insert_resource(config);
+        }
+        Err(e) => info!("Using default game config ({}: {})", GAME_CONFIG_PATH, e),
+    }
+}
+
+/// System to reload the game config when its file changes (debug builds only).
+/// Polls the file's modification time instead of watching it, so no extra dependencies are needed.
+pub fn hot_reload_game_config(
+    real_time: Res<Time<Real>>,
+    mut watcher: ResMut<GameConfigWatcher>,
+    mut config: ResMut<GameConfig>,
+) {
+    const POLL_INTERVAL: f32 = 1.0; // Seconds between file checks
+
+    watcher.poll_timer -= real_time.delta_secs();
+    if watcher.poll_timer > 0.0 {
+        return;
+    }
+    watcher.poll_timer = POLL_INTERVAL;
+
+    let modified = config_modified_time();
+    if modified.is_none() || modified == watcher.last_modified {
+        return;
+    }
+    watcher.last_modified = modified;
+
+    match GameConfig::load_from_file(GAME_CONFIG_PATH) {
+        Ok(new_config) => {
+            *config = new_config;
+            info!("Reloaded game config from {}", GAME_CONFIG_PATH);
+        }
+        // Keep the previous values so a typo mid-edit doesn't reset the tuning
+        Err(e) => warn!("Failed to reload {}: {}", GAME_CONFIG_PATH, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_reproduces_config_constants() {
+        let config = GameConfig::default();
+        assert_eq!(config.base_gravity, PLAYER_BASE_GRAVITY);
+        assert_eq!(config.high_jump_strength, PLAYER_HIGH_JUMP_STRENGTH);
+        assert_eq!(
+            config.high_jump_gravity_scale,
+            PLAYER_HIGH_JUMP_GRAVITY_SCALE
+        );
+        assert_eq!(config.small_jump_strength, PLAYER_SMALL_JUMP_STRENGTH);
+        assert_eq!(
+            config.small_jump_gravity_scale,
+            PLAYER_SMALL_JUMP_GRAVITY_SCALE
+        );
+        assert_eq!(config.jump_max_charge_time, PLAYER_JUMP_MAX_CHARGE_TIME);
+        assert_eq!(
+            config.small_jump_charge_ratio,
+            PLAYER_SMALL_JUMP_CHARGE_RATIO
+        );
+        assert_eq!(config.hit_stop_enabled, HIT_STOP_ENABLED);
+
+        assert_eq!(
+            config.jump_strength(JumpType::High),
+            PLAYER_HIGH_JUMP_STRENGTH
+        );
+        assert_eq!(
+            config.jump_strength(JumpType::Small),
+            PLAYER_SMALL_JUMP_STRENGTH
+        );
+        assert_eq!(config.jump_strength(JumpType::None), 0.0);
+        assert_eq!(
+            config.jump_gravity(JumpType::High),
+            PLAYER_BASE_GRAVITY * PLAYER_HIGH_JUMP_GRAVITY_SCALE
+        );
+        assert_eq!(
+            config.jump_gravity(JumpType::Small),
+            PLAYER_BASE_GRAVITY * PLAYER_SMALL_JUMP_GRAVITY_SCALE
+        );
+        assert_eq!(config.jump_gravity(JumpType::None), PLAYER_BASE_GRAVITY);
+
+        let threshold = PLAYER_JUMP_MAX_CHARGE_TIME * PLAYER_SMALL_JUMP_CHARGE_RATIO;
+        assert_eq!(config.jump_for_charge(threshold * 0.5), JumpType::Small);
+        assert_eq!(
+            config.jump_for_charge(PLAYER_JUMP_MAX_CHARGE_TIME),
+            JumpType::High
+        );
+    }
+
+    #[test]
+    fn small_jump_charge_ratio_moves_the_jump_threshold() {
+        let charge_time = PLAYER_JUMP_MAX_CHARGE_TIME * 0.6;
+        assert_eq!(
+            GameConfig::default().jump_for_charge(charge_time),
+            JumpType::High
+        );
+
+        let config = GameConfig {
+            small_jump_charge_ratio: 0.8,
+            ..default()
+        };
+        assert_eq!(config.jump_for_charge(charge_time), JumpType::Small);
+    }
+
+    #[test]
+    fn gravity_tuning_changes_jump_gravity() {
+        let config = GameConfig {
+            high_jump_gravity_scale: 2.0,
+            ..default()
+        };
+        assert_eq!(
+            config.jump_gravity(JumpType::High),
+            PLAYER_BASE_GRAVITY * 2.0
+        );
+        assert_eq!(
+            config.jump_gravity(JumpType::Small),
+            PLAYER_BASE_GRAVITY * PLAYER_SMALL_JUMP_GRAVITY_SCALE
+        );
+
+        // Every arc follows the base gravity
+        let config = GameConfig {
+            base_gravity: 1000.0,
+            ..config
+        };
+        assert_eq!(config.jump_gravity(JumpType::High), 2000.0);
+        assert_eq!(
+            config.jump_gravity(JumpType::Small),
+            1000.0 * PLAYER_SMALL_JUMP_GRAVITY_SCALE
+        );
+        assert_eq!(config.jump_gravity(JumpType::None), 1000.0);
+    }
+
+    #[test]
+    fn jump_strength_follows_its_tuning_field() {
+        let config = GameConfig {
+            small_jump_strength: 300.0,
+            ..default()
+        };
+        assert_eq!(config.jump_strength(JumpType::Small), 300.0);
+        assert_eq!(
+            config.jump_strength(JumpType::High),
+            PLAYER_HIGH_JUMP_STRENGTH
+        );
+    }
+}
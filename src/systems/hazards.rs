@@ -0,0 +1,136 @@
+use crate::components::gameplay::GameplayEntity;
+use crate::components::player::{Hp, Player};
+use crate::stages::game_menu::PlayerUpgrades;
+use crate::systems::boundaries::ArenaBounds;
+use crate::systems::collision::{Enemy, Hitbox, PLAYER_HITBOX_SIZE};
+use crate::systems::config::{DESTRUCTIBLE_HAZARD_HITBOX_HEIGHT, HAZARD_ALPHA, HAZARD_HEIGHT};
+use crate::systems::health::{Overshield, take_damage};
+use crate::systems::palette::ColorPalette;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Kinds of lingering ground hazards (only the look differs)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum HazardKind {
+    #[default]
+    Fire,
+    Acid,
+}
+
+/// JSON structure for a ground hazard left behind by an attack
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HazardConfig {
+    #[serde(default)]
+    pub kind: HazardKind,
+    pub width: f32,
+    pub duration: f32,
+    pub damage_per_second: f32,
+    #[serde(default)]
+    pub hp: Option<f32>, // When set, player shots can destroy the hazard before it expires
+}
+
+/// Component for a lingering ground hazard that hurts a player standing in it
+#[derive(Component)]
+#[require(GameplayEntity)]
+pub struct HazardZone {
+    pub width: f32,
+    pub damage_per_second: f32,
+    pub timer: f32,    // Remaining lifetime
+    pub duration: f32, // Total lifetime (used to fade the hazard out)
+}
+
+/// Spawns a hazard on the floor centered at `x`
+pub fn spawn_hazard(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    palette: ColorPalette,
+    hazard: &HazardConfig,
+    arena: &ArenaBounds,
+    x: f32,
+) {
+    let mut hazard_entity = commands.spawn((
+        Mesh2d(meshes.add(Rectangle::new(hazard.width, HAZARD_HEIGHT))),
+        MeshMaterial2d(materials.add(palette.hazard_color(hazard.kind).with_alpha(HAZARD_ALPHA))),
+        Transform::from_xyz(x, arena.floor_top() + HAZARD_HEIGHT / 2.0, 0.5),
+        HazardZone {
+            width: hazard.width,
+            damage_per_second: hazard.damage_per_second,
+            timer: hazard.duration,
+            duration: hazard.duration.max(0.01),
+        },
+    ));
+
+    if let Some(hp) = hazard.hp {
+        hazard_entity.insert((
+            Enemy,
+            Hitbox(Vec2::new(hazard.width, DESTRUCTIBLE_HAZARD_HITBOX_HEIGHT)),
+            Hp {
+                current: hp,
+                max: hp,
+            },
+        ));
+    }
+}
+
+/// System to fade out hazards over their lifetime and despawn them when they expire.
+/// Hazards placed in the stage layout never expire (their duration is infinite).
+pub fn update_hazard_zones(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut hazard_query: Query<(Entity, &mut HazardZone, &MeshMaterial2d<ColorMaterial>)>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (entity, mut hazard, material) in &mut hazard_query {
+        if hazard.duration.is_infinite() {
+            continue;
+        }
+        hazard.timer -= time.delta_secs();
+        if hazard.timer <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if let Some(material) = materials.get_mut(&material.0) {
+            let alpha = HAZARD_ALPHA * (hazard.timer / hazard.duration).min(1.0);
+            material.color = material.color.with_alpha(alpha);
+        }
+    }
+}
+
+/// System to damage a grounded player standing in a hazard.
+/// Damage is continuous (per second) and ignores invincibility frames, so hazards can't be tanked.
+pub fn hazard_player_damage(
+    time: Res<Time>,
+    hazard_query: Query<(&Transform, &HazardZone), Without<Player>>,
+    mut player_query: Query<(&Transform, &mut Hp, Option<&mut Overshield>), With<Player>>,
+    player_upgrades: Option<Res<PlayerUpgrades>>,
+    arena: Res<ArenaBounds>,
+) {
+    let defense_multiplier = player_upgrades
+        .as_ref()
+        .map(|u| u.defense_multiplier)
+        .unwrap_or(1.0);
+
+    for (player_transform, mut player_hp, mut overshield) in &mut player_query {
+        let is_grounded = player_transform.translation.y <= arena.bottom + 1.0;
+        if !is_grounded {
+            continue;
+        }
+
+        // Overlapping hazards don't stack; the strongest one applies
+        let damage_per_second = hazard_query
+            .iter()
+            .filter(|(hazard_transform, hazard)| {
+                (player_transform.translation.x - hazard_transform.translation.x).abs()
+                    < (PLAYER_HITBOX_SIZE.x + hazard.width) / 2.0
+            })
+            .map(|(_, hazard)| hazard.damage_per_second)
+            .fold(0.0, f32::max);
+
+        if damage_per_second > 0.0 {
+            let damage = damage_per_second * defense_multiplier * time.delta_secs();
+            take_damage(&mut player_hp, overshield.as_deref_mut(), damage);
+        }
+    }
+}
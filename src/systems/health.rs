@@ -0,0 +1,121 @@
+use crate::components::player::{Hp, Player};
+use crate::plugins::run_plugin::RunStats;
+use bevy::prelude::*;
+
+/// Message sent whenever an entity's HP changes, from any source (hits, hazards, healing, upgrades).
+/// Also sent once when an entity with `Hp` first appears (`previous == current`), so observers
+/// like HP bars start in sync.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct HealthChanged {
+    pub entity: Entity,
+    pub previous: f32,
+    pub current: f32,
+    pub max: f32,
+}
+
+impl HealthChanged {
+    /// HP lost in this change (0.0 when healed)
+    pub fn damage(&self) -> f32 {
+        (self.previous - self.current).max(0.0)
+    }
+
+    pub fn fraction(&self) -> f32 {
+        (self.current / self.max).clamp(0.0, 1.0)
+    }
+}
+
+/// Message sent once when an entity's HP drops to 0
+#[derive(Message, Clone, Copy, Debug)]
+pub struct EntityDied {
+    pub entity: Entity,
+}
+
+/// Component for a temporary overshield on top of HP: damage is taken from it first,
+/// and whatever is left of it runs out after `timer` seconds
+#[derive(Component)]
+pub struct Overshield {
+    pub amount: f32,
+    pub max: f32,
+    pub timer: f32,
+}
+
+impl Overshield {
+    pub fn fraction(&self) -> f32 {
+        (self.amount / self.max).clamp(0.0, 1.0)
+    }
+}
+
+/// Takes `damage` off `hp`, out of the overshield first when there is one (HP stops at 0)
+pub fn take_damage(hp: &mut Hp, overshield: Option<&mut Overshield>, damage: f32) {
+    let mut damage = damage;
+    if let Some(overshield) = overshield {
+        let absorbed = damage.min(overshield.amount);
+        overshield.amount -= absorbed;
+        damage -= absorbed;
+    }
+    hp.current = (hp.current - damage).max(0.0);
+}
+
+/// Component with the HP last reported in a `HealthChanged`, added on an entity's first report
+#[derive(Component)]
+pub struct ReportedHp(pub f32);
+
+/// System to turn HP changes into `HealthChanged` and `EntityDied` messages.
+/// Systems change `Hp` directly (damage through `take_damage`, so overshields absorb it first);
+/// this is the one place that notices and reports it. In a Crumb of Death run any HP the player
+/// loses is all of it.
+pub fn emit_health_events(
+    mut commands: Commands,
+    mut hp_query: Query<(Entity, &mut Hp, Option<&mut ReportedHp>, Has<Player>), Changed<Hp>>,
+    run_stats: Option<Res<RunStats>>,
+    mut health_changed: MessageWriter<HealthChanged>,
+    mut entity_died: MessageWriter<EntityDied>,
+) {
+    let one_hit_kills = run_stats.is_some_and(|stats| stats.crumb_of_death);
+    for (entity, mut hp, reported, is_player) in &mut hp_query {
+        let Some(mut reported) = reported else {
+            commands.entity(entity).insert(ReportedHp(hp.current));
+            health_changed.write(HealthChanged {
+                entity,
+                previous: hp.current,
+                current: hp.current,
+                max: hp.max,
+            });
+            continue;
+        };
+
+        let previous = reported.0;
+        if one_hit_kills && is_player && hp.current < previous {
+            hp.current = 0.0;
+        }
+
+        if hp.current == previous {
+            continue;
+        }
+        reported.0 = hp.current;
+
+        health_changed.write(HealthChanged {
+            entity,
+            previous,
+            current: hp.current,
+            max: hp.max,
+        });
+        if hp.current <= 0.0 && previous > 0.0 {
+            entity_died.write(EntityDied { entity });
+        }
+    }
+}
+
+/// System to count down overshields, removing them once they run out or are used up
+pub fn tick_overshields(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut shield_query: Query<(Entity, &mut Overshield)>,
+) {
+    for (entity, mut overshield) in &mut shield_query {
+        overshield.timer -= time.delta_secs();
+        if overshield.timer <= 0.0 || overshield.amount <= 0.0 {
+            commands.entity(entity).remove::<Overshield>();
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::plugins::run_plugin::RunStats;
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::config::{HIT_STOP_DURATION, HIT_STOP_TIME_SCALE, WEAPON_WHEEL_TIME_SCALE};
+use crate::systems::game_config::GameConfig;
+use crate::systems::weapons::WeaponWheel;
+use bevy::prelude::*;
+
+/// Resource to track the remaining hit-stop time (brief slow-motion on heavy impacts)
+#[derive(Resource, Default)]
+pub struct HitStop {
+    pub timer: f32, // Remaining hit-stop time in real-time seconds
+}
+
+impl HitStop {
+    /// Start (or extend) a hit-stop using the configured duration
+    pub fn trigger(&mut self) {
+        self.trigger_for(HIT_STOP_DURATION);
+    }
+
+    /// Start (or extend) a hit-stop lasting `duration` real-time seconds
+    pub fn trigger_for(&mut self, duration: f32) {
+        self.timer = self.timer.max(duration);
+    }
+
+    /// Whether a hit-stop is currently slowing down gameplay
+    pub fn is_active(&self) -> bool {
+        self.timer > 0.0
+    }
+}
+
+/// System to apply the game speed setting, hit-stop and the weapon wheel slow-down to virtual time.
+/// Ticks with real time so the slow-motion itself doesn't stretch the hit-stop duration.
+/// Daily challenges always run at normal speed so their scores compare, and hit-stops are dropped
+/// while `GameConfig::hit_stop_enabled` is off.
+pub fn update_hit_stop(
+    real_time: Res<Time<Real>>,
+    config: Res<GameConfig>,
+    settings: Res<GameSettings>,
+    run_stats: Res<RunStats>,
+    weapon_wheel: Res<WeaponWheel>,
+    mut hit_stop: ResMut<HitStop>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if !config.hit_stop_enabled {
+        hit_stop.timer = 0.0;
+    } else if hit_stop.is_active() {
+        hit_stop.timer -= real_time.delta_secs();
+    }
+
+    let hit_stop_scale = if hit_stop.is_active() {
+        HIT_STOP_TIME_SCALE
+    } else {
+        1.0
+    };
+    let wheel_scale = if weapon_wheel.open {
+        WEAPON_WHEEL_TIME_SCALE
+    } else {
+        1.0
+    };
+    let game_speed = if run_stats.daily_day.is_some() {
+        1.0
+    } else {
+        settings.game_speed.multiplier()
+    };
+    let speed = game_speed * hit_stop_scale * wheel_scale;
+
+    if virtual_time.relative_speed() != speed {
+        virtual_time.set_relative_speed(speed);
+    }
+}
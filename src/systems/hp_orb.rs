@@ -0,0 +1,30 @@
+use crate::systems::config::HP_ORB_SHADER_PATH;
+use bevy::prelude::*;
+use bevy::render::render_resource::AsBindGroup;
+use bevy::shader::ShaderRef;
+use bevy::sprite_render::{AlphaMode2d, Material2d};
+
+/// 2D material for the player's HP orb (see `shaders/hp_orb.wgsl`).
+/// The shader clips the liquid to the circle itself, so the orb is a single quad.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct HpOrbMaterial {
+    #[uniform(0)]
+    pub color: LinearRgba,
+    /// Liquid level from 0.0 (empty) to 1.0 (full), measured as height inside the orb
+    #[uniform(0)]
+    pub fill: f32,
+    /// Remaining overshield from 0.0 (none) to 1.0 (full), drawn as a ring in the orb's rim
+    #[uniform(0)]
+    pub shield: f32,
+}
+
+impl Material2d for HpOrbMaterial {
+    fn fragment_shader() -> ShaderRef {
+        HP_ORB_SHADER_PATH.into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        // Blended so the orb's edge is anti-aliased
+        AlphaMode2d::Blend
+    }
+}
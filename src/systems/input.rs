@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+/// Gameplay actions and the keys bound to them. Gameplay systems and the input display read
+/// the keyboard through these, so a binding only has to change in one place.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputAction {
+    Left,
+    Right,
+    Up,   // Aim up
+    Down, // Held with Jump to dash
+    Jump,
+    Shoot,
+    Potion,
+    WeaponWheel,
+}
+
+impl InputAction {
+    pub const ALL: [InputAction; 8] = [
+        InputAction::Left,
+        InputAction::Right,
+        InputAction::Up,
+        InputAction::Down,
+        InputAction::Jump,
+        InputAction::Shoot,
+        InputAction::Potion,
+        InputAction::WeaponWheel,
+    ];
+
+    pub fn keys(self) -> &'static [KeyCode] {
+        match self {
+            InputAction::Left => &[KeyCode::ArrowLeft],
+            InputAction::Right => &[KeyCode::ArrowRight],
+            InputAction::Up => &[KeyCode::ArrowUp],
+            InputAction::Down => &[KeyCode::ArrowDown],
+            InputAction::Jump => &[KeyCode::Space, KeyCode::KeyX],
+            InputAction::Shoot => &[KeyCode::KeyC],
+            InputAction::Potion => &[KeyCode::KeyQ],
+            InputAction::WeaponWheel => &[KeyCode::Tab],
+        }
+    }
+
+    /// Short name shown on the input display
+    pub fn label(self) -> &'static str {
+        match self {
+            InputAction::Left => "<",
+            InputAction::Right => ">",
+            InputAction::Up => "^",
+            InputAction::Down => "v",
+            InputAction::Jump => "JUMP",
+            InputAction::Shoot => "SHOOT",
+            InputAction::Potion => "POTION",
+            InputAction::WeaponWheel => "WHEEL",
+        }
+    }
+
+    pub fn pressed(self, keyboard_input: &ButtonInput<KeyCode>) -> bool {
+        keyboard_input.any_pressed(self.keys().iter().copied())
+    }
+
+    pub fn just_pressed(self, keyboard_input: &ButtonInput<KeyCode>) -> bool {
+        keyboard_input.any_just_pressed(self.keys().iter().copied())
+    }
+
+    pub fn just_released(self, keyboard_input: &ButtonInput<KeyCode>) -> bool {
+        keyboard_input.any_just_released(self.keys().iter().copied())
+    }
+}
@@ -0,0 +1,173 @@
+use crate::components::boss::Boss;
+use crate::components::player::Defeated;
+use crate::plugins::camera_plugin::{BACKGROUND_RENDER_LAYER, EFFECTS_RENDER_LAYER, GameCamera};
+use crate::plugins::particle_plugin::{FeedbackParticle, FeedbackParticleAssets};
+use crate::stages::game_menu::GameState;
+use crate::systems::arena_doors::ArenaDoors;
+use crate::systems::config::{
+    KILL_CAM_DESATURATION, KILL_CAM_DURATION, KILL_CAM_EASE_TIME, KILL_CAM_EXPLOSION_PARTICLES,
+    KILL_CAM_EXPLOSION_SPEED, KILL_CAM_ZOOM,
+};
+use crate::systems::effects::ScreenShake;
+use crate::systems::health::EntityDied;
+use crate::systems::hit_stop::HitStop;
+use crate::systems::player::finish_fight;
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+
+/// Resource with the kill cam in progress: on the blow that beats the last boss of a fight, the
+/// camera zooms toward the boss, the game freezes in hit-stop and the background loses its color,
+/// then the boss explodes
+#[derive(Resource, Default)]
+pub struct KillCam {
+    pub boss: Option<Entity>, // Boss the kill cam is on (None = not playing)
+    pub focus: Vec2,          // Where the boss fell
+    pub timer: f32,           // Real-time seconds since the final blow
+    pub offset: Vec2,         // How far the camera is moved toward the boss this frame
+    pub finish_pending: bool, // The fight was won while playing: it ends once the boss explodes
+}
+
+impl KillCam {
+    /// Whether the kill cam is playing
+    pub fn is_playing(&self) -> bool {
+        self.boss.is_some()
+    }
+
+    /// How far the zoom and desaturation have come in (0.0 to 1.0)
+    fn strength(&self) -> f32 {
+        if !self.is_playing() {
+            return 0.0;
+        }
+        let t = (self.timer / KILL_CAM_EASE_TIME).min(1.0);
+        t * (2.0 - t) // Ease out
+    }
+
+    /// Projection scale the kill cam puts on top of the camera's own zoom (1.0 when not playing)
+    pub fn zoom(&self) -> f32 {
+        1.0 - KILL_CAM_ZOOM * self.strength()
+    }
+}
+
+/// Marker component for the gray laid over the background during the kill cam
+#[derive(Component)]
+pub struct KillCamOverlay;
+
+/// System to start the kill cam when the last boss standing dies (seen through `EntityDied`)
+pub fn start_kill_cam(
+    mut commands: Commands,
+    mut entity_died: MessageReader<EntityDied>,
+    boss_query: Query<(Entity, &Transform, Has<Defeated>), With<Boss>>,
+    mut kill_cam: ResMut<KillCam>,
+    mut hit_stop: ResMut<HitStop>,
+) {
+    let died: Vec<Entity> = entity_died.read().map(|message| message.entity).collect();
+    if kill_cam.is_playing() {
+        return;
+    }
+    let Some((boss, transform, _)) = boss_query
+        .iter()
+        .find(|(entity, _, _)| died.contains(entity))
+    else {
+        return;
+    };
+    // Other bosses still fighting get no kill cam
+    if boss_query
+        .iter()
+        .any(|(entity, _, defeated)| !defeated && !died.contains(&entity))
+    {
+        return;
+    }
+
+    *kill_cam = KillCam {
+        boss: Some(boss),
+        focus: transform.translation.truncate(),
+        timer: 0.0,
+        offset: Vec2::ZERO,
+        finish_pending: false,
+    };
+    hit_stop.trigger_for(KILL_CAM_DURATION);
+    commands.spawn((
+        DespawnOnExit(GameState::InGame),
+        Sprite {
+            color: Color::srgba(0.35, 0.35, 0.35, 0.0),
+            custom_size: Some(Vec2::splat(4000.0)), // Covers the background at any scroll
+            ..default()
+        },
+        Transform::from_translation(kill_cam.focus.extend(1.0)), // Over the background image
+        RenderLayers::layer(BACKGROUND_RENDER_LAYER),
+        KillCamOverlay,
+    ));
+}
+
+/// System to play the kill cam (in real time, since the game is frozen in hit-stop meanwhile):
+/// moves the camera toward the fallen boss as `KillCam::zoom` closes in on it and grays out the
+/// background, then blows the boss up and ends the fight. Runs after the camera is placed for the
+/// frame.
+pub fn update_kill_cam(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    particle_assets: Res<FeedbackParticleAssets>,
+    mut kill_cam: ResMut<KillCam>,
+    mut screen_shake: ResMut<ScreenShake>,
+    mut arena_doors: ResMut<ArenaDoors>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut boss_query: Query<(&mut Visibility, Option<&MeshMaterial2d<ColorMaterial>>), With<Boss>>,
+    mut game_camera_query: Query<&mut Transform, With<GameCamera>>,
+    mut overlay_query: Query<(Entity, &mut Sprite), With<KillCamOverlay>>,
+) {
+    let Some(boss) = kill_cam.boss else {
+        return;
+    };
+    kill_cam.timer += time.delta_secs();
+
+    if kill_cam.timer >= KILL_CAM_DURATION {
+        // The death explosion, in the boss's own color
+        if let Ok((mut visibility, material)) = boss_query.get_mut(boss) {
+            *visibility = Visibility::Hidden;
+            if let Some(material) = material {
+                for i in 0..KILL_CAM_EXPLOSION_PARTICLES {
+                    let angle =
+                        std::f32::consts::TAU * i as f32 / KILL_CAM_EXPLOSION_PARTICLES as f32;
+                    commands.spawn((
+                        Mesh2d(particle_assets.mesh.clone()),
+                        material.clone(),
+                        Transform::from_translation(kill_cam.focus.extend(0.0)),
+                        RenderLayers::layer(EFFECTS_RENDER_LAYER),
+                        FeedbackParticle {
+                            velocity: Vec2::from_angle(angle) * KILL_CAM_EXPLOSION_SPEED,
+                            age: 0.0,
+                        },
+                    ));
+                }
+            }
+        }
+        screen_shake.trigger();
+        for (entity, _) in &overlay_query {
+            commands.entity(entity).despawn();
+        }
+        if kill_cam.finish_pending {
+            finish_fight(&mut arena_doors, &mut next_state);
+        }
+        *kill_cam = KillCam::default();
+        return;
+    }
+
+    // Zooming about the boss keeps it where it is on screen while the view closes in on it
+    let zoom = kill_cam.zoom();
+    for mut transform in &mut game_camera_query {
+        let position = transform.translation.truncate();
+        let offset = (kill_cam.focus - position) * (1.0 - zoom);
+        transform.translation.x += offset.x;
+        transform.translation.y += offset.y;
+        kill_cam.offset = offset;
+    }
+    let strength = kill_cam.strength();
+    for (_, mut sprite) in &mut overlay_query {
+        sprite.color.set_alpha(KILL_CAM_DESATURATION * strength);
+    }
+}
+
+/// System to drop a kill cam cut short by leaving the fight
+pub fn reset_kill_cam(mut kill_cam: ResMut<KillCam>) {
+    *kill_cam = KillCam::default();
+}
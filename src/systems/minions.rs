@@ -0,0 +1,110 @@
+use crate::components::gameplay::GameplayEntity;
+use crate::components::player::{Defeated, Hp, Invincibility, Knockback, Player};
+use crate::stages::game_menu::PlayerUpgrades;
+use crate::systems::collision::{ColliderKind, CollisionLookup, Enemy, Hitbox, PLAYER_HITBOX_SIZE};
+use crate::systems::config::{
+    FLOOR_TOP, MINION_CONTACT_DAMAGE, MINION_HEIGHT, MINION_SPEED, MINION_WIDTH,
+};
+use crate::systems::effects::ScreenShake;
+use crate::systems::feedback::FeedbackEvent;
+use crate::systems::game_config::GameConfig;
+use crate::systems::health::{Overshield, take_damage};
+use bevy::prelude::*;
+
+/// Component for a minion walking back and forth around `home_x` (up to `range` either side).
+/// Minions are `Enemy` colliders, so player shots hit them like destructible hazards.
+#[derive(Component)]
+#[require(GameplayEntity)]
+pub struct Minion {
+    pub home_x: f32,
+    pub range: f32,
+    pub direction: f32,
+}
+
+/// Spawns a minion standing on the floor at `x`
+pub fn spawn_minion(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    x: f32,
+    range: f32,
+    hp: f32,
+) {
+    let size = Vec2::new(MINION_WIDTH, MINION_HEIGHT);
+    commands.spawn((
+        Mesh2d(meshes.add(Rectangle::from_size(size))),
+        MeshMaterial2d(materials.add(Color::srgb(0.6, 0.3, 0.7))),
+        Transform::from_xyz(x, FLOOR_TOP + MINION_HEIGHT / 2.0, 0.9),
+        Minion {
+            home_x: x,
+            range,
+            direction: -1.0, // Start walking toward the player
+        },
+        Enemy,
+        Hitbox(size),
+        Hp {
+            current: hp,
+            max: hp,
+        },
+    ));
+}
+
+/// System to walk minions along their patrol, turning around at either end
+pub fn patrol_minions(time: Res<Time>, mut minion_query: Query<(&mut Minion, &mut Transform)>) {
+    for (mut minion, mut transform) in &mut minion_query {
+        let x = transform.translation.x + minion.direction * MINION_SPEED * time.delta_secs();
+        let offset = x - minion.home_x;
+        if offset.abs() >= minion.range {
+            minion.direction = -offset.signum();
+        }
+        transform.translation.x = minion.home_x + offset.clamp(-minion.range, minion.range);
+    }
+}
+
+/// System to hurt and knock back the player on touching a minion
+/// (invincibility frames are counted down by `player_boss_collision`)
+pub fn minion_player_collision(
+    mut commands: Commands,
+    mut player_query: Query<
+        (Entity, &Transform, &mut Hp, Option<&mut Overshield>),
+        (With<Player>, Without<Invincibility>, Without<Defeated>),
+    >,
+    minion_query: Query<&Transform, (With<Minion>, Without<Player>)>,
+    collisions: CollisionLookup,
+    player_upgrades: Option<Res<PlayerUpgrades>>,
+    mut screen_shake: ResMut<ScreenShake>,
+    mut feedback: MessageWriter<FeedbackEvent>,
+    config: Res<GameConfig>,
+) {
+    let defense_multiplier = player_upgrades.map_or(1.0, |u| u.defense_multiplier);
+
+    for (player_entity, player_transform, mut player_hp, mut overshield) in &mut player_query {
+        let position = player_transform.translation.truncate();
+        let touching_minion = collisions
+            .overlapping(position, PLAYER_HITBOX_SIZE, ColliderKind::Enemy)
+            .into_iter()
+            .find_map(|entity| minion_query.get(entity).ok());
+        let Some(minion_transform) = touching_minion else {
+            continue;
+        };
+
+        take_damage(
+            &mut player_hp,
+            overshield.as_deref_mut(),
+            MINION_CONTACT_DAMAGE * defense_multiplier,
+        );
+        screen_shake.trigger();
+        feedback.write(FeedbackEvent::Hurt);
+
+        let away = (position.x - minion_transform.translation.x).signum();
+        commands.entity(player_entity).insert((
+            Invincibility {
+                timer: config.invincibility_duration,
+            },
+            Knockback {
+                velocity: Vec2::new(away, 0.5).normalize() * config.knockback_force,
+                timer: config.knockback_duration,
+            },
+        ));
+    }
+}
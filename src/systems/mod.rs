@@ -1,4 +1,35 @@
+pub mod arena_doors;
+pub mod blocks;
 pub mod boss;
+pub mod boss_ai;
 pub mod boundaries;
+pub mod collision;
+#[cfg(not(feature = "avian"))]
+pub mod collision_grid;
 pub mod config;
+pub mod corridor;
+pub mod display;
+pub mod effects;
+pub mod feedback;
+pub mod game_config;
+pub mod hazards;
+pub mod health;
+pub mod hit_stop;
+pub mod hp_orb;
+pub mod input;
+pub mod kill_cam;
+pub mod minions;
+pub mod palette;
+pub mod pattern_validation;
+pub mod physics;
+pub mod platforms;
 pub mod player;
+pub mod player_animation;
+pub mod projectile_cap;
+pub mod projectile_styles;
+pub mod rng;
+pub mod save_data;
+pub mod spawn;
+pub mod stage_modifier;
+pub mod stage_layout;
+pub mod weapons;
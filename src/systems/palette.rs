@@ -0,0 +1,105 @@
+use crate::systems::hazards::HazardKind;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Color palette used for gameplay feedback (HP, projectiles)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColorPalette {
+    #[default]
+    Standard,
+    /// Blue/orange palette (Okabe-Ito based) that stays distinguishable for
+    /// red-green colorblindness, with shape/outline cues on boss projectiles
+    ColorblindSafe,
+}
+
+impl ColorPalette {
+    pub fn label(self) -> &'static str {
+        match self {
+            ColorPalette::Standard => "Standard",
+            ColorPalette::ColorblindSafe => "Colorblind",
+        }
+    }
+
+    pub fn toggled(self) -> Self {
+        match self {
+            ColorPalette::Standard => ColorPalette::ColorblindSafe,
+            ColorPalette::ColorblindSafe => ColorPalette::Standard,
+        }
+    }
+
+    /// Whether boss projectiles should use a distinct shape and outline
+    pub fn uses_shape_cues(self) -> bool {
+        matches!(self, ColorPalette::ColorblindSafe)
+    }
+
+    /// HP orb color for a health fraction (0.0 to 1.0)
+    pub fn hp_color(self, health_percentage: f32) -> Color {
+        match self {
+            ColorPalette::Standard => {
+                // Change color based on HP (green -> yellow -> red)
+                if health_percentage > 0.5 {
+                    // Green to yellow transition
+                    let t = (health_percentage - 0.5) * 2.0;
+                    Color::srgb(1.0 - t, 1.0, 0.0)
+                } else {
+                    // Yellow to red transition
+                    let t = health_percentage * 2.0;
+                    Color::srgb(1.0, t, 0.0)
+                }
+            }
+            ColorPalette::ColorblindSafe => {
+                // Blue -> orange, also getting darker as HP drops so brightness carries the signal too
+                let blue = Vec3::new(0.35, 0.7, 0.9);
+                let orange = Vec3::new(0.9, 0.45, 0.0);
+                let mixed = orange.lerp(blue, health_percentage) * (0.6 + 0.4 * health_percentage);
+                Color::srgb(mixed.x, mixed.y, mixed.z)
+            }
+        }
+    }
+
+    /// Player projectile color (normal or charged)
+    pub fn player_projectile_color(self, charge_level: f32, is_charged: bool) -> Color {
+        match (self, is_charged) {
+            (ColorPalette::Standard, true) => {
+                // Color transitions from yellow (low charge) to bright orange/red (full charge)
+                let r = 1.0;
+                let g = 1.0 - (charge_level * 0.3); // 1.0 to 0.7
+                let b = charge_level * 0.2; // 0.0 to 0.2
+                Color::srgb(r, g, b)
+            }
+            (ColorPalette::Standard, false) => Color::srgb(1.0, 0.0, 0.0), // Normal shot: small red
+            (ColorPalette::ColorblindSafe, true) => {
+                // Sky blue brightening to near-white at full charge
+                let t = charge_level * 0.6;
+                Color::srgb(0.35 + t * 0.6, 0.7 + t * 0.3, 0.9 + t * 0.1)
+            }
+            (ColorPalette::ColorblindSafe, false) => Color::srgb(0.35, 0.7, 0.9), // Sky blue
+        }
+    }
+
+    /// Boss projectile fill color
+    pub fn boss_projectile_color(self) -> Color {
+        match self {
+            ColorPalette::Standard => Color::srgb(1.0, 0.5, 0.0), // Orange boss projectiles
+            ColorPalette::ColorblindSafe => Color::srgb(0.9, 0.6, 0.0), // Okabe-Ito orange
+        }
+    }
+
+    /// Ground hazard color
+    pub fn hazard_color(self, kind: HazardKind) -> Color {
+        match (self, kind) {
+            (ColorPalette::Standard, HazardKind::Fire) => Color::srgb(1.0, 0.3, 0.0),
+            (ColorPalette::Standard, HazardKind::Acid) => Color::srgb(0.4, 0.9, 0.1),
+            (ColorPalette::ColorblindSafe, HazardKind::Fire) => Color::srgb(0.8, 0.4, 0.0), // Vermillion
+            (ColorPalette::ColorblindSafe, HazardKind::Acid) => Color::srgb(0.8, 0.6, 0.7), // Reddish purple
+        }
+    }
+
+    /// Boss HP bar fill color
+    pub fn boss_hp_bar_color(self) -> Color {
+        match self {
+            ColorPalette::Standard => Color::srgb(1.0, 0.0, 0.0), // Red for boss
+            ColorPalette::ColorblindSafe => Color::srgb(0.8, 0.4, 0.0), // Vermillion
+        }
+    }
+}
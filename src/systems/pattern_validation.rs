@@ -0,0 +1,630 @@
+use crate::stages::game_menu::GameState;
+use crate::systems::boss::{AttackPatternConfig, BossPatternConfig, MovementPatternConfig};
+use crate::systems::boss_ai::{BehaviorCondition, BehaviorNode};
+use crate::systems::boundaries::ArenaBounds;
+use crate::systems::config::{BOSS_PATTERNS_DIR, SECRET_TOKENS_PER_STAGE};
+use bevy::prelude::*;
+
+/// Action types understood in `Sequence` attack patterns
+const KNOWN_ACTION_TYPES: [&str; 3] = ["shoot", "wait", "burst"];
+
+/// How serious a pattern problem is. Errors reject the pattern (the default boss is used instead).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// A single problem found in a boss pattern file
+#[derive(Clone, Debug)]
+pub struct PatternDiagnostic {
+    pub severity: Severity,
+    pub path: String, // Location inside the pattern, e.g. "behavior.children[1].attack.cooldown"
+    pub message: String,
+}
+
+impl std::fmt::Display for PatternDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{}: {}: {}", label, self.path, self.message)
+    }
+}
+
+/// All diagnostics for one pattern file
+#[derive(Clone, Debug)]
+pub struct PatternReport {
+    pub file_path: String,
+    pub diagnostics: Vec<PatternDiagnostic>,
+}
+
+impl PatternReport {
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity == Severity::Error)
+    }
+}
+
+/// Resource holding the reports of pattern files that had problems (shown in the debug panel)
+#[derive(Resource, Default)]
+pub struct PatternDiagnostics {
+    pub reports: Vec<PatternReport>,
+}
+
+impl PatternDiagnostics {
+    /// Log a report and keep it for the debug panel (replaces an older report for the same file)
+    pub fn record(&mut self, report: PatternReport) {
+        for diagnostic in &report.diagnostics {
+            match diagnostic.severity {
+                Severity::Warning => warn!("{}: {}", report.file_path, diagnostic),
+                Severity::Error => error!("{}: {}", report.file_path, diagnostic),
+            }
+        }
+        self.reports
+            .retain(|existing| existing.file_path != report.file_path);
+        if !report.diagnostics.is_empty() {
+            self.reports.push(report);
+        }
+    }
+}
+
+/// Collects diagnostics while walking a pattern
+#[derive(Default)]
+struct Validator {
+    diagnostics: Vec<PatternDiagnostic>,
+}
+
+impl Validator {
+    fn push(&mut self, severity: Severity, path: &str, message: impl Into<String>) {
+        self.diagnostics.push(PatternDiagnostic {
+            severity,
+            path: path.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// Durations, cooldowns and speeds can't be negative
+    fn non_negative(&mut self, path: &str, field: &str, value: f32) {
+        if value < 0.0 {
+            self.push(
+                Severity::Error,
+                &format!("{}.{}", path, field),
+                format!("must not be negative (got {})", value),
+            );
+        }
+    }
+
+    /// Counts of zero make an attack do nothing
+    fn non_zero(&mut self, path: &str, field: &str, value: u32) {
+        if value == 0 {
+            self.push(
+                Severity::Warning,
+                &format!("{}.{}", path, field),
+                "is 0, so this attack fires nothing",
+            );
+        }
+    }
+
+    /// HP thresholds are fractions of max HP
+    fn fraction(&mut self, path: &str, value: f32) {
+        if !(0.0..=1.0).contains(&value) {
+            self.push(
+                Severity::Error,
+                &format!("{}.fraction", path),
+                format!("must be between 0.0 and 1.0 (got {})", value),
+            );
+        }
+    }
+
+    fn attack(&mut self, path: &str, attack: &AttackPatternConfig) {
+        match attack {
+            AttackPatternConfig::None => {}
+            AttackPatternConfig::SingleShot {
+                cooldown,
+                projectile_speed,
+                ..
+            }
+            | AttackPatternConfig::TripleShot {
+                cooldown,
+                projectile_speed,
+                ..
+            } => {
+                self.non_negative(path, "cooldown", *cooldown);
+                self.non_negative(path, "projectile_speed", *projectile_speed);
+            }
+            AttackPatternConfig::RapidFire {
+                cooldown,
+                projectile_speed,
+                burst_count,
+                burst_delay,
+                ..
+            } => {
+                self.non_negative(path, "cooldown", *cooldown);
+                self.non_negative(path, "projectile_speed", *projectile_speed);
+                self.non_negative(path, "burst_delay", *burst_delay);
+                self.non_zero(path, "burst_count", *burst_count);
+            }
+            AttackPatternConfig::Melee {
+                cooldown,
+                windup,
+                active,
+                recovery,
+                reach,
+                height,
+            } => {
+                self.non_negative(path, "cooldown", *cooldown);
+                self.non_negative(path, "windup", *windup);
+                self.non_negative(path, "active", *active);
+                self.non_negative(path, "recovery", *recovery);
+                self.non_negative(path, "reach", *reach);
+                self.non_negative(path, "height", *height);
+            }
+            AttackPatternConfig::Ring {
+                cooldown,
+                projectile_speed,
+                count,
+                ..
+            }
+            | AttackPatternConfig::Fan {
+                cooldown,
+                projectile_speed,
+                count,
+                ..
+            } => {
+                self.non_negative(path, "cooldown", *cooldown);
+                self.non_negative(path, "projectile_speed", *projectile_speed);
+                self.non_zero(path, "count", *count);
+            }
+            AttackPatternConfig::Spiral {
+                cooldown,
+                projectile_speed,
+                arms,
+                ..
+            } => {
+                self.non_negative(path, "cooldown", *cooldown);
+                self.non_negative(path, "projectile_speed", *projectile_speed);
+                self.non_zero(path, "arms", *arms);
+            }
+            AttackPatternConfig::Mortar {
+                cooldown,
+                flight_time,
+                count,
+                ..
+            } => {
+                self.non_negative(path, "cooldown", *cooldown);
+                self.non_zero(path, "count", *count);
+                if *flight_time <= 0.0 {
+                    self.push(
+                        Severity::Error,
+                        &format!("{}.flight_time", path),
+                        format!("must be greater than 0 (got {})", flight_time),
+                    );
+                }
+            }
+            AttackPatternConfig::Beam {
+                cooldown,
+                telegraph,
+                duration,
+                length,
+                width,
+                ..
+            } => {
+                self.non_negative(path, "cooldown", *cooldown);
+                self.non_negative(path, "telegraph", *telegraph);
+                self.non_negative(path, "duration", *duration);
+                self.non_negative(path, "length", *length);
+                self.non_negative(path, "width", *width);
+            }
+            AttackPatternConfig::Sequence { actions, .. } => {
+                if actions.is_empty() {
+                    self.push(Severity::Error, &format!("{}.actions", path), "is empty");
+                }
+                for (index, action) in actions.iter().enumerate() {
+                    let action_path = format!("{}.actions[{}]", path, index);
+                    if !KNOWN_ACTION_TYPES.contains(&action.action_type.as_str()) {
+                        self.push(
+                            Severity::Error,
+                            &format!("{}.action_type", action_path),
+                            format!(
+                                "unknown action type \"{}\" (expected one of: {})",
+                                action.action_type,
+                                KNOWN_ACTION_TYPES.join(", ")
+                            ),
+                        );
+                    }
+                    if let Some(delay) = action.delay {
+                        self.non_negative(&action_path, "delay", delay);
+                    }
+                }
+            }
+        }
+    }
+
+    fn movement(&mut self, path: &str, movement: &MovementPatternConfig) {
+        match movement {
+            MovementPatternConfig::Stationary => {}
+            MovementPatternConfig::HorizontalPatrol {
+                left_bound,
+                right_bound,
+                speed,
+            } => {
+                self.non_negative(path, "speed", *speed);
+                if left_bound > right_bound {
+                    self.push(
+                        Severity::Error,
+                        path,
+                        format!(
+                            "left_bound ({}) is greater than right_bound ({})",
+                            left_bound, right_bound
+                        ),
+                    );
+                }
+            }
+            MovementPatternConfig::VerticalPatrol {
+                top_bound,
+                bottom_bound,
+                speed,
+            } => {
+                self.non_negative(path, "speed", *speed);
+                if bottom_bound > top_bound {
+                    self.push(
+                        Severity::Error,
+                        path,
+                        format!(
+                            "bottom_bound ({}) is greater than top_bound ({})",
+                            bottom_bound, top_bound
+                        ),
+                    );
+                }
+            }
+            MovementPatternConfig::Circular { radius, speed, .. } => {
+                self.non_negative(path, "radius", *radius);
+                self.non_negative(path, "speed", *speed);
+            }
+            MovementPatternConfig::Waypoint {
+                waypoints, speed, ..
+            } => {
+                self.non_negative(path, "speed", *speed);
+                if waypoints.is_empty() {
+                    self.push(Severity::Error, &format!("{}.waypoints", path), "is empty");
+                }
+            }
+            MovementPatternConfig::Chase {
+                speed,
+                stop_distance,
+            } => {
+                self.non_negative(path, "speed", *speed);
+                self.non_negative(path, "stop_distance", *stop_distance);
+            }
+            MovementPatternConfig::Retreat { speed } => self.non_negative(path, "speed", *speed),
+            MovementPatternConfig::JumpTowardPlayer {
+                interval,
+                arc_height,
+            } => {
+                self.non_negative(path, "interval", *interval);
+                self.non_negative(path, "arc_height", *arc_height);
+            }
+            MovementPatternConfig::GroundSlam { interval, height } => {
+                self.non_negative(path, "interval", *interval);
+                self.non_negative(path, "height", *height);
+            }
+        }
+    }
+
+    fn behavior(&mut self, path: &str, node: &BehaviorNode) {
+        match node {
+            BehaviorNode::Sequence { children } | BehaviorNode::Selector { children } => {
+                self.children(path, children);
+            }
+            BehaviorNode::RandomSelector { children, weights } => {
+                self.children(path, children);
+                if !weights.is_empty() && weights.len() != children.len() {
+                    self.push(
+                        Severity::Warning,
+                        &format!("{}.weights", path),
+                        format!(
+                            "has {} entries for {} children (missing weights count as 1.0, extra ones are ignored)",
+                            weights.len(),
+                            children.len()
+                        ),
+                    );
+                }
+                for (index, weight) in weights.iter().enumerate() {
+                    self.non_negative(path, &format!("weights[{}]", index), *weight);
+                }
+            }
+            BehaviorNode::Condition { condition } => {
+                let condition_path = format!("{}.condition", path);
+                match condition {
+                    BehaviorCondition::HpBelow { fraction }
+                    | BehaviorCondition::HpAbove { fraction } => {
+                        self.fraction(&condition_path, *fraction);
+                    }
+                    BehaviorCondition::PlayerCloserThan { distance }
+                    | BehaviorCondition::PlayerFartherThan { distance } => {
+                        self.non_negative(&condition_path, "distance", *distance);
+                    }
+                    BehaviorCondition::Cornered { margin } => {
+                        self.non_negative(&condition_path, "margin", *margin);
+                    }
+                    BehaviorCondition::PlayerAirborne
+                    | BehaviorCondition::PlayerGrounded
+                    | BehaviorCondition::InPhase { .. }
+                    | BehaviorCondition::PlayerAbove { .. } => {}
+                }
+            }
+            BehaviorNode::Attack { attack } => self.attack(&format!("{}.attack", path), attack),
+            BehaviorNode::Move { movement } => {
+                self.movement(&format!("{}.movement", path), movement);
+            }
+            BehaviorNode::Wait { duration } => self.non_negative(path, "duration", *duration),
+            BehaviorNode::Survive { duration, attack } => {
+                self.non_negative(path, "duration", *duration);
+                self.attack(&format!("{}.attack", path), attack);
+            }
+        }
+    }
+
+    fn children(&mut self, path: &str, children: &[BehaviorNode]) {
+        if children.is_empty() {
+            self.push(Severity::Warning, &format!("{}.children", path), "is empty");
+        }
+        for (index, child) in children.iter().enumerate() {
+            self.behavior(&format!("{}.children[{}]", path, index), child);
+        }
+    }
+}
+
+/// Checks a parsed pattern for values that deserialize fine but can't work in game
+pub fn validate_pattern(config: &BossPatternConfig) -> Vec<PatternDiagnostic> {
+    let mut validator = Validator::default();
+    validator.attack("attack", &config.attack);
+    validator.movement("movement", &config.movement);
+    if let Some(behavior) = &config.behavior {
+        validator.behavior("behavior", behavior);
+    }
+    if let Some(max_hp) = config.max_hp
+        && max_hp <= 0.0
+    {
+        validator.push(
+            Severity::Error,
+            "max_hp",
+            format!("must be greater than 0 (got {})", max_hp),
+        );
+    }
+    if config.count == 0 {
+        validator.push(Severity::Error, "count", "must be at least 1");
+    }
+    if let Some(arena) = &config.arena {
+        if arena.left >= arena.right {
+            validator.push(
+                Severity::Error,
+                "arena",
+                format!(
+                    "left ({}) must be less than right ({})",
+                    arena.left, arena.right
+                ),
+            );
+        }
+        let bounds = ArenaBounds::from_config(arena);
+        if bounds.top <= bounds.bottom {
+            validator.push(
+                Severity::Error,
+                "arena.top",
+                format!(
+                    "must be above the floor at {} (got {})",
+                    bounds.bottom, bounds.top
+                ),
+            );
+        }
+    }
+    if let Some(corridor) = &config.corridor {
+        if corridor.length <= 0.0 {
+            validator.push(
+                Severity::Error,
+                "corridor.length",
+                format!("must be greater than 0 (got {})", corridor.length),
+            );
+        }
+        let positions = corridor
+            .minions
+            .iter()
+            .enumerate()
+            .map(|(i, minion)| (format!("corridor.minions[{}].x", i), minion.x))
+            .chain(
+                corridor
+                    .pickups
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| (format!("corridor.pickups[{}]", i), *x)),
+            )
+            .chain(
+                corridor
+                    .checkpoints
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| (format!("corridor.checkpoints[{}]", i), *x)),
+            );
+        for (path, x) in positions {
+            if x < 0.0 || x > corridor.length {
+                validator.push(
+                    Severity::Warning,
+                    &path,
+                    format!("{} is outside the corridor (0 to {})", x, corridor.length),
+                );
+            }
+        }
+    }
+    if let Some(medals) = &config.medals
+        && !(medals.gold <= medals.silver && medals.silver <= medals.bronze)
+    {
+        validator.push(
+            Severity::Error,
+            "medals",
+            format!(
+                "times must go gold <= silver <= bronze (got {}, {}, {})",
+                medals.gold, medals.silver, medals.bronze
+            ),
+        );
+    }
+    if config.secret_tokens.len() > SECRET_TOKENS_PER_STAGE {
+        validator.push(
+            Severity::Warning,
+            "secret_tokens",
+            format!(
+                "only the first {} tokens are placed (got {})",
+                SECRET_TOKENS_PER_STAGE,
+                config.secret_tokens.len()
+            ),
+        );
+    }
+    validator.diagnostics
+}
+
+/// Reads, parses and validates a pattern file. Returns the pattern if it has no errors.
+pub fn check_pattern_file(file_path: &str) -> (Option<BossPatternConfig>, PatternReport) {
+    let mut report = PatternReport {
+        file_path: file_path.to_string(),
+        diagnostics: Vec::new(),
+    };
+
+    let parsed = std::fs::read_to_string(file_path)
+        .map_err(|e| e.to_string())
+        .and_then(|json| {
+            serde_json::from_str::<BossPatternConfig>(&json).map_err(|e| e.to_string())
+        });
+    let config = match parsed {
+        Ok(config) => config,
+        Err(e) => {
+            report.diagnostics.push(PatternDiagnostic {
+                severity: Severity::Error,
+                path: "(file)".to_string(),
+                message: e,
+            });
+            return (None, report);
+        }
+    };
+
+    report.diagnostics = validate_pattern(&config);
+    if report.has_errors() {
+        (None, report)
+    } else {
+        (Some(config), report)
+    }
+}
+
+/// Sorted paths of every JSON pattern file in the pattern directory
+pub fn pattern_file_paths() -> std::io::Result<Vec<String>> {
+    let mut file_paths: Vec<String> = std::fs::read_dir(BOSS_PATTERNS_DIR)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect();
+    file_paths.sort();
+    Ok(file_paths)
+}
+
+/// `--validate-patterns` CLI mode: checks every JSON file in the pattern directory, prints the
+/// diagnostics and returns the process exit code (1 if any file has errors)
+pub fn validate_all_pattern_files() -> i32 {
+    let file_paths = match pattern_file_paths() {
+        Ok(file_paths) => file_paths,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", BOSS_PATTERNS_DIR, e);
+            return 1;
+        }
+    };
+
+    let mut error_count = 0;
+    for file_path in &file_paths {
+        let (_, report) = check_pattern_file(file_path);
+        if report.diagnostics.is_empty() {
+            println!("ok      {}", file_path);
+            continue;
+        }
+        if report.has_errors() {
+            error_count += 1;
+            println!("FAILED  {}", file_path);
+        } else {
+            println!("ok      {}", file_path);
+        }
+        for diagnostic in &report.diagnostics {
+            println!("        {}", diagnostic);
+        }
+    }
+
+    println!(
+        "{} pattern file(s) checked, {} with errors",
+        file_paths.len(),
+        error_count
+    );
+    if error_count > 0 { 1 } else { 0 }
+}
+
+/// Marker component for the pattern diagnostics debug panel
+#[derive(Component)]
+pub struct PatternDiagnosticsPanel;
+
+/// Spawns a debug panel listing pattern problems (only when there are any)
+pub fn spawn_pattern_diagnostics_panel(
+    mut commands: Commands,
+    diagnostics: Res<PatternDiagnostics>,
+) {
+    if diagnostics.reports.is_empty() {
+        return;
+    }
+
+    commands
+        .spawn((
+            DespawnOnExit(GameState::InGame),
+            Node {
+                position_type: PositionType::Absolute,
+                right: px(10.0),
+                bottom: px(10.0),
+                max_width: px(520.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: px(4.0),
+                padding: UiRect::all(px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+            PatternDiagnosticsPanel,
+        ))
+        .with_children(|parent| {
+            for report in &diagnostics.reports {
+                let status = if report.has_errors() {
+                    " (rejected, using default pattern)"
+                } else {
+                    ""
+                };
+                parent.spawn((
+                    Text::new(format!("{}{}", report.file_path, status)),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+                for diagnostic in &report.diagnostics {
+                    let color = match diagnostic.severity {
+                        Severity::Warning => Color::srgb(1.0, 0.85, 0.3),
+                        Severity::Error => Color::srgb(1.0, 0.4, 0.4),
+                    };
+                    parent.spawn((
+                        Text::new(format!("  {}", diagnostic)),
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(color),
+                    ));
+                }
+            }
+        });
+}
@@ -0,0 +1,108 @@
+use crate::components::boss::Boss;
+use crate::components::player::{Player, Projectile};
+use crate::systems::boss::{BossProjectile, BossShockwave};
+use crate::systems::collision::{
+    BOSS_HITBOX_SIZE, ColliderKind, Enemy, Hitbox, PLAYER_HITBOX_SIZE, PROJECTILE_HITBOX_SIZE,
+    SHOCKWAVE_HITBOX_SIZE,
+};
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+/// avian2d collision layers, one per collider kind, so spatial queries only return the kind asked for
+#[derive(PhysicsLayer, Clone, Copy, Debug, Default)]
+pub enum GameLayer {
+    #[default]
+    Default,
+    Player,
+    Boss,
+    PlayerProjectile,
+    BossProjectile,
+    Shockwave,
+    Enemy,
+}
+
+impl From<ColliderKind> for GameLayer {
+    fn from(kind: ColliderKind) -> Self {
+        match kind {
+            ColliderKind::Player => GameLayer::Player,
+            ColliderKind::Boss => GameLayer::Boss,
+            ColliderKind::PlayerProjectile => GameLayer::PlayerProjectile,
+            ColliderKind::BossProjectile => GameLayer::BossProjectile,
+            ColliderKind::Shockwave => GameLayer::Shockwave,
+            ColliderKind::Enemy => GameLayer::Enemy,
+        }
+    }
+}
+
+/// Kinematic sensor collider for a hitbox. Movement stays in the game's own systems (tuned through
+/// `GameConfig`), so the bodies are only moved through their transforms and never push each other;
+/// an empty filter mask skips contact generation, as the collision systems use spatial queries.
+/// The physics step runs in `UpdateColliders`, which syncs the colliders to the transforms.
+fn hitbox_collider(kind: ColliderKind, size: Vec2) -> impl Bundle {
+    (
+        RigidBody::Kinematic,
+        Collider::rectangle(size.x, size.y),
+        Sensor,
+        CollisionLayers::new(GameLayer::from(kind), LayerMask::NONE),
+    )
+}
+
+/// System to give newly spawned fighters, enemies, projectiles and shockwaves avian colliders
+/// matching the hitboxes the built-in collision grid uses (before the physics step, so they're
+/// queryable the frame they spawn)
+pub fn attach_physics_colliders(
+    mut commands: Commands,
+    player_query: Query<Entity, Added<Player>>,
+    boss_query: Query<Entity, Added<Boss>>,
+    projectile_query: Query<(Entity, Has<BossProjectile>), Added<Projectile>>,
+    shockwave_query: Query<Entity, Added<BossShockwave>>,
+    enemy_query: Query<(Entity, &Hitbox), Added<Enemy>>,
+) {
+    for entity in &player_query {
+        commands
+            .entity(entity)
+            .insert(hitbox_collider(ColliderKind::Player, PLAYER_HITBOX_SIZE));
+    }
+    for entity in &boss_query {
+        commands
+            .entity(entity)
+            .insert(hitbox_collider(ColliderKind::Boss, BOSS_HITBOX_SIZE));
+    }
+    for (entity, is_boss_projectile) in &projectile_query {
+        let kind = if is_boss_projectile {
+            ColliderKind::BossProjectile
+        } else {
+            ColliderKind::PlayerProjectile
+        };
+        // Charged shots are spawned scaled up, and avian scales colliders with the transform
+        commands
+            .entity(entity)
+            .insert(hitbox_collider(kind, PROJECTILE_HITBOX_SIZE));
+    }
+    for entity in &shockwave_query {
+        commands
+            .entity(entity)
+            .insert(hitbox_collider(ColliderKind::Shockwave, SHOCKWAVE_HITBOX_SIZE));
+    }
+    for (entity, hitbox) in &enemy_query {
+        commands
+            .entity(entity)
+            .insert(hitbox_collider(ColliderKind::Enemy, hitbox.0));
+    }
+}
+
+/// Colliders of the given kind overlapping the box at `center` with `size`
+/// (positions are those of this frame's physics step in `UpdateColliders`)
+pub fn overlapping(
+    spatial_query: &SpatialQuery,
+    center: Vec2,
+    size: Vec2,
+    kind: ColliderKind,
+) -> Vec<Entity> {
+    spatial_query.shape_intersections(
+        &Collider::rectangle(size.x, size.y),
+        center,
+        0.0,
+        &SpatialQueryFilter::from_mask(GameLayer::from(kind)),
+    )
+}
@@ -0,0 +1,227 @@
+use crate::components::player::JumpType;
+use crate::systems::config::{
+    KNOCKBACK_SIDE_VERTICAL_COMPONENT, KNOCKBACK_TOP_HORIZONTAL_COMPONENT,
+    KNOCKBACK_TOP_VERTICAL_COMPONENT,
+};
+use bevy::math::Vec2;
+
+/// Jump a charge of `charge_time` seconds resolves to: charges shorter than
+/// `small_jump_charge_ratio` of `max_charge_time` make a small jump, longer ones a high jump.
+/// A `max_charge_time` of zero makes every jump a high jump.
+pub fn resolve_jump_charge(
+    charge_time: f32,
+    max_charge_time: f32,
+    small_jump_charge_ratio: f32,
+) -> JumpType {
+    // Without a charge to build up, every jump is already fully charged
+    if max_charge_time <= 0.0 {
+        return JumpType::High;
+    }
+    let charge_ratio = (charge_time / max_charge_time).clamp(0.0, 1.0);
+    if charge_ratio < small_jump_charge_ratio {
+        JumpType::Small
+    } else {
+        JumpType::High
+    }
+}
+
+/// Gravity pulling on the player during a jump of `jump_type`: `base_gravity` scaled by that
+/// jump's own multiplier (falling without a jump uses `base_gravity` as is)
+pub fn select_gravity(
+    jump_type: JumpType,
+    base_gravity: f32,
+    high_jump_gravity_scale: f32,
+    small_jump_gravity_scale: f32,
+) -> f32 {
+    match jump_type {
+        JumpType::High => base_gravity * high_jump_gravity_scale,
+        JumpType::Small => base_gravity * small_jump_gravity_scale,
+        JumpType::None => base_gravity,
+    }
+}
+
+/// Knockback direction for a player touching a boss, from the boss toward the player.
+/// Hits from above or below bounce the player up or down and off to the side, while side hits
+/// push them straight away with a slight upward lift. Overlapping centers push to the left.
+pub fn knockback_direction(direction_to_player: Vec2) -> Vec2 {
+    if direction_to_player.length() < 0.001 {
+        return Vec2::new(-1.0, 0.0);
+    }
+
+    let horizontal_dir = if direction_to_player.x > 0.0 {
+        1.0
+    } else {
+        -1.0
+    };
+    if direction_to_player.y.abs() > direction_to_player.x.abs() {
+        // Top or bottom collision
+        let vertical_dir = direction_to_player.y.signum();
+        Vec2::new(
+            horizontal_dir * KNOCKBACK_TOP_HORIZONTAL_COMPONENT,
+            vertical_dir * KNOCKBACK_TOP_VERTICAL_COMPONENT,
+        )
+        .normalize()
+    } else {
+        // Side collision
+        Vec2::new(horizontal_dir, KNOCKBACK_SIDE_VERTICAL_COMPONENT).normalize()
+    }
+}
+
+/// `value` kept within `min..=max`. Unlike `f32::clamp` it doesn't panic on an arena narrower
+/// than nothing (`min > max`), where it settles on `max`.
+pub fn clamp_to_bounds(value: f32, min: f32, max: f32) -> f32 {
+    value.max(min).min(max)
+}
+
+/// Share of a value left after decaying exponentially at `rate` (per second) for `delta`
+/// seconds. Unlike a per-frame multiplier, it decays by the same amount each second at any frame
+/// rate.
+pub fn decay_factor(rate: f32, delta: f32) -> f32 {
+    (-rate * delta).exp()
+}
+
+/// Share of the way to move toward a target this frame when closing in on it at `rate` (per
+/// second), independent of the frame rate
+pub fn smoothing_blend(rate: f32, delta: f32) -> f32 {
+    1.0 - decay_factor(rate, delta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::config::KNOCKBACK_DECAY_RATE;
+
+    const MAX_CHARGE_TIME: f32 = 0.2;
+    const SMALL_JUMP_CHARGE_RATIO: f32 = 0.43;
+
+    fn assert_close(actual: Vec2, expected: Vec2) {
+        assert!(
+            actual.abs_diff_eq(expected, 1e-6),
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn jump_charge_below_the_ratio_is_a_small_jump() {
+        let threshold = MAX_CHARGE_TIME * SMALL_JUMP_CHARGE_RATIO;
+        assert_eq!(
+            resolve_jump_charge(threshold * 0.99, MAX_CHARGE_TIME, SMALL_JUMP_CHARGE_RATIO),
+            JumpType::Small
+        );
+        assert_eq!(
+            resolve_jump_charge(0.0, MAX_CHARGE_TIME, SMALL_JUMP_CHARGE_RATIO),
+            JumpType::Small
+        );
+    }
+
+    #[test]
+    fn jump_charge_at_or_above_the_ratio_is_a_high_jump() {
+        // Exactly on the ratio, with values that divide without rounding
+        assert_eq!(resolve_jump_charge(0.25, 1.0, 0.25), JumpType::High);
+
+        let threshold = MAX_CHARGE_TIME * SMALL_JUMP_CHARGE_RATIO;
+        assert_eq!(
+            resolve_jump_charge(threshold * 1.01, MAX_CHARGE_TIME, SMALL_JUMP_CHARGE_RATIO),
+            JumpType::High
+        );
+        // Charging past the max doesn't change anything
+        assert_eq!(
+            resolve_jump_charge(
+                MAX_CHARGE_TIME * 3.0,
+                MAX_CHARGE_TIME,
+                SMALL_JUMP_CHARGE_RATIO
+            ),
+            JumpType::High
+        );
+    }
+
+    #[test]
+    fn zero_max_charge_time_always_high_jumps() {
+        assert_eq!(
+            resolve_jump_charge(0.0, 0.0, SMALL_JUMP_CHARGE_RATIO),
+            JumpType::High
+        );
+        assert_eq!(
+            resolve_jump_charge(0.1, 0.0, SMALL_JUMP_CHARGE_RATIO),
+            JumpType::High
+        );
+    }
+
+    #[test]
+    fn side_hits_push_straight_away_with_a_lift() {
+        let expected = Vec2::new(1.0, KNOCKBACK_SIDE_VERTICAL_COMPONENT).normalize();
+        assert_close(knockback_direction(Vec2::new(1.0, 0.2)), expected);
+        assert_close(
+            knockback_direction(Vec2::new(-1.0, -0.2)),
+            Vec2::new(-expected.x, expected.y),
+        );
+    }
+
+    #[test]
+    fn top_hits_bounce_up_and_off_to_the_side() {
+        let expected = Vec2::new(
+            KNOCKBACK_TOP_HORIZONTAL_COMPONENT,
+            KNOCKBACK_TOP_VERTICAL_COMPONENT,
+        )
+        .normalize();
+        assert_close(knockback_direction(Vec2::new(0.2, 1.0)), expected);
+        assert_close(
+            knockback_direction(Vec2::new(-0.2, 1.0)),
+            Vec2::new(-expected.x, expected.y),
+        );
+    }
+
+    #[test]
+    fn bottom_hits_push_down_and_off_to_the_side() {
+        let expected = Vec2::new(
+            KNOCKBACK_TOP_HORIZONTAL_COMPONENT,
+            -KNOCKBACK_TOP_VERTICAL_COMPONENT,
+        )
+        .normalize();
+        assert_close(knockback_direction(Vec2::new(0.2, -1.0)), expected);
+    }
+
+    #[test]
+    fn coincident_centers_push_left() {
+        assert_close(knockback_direction(Vec2::ZERO), Vec2::new(-1.0, 0.0));
+    }
+
+    #[test]
+    fn clamp_to_bounds_settles_on_max_when_min_is_above_max() {
+        assert_eq!(clamp_to_bounds(5.0, 10.0, 0.0), 0.0);
+        assert_eq!(clamp_to_bounds(-5.0, 10.0, 0.0), 0.0);
+        assert_eq!(clamp_to_bounds(5.0, 0.0, 10.0), 5.0);
+        assert_eq!(clamp_to_bounds(15.0, 0.0, 10.0), 10.0);
+    }
+
+    /// Share of a value left after decaying for one second in steps of `1 / hz` seconds
+    fn decay_over_one_second(rate: f32, hz: u32) -> f32 {
+        let delta = 1.0 / hz as f32;
+        (0..hz).map(|_| decay_factor(rate, delta)).product()
+    }
+
+    #[test]
+    fn decay_factor_is_the_same_at_60hz_and_144hz() {
+        let at_60hz = decay_over_one_second(KNOCKBACK_DECAY_RATE, 60);
+        let at_144hz = decay_over_one_second(KNOCKBACK_DECAY_RATE, 144);
+        assert!((at_60hz - at_144hz).abs() < 1e-5, "{at_60hz} vs {at_144hz}");
+        assert!((at_60hz - (-KNOCKBACK_DECAY_RATE).exp()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn knockback_decay_matches_the_old_per_frame_tuning_at_60hz() {
+        // Knockback used to keep 90% of its speed each frame, tuned at 60 Hz
+        assert!((decay_factor(KNOCKBACK_DECAY_RATE, 1.0 / 60.0) - 0.9).abs() < 1e-3);
+    }
+
+    #[test]
+    fn smoothing_blend_covers_the_same_ground_at_60hz_and_144hz() {
+        let left_at = |hz: u32| {
+            let delta = 1.0 / hz as f32;
+            (0..hz).fold(1.0, |left: f32, _| {
+                left * (1.0 - smoothing_blend(6.0, delta))
+            })
+        };
+        assert!((left_at(60) - left_at(144)).abs() < 1e-5);
+    }
+}
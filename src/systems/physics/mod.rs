@@ -0,0 +1,6 @@
+#[cfg(feature = "avian")]
+mod avian;
+pub mod kinematics; // Player movement math, free of ECS types (used with or without avian)
+
+#[cfg(feature = "avian")]
+pub use avian::*;
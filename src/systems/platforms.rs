@@ -0,0 +1,516 @@
+use crate::components::gameplay::GameplayEntity;
+use crate::stages::game_menu::CurrentStage;
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::blocks::{BLOCK_COLOR, spawn_breakable_block};
+use crate::systems::boundaries::ArenaBounds;
+use crate::systems::config::{
+    BLOCK_DEFAULT_HP, CONVEYOR_CHEVRON_SPACING, CONVEYOR_HEIGHT, FLOOR_TOP, LADDER_RUNG_SPACING,
+    LADDER_WIDTH,
+};
+use crate::systems::hazards::{HazardConfig, spawn_hazard};
+use crate::systems::stage_layout::{StageLayout, stage_layout_path};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const STANDING_TOLERANCE: f32 = 0.5; // How far above a surface a body still counts as standing on it
+pub const PLATFORM_COLOR: Color = Color::srgb(0.45, 0.4, 0.35);
+
+/// What a platform or the floor is made of, picking its footstep sounds and landing dust
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SurfaceMaterial {
+    #[default]
+    Stone,
+    Metal,
+    Cheese,
+}
+
+impl SurfaceMaterial {
+    pub const ALL: [SurfaceMaterial; 3] = [
+        SurfaceMaterial::Stone,
+        SurfaceMaterial::Metal,
+        SurfaceMaterial::Cheese,
+    ];
+
+    /// Name used for the material's sound files (e.g. `sounds/footstep_metal.ogg`)
+    pub fn name(self) -> &'static str {
+        match self {
+            SurfaceMaterial::Stone => "stone",
+            SurfaceMaterial::Metal => "metal",
+            SurfaceMaterial::Cheese => "cheese",
+        }
+    }
+
+    /// Color of platforms made of this material
+    pub fn platform_color(self) -> Color {
+        match self {
+            SurfaceMaterial::Stone => PLATFORM_COLOR,
+            SurfaceMaterial::Metal => Color::srgb(0.5, 0.55, 0.6),
+            SurfaceMaterial::Cheese => Color::srgb(0.95, 0.8, 0.3),
+        }
+    }
+
+    /// Color of the dust kicked up by steps and landings on this material
+    pub fn dust_color(self) -> Color {
+        match self {
+            SurfaceMaterial::Stone => Color::srgb(0.6, 0.55, 0.45),
+            SurfaceMaterial::Metal => Color::srgb(0.85, 0.9, 1.0), // Sparks off the metal
+            SurfaceMaterial::Cheese => Color::srgb(1.0, 0.9, 0.45), // Crumbs
+        }
+    }
+}
+
+/// Resource with the solid platforms, the breakable blocks still standing, the ladders, the
+/// water, wind and conveyor zones and the spawn points of the current fight, from the stage's
+/// layout file
+#[derive(Resource, Default)]
+pub struct StagePlatforms {
+    pub rects: Vec<Rect>,
+    pub surfaces: Vec<SurfaceMaterial>, // Material of each of `rects`, in the same order
+    pub floor: SurfaceMaterial,
+    pub blocks: Vec<(Entity, Rect)>, // Solid on every side, unlike platforms
+    pub ladders: Vec<Rect>,
+    pub water: Vec<Rect>,
+    pub wind: Vec<ForceZone>,
+    pub conveyors: Vec<ForceZone>,
+    pub player_spawn: Option<Vec2>, // None without a layout file (the default spawn points are used)
+    pub boss_spawn: Option<Vec2>,   // Where the first boss stands; more bosses line up to its left
+}
+
+/// Area pushing bodies sideways at `speed` (pixels per second, negative is left): bodies inside a
+/// wind zone, or standing on top of a conveyor
+#[derive(Clone, Copy, Debug)]
+pub struct ForceZone {
+    pub rect: Rect,
+    pub speed: f32,
+}
+
+/// Marker component for a platform's mesh
+#[derive(Component)]
+pub struct Platform;
+
+/// Marker component for a ladder's mesh
+#[derive(Component)]
+pub struct Ladder;
+
+/// Marker component for a water zone's mesh
+#[derive(Component)]
+pub struct Water;
+
+/// Marker component for a wind zone's or a conveyor's mesh
+#[derive(Component)]
+pub struct ForceZoneMarker;
+
+/// Spawns the mesh of a platform covering `rect`
+pub fn spawn_platform(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    material: Handle<ColorMaterial>,
+    rect: Rect,
+) {
+    commands.spawn((
+        GameplayEntity,
+        Mesh2d(meshes.add(Rectangle::new(rect.width(), rect.height()))),
+        MeshMaterial2d(material),
+        Transform::from_translation(rect.center().extend(0.2)),
+        Platform,
+    ));
+}
+
+/// Loads the current stage's layout (`STAGE_LAYOUT_DIR/stage_N.json`, made with the stage editor)
+/// and spawns its platforms, breakable blocks, ladders, water, wind zones, conveyors and
+/// hazards, keeping its spawn points for the player and the boss.
+/// Stages without a layout file are fought on the bare floor.
+pub fn spawn_stage_platforms(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    current_stage: Res<CurrentStage>,
+    mut platforms: ResMut<StagePlatforms>,
+    settings: Res<GameSettings>,
+    arena: Res<ArenaBounds>,
+) {
+    let file_path = stage_layout_path(current_stage.0);
+    let layout = match StageLayout::load_from_file(&file_path) {
+        Ok(layout) => {
+            platforms.player_spawn = Some(layout.player_spawn.clone().into());
+            platforms.boss_spawn = Some(layout.boss_spawn.clone().into());
+            layout
+        }
+        Err(e) => {
+            info!("No stage layout loaded from {}: {}", file_path, e);
+            platforms.player_spawn = None;
+            platforms.boss_spawn = None;
+            StageLayout::default()
+        }
+    };
+
+    platforms.rects = layout
+        .platforms
+        .iter()
+        .map(|platform| {
+            Rect::from_center_size(
+                Vec2::new(platform.x, platform.y),
+                Vec2::new(platform.width, platform.height),
+            )
+        })
+        .collect();
+    platforms.surfaces = layout
+        .platforms
+        .iter()
+        .map(|platform| platform.material)
+        .collect();
+    platforms.floor = layout.floor_material;
+
+    let surface_materials: Vec<_> = SurfaceMaterial::ALL
+        .into_iter()
+        .map(|surface| materials.add(surface.platform_color()))
+        .collect();
+    for (rect, surface) in platforms.rects.iter().zip(&platforms.surfaces) {
+        spawn_platform(
+            &mut commands,
+            &mut meshes,
+            surface_materials[*surface as usize].clone(),
+            *rect,
+        );
+    }
+
+    let block_material = materials.add(BLOCK_COLOR);
+    platforms.blocks = layout
+        .blocks
+        .iter()
+        .map(|block| {
+            let rect = Rect::from_center_size(
+                Vec2::new(block.x, block.y),
+                Vec2::new(block.width, block.height),
+            );
+            let entity = spawn_breakable_block(
+                &mut commands,
+                &mut meshes,
+                block_material.clone(),
+                rect,
+                block.hp.unwrap_or(BLOCK_DEFAULT_HP),
+                block.pickup,
+            );
+            (entity, rect)
+        })
+        .collect();
+
+    platforms.ladders = layout
+        .ladders
+        .iter()
+        .map(|ladder| {
+            Rect::new(
+                ladder.x - LADDER_WIDTH / 2.0,
+                ladder.bottom,
+                ladder.x + LADDER_WIDTH / 2.0,
+                ladder.top,
+            )
+        })
+        .collect();
+
+    // Ladders are drawn as a faint backing with rungs across it
+    let ladder_material = materials.add(PLATFORM_COLOR.with_alpha(0.35));
+    let rung_material = materials.add(PLATFORM_COLOR);
+    let rung_mesh = meshes.add(Rectangle::new(LADDER_WIDTH, 3.0));
+    for rect in &platforms.ladders {
+        let rungs = (rect.height() / LADDER_RUNG_SPACING).floor() as usize;
+        commands
+            .spawn((
+                GameplayEntity,
+                Mesh2d(meshes.add(Rectangle::new(rect.width(), rect.height()))),
+                MeshMaterial2d(ladder_material.clone()),
+                Transform::from_translation(rect.center().extend(0.15)),
+                Ladder,
+            ))
+            .with_children(|ladder| {
+                for rung in 1..=rungs {
+                    let y = rect.min.y + rung as f32 * LADDER_RUNG_SPACING - rect.center().y;
+                    ladder.spawn((
+                        Mesh2d(rung_mesh.clone()),
+                        MeshMaterial2d(rung_material.clone()),
+                        Transform::from_xyz(0.0, y, 0.01),
+                    ));
+                }
+            });
+    }
+
+    platforms.water = layout
+        .water
+        .iter()
+        .map(|water| {
+            Rect::from_center_size(
+                Vec2::new(water.x, water.y),
+                Vec2::new(water.width, water.height),
+            )
+        })
+        .collect();
+
+    let water_material = materials.add(Color::srgba(0.2, 0.45, 0.9, 0.35));
+    for rect in &platforms.water {
+        commands.spawn((
+            GameplayEntity,
+            Mesh2d(meshes.add(Rectangle::new(rect.width(), rect.height()))),
+            MeshMaterial2d(water_material.clone()),
+            Transform::from_translation(rect.center().extend(1.5)), // See-through, over the fighters
+            Water,
+        ));
+    }
+
+    platforms.wind = layout
+        .wind
+        .iter()
+        .map(|wind| ForceZone {
+            rect: Rect::from_center_size(
+                Vec2::new(wind.x, wind.y),
+                Vec2::new(wind.width, wind.height),
+            ),
+            speed: wind.speed,
+        })
+        .collect();
+    platforms.conveyors = layout
+        .conveyors
+        .iter()
+        .map(|conveyor| ForceZone {
+            rect: Rect::new(
+                conveyor.x - conveyor.width / 2.0,
+                FLOOR_TOP - CONVEYOR_HEIGHT,
+                conveyor.x + conveyor.width / 2.0,
+                FLOOR_TOP,
+            ),
+            speed: conveyor.speed,
+        })
+        .collect();
+
+    // Wind is a faint haze, conveyors a strip in the floor; both get arrows showing their direction
+    let wind_material = materials.add(Color::srgba(0.85, 0.9, 1.0, 0.12));
+    let conveyor_material = materials.add(Color::srgb(0.25, 0.25, 0.28));
+    let chevron_material = materials.add(Color::srgb(0.95, 0.75, 0.2));
+    let chevron_mesh = meshes.add(Triangle2d::new(
+        Vec2::new(5.0, 0.0),
+        Vec2::new(-5.0, 4.0),
+        Vec2::new(-5.0, -4.0),
+    ));
+    let zones = platforms
+        .wind
+        .iter()
+        .map(|zone| (zone, wind_material.clone()))
+        .chain(
+            platforms
+                .conveyors
+                .iter()
+                .map(|zone| (zone, conveyor_material.clone())),
+        );
+    for (zone, material) in zones {
+        let chevrons = (zone.rect.width() / CONVEYOR_CHEVRON_SPACING).floor() as usize;
+        let flip = if zone.speed < 0.0 { -1.0 } else { 1.0 };
+        commands
+            .spawn((
+                GameplayEntity,
+                Mesh2d(meshes.add(Rectangle::new(zone.rect.width(), zone.rect.height()))),
+                MeshMaterial2d(material),
+                Transform::from_translation(zone.rect.center().extend(0.1)),
+                ForceZoneMarker,
+            ))
+            .with_children(|parent| {
+                for chevron in 0..chevrons {
+                    let x =
+                        (chevron as f32 + 0.5) * CONVEYOR_CHEVRON_SPACING - zone.rect.width() / 2.0;
+                    parent.spawn((
+                        Mesh2d(chevron_mesh.clone()),
+                        MeshMaterial2d(chevron_material.clone()),
+                        Transform::from_xyz(x, 0.0, 0.01).with_scale(Vec3::new(flip, 1.0, 1.0)),
+                    ));
+                }
+            });
+    }
+
+    // Hazards placed with the editor stay for the whole fight
+    for placed in &layout.hazards {
+        let hazard = HazardConfig {
+            kind: placed.kind,
+            width: placed.width,
+            duration: f32::INFINITY,
+            damage_per_second: placed.damage_per_second,
+            hp: None,
+        };
+        spawn_hazard(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            settings.palette,
+            &hazard,
+            &arena,
+            placed.x,
+        );
+    }
+}
+
+/// System to clear the platforms, ladders, water and spawn points once the fight is over
+pub fn clear_stage_platforms(mut platforms: ResMut<StagePlatforms>) {
+    platforms.rects.clear();
+    platforms.surfaces.clear();
+    platforms.floor = SurfaceMaterial::default();
+    platforms.blocks.clear();
+    platforms.ladders.clear();
+    platforms.water.clear();
+    platforms.wind.clear();
+    platforms.conveyors.clear();
+    platforms.player_spawn = None;
+    platforms.boss_spawn = None;
+}
+
+/// Ground resolver shared by the player's and the bosses' movement: the arena floor plus the
+/// stage's platforms. Platforms are one-way, so bodies jump up through them and land on top.
+/// The top of a ladder is a one-way ledge like a platform, while breakable blocks are solid all
+/// around until they are shot down. It also knows where the water is, for
+/// the slower, floatier movement inside it, and where wind and conveyors push bodies along.
+#[derive(SystemParam)]
+pub struct Ground<'w> {
+    platforms: Res<'w, StagePlatforms>,
+    arena: Res<'w, ArenaBounds>,
+}
+
+impl Ground<'_> {
+    /// Platforms under a body centered at `x`
+    fn platforms_under(&self, x: f32, half_size: Vec2) -> impl Iterator<Item = &Rect> {
+        self.platforms
+            .rects
+            .iter()
+            .chain(&self.platforms.ladders)
+            .chain(self.platforms.blocks.iter().map(|(_, rect)| rect))
+            .filter(move |rect| x + half_size.x > rect.min.x && x - half_size.x < rect.max.x)
+    }
+
+    /// Where a body centered at `y` ends up moving sideways from `previous_x` to `x`: up against
+    /// the first block in its way, if any
+    pub fn blocked_x(&self, previous_x: f32, x: f32, y: f32, half_size: Vec2) -> f32 {
+        let mut x = x;
+        for (_, rect) in &self.platforms.blocks {
+            // Bodies standing on a block (or passing under it) aren't in its way
+            if y - half_size.y >= rect.max.y - STANDING_TOLERANCE || y + half_size.y <= rect.min.y {
+                continue;
+            }
+            if previous_x + half_size.x <= rect.min.x && x + half_size.x > rect.min.x {
+                x = rect.min.x - half_size.x;
+            } else if previous_x - half_size.x >= rect.max.x && x - half_size.x < rect.max.x {
+                x = rect.max.x + half_size.x;
+            }
+        }
+        x
+    }
+
+    /// Center height a body rising from `previous_y` to `y` this frame bumps its head at
+    /// (the underside of a block), or None if nothing is above it
+    pub fn ceiling_y(&self, x: f32, previous_y: f32, y: f32, half_size: Vec2) -> Option<f32> {
+        self.platforms
+            .blocks
+            .iter()
+            .map(|(_, rect)| rect)
+            .filter(|rect| x + half_size.x > rect.min.x && x - half_size.x < rect.max.x)
+            .map(|rect| rect.min.y - half_size.y)
+            .filter(|ceiling_y| previous_y <= *ceiling_y && y > *ceiling_y)
+            .reduce(f32::min)
+    }
+
+    /// Ladder a body centered at (`x`, `y`) can grab: one it stands in front of when climbing up,
+    /// or one below its feet (like standing on its top) when climbing down
+    pub fn ladder_at(&self, x: f32, y: f32, half_size: Vec2, downward: bool) -> Option<Rect> {
+        let feet = y - half_size.y;
+        self.platforms.ladders.iter().copied().find(|ladder| {
+            let in_reach = x >= ladder.min.x && x <= ladder.max.x;
+            let along = if downward {
+                feet > ladder.min.y + STANDING_TOLERANCE
+                    && feet <= ladder.max.y + STANDING_TOLERANCE
+            } else {
+                feet < ladder.max.y - STANDING_TOLERANCE && y + half_size.y > ladder.min.y
+            };
+            in_reach && along
+        })
+    }
+
+    /// `scale` for something at `position` inside a water zone, 1.0 out of the water
+    /// (for the `WATER_*_SCALE` multipliers)
+    pub fn water_scale(&self, position: Vec2, scale: f32) -> f32 {
+        if self
+            .platforms
+            .water
+            .iter()
+            .any(|rect| rect.contains(position))
+        {
+            scale
+        } else {
+            1.0
+        }
+    }
+
+    /// Horizontal speed the stage pushes a body centered at `position` along with: the wind zones
+    /// it's in, plus the conveyor under its feet when it's standing (`grounded`)
+    pub fn push_speed(&self, position: Vec2, half_size: Vec2, grounded: bool) -> f32 {
+        let wind: f32 = self
+            .platforms
+            .wind
+            .iter()
+            .filter(|zone| zone.rect.contains(position))
+            .map(|zone| zone.speed)
+            .sum();
+        if !grounded {
+            return wind;
+        }
+
+        let feet = position.y - half_size.y;
+        let conveyor: f32 = self
+            .platforms
+            .conveyors
+            .iter()
+            .filter(|zone| {
+                position.x >= zone.rect.min.x
+                    && position.x <= zone.rect.max.x
+                    && (feet - zone.rect.max.y).abs() <= STANDING_TOLERANCE
+            })
+            .map(|zone| zone.speed)
+            .sum();
+        wind + conveyor
+    }
+
+    /// Whether a body centered at (`x`, `y`) stands on the floor or on a platform
+    pub fn is_supported(&self, x: f32, y: f32, half_size: Vec2) -> bool {
+        y <= self.arena.bottom
+            || self.platforms_under(x, half_size).any(|rect| {
+                let resting_y = rect.max.y + half_size.y;
+                y >= resting_y && y - resting_y <= STANDING_TOLERANCE
+            })
+    }
+
+    /// Material of the surface a body centered at (`x`, `y`) stands on: the platform under its
+    /// feet, or else the floor (ladder tops and blocks count as the floor)
+    pub fn surface_at(&self, x: f32, y: f32, half_size: Vec2) -> SurfaceMaterial {
+        self.platforms
+            .rects
+            .iter()
+            .zip(&self.platforms.surfaces)
+            .find(|(rect, _)| {
+                let resting_y = rect.max.y + half_size.y;
+                x + half_size.x > rect.min.x
+                    && x - half_size.x < rect.max.x
+                    && (y - resting_y).abs() <= STANDING_TOLERANCE
+            })
+            .map_or(self.platforms.floor, |(_, surface)| *surface)
+    }
+
+    /// Center height a body lands at when it falls from `previous_y` to `y` this frame
+    /// (the highest surface its feet passed through), or None if it's still in the air
+    pub fn landing_y(&self, x: f32, previous_y: f32, y: f32, half_size: Vec2) -> Option<f32> {
+        let platform_landing = self
+            .platforms_under(x, half_size)
+            .map(|rect| rect.max.y + half_size.y)
+            .filter(|resting_y| previous_y >= *resting_y && y <= *resting_y)
+            .reduce(f32::max);
+
+        match platform_landing {
+            Some(resting_y) => Some(resting_y),
+            None if y <= self.arena.bottom => Some(self.arena.bottom),
+            None => None,
+        }
+    }
+}
@@ -1,14 +1,44 @@
 use crate::components::boss::*;
+use crate::components::gameplay::{GameplayEntity, Lifetime};
 use crate::components::player::{ChargeEffect, ChargeShot, *};
 use crate::stages::game_menu::PlayerUpgrades;
-use crate::stages::game_menu::{DefeatedBoss, GameState, SelectedCharacter};
+use crate::stages::game_menu::{DefeatedBoss, GameState, Lives, SelectedCharacter};
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::effects::{MaterialGradient, ScreenAnchored, ScreenShake};
 use crate::systems::config::{
-    BOUNDARY_BOTTOM, BOUNDARY_LEFT, BOUNDARY_RIGHT, BOUNDARY_TOP, CHARGE_SHOT_COOLDOWN,
-    CHARGE_SHOT_DAMAGE_MULTIPLIER, CHARGE_SHOT_MAX_TIME, CHARGE_SHOT_MIN_TIME,
-    INVINCIBILITY_DURATION, KNOCKBACK_DECAY_RATE, KNOCKBACK_DURATION, KNOCKBACK_FORCE,
-    KNOCKBACK_MOVEMENT_REDUCTION, NORMAL_SHOT_COOLDOWN, PLAYER_HP_BAR_MARGIN_LEFT,
-    PLAYER_HP_BAR_RADIUS, PLAYER_PROJECTILE_DAMAGE, SMALL_JUMP_CHARGE_RATIO,
+    AIM_ASSIST_CONE_ANGLE, AIM_ASSIST_STRENGTH, BOSS_ATTACK_STAGGER, BOSS_DEFAULT_HP,
+    BOSS_SPAWN_SPACING, BOSS_WEAPON_DAMAGE_MULTIPLIER, BOUNDARY_LEFT, BOUNDARY_TOP,
+    CHARGE_READY_FLASH_DURATION, CHARGE_READY_FLASH_SCALE,
+    CHARGE_SHOT_COOLDOWN, CHARGE_SHOT_DAMAGE_MULTIPLIER, CHARGE_SHOT_MAX_TIME, CHARGE_SHOT_MIN_TIME,
+    LADDER_CLIMB_SPEED, LOW_HP_WARNING_COLOR, LOW_HP_WARNING_FRACTION, NORMAL_SHOT_COOLDOWN, PLAYER_FOOTSTEP_INTERVAL, PLAYER_HP_BAR_MARGIN_LEFT, PLAYER_HP_BAR_RADIUS, POTION_HEAL_AMOUNT, REGEN_DELAY, WATER_GRAVITY_SCALE, WATER_MOVE_SCALE, WATER_PROJECTILE_SCALE, WEAPON_ENERGY_PER_SHOT,
 };
+use crate::systems::arena_doors::ArenaDoors;
+use crate::systems::blocks::BreakableBlock;
+use crate::systems::boundaries::ArenaBounds;
+use crate::systems::collision::{
+    ColliderKind, CollisionLookup, Enemy, PLAYER_HITBOX_SIZE, projectile_hitbox_size,
+};
+use crate::systems::feedback::FeedbackEvent;
+use crate::systems::game_config::GameConfig;
+use crate::systems::input::InputAction;
+use crate::systems::kill_cam::KillCam;
+use crate::plugins::camera_plugin::HUD_RENDER_LAYER;
+use crate::plugins::low_hp_warning_plugin::LowHpWarning;
+use crate::plugins::run_plugin::RunStats;
+use crate::systems::health::{EntityDied, HealthChanged, Overshield, take_damage};
+use crate::systems::hp_orb::HpOrbMaterial;
+use crate::systems::physics::kinematics::{decay_factor, knockback_direction};
+use crate::systems::platforms::{Ground, StagePlatforms};
+use crate::systems::projectile_styles::{
+    ProjectileShape, ProjectileStyles, play_projectile_sound,
+};
+use crate::systems::spawn::{
+    BOSS_SPAWN_POSITION, BossBundle, PLAYER_SPAWN_POSITION, PlayerAssets, ProjectileBundle,
+    spawn_player,
+};
+use crate::systems::stage_modifier::StageModifier;
+use crate::ui::orb_math::segment_height_for_fraction;
+use bevy::camera::visibility::RenderLayers;
 use bevy::prelude::*;
 
 /// Spawns the ingame 2D game scene when entering the InGame state
@@ -18,57 +48,23 @@ pub fn spawn_player_and_level(
     mut materials: ResMut<Assets<ColorMaterial>>,
     selected_character: Res<SelectedCharacter>,
     player_upgrades: Option<Res<PlayerUpgrades>>,
+    platforms: Res<StagePlatforms>,
 ) {
-    // Determine character color based on selection
-    let character_color = match *selected_character {
-        SelectedCharacter::Breadman => Color::srgb(0.2, 0.4, 0.9), // Blue
-        SelectedCharacter::Cheeseman => Color::srgb(0.9, 0.2, 0.2), // Red
-    };
-
-    // Calculate HP with upgrades
-    let base_max_hp = 100.0;
-    let max_hp_bonus = player_upgrades
-        .as_ref()
-        .map(|u| u.max_hp_bonus)
-        .unwrap_or(0.0);
-    let max_hp = base_max_hp + max_hp_bonus;
-
-    // Use preserved current HP if available, otherwise start with full HP
-    let current_hp = player_upgrades
-        .as_ref()
-        .map(|u| u.current_hp.min(max_hp)) // Ensure current HP doesn't exceed new max HP
-        .unwrap_or(max_hp);
-
-    // Spawn the player character as a rectangle
-    // Floor top is at y = -230 (floor center -250 + half-height 20)
-    // Character center should be at floor top + character half-height = -230 + 32 = -198
-    commands.spawn((
-        Mesh2d(meshes.add(Rectangle::new(32.0, 64.0))), // 32x64 rectangle
-        MeshMaterial2d(materials.add(character_color)),
-        Transform::from_xyz(0.0, -198.0, 1.0), // Positioned on top of the floor
-        Player,
-        Hp {
-            current: current_hp, // Start with preserved HP or full HP
-            max: max_hp,
-        },
-        PlayerVelocity {
-            y: 0.0,
-            jump_type: JumpType::None,
-            facing_direction: Vec2::new(1.0, 0.0),
-        },
-        JumpCharge {
-            timer: 0.0,
-            is_charging: false,
-        },
-        Shooting { timer: 0.0 },
-        ChargeShot {
-            timer: 0.0,
-            is_charging: false,
-        },
-    ));
+    let player_assets = PlayerAssets::new(&mut meshes, &mut materials, *selected_character);
+    // The stage's layout can move the player's spawn point
+    let position = platforms
+        .player_spawn
+        .map_or(PLAYER_SPAWN_POSITION, |spawn| spawn.extend(PLAYER_SPAWN_POSITION.z));
+    spawn_player(
+        &mut commands,
+        &player_assets,
+        player_upgrades.as_deref(),
+        position,
+    );
 
     // Spawn the floor/platform at the bottom
     commands.spawn((
+        GameplayEntity,
         Mesh2d(meshes.add(Rectangle::new(800.0, 40.0))), // Wide floor
         MeshMaterial2d(materials.add(Color::srgb(0.3, 0.3, 0.3))), // Gray floor
         Transform::from_xyz(0.0, -250.0, 0.0),           // Position at bottom
@@ -84,8 +80,16 @@ pub fn spawn_boss(
     boss_registry: Option<Res<BossRegistry>>,
     pattern_registry: Option<Res<crate::systems::boss::BossPatternRegistry>>,
     current_stage: Option<Res<crate::stages::game_menu::CurrentStage>>,
+    mut checkpoint: ResMut<crate::stages::game_menu::FightCheckpoint>,
+    run_stats: Option<Res<crate::plugins::run_plugin::RunStats>>,
+    randomized_boss: Option<Res<crate::stages::randomizer::RandomizedBoss>>,
+    platforms: Res<StagePlatforms>,
 ) {
-    use crate::systems::boss::{convert_attack_pattern, convert_movement_pattern};
+    use crate::stages::endless::{wave_hp_scale, wave_speed_scale};
+    use crate::systems::boss::{
+        MovementPatternConfig, convert_attack_pattern, convert_movement_pattern,
+    };
+    use crate::systems::boss_ai::BossBrain;
 
     // Get boss data from registry or use default
     let mut boss_data = boss_registry
@@ -95,33 +99,95 @@ pub fn spawn_boss(
         .unwrap_or_else(|| BossData::default());
 
     // Try to load pattern from JSON based on stage number
+    let mut behavior = None;
+    let mut max_hp = BOSS_DEFAULT_HP;
+    let mut count = 1;
+    let mut grounded = false;
     if let (Some(registry), Some(stage)) = (pattern_registry.as_ref(), current_stage.as_ref()) {
         let stage_num = stage.0;
         let pattern_name = format!("stage_{}", stage_num);
+        // Randomizer runs fight a shuffled boss instead of the stage's own
+        let randomized = randomized_boss.as_ref().and_then(|boss| boss.0.as_ref());
 
-        if let Some(pattern_config) = registry.get_pattern(&pattern_name) {
+        if let Some(pattern_config) = randomized.or_else(|| registry.get_pattern(&pattern_name)) {
             // Convert JSON patterns to internal patterns
             boss_data.attack_pattern = convert_attack_pattern(&pattern_config.attack);
             boss_data.movement_pattern = convert_movement_pattern(&pattern_config.movement);
+            behavior = pattern_config.behavior.clone();
+            max_hp = pattern_config.max_hp.unwrap_or(BOSS_DEFAULT_HP);
+            if let Some(name) = &pattern_config.name {
+                boss_data.name = name.clone();
+            }
+            count = pattern_config.count.max(1);
+            grounded = pattern_config.grounded
+                || matches!(
+                    pattern_config.movement,
+                    MovementPatternConfig::JumpTowardPlayer { .. }
+                        | MovementPatternConfig::GroundSlam { .. }
+                );
         }
     }
 
-    // Spawn the boss character on the right side
-    // Position at x = 300 (right side), same y as player (-198)
-    let _boss_entity = commands.spawn((
-        Mesh2d(meshes.add(Rectangle::new(boss_data.size.x, boss_data.size.y))),
-        MeshMaterial2d(materials.add(boss_data.color)),
-        Transform::from_xyz(300.0, -198.0, 1.0), // Positioned on the right side, on top of the floor
-        Boss,
-        boss_data.boss_type,
-        boss_data.clone(),
-        Hp {
-            current: 200.0,
-            max: 200.0,
-        },
-        BossAttackState::default(),
-        BossMovementState::default(),
-    ));
+    // Endless mode bosses get tougher and faster with every wave
+    let endless_wave = run_stats.as_ref().and_then(|stats| stats.endless_wave);
+    if let Some(wave) = endless_wave {
+        max_hp *= wave_hp_scale(wave);
+    }
+
+    let mesh = meshes.add(Rectangle::new(boss_data.size.x, boss_data.size.y));
+    let material = materials.add(boss_data.color);
+    let resume = std::mem::take(&mut checkpoint.resume);
+    // The stage's layout can move the first boss's spawn point
+    let origin = platforms.boss_spawn.unwrap_or(BOSS_SPAWN_POSITION);
+
+    for index in 0..count {
+        // Retrying from a checkpoint restores each boss's own HP and phase, and bosses already
+        // beaten there stay beaten
+        let restored = checkpoint.bosses.get(index as usize).copied().filter(|_| resume);
+        if restored.is_some_and(|boss| boss.hp <= 0.0) {
+            continue;
+        }
+
+        // Spawn the boss characters lined up leftwards from the spawn point
+        let position = origin - Vec2::new(index as f32 * BOSS_SPAWN_SPACING, 0.0);
+        let mut boss_entity = commands.spawn(
+            BossBundle::from_data(
+                &boss_data,
+                mesh.clone(),
+                material.clone(),
+                position,
+                max_hp,
+            )
+            .with_attack_delay(index as f32 * BOSS_ATTACK_STAGGER)
+            .with_spawn_index(index as usize),
+        );
+
+        if let Some(restored) = restored {
+            boss_entity.insert((
+                Hp {
+                    current: restored.hp,
+                    max: max_hp,
+                },
+                BossPhaseState {
+                    phase: restored.phase,
+                    desperate: false, // Worked out again from the restored HP
+                },
+            ));
+        }
+
+        if let Some(wave) = endless_wave {
+            boss_entity.insert(BossSpeedMultiplier(wave_speed_scale(wave)));
+        }
+
+        if grounded {
+            boss_entity.insert(GroundedBoss);
+        }
+
+        // Bosses with a behavior tree switch patterns at runtime instead of running one fixed pattern
+        if let Some(tree) = &behavior {
+            boss_entity.insert(BossBrain::new(tree.clone()));
+        }
+    }
 
     // TODO: Add sprite rendering when sprite is available
     // In Bevy 0.17, you would use Sprite2d or Image2d depending on your setup
@@ -142,92 +208,204 @@ pub fn player_movement(
             &mut Transform,
             &mut PlayerVelocity,
             &mut JumpCharge,
+            &mut DashCooldown,
             Option<&mut Dash>,
-            Option<&Knockback>,
+            Option<&mut Knockback>,
+            Option<&Climbing>,
+            &CharacterKit,
+            &ChargeShot,
         ),
         With<Player>,
     >,
+    config: Res<GameConfig>,
+    stage_modifier: Res<StageModifier>,
+    arena: Res<ArenaBounds>,
+    ground: Ground,
+    mut feedback: MessageWriter<FeedbackEvent>,
 ) {
-    const SPEED: f32 = 200.0; // Pixels per second
-    const DASH_SPEED: f32 = 400.0; // Pixels per second
-    const DASH_DURATION: f32 = 0.2; // Seconds
-    const BASE_JUMP_STRENGTH: f32 = 400.0; // Base jump velocity in pixels per second
-    const BASE_GRAVITY: f32 = 800.0; // Base gravity acceleration in pixels per second squared
-    const GROUND_Y: f32 = -198.0; // Ground level (character center when on floor)
-
-    // High jump: 10% higher (1.1x), 10% faster gravity (1.1x)
-    const HIGH_JUMP_STRENGTH: f32 = 620.0; // 440.0
-    const HIGH_JUMP_GRAVITY: f32 = 1200.0; // 880.0
-
-    // Small jump: 40% of base jump (0.4x), 20% faster gravity (1.2x)
-    const SMALL_JUMP_STRENGTH: f32 = 350.5; // 160.0
-    const SMALL_JUMP_GRAVITY: f32 = BASE_GRAVITY * 1.2; // 960.0
-
-    const MAX_CHARGE_TIME: f32 = 0.2; // Maximum charge time for high jump (0.2 seconds)
-
-    for (entity, mut transform, mut velocity, mut jump_charge, dash, knockback) in &mut player_query
+    let half_size = PLAYER_HITBOX_SIZE / 2.0;
+
+    for (
+        entity,
+        mut transform,
+        mut velocity,
+        mut jump_charge,
+        mut dash_cooldown,
+        dash,
+        mut knockback,
+        climbing,
+        kit,
+        charge_shot,
+    ) in &mut player_query
     {
+        let previous_x = transform.translation.x;
+
         // Movement
         let mut direction = Vec2::ZERO;
 
-        if keyboard_input.pressed(KeyCode::ArrowLeft) {
+        if InputAction::Left.pressed(&keyboard_input) {
             direction.x -= 1.0;
         }
-        if keyboard_input.pressed(KeyCode::ArrowRight) {
+        if InputAction::Right.pressed(&keyboard_input) {
             direction.x += 1.0;
         }
-        if keyboard_input.pressed(KeyCode::ArrowUp) {
+        if InputAction::Up.pressed(&keyboard_input) {
             direction.y += 1.0;
         }
-        // We don't handle ArrowDown for movement, only for dash
-        // if keyboard_input.pressed(KeyCode::ArrowDown) {
-        //     direction.y -= 1.0;
-        // }
-
-        if direction != Vec2::ZERO {
-            velocity.facing_direction = direction.normalize();
+        // We don't handle Down for walking, only for dashing and climbing down ladders
+        if stage_modifier.mirrored_controls() {
+            direction.x = -direction.x;
         }
 
+        // Check if jump button is pressed (Space, or X)
+        let jump_button_pressed = InputAction::Jump.pressed(&keyboard_input);
+        let jump_button_just_pressed = InputAction::Jump.just_pressed(&keyboard_input);
+        let jump_button_just_released = InputAction::Jump.just_released(&keyboard_input);
+
+        let dashing = dash.is_some();
+
+        // Dash: replaces walking until it runs out, or until jump cancels it into a jump below.
+        // The player keeps facing the dash direction (aiming up still works), so shots fired
+        // during a dash go forward. The cooldown starts once the dash is over.
         if let Some(mut dash) = dash {
-            transform.translation.x += dash.direction * DASH_SPEED * time.delta_secs();
-            dash.timer -= time.delta_secs();
-            if dash.timer <= 0.0 {
+            if jump_button_just_pressed {
                 commands.entity(entity).remove::<Dash>();
+                dash_cooldown.timer = config.dash_cooldown;
+            } else {
+                transform.translation.x +=
+                    dash.direction * config.dash_speed * kit.move_speed_scale * time.delta_secs();
+                dash.timer -= time.delta_secs();
+                if dash.timer <= 0.0 {
+                    commands.entity(entity).remove::<Dash>();
+                    dash_cooldown.timer = config.dash_cooldown;
+                }
+                direction.x = 0.0;
+                velocity.facing_direction = Vec2::new(dash.direction, 0.0);
+            }
+        } else {
+            dash_cooldown.timer -= time.delta_secs();
+        }
+
+        // Ladders: Up or Down grabs one in reach (not mid-dash or knockback). Climbing replaces
+        // walking and gravity; Left/Right only turn the player so they can shoot either way, and
+        // jumping lets go. Climbing past either end steps off onto the top or the ground below.
+        let climb = match (
+            InputAction::Up.pressed(&keyboard_input),
+            InputAction::Down.pressed(&keyboard_input),
+        ) {
+            (true, false) => 1.0,
+            (false, true) => -1.0,
+            _ => 0.0,
+        };
+        let mut ladder = climbing.map(|climbing| climbing.ladder);
+        if ladder.is_none() && climb != 0.0 && !dashing && knockback.is_none() {
+            ladder = ground.ladder_at(
+                transform.translation.x,
+                transform.translation.y,
+                half_size,
+                climb < 0.0,
+            );
+            if let Some(ladder) = ladder {
+                commands.entity(entity).insert(Climbing { ladder });
+                velocity.y = 0.0;
+                velocity.jump_type = JumpType::None;
+                jump_charge.is_charging = false;
+            }
+        }
+        if let Some(ladder) = ladder {
+            if jump_button_just_pressed || knockback.is_some() {
+                commands.entity(entity).remove::<Climbing>();
+            } else {
+                if direction.x != 0.0 {
+                    velocity.facing_direction = Vec2::new(direction.x, 0.0);
+                }
+                transform.translation.x = ladder.center().x;
+                transform.translation.y += climb * LADDER_CLIMB_SPEED * time.delta_secs();
+
+                let feet = transform.translation.y - half_size.y;
+                if feet >= ladder.max.y {
+                    transform.translation.y = ladder.max.y + half_size.y; // Standing on top
+                    commands.entity(entity).remove::<Climbing>();
+                } else if feet <= ladder.min.y || transform.translation.y <= arena.bottom {
+                    transform.translation.y = (ladder.min.y + half_size.y).max(arena.bottom);
+                    commands.entity(entity).remove::<Climbing>();
+                }
+                continue;
             }
-            return; // No other movement during dash
         }
 
-        // Apply movement, but reduce it if knockback is active
+        if direction != Vec2::ZERO {
+            velocity.facing_direction = direction.normalize();
+        }
+
+        // Apply movement, but reduce it if knockback is active, the player is in water or (if the
+        // config says so) charging a shot
         let movement_speed = if knockback.is_some() {
-            SPEED * KNOCKBACK_MOVEMENT_REDUCTION // Reduce movement speed during knockback
+            config.move_speed * config.knockback_movement_reduction // Reduce movement speed during knockback
+        } else {
+            config.move_speed
+        } * kit.move_speed_scale;
+        let movement_speed = if charge_shot.is_charging {
+            movement_speed * config.charge_move_speed_scale
         } else {
-            SPEED
+            movement_speed
         };
-        transform.translation.x += direction.x * movement_speed * time.delta_secs();
-        // Keep player within boundaries
-        transform.translation.x = transform.translation.x.clamp(BOUNDARY_LEFT, BOUNDARY_RIGHT);
-        transform.translation.y = transform.translation.y.clamp(BOUNDARY_BOTTOM, BOUNDARY_TOP);
-
-        // Check if jump button is pressed (Space, or X)
-        let jump_button_pressed =
-            keyboard_input.pressed(KeyCode::Space) || keyboard_input.pressed(KeyCode::KeyX);
-        let jump_button_just_pressed = keyboard_input.just_pressed(KeyCode::Space)
-            || keyboard_input.just_pressed(KeyCode::KeyX);
-        let jump_button_just_released = keyboard_input.just_released(KeyCode::Space)
-            || keyboard_input.just_released(KeyCode::KeyX);
+        let water_scale = ground.water_scale(transform.translation.truncate(), WATER_MOVE_SCALE);
+        transform.translation.x += direction.x * movement_speed * water_scale * time.delta_secs();
+
+        // Knockback: the horizontal push decays over its duration, while the vertical part
+        // launches the player once and then arcs under the same gravity as a jump. Heavier
+        // characters are pushed less.
+        if let Some(knockback) = knockback.as_mut() {
+            if knockback.velocity.y != 0.0 {
+                velocity.y =
+                    knockback.velocity.y * config.knockback_launch_scale * kit.knockback_scale;
+                velocity.jump_type = JumpType::None;
+                knockback.velocity.y = 0.0;
+            }
+            transform.translation.x +=
+                knockback.velocity.x * kit.knockback_scale * time.delta_secs();
+            knockback.velocity.x *= decay_factor(config.knockback_decay_rate, time.delta_secs());
+            knockback.timer -= time.delta_secs();
+            if knockback.timer <= 0.0 {
+                commands.entity(entity).remove::<Knockback>();
+            }
+        }
 
-        let is_on_ground = transform.translation.y <= GROUND_Y;
+        // Wind zones and conveyor floors carry the player along
+        let grounded =
+            ground.is_supported(transform.translation.x, transform.translation.y, half_size);
+        transform.translation.x +=
+            ground.push_speed(transform.translation.truncate(), half_size, grounded)
+                * time.delta_secs();
+
+        // Keep player within boundaries, and out of breakable blocks
+        transform.translation.x = ground.blocked_x(
+            previous_x,
+            arena.clamp_x(transform.translation.x),
+            transform.translation.y,
+            half_size,
+        );
+        transform.translation.y = arena.clamp_y(transform.translation.y);
+
+        let is_on_ground =
+            ground.is_supported(transform.translation.x, transform.translation.y, half_size);
 
         // Dash
-        if keyboard_input.pressed(KeyCode::ArrowDown) && jump_button_just_pressed && is_on_ground {
+        if InputAction::Down.pressed(&keyboard_input)
+            && jump_button_just_pressed
+            && is_on_ground
+            && dash_cooldown.timer <= 0.0
+        {
             commands.entity(entity).insert(Dash {
-                timer: DASH_DURATION,
+                timer: config.dash_duration,
                 direction: velocity.facing_direction.x,
             });
-            return; // No other movement during dash
+            feedback.write(FeedbackEvent::Dashed);
+            continue; // The dash starts moving next frame
         }
 
-        // Start charging jump when button is pressed on ground
+        // Start charging jump when button is pressed on ground (this also cancels a dash)
         if jump_button_just_pressed && is_on_ground {
             jump_charge.is_charging = true;
             jump_charge.timer = 0.0;
@@ -241,19 +419,11 @@ pub fn player_movement(
         // Execute jump when button is released
         if jump_button_just_released && jump_charge.is_charging {
             if is_on_ground {
-                // Calculate jump strength based on charge time
-                let charge_ratio = (jump_charge.timer / MAX_CHARGE_TIME).clamp(0.0, 1.0);
-
-                // Interpolate between small and high jump based on charge time
-                if charge_ratio < SMALL_JUMP_CHARGE_RATIO {
-                    // Short press = small jump
-                    velocity.y = SMALL_JUMP_STRENGTH;
-                    velocity.jump_type = JumpType::Small;
-                } else {
-                    // Long press = high jump
-                    velocity.y = HIGH_JUMP_STRENGTH;
-                    velocity.jump_type = JumpType::High;
-                }
+                // Short press = small jump, long press = high jump
+                let jump_type = config.jump_for_charge(jump_charge.timer);
+                velocity.y = config.jump_strength(jump_type);
+                velocity.jump_type = jump_type;
+                feedback.write(FeedbackEvent::Jumped);
             }
 
             // Reset charge
@@ -261,12 +431,10 @@ pub fn player_movement(
             jump_charge.timer = 0.0;
         }
 
-        // Determine gravity based on current jump type
-        let current_gravity = match velocity.jump_type {
-            JumpType::High => HIGH_JUMP_GRAVITY,
-            JumpType::Small => SMALL_JUMP_GRAVITY,
-            JumpType::None => BASE_GRAVITY,
-        };
+        // Determine gravity based on current jump type (lighter in water, so jumps float higher)
+        let current_gravity = config.jump_gravity(velocity.jump_type)
+            * stage_modifier.gravity_scale()
+            * ground.water_scale(transform.translation.truncate(), WATER_GRAVITY_SCALE);
 
         // Apply gravity only when in the air
         if !is_on_ground {
@@ -274,17 +442,126 @@ pub fn player_movement(
         }
 
         // Apply vertical velocity
+        let previous_y = transform.translation.y;
         transform.translation.y += velocity.y * time.delta_secs();
 
-        // Ground collision - stop falling when hitting the ground
-        if transform.translation.y < GROUND_Y {
-            transform.translation.y = GROUND_Y;
+        // Jumping into the underside of a block ends the rise
+        if velocity.y > 0.0
+            && let Some(ceiling_y) = ground.ceiling_y(
+                transform.translation.x,
+                previous_y,
+                transform.translation.y,
+                half_size,
+            )
+        {
+            transform.translation.y = ceiling_y;
+            velocity.y = 0.0;
+        }
+
+        // Ground collision - stop falling when hitting the floor or a platform
+        if velocity.y <= 0.0
+            && let Some(landing_y) = ground.landing_y(
+                transform.translation.x,
+                previous_y,
+                transform.translation.y,
+                half_size,
+            )
+        {
+            transform.translation.y = landing_y;
             velocity.y = 0.0;
             velocity.jump_type = JumpType::None; // Reset jump type when landing
+
+            if !is_on_ground {
+                feedback.write(FeedbackEvent::Landed);
+                // Landing from a knockback arc ends the knockback
+                if knockback.is_some() {
+                    commands.entity(entity).remove::<Knockback>();
+                }
+            }
+        }
+    }
+}
+
+/// Side length of the shared square projectile mesh (each shot scales it to its own size)
+pub const PROJECTILE_MESH_SIZE: f32 = 10.0;
+/// Radius of the charge effect circle mesh
+const CHARGE_EFFECT_RADIUS: f32 = 40.0;
+
+/// Charge effect color for a charge level: yellow -> orange -> red, more opaque when charged
+fn charge_effect_color(charge_level: f32) -> Color {
+    let r = 1.0;
+    let g = 1.0 - (charge_level * 0.5); // 1.0 to 0.5
+    let b = charge_level * 0.3; // 0.0 to 0.3
+    let alpha = 0.3 + (charge_level * 0.4); // 0.3 to 0.7
+    Color::srgba(r, g, b, alpha)
+}
+
+/// Shared handles for the charge effect, so starting a charge doesn't create new assets.
+/// The color animates by swapping between the pre-built gradient materials.
+#[derive(Resource)]
+pub struct ChargeEffectAssets {
+    pub mesh: Handle<Mesh>,
+    pub materials: MaterialGradient,
+    pub ready_material: Handle<ColorMaterial>, // Steady glow once the charge is full
+    pub flash_material: Handle<ColorMaterial>, // Flash played when the charge becomes full
+}
+
+/// Shared projectile meshes, one per `ProjectileShape` (boss projectiles use the square)
+#[derive(Resource)]
+pub struct ProjectileAssets {
+    pub square: Handle<Mesh>,
+    pub circle: Handle<Mesh>,
+    pub diamond: Handle<Mesh>,
+}
+
+impl ProjectileAssets {
+    /// Mesh of a projectile of the given shape
+    pub fn mesh(&self, shape: ProjectileShape) -> Handle<Mesh> {
+        match shape {
+            ProjectileShape::Square => self.square.clone(),
+            ProjectileShape::Circle => self.circle.clone(),
+            ProjectileShape::Diamond => self.diamond.clone(),
         }
     }
 }
 
+/// Aim assist: turns `direction` part of the way toward the closest (in angle) target
+/// within the assist cone around it, or leaves it unchanged if none is
+fn aim_assist_direction(
+    direction: Vec2,
+    origin: Vec2,
+    targets: impl IntoIterator<Item = Vec2>,
+) -> Vec2 {
+    let closest_angle = targets
+        .into_iter()
+        .map(|target| direction.angle_to(target - origin))
+        .filter(|angle| angle.abs() <= AIM_ASSIST_CONE_ANGLE)
+        .min_by(|a, b| a.abs().total_cmp(&b.abs()));
+    match closest_angle {
+        Some(angle) => Vec2::from_angle(angle * AIM_ASSIST_STRENGTH).rotate(direction),
+        None => direction,
+    }
+}
+
+/// Creates the shared charge effect and projectile assets once at startup
+pub fn setup_shared_combat_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.insert_resource(ChargeEffectAssets {
+        mesh: meshes.add(Circle::new(CHARGE_EFFECT_RADIUS)),
+        materials: MaterialGradient::new(&mut materials, charge_effect_color),
+        ready_material: materials.add(Color::srgba(1.0, 0.95, 0.6, 0.8)),
+        flash_material: materials.add(Color::srgba(1.0, 1.0, 1.0, 0.9)),
+    });
+    commands.insert_resource(ProjectileAssets {
+        square: meshes.add(Rectangle::new(PROJECTILE_MESH_SIZE, PROJECTILE_MESH_SIZE)),
+        circle: meshes.add(Circle::new(PROJECTILE_MESH_SIZE / 2.0)),
+        diamond: meshes.add(Rhombus::new(PROJECTILE_MESH_SIZE, PROJECTILE_MESH_SIZE)),
+    });
+}
+
 pub fn player_shooting(
     mut commands: Commands,
     keyboard_input: Res<ButtonInput<KeyCode>>,
@@ -293,18 +570,24 @@ pub fn player_shooting(
         (&Transform, &PlayerVelocity, &mut Shooting, &mut ChargeShot),
         With<Player>,
     >,
-    mut meshes: ResMut<Assets<Mesh>>,
+    projectile_assets: Res<ProjectileAssets>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     selected_character: Res<SelectedCharacter>,
+    settings: Res<GameSettings>,
+    mut player_upgrades: ResMut<PlayerUpgrades>,
+    boss_registry: Res<BossRegistry>,
+    projectile_styles: Res<ProjectileStyles>,
+    boss_query: Query<&Transform, (With<Boss>, Without<Defeated>)>,
+    mut feedback: MessageWriter<FeedbackEvent>,
 ) {
     let is_breadman = matches!(*selected_character, SelectedCharacter::Breadman);
 
     for (player_transform, player_velocity, mut shooting, mut charge_shot) in &mut player_query {
         shooting.timer -= time.delta_secs();
 
-        let shoot_button_pressed = keyboard_input.pressed(KeyCode::KeyC);
-        let shoot_button_just_pressed = keyboard_input.just_pressed(KeyCode::KeyC);
-        let shoot_button_just_released = keyboard_input.just_released(KeyCode::KeyC);
+        let shoot_button_pressed = InputAction::Shoot.pressed(&keyboard_input);
+        let shoot_button_just_pressed = InputAction::Shoot.just_pressed(&keyboard_input);
+        let shoot_button_just_released = InputAction::Shoot.just_released(&keyboard_input);
 
         // Helper function to determine shooting direction
         let get_shoot_direction = || -> Option<Vec2> {
@@ -328,38 +611,65 @@ pub fn player_shooting(
             Some(shoot_direction)
         };
 
-        // Helper function to spawn a projectile
+        // Helper function to spawn a projectile (uses up boss weapon energy if one is equipped),
+        // returning the feedback for the shot
         let mut spawn_projectile = |direction: Vec2, charge_level: f32, is_charged: bool| {
-            let projectile_transform = Transform::from_xyz(
-                player_transform.translation.x,
-                player_transform.translation.y,
-                0.0,
-            );
+            let direction = if settings.aim_assist {
+                aim_assist_direction(
+                    direction,
+                    player_transform.translation.truncate(),
+                    boss_query.iter().map(|transform| transform.translation.truncate()),
+                )
+            } else {
+                direction
+            };
+
+            // Boss weapons fire while they have energy left, otherwise fall back to the default shot
+            let weapon = player_upgrades
+                .equipped_boss_weapon_mut()
+                .filter(|weapon| weapon.energy >= WEAPON_ENERGY_PER_SHOT)
+                .map(|weapon| {
+                    weapon.energy -= WEAPON_ENERGY_PER_SHOT;
+                    weapon.boss_type
+                });
 
-            // Determine projectile size and color based on charge level
-            let (size, color) = if is_charged {
-                // Charged shot: larger and brighter (yellow/orange)
+            // Determine projectile size based on charge level (color comes from the active palette)
+            let size = if is_charged {
+                // Charged shot: larger and brighter
                 let size_multiplier = 1.0 + (charge_level * 1.5); // 1.0x to 2.5x size
-                let size = 10.0 * size_multiplier;
-                // Color transitions from yellow (low charge) to bright orange/red (full charge)
-                let r = 1.0;
-                let g = 1.0 - (charge_level * 0.3); // 1.0 to 0.7
-                let b = charge_level * 0.2; // 0.0 to 0.2
-                (size, Color::srgb(r, g, b))
+                10.0 * size_multiplier
             } else {
-                // Normal shot: small red
-                (10.0, Color::srgb(1.0, 0.0, 0.0))
+                10.0
             };
 
-            commands.spawn((
-                Mesh2d(meshes.add(Rectangle::new(size, size))),
-                MeshMaterial2d(materials.add(color)),
-                projectile_transform,
-                Projectile {
-                    direction,
-                    charge_level,
-                },
+            let color = match weapon.and_then(|boss_type| boss_registry.get_boss_data(boss_type)) {
+                Some(boss_data) => boss_data.color,
+                None => settings
+                    .palette
+                    .player_projectile_color(charge_level, is_charged),
+            };
+            let style = projectile_styles.get(weapon);
+
+            play_projectile_sound(
+                &mut commands,
+                style,
+                player_transform.translation.truncate(),
+            );
+            commands.spawn(ProjectileBundle::player(
+                &projectile_assets,
+                style,
+                materials.add(style.color_or(color)),
+                player_transform.translation.truncate(),
+                size,
+                direction,
+                charge_level,
+                weapon,
             ));
+            if is_charged {
+                FeedbackEvent::ChargedShotFired
+            } else {
+                FeedbackEvent::ShotFired
+            }
         };
 
         if is_breadman {
@@ -372,8 +682,12 @@ pub fn player_shooting(
 
             // Charge while button is held
             if charge_shot.is_charging && shoot_button_pressed {
+                let was_full = charge_shot.timer >= CHARGE_SHOT_MAX_TIME;
                 charge_shot.timer += time.delta_secs();
                 charge_shot.timer = charge_shot.timer.min(CHARGE_SHOT_MAX_TIME);
+                if !was_full && charge_shot.timer >= CHARGE_SHOT_MAX_TIME {
+                    feedback.write(FeedbackEvent::ChargeReady);
+                }
             }
 
             // Fire when button is released
@@ -382,7 +696,11 @@ pub fn player_shooting(
                     let charge_level = (charge_shot.timer / CHARGE_SHOT_MAX_TIME).clamp(0.0, 1.0);
                     let is_charged_shot = charge_shot.timer >= CHARGE_SHOT_MIN_TIME;
 
-                    spawn_projectile(shoot_direction, charge_level, is_charged_shot);
+                    feedback.write(spawn_projectile(
+                        shoot_direction,
+                        charge_level,
+                        is_charged_shot,
+                    ));
 
                     // Set cooldown based on shot type
                     shooting.timer = if is_charged_shot {
@@ -401,7 +719,7 @@ pub fn player_shooting(
             // Fire immediately when button is pressed
             if shoot_button_just_pressed && shooting.timer <= 0.0 {
                 if let Some(shoot_direction) = get_shoot_direction() {
-                    spawn_projectile(shoot_direction, 0.0, false);
+                    feedback.write(spawn_projectile(shoot_direction, 0.0, false));
                     shooting.timer = NORMAL_SHOT_COOLDOWN;
                 }
             }
@@ -416,8 +734,7 @@ pub fn player_shooting(
 /// System to manage charge effect visual (spawn/despawn based on charging state)
 pub fn manage_charge_effect(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
+    charge_effect_assets: Res<ChargeEffectAssets>,
     player_query: Query<(Entity, &Transform, &ChargeShot), With<Player>>,
     charge_effect_query: Query<(Entity, &ChargeEffect)>,
     selected_character: Res<SelectedCharacter>,
@@ -443,11 +760,13 @@ pub fn manage_charge_effect(
             if !has_effect {
                 // Spawn a pulsing circle around the player
                 commands.spawn((
-                    Mesh2d(meshes.add(Circle::new(40.0))),
-                    MeshMaterial2d(materials.add(Color::srgba(1.0, 1.0, 0.0, 0.3))), // Yellow, semi-transparent
+                    Mesh2d(charge_effect_assets.mesh.clone()),
+                    MeshMaterial2d(charge_effect_assets.materials.get(0.0).clone()),
                     Transform::from_translation(player_transform.translation),
                     ChargeEffect {
                         player_entity,
+                        ready: false,
+                        flash_timer: 0.0,
                     },
                 ));
             }
@@ -467,42 +786,63 @@ pub fn manage_charge_effect(
     }
 }
 
-/// System to animate charge effect (pulsing, color changes based on charge level)
+/// System to animate charge effect (pulsing, color changes based on charge level).
+/// Once the charge is full it flashes briefly, then holds a steady "ready" glow until release.
 pub fn animate_charge_effect(
     time: Res<Time>,
     player_query: Query<(&Transform, &ChargeShot), With<Player>>,
-    mut charge_effect_query: Query<(&ChargeEffect, &mut Transform, &mut MeshMaterial2d<ColorMaterial>), Without<Player>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut charge_effect_query: Query<(&mut ChargeEffect, &mut Transform, &mut MeshMaterial2d<ColorMaterial>), Without<Player>>,
+    charge_effect_assets: Res<ChargeEffectAssets>,
+    settings: Res<GameSettings>,
 ) {
-    for (charge_effect, mut effect_transform, mesh_material) in &mut charge_effect_query {
-        if let Ok((player_transform, charge_shot)) = player_query.get(charge_effect.player_entity) {
-            if charge_shot.is_charging {
-                // Update position to follow player
-                effect_transform.translation = player_transform.translation;
-
-                // Calculate charge level (0.0 to 1.0)
-                let charge_level = (charge_shot.timer / CHARGE_SHOT_MAX_TIME).clamp(0.0, 1.0);
-
-                // Pulsing animation: base size + charge-based size + sine wave pulse
-                let base_size = 40.0;
-                let charge_size = charge_level * 20.0; // Grows up to 20px more when fully charged
-                let pulse = (time.elapsed_secs() * 8.0).sin() * 5.0; // Fast pulsing (8 Hz, ±5px)
-                let current_size = base_size + charge_size + pulse;
-
-                // Update mesh size (we'll need to recreate the mesh, but for now update scale)
-                effect_transform.scale = Vec3::splat(current_size / base_size);
-
-                // Color transitions: yellow -> orange -> red as charge increases
-                let r = 1.0;
-                let g = 1.0 - (charge_level * 0.5); // 1.0 to 0.5
-                let b = charge_level * 0.3; // 0.0 to 0.3
-                let alpha = 0.3 + (charge_level * 0.4); // 0.3 to 0.7 (more opaque when charged)
-
-                // Update material color
-                if let Some(material) = materials.get_mut(&mesh_material.0) {
-                    material.color = Color::srgba(r, g, b, alpha);
+    for (mut charge_effect, mut effect_transform, mut mesh_material) in &mut charge_effect_query {
+        if let Ok((player_transform, charge_shot)) = player_query.get(charge_effect.player_entity)
+            && charge_shot.is_charging
+        {
+            // Update position to follow player
+            effect_transform.translation = player_transform.translation;
+
+            // Calculate charge level (0.0 to 1.0)
+            let charge_level = (charge_shot.timer / CHARGE_SHOT_MAX_TIME).clamp(0.0, 1.0);
+
+            if charge_level >= 1.0 {
+                if !charge_effect.ready {
+                    charge_effect.ready = true;
+                    charge_effect.flash_timer = CHARGE_READY_FLASH_DURATION;
                 }
+                charge_effect.flash_timer = (charge_effect.flash_timer - time.delta_secs()).max(0.0);
+
+                // Ready: no pulse, so the full charge reads as a stable glow
+                let ready_scale = (CHARGE_EFFECT_RADIUS + 20.0) / CHARGE_EFFECT_RADIUS;
+                let (scale, material) = if charge_effect.flash_timer > 0.0 && !settings.reduced_flashing {
+                    (ready_scale * CHARGE_READY_FLASH_SCALE, &charge_effect_assets.flash_material)
+                } else {
+                    (ready_scale, &charge_effect_assets.ready_material)
+                };
+                effect_transform.scale = Vec3::splat(scale);
+                if mesh_material.0 != *material {
+                    mesh_material.0 = material.clone();
+                }
+                continue;
             }
+
+            // Pulsing animation: base size + charge-based size + sine wave pulse
+            let base_size = CHARGE_EFFECT_RADIUS;
+            let charge_size = charge_level * 20.0; // Grows up to 20px more when fully charged
+            let pulse = if settings.reduced_flashing {
+                0.0 // Steady glow that only grows with charge
+            } else {
+                (time.elapsed_secs() * 8.0).sin() * 5.0 // Fast pulsing (8 Hz, ±5px)
+            };
+            let current_size = base_size + charge_size + pulse;
+
+            // Scale the shared circle mesh to the current size
+            effect_transform.scale = Vec3::splat(current_size / base_size);
+
+            // Color transitions: yellow -> orange -> red as charge increases
+            charge_effect_assets
+                .materials
+                .apply(charge_level, &mut mesh_material);
         }
     }
 }
@@ -510,154 +850,301 @@ pub fn animate_charge_effect(
 pub fn projectile_movement(
     mut commands: Commands,
     time: Res<Time>,
-    mut projectile_query: Query<(Entity, &mut Transform, &Projectile)>,
+    // Boss projectiles fly on their own velocity in `boss_projectile_movement`
+    mut projectile_query: Query<
+        (Entity, &mut Transform, &Projectile),
+        Without<crate::systems::boss::BossProjectile>,
+    >,
+    arena: Res<ArenaBounds>,
+    ground: Ground,
 ) {
     const PROJECTILE_SPEED: f32 = 500.0; // Pixels per second
 
     for (entity, mut transform, projectile) in &mut projectile_query {
-        transform.translation.x += projectile.direction.x * PROJECTILE_SPEED * time.delta_secs();
-        transform.translation.y += projectile.direction.y * PROJECTILE_SPEED * time.delta_secs();
+        // Shots slow down while passing through water
+        let speed = PROJECTILE_SPEED
+            * ground.water_scale(transform.translation.truncate(), WATER_PROJECTILE_SCALE);
+        transform.translation.x += projectile.direction.x * speed * time.delta_secs();
+        transform.translation.y += projectile.direction.y * speed * time.delta_secs();
 
         // Despawn projectile after it goes outside boundaries
-        if transform.translation.x < BOUNDARY_LEFT
-            || transform.translation.x > BOUNDARY_RIGHT
-            || transform.translation.y < BOUNDARY_BOTTOM
-            || transform.translation.y > BOUNDARY_TOP
+        if arena.outside_x(transform.translation.x)
+            || transform.translation.y < arena.bottom
+            || transform.translation.y > arena.top
         {
-            commands.entity(entity).despawn();
+            commands.entity(entity).try_despawn();
         }
     }
 }
 
-/// Spawns the player's HP bar as a circular bar at the top-left (Diablo 2 style - drains from top).
-pub fn setup_player_hp_bar(
+/// System to give every newly spawned player an HP orb at the top-left (Diablo 2 style - drains from top).
+/// The orb is a single quad; `shaders/hp_orb.wgsl` draws the rim and clips the liquid to the circle.
+/// Additional players get their orbs lined up to the right of the first one.
+pub fn spawn_player_hp_orbs(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    player_query: Query<Entity, With<Player>>,
+    mut orb_materials: ResMut<Assets<HpOrbMaterial>>,
+    settings: Res<GameSettings>,
+    player_query: Query<Entity, Added<Player>>,
+    orb_query: Query<(), With<MeshMaterial2d<HpOrbMaterial>>>,
 ) {
-    let Ok(player) = player_query.single() else {
-        // Player doesn't exist yet, skip creating HP bar
-        return;
-    };
+    let diameter = PLAYER_HP_BAR_RADIUS * 2.0;
+    let existing_orbs = orb_query.iter().count();
+
+    for (index, player) in player_query.iter().enumerate() {
+        // Calculate position: top-left, with Y at the ceiling (BOUNDARY_TOP)
+        let slot = (existing_orbs + index) as f32;
+        let screen_y = BOUNDARY_TOP;
+        let screen_x = BOUNDARY_LEFT
+            + PLAYER_HP_BAR_MARGIN_LEFT
+            + PLAYER_HP_BAR_RADIUS
+            + slot * (diameter + PLAYER_HP_BAR_MARGIN_LEFT);
+
+        commands.spawn((
+            DespawnOnExit(GameState::InGame),
+            Mesh2d(meshes.add(Rectangle::new(diameter, diameter))),
+            MeshMaterial2d(orb_materials.add(HpOrbMaterial {
+                color: settings.palette.hp_color(1.0).into(), // Full HP color
+                fill: 1.0,
+                shield: 0.0,
+            })),
+            Transform::from_xyz(screen_x, screen_y, 0.0),
+            RenderLayers::layer(HUD_RENDER_LAYER), // Drawn over the game elements
+            ScreenAnchored(Vec2::new(screen_x, screen_y)),
+            HealthBar { entity: player },
+            HealthBarRoot { entity: player },
+        ));
+    }
+}
 
-    // Calculate position: top-left, with Y at the ceiling (BOUNDARY_TOP)
-    let screen_y = BOUNDARY_TOP;
+/// System to remove the health bars of entities that no longer exist (e.g. a despawned boss)
+pub fn despawn_orphaned_health_bars(
+    mut commands: Commands,
+    root_query: Query<(Entity, &HealthBarRoot)>,
+    tracked_query: Query<(), With<Hp>>,
+) {
+    for (root, health_bar) in &root_query {
+        if !tracked_query.contains(health_bar.entity) {
+            commands.entity(root).despawn();
+        }
+    }
+}
+
+/// Spawns the remaining lives and potion counters just below the player's HP bar.
+pub fn setup_lives_display(
+    mut commands: Commands,
+    lives: Res<Lives>,
+    player_upgrades: Option<Res<PlayerUpgrades>>,
+) {
     let screen_x = BOUNDARY_LEFT + PLAYER_HP_BAR_MARGIN_LEFT + PLAYER_HP_BAR_RADIUS;
+    let screen_y = BOUNDARY_TOP - PLAYER_HP_BAR_RADIUS - 14.0;
 
-    // Spawn circular HP bar background (outer circle - black border)
     commands.spawn((
-        Mesh2d(meshes.add(Circle::new(PLAYER_HP_BAR_RADIUS))),
-        MeshMaterial2d(materials.add(Color::BLACK)),
-        Transform::from_xyz(screen_x, screen_y, 2.0), // Z=2.0 to be above game elements
-        HealthBarBackground,
+        DespawnOnExit(GameState::InGame),
+        Text2d::new(format!("Lives: {}", lives.0)),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Transform::from_xyz(screen_x, screen_y, 0.0),
+        RenderLayers::layer(HUD_RENDER_LAYER),
+        ScreenAnchored(Vec2::new(screen_x, screen_y)),
+        LivesDisplay,
     ));
 
-    // Spawn circular HP bar fill (inner circle that drains from top)
-    // We'll use a rectangle mask approach: the fill circle is clipped from the top based on HP
-    let fill_radius = PLAYER_HP_BAR_RADIUS - 4.0; // Slightly smaller for border effect
-
-    // Create the fill circle
+    let potions = player_upgrades.map_or(0, |u| u.potions);
     commands.spawn((
-        Mesh2d(meshes.add(Circle::new(fill_radius))),
-        MeshMaterial2d(materials.add(Color::srgb(0.0, 1.0, 0.0))), // Green
-        Transform::from_xyz(screen_x, screen_y, 2.1),              // Slightly above background
-        HealthBar { entity: player },
+        DespawnOnExit(GameState::InGame),
+        Text2d::new(format!("Potions: {}", potions)),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Transform::from_xyz(screen_x, screen_y - 18.0, 0.0),
+        RenderLayers::layer(HUD_RENDER_LAYER),
+        ScreenAnchored(Vec2::new(screen_x, screen_y - 18.0)),
+        PotionDisplay,
     ));
+}
 
-    // Spawn a rectangular mask above the fill circle to hide the top portion.
-    // This achieves a linear "drain from top" visual without distorting the circle.
-    let diameter = fill_radius * 2.0;
-    commands.spawn((
-        Mesh2d(meshes.add(Rectangle::new(diameter, diameter))),
-        MeshMaterial2d(materials.add(Color::BLACK)),
-        Transform::from_xyz(screen_x, screen_y, 2.2), // Above the fill
-        HealthBarMask { entity: player },
-    ));
+/// System to drink a potion (Q) bought in the shop, healing the player mid-fight
+pub fn use_potion(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut player_upgrades: ResMut<PlayerUpgrades>,
+    mut player_query: Query<&mut Hp, (With<Player>, Without<Defeated>)>,
+    mut display_query: Query<&mut Text2d, With<PotionDisplay>>,
+) {
+    if !InputAction::Potion.just_pressed(&keyboard_input) || player_upgrades.potions == 0 {
+        return;
+    }
+
+    // Potions aren't wasted at full HP
+    let mut drank = false;
+    for mut hp in &mut player_query {
+        if hp.current < hp.max {
+            hp.current = (hp.current + POTION_HEAL_AMOUNT).min(hp.max);
+            drank = true;
+        }
+    }
+    if !drank {
+        return;
+    }
+
+    player_upgrades.potions -= 1;
+    for mut text in &mut display_query {
+        text.0 = format!("Potions: {}", player_upgrades.potions);
+    }
 }
 
 /// System to update the health bars based on the entity's HP.
-/// Handles both circular HP bars (player - Diablo 2 style) and rectangular HP bars (boss).
+/// Handles both circular HP orbs (player - Diablo 2 style) and rectangular HP bars (boss).
+/// Boss bars follow `HealthChanged`; orbs are refreshed every frame for the low HP pulse
+/// and the overshield ring.
 pub fn update_health_bars(
     hp_query: Query<&Hp>,
-    // Query for circular HP bars (player) - uses Mesh2d with Transform and MeshMaterial2d
-    mut circular_health_bar_query: Query<
-        (&HealthBar, &mut MeshMaterial2d<ColorMaterial>),
-        (With<Mesh2d>, Without<Node>, Without<HealthBarMask>),
-    >,
-    // Query for circular HP mask rectangles (player), disjoint from the fill
-    mut mask_query: Query<(&HealthBarMask, &mut Transform), (Without<HealthBar>,)>,
+    overshield_query: Query<&Overshield>,
+    // Query for HP orbs (player) - the shader reads the fill level and color from the material
+    orb_query: Query<(&HealthBar, &MeshMaterial2d<HpOrbMaterial>)>,
+    mut orb_materials: ResMut<Assets<HpOrbMaterial>>,
     // Query for rectangular HP bars (boss) - uses UI Node
     mut rectangular_health_bar_query: Query<(&HealthBar, &mut Node), (With<Node>, Without<Mesh2d>)>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
+    settings: Res<GameSettings>,
+    low_hp_warning: Res<LowHpWarning>,
+    mut health_changed: MessageReader<HealthChanged>,
 ) {
-    // Update circular HP bars (player) - keep circle shape, only change color
-    for (health_bar, mesh_material) in circular_health_bar_query.iter_mut() {
+    // Update HP orbs (player): liquid level and color (green -> yellow -> red, or the colorblind-safe ramp)
+    for (health_bar, mesh_material) in orb_query.iter() {
         if let Ok(hp) = hp_query.get(health_bar.entity) {
-            let health_percentage = (hp.current / hp.max).clamp(0.0, 1.0);
-
-            // Change color based on HP (green -> yellow -> red)
-            let color = if health_percentage > 0.5 {
-                // Green to yellow transition
-                let t = (health_percentage - 0.5) * 2.0;
-                Color::srgb(1.0 - t, 1.0, 0.0)
-            } else {
-                // Yellow to red transition
-                let t = health_percentage * 2.0;
-                Color::srgb(1.0, t, 0.0)
-            };
-
-            // Update the material color
-            if let Some(material) = materials.get_mut(&mesh_material.0) {
+            let hp_fraction = (hp.current / hp.max).clamp(0.0, 1.0);
+            // The liquid level is a height, picked so the liquid's area matches the HP fraction
+            let fill = segment_height_for_fraction(hp_fraction, 1.0) / 2.0;
+            let mut color = settings.palette.hp_color(hp_fraction);
+            // Low HP: pulse toward red
+            if low_hp_warning.active && hp_fraction < LOW_HP_WARNING_FRACTION {
+                color = color.mix(&LOW_HP_WARNING_COLOR, low_hp_warning.pulse);
+            }
+            let color: LinearRgba = color.into();
+            let shield = overshield_query
+                .get(health_bar.entity)
+                .map_or(0.0, Overshield::fraction);
+
+            // Only touch the asset when something changed, so it isn't re-uploaded every frame
+            let unchanged = orb_materials.get(&mesh_material.0).is_some_and(|material| {
+                material.fill == fill && material.color == color && material.shield == shield
+            });
+            if !unchanged && let Some(material) = orb_materials.get_mut(&mesh_material.0) {
+                material.fill = fill;
                 material.color = color;
+                material.shield = shield;
             }
         }
     }
 
-    // Update the rectangular mask to linearly hide the top portion of the circle
-    for (mask, mut transform) in mask_query.iter_mut() {
-        if let Ok(hp) = hp_query.get(mask.entity) {
-            let health_percentage = (hp.current / hp.max).clamp(0.0, 1.0);
-            let missing_fraction = (1.0 - health_percentage).clamp(0.0, 1.0);
-
-            let fill_radius = PLAYER_HP_BAR_RADIUS - 4.0;
-            let diameter = fill_radius * 2.0;
-            let base_y = BOUNDARY_TOP;
+    // Update rectangular HP bars (boss) when their boss's HP changes
+    for message in health_changed.read() {
+        for (health_bar, mut node) in &mut rectangular_health_bar_query {
+            if health_bar.entity == message.entity {
+                node.width = percent(message.fraction() * 100.0);
+            }
+        }
+    }
+}
 
-            // Convert missing health into a circular segment height so that the
-            // visible area of the orb matches the remaining HP percentage.
-            let mask_height = segment_height_for_fraction(missing_fraction, fill_radius);
-            let y_scale = if diameter > 0.0 {
-                (mask_height / diameter).clamp(0.0, 1.0)
-            } else {
-                0.0
-            };
-            let scaled_half_height = mask_height * 0.5;
+/// System to regenerate player HP from the regeneration upgrade.
+/// Any HP loss (seen through `HealthChanged`) restarts the out-of-combat delay;
+/// defeated players don't regenerate.
+pub fn regenerate_health(
+    time: Res<Time>,
+    player_upgrades: Option<Res<PlayerUpgrades>>,
+    mut player_query: Query<
+        (Entity, &mut Hp, &mut Regeneration),
+        (With<Player>, Without<Defeated>),
+    >,
+    mut health_changed: MessageReader<HealthChanged>,
+) {
+    let regen_per_second = player_upgrades.map_or(0.0, |u| u.regen_per_second);
+    let hurt: Vec<Entity> = health_changed
+        .read()
+        .filter(|message| message.damage() > 0.0)
+        .map(|message| message.entity)
+        .collect();
+
+    for (entity, mut hp, mut regeneration) in &mut player_query {
+        if hurt.contains(&entity) {
+            regeneration.delay_timer = REGEN_DELAY;
+        } else {
+            regeneration.delay_timer -= time.delta_secs();
+        }
 
-            // Position the mask so its top edge aligns with the top of the circle,
-            // and it grows downward as HP is lost.
-            let mask_center_y = base_y + fill_radius - scaled_half_height;
-            transform.scale = Vec3::new(1.0, y_scale, 1.0);
-            transform.translation.y = mask_center_y;
+        if regen_per_second > 0.0
+            && regeneration.delay_timer <= 0.0
+            && hp.current > 0.0
+            && hp.current < hp.max
+        {
+            hp.current = (hp.current + regen_per_second * time.delta_secs()).min(hp.max);
         }
     }
+}
 
-    // Update rectangular HP bars (boss) - existing UI-based system
-    for (health_bar, mut node) in rectangular_health_bar_query.iter_mut() {
-        if let Ok(hp) = hp_query.get(health_bar.entity) {
-            let health_percentage = (hp.current / hp.max) * 100.0;
-            node.width = percent(health_percentage);
+/// System to apply `GameConfig::charge_hit_rule` to the player's charge shot when they take
+/// damage (seen through `HealthChanged`)
+pub fn apply_charge_hit_rule(
+    config: Res<GameConfig>,
+    mut player_query: Query<(Entity, &mut ChargeShot), With<Player>>,
+    mut health_changed: MessageReader<HealthChanged>,
+) {
+    let hurt: Vec<Entity> = health_changed
+        .read()
+        .filter(|message| message.damage() > 0.0)
+        .map(|message| message.entity)
+        .collect();
+
+    for (entity, mut charge_shot) in &mut player_query {
+        if !charge_shot.is_charging || !hurt.contains(&entity) {
+            continue;
+        }
+        match config.charge_after_hit(charge_shot.timer) {
+            Some(timer) => charge_shot.timer = timer,
+            None => {
+                charge_shot.is_charging = false;
+                charge_shot.timer = 0.0;
+            }
         }
     }
 }
 
-/// System to handle health regeneration (currently disabled - player doesn't regenerate)
-/// This can be enabled later if you want health regeneration mechanics
-pub fn change_health(_time: Res<Time>, _player_query: Query<&mut Hp, With<Player>>) {
-    // Health regeneration disabled - player HP stays at current value
-    // Uncomment below to enable regeneration:
-    // let mut player_hp = player_query.single_mut().unwrap();
-    // player_hp.current = (player_hp.current + 5.0 * time.delta_secs()).min(player_hp.max);
+/// System to send a footstep every PLAYER_FOOTSTEP_INTERVAL while the player runs along the
+/// ground (the first one as soon as they start)
+pub fn emit_footsteps(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    ground: Ground,
+    mut player_query: Query<
+        (&Transform, &mut Footsteps),
+        (With<Player>, Without<Dash>, Without<Climbing>),
+    >,
+    mut feedback: MessageWriter<FeedbackEvent>,
+) {
+    let running =
+        InputAction::Left.pressed(&keyboard_input) != InputAction::Right.pressed(&keyboard_input);
+    for (transform, mut footsteps) in &mut player_query {
+        let grounded = ground.is_supported(
+            transform.translation.x,
+            transform.translation.y,
+            PLAYER_HITBOX_SIZE / 2.0,
+        );
+        if !running || !grounded {
+            footsteps.timer = 0.0;
+            continue;
+        }
+        footsteps.timer -= time.delta_secs();
+        if footsteps.timer <= 0.0 {
+            footsteps.timer = PLAYER_FOOTSTEP_INTERVAL;
+            feedback.write(FeedbackEvent::Footstep);
+        }
+    }
 }
 
 /// Helper function to check AABB (Axis-Aligned Bounding Box) collision
@@ -671,143 +1158,37 @@ pub fn check_aabb_collision(pos1: Vec3, size1: Vec2, pos2: Vec3, size2: Vec2) ->
         && pos1.y + half_size1.y > pos2.y - half_size2.y
 }
 
-/// Compute the area of a circular segment (cap) with a given height.
-fn circular_segment_area(height: f32, radius: f32) -> f32 {
-    if radius <= 0.0 {
-        return 0.0;
-    }
-
-    let clamped_height = height.clamp(0.0, 2.0 * radius);
-    if clamped_height <= f32::EPSILON {
-        return 0.0;
-    }
-
-    if (clamped_height - 2.0 * radius).abs() <= f32::EPSILON {
-        return std::f32::consts::PI * radius * radius;
-    }
-
-    let r = radius;
-    let h = clamped_height;
-    let term = ((r - h) / r).clamp(-1.0, 1.0);
-    let theta = term.acos();
-    let sqrt_term = (2.0 * r * h - h * h).max(0.0).sqrt();
-
-    r * r * theta - (r - h) * sqrt_term
-}
-
-/// Convert a missing area fraction into a mask height so that the visible
-/// portion of the HP orb matches the remaining HP percentage.
-fn segment_height_for_fraction(fraction: f32, radius: f32) -> f32 {
-    if radius <= 0.0 {
-        return 0.0;
-    }
-
-    let frac = fraction.clamp(0.0, 1.0);
-    if frac <= f32::EPSILON {
-        return 0.0;
-    }
-
-    if frac >= 1.0 - f32::EPSILON {
-        return 2.0 * radius;
-    }
-
-    let target_area = frac * std::f32::consts::PI * radius * radius;
-    let mut low = 0.0;
-    let mut high = 2.0 * radius;
-
-    for _ in 0..20 {
-        let mid = 0.5 * (low + high);
-        let area = circular_segment_area(mid, radius);
-
-        if (area - target_area).abs() <= 1e-4 {
-            return mid;
-        }
-
-        if area < target_area {
-            low = mid;
-        } else {
-            high = mid;
-        }
-    }
-
-    0.5 * (low + high)
-}
-
-/// Calculate improved knockback direction based on collision angle
-/// This makes knockback feel more dynamic and appropriate for different collision sides
-fn calculate_knockback_direction(
-    direction_to_player: Vec2,
-    _player_pos: Vec3,
-    _boss_pos: Vec3,
-) -> Vec2 {
-    use crate::systems::config::{
-        KNOCKBACK_SIDE_VERTICAL_COMPONENT, KNOCKBACK_TOP_HORIZONTAL_COMPONENT,
-        KNOCKBACK_TOP_VERTICAL_COMPONENT,
-    };
-
-    if direction_to_player.length() < 0.001 {
-        // If positions are exactly the same, push to the left
-        return Vec2::new(-1.0, 0.0);
-    }
-
-    let normalized = direction_to_player.normalize();
-    let dx = direction_to_player.x.abs();
-    let dy = direction_to_player.y.abs();
-
-    // Determine which side of the boss the player is hitting
-    // If vertical distance is greater, it's a top/bottom collision
-    // If horizontal distance is greater, it's a left/right collision
-    if dy > dx {
-        // Top or bottom collision
-        if normalized.y > 0.0 {
-            // Player is above boss (hitting from top)
-            // Push upward and to the side for more dynamic feel
-            let horizontal_dir = if normalized.x > 0.0 { 1.0 } else { -1.0 };
-            Vec2::new(
-                horizontal_dir * KNOCKBACK_TOP_HORIZONTAL_COMPONENT,
-                KNOCKBACK_TOP_VERTICAL_COMPONENT,
-            )
-            .normalize()
-        } else {
-            // Player is below boss (hitting from bottom)
-            // Push downward and to the side
-            let horizontal_dir = if normalized.x > 0.0 { 1.0 } else { -1.0 };
-            Vec2::new(
-                horizontal_dir * KNOCKBACK_TOP_HORIZONTAL_COMPONENT,
-                -KNOCKBACK_TOP_VERTICAL_COMPONENT,
-            )
-            .normalize()
-        }
-    } else {
-        // Left or right collision (side collision)
-        // Push horizontally away with slight upward component for more dynamic feel
-        let horizontal_dir = if normalized.x > 0.0 { 1.0 } else { -1.0 };
-        Vec2::new(horizontal_dir, KNOCKBACK_SIDE_VERTICAL_COMPONENT).normalize()
-    }
-}
-
 /// System to handle player-boss collision (player takes damage)
 pub fn player_boss_collision(
     time: Res<Time>,
     mut player_query: Query<
-        (Entity, &Transform, &mut Hp, Option<&mut Invincibility>),
+        (
+            Entity,
+            &Transform,
+            &mut Hp,
+            Option<&mut Invincibility>,
+            Option<&mut Overshield>,
+        ),
         With<Player>,
     >,
     boss_query: Query<&Transform, With<Boss>>,
+    collisions: CollisionLookup,
     mut commands: Commands,
     player_upgrades: Option<Res<PlayerUpgrades>>,
+    mut screen_shake: ResMut<ScreenShake>,
+    mut feedback: MessageWriter<FeedbackEvent>,
+    config: Res<GameConfig>,
 ) {
-    const PLAYER_SIZE: Vec2 = Vec2::new(32.0, 64.0);
-    const BOSS_SIZE: Vec2 = Vec2::new(32.0, 64.0);
-
     // Apply defense multiplier to damage
     let defense_multiplier = player_upgrades
         .as_ref()
         .map(|u| u.defense_multiplier)
         .unwrap_or(1.0);
-    let DAMAGE = crate::systems::config::BOSS_COLLISION_DAMAGE * defense_multiplier;
+    let damage = config.boss_collision_damage * defense_multiplier;
 
-    for (player_entity, player_transform, mut player_hp, invincibility) in &mut player_query {
+    for (player_entity, player_transform, mut player_hp, invincibility, mut overshield) in
+        &mut player_query
+    {
         // Check if player is invincible
         let is_invincible = if let Some(mut inv) = invincibility {
             inv.timer -= time.delta_secs();
@@ -825,35 +1206,33 @@ pub fn player_boss_collision(
             continue;
         }
 
-        // Check collision with boss
-        for boss_transform in &boss_query {
-            if check_aabb_collision(
-                player_transform.translation,
-                PLAYER_SIZE,
-                boss_transform.translation,
-                BOSS_SIZE,
-            ) {
+        // Check collision with the bosses overlapping the player
+        let touching_bosses = collisions.overlapping(
+            player_transform.translation.truncate(),
+            PLAYER_HITBOX_SIZE,
+            ColliderKind::Boss,
+        );
+        for boss in touching_bosses {
+            if let Ok(boss_transform) = boss_query.get(boss) {
                 // Calculate knockback direction based on collision side
                 let direction_to_player =
                     (player_transform.translation - boss_transform.translation).truncate();
-                let knockback_direction = calculate_knockback_direction(
-                    direction_to_player,
-                    player_transform.translation,
-                    boss_transform.translation,
-                );
+                let push_direction = knockback_direction(direction_to_player);
 
                 // Player takes damage
-                player_hp.current = (player_hp.current - DAMAGE).max(0.0);
+                take_damage(&mut player_hp, overshield.as_deref_mut(), damage);
+                screen_shake.trigger();
+                feedback.write(FeedbackEvent::Hurt);
 
                 // Add invincibility frames
                 commands.entity(player_entity).insert(Invincibility {
-                    timer: INVINCIBILITY_DURATION,
+                    timer: config.invincibility_duration,
                 });
 
                 // Add knockback effect
                 commands.entity(player_entity).insert(Knockback {
-                    velocity: knockback_direction * KNOCKBACK_FORCE,
-                    timer: KNOCKBACK_DURATION,
+                    velocity: push_direction * config.knockback_force,
+                    timer: config.knockback_duration,
                 });
 
                 // Only process one collision per frame
@@ -863,37 +1242,13 @@ pub fn player_boss_collision(
     }
 }
 
-/// System to apply knockback effect to player
-pub fn apply_knockback(
-    time: Res<Time>,
-    mut player_query: Query<(Entity, &mut Transform, &mut Knockback), With<Player>>,
-    mut commands: Commands,
-) {
-    for (entity, mut transform, mut knockback) in &mut player_query {
-        // Apply knockback velocity
-        transform.translation.x += knockback.velocity.x * time.delta_secs();
-        transform.translation.y += knockback.velocity.y * time.delta_secs();
-
-        // Keep player within boundaries even during knockback
-        transform.translation.x = transform.translation.x.clamp(BOUNDARY_LEFT, BOUNDARY_RIGHT);
-        transform.translation.y = transform.translation.y.clamp(BOUNDARY_BOTTOM, BOUNDARY_TOP);
-
-        // Decay knockback over time
-        knockback.velocity *= KNOCKBACK_DECAY_RATE; // Reduce velocity each frame
-        knockback.timer -= time.delta_secs();
-
-        // Remove knockback when timer expires
-        if knockback.timer <= 0.0 {
-            commands.entity(entity).remove::<Knockback>();
-        }
-    }
-}
-
 /// System to apply knockback effect to boss
 pub fn apply_boss_knockback(
     time: Res<Time>,
     mut boss_query: Query<(Entity, &mut Transform, &mut Knockback), With<Boss>>,
     mut commands: Commands,
+    config: Res<GameConfig>,
+    arena: Res<ArenaBounds>,
 ) {
     for (entity, mut transform, mut knockback) in &mut boss_query {
         // Apply knockback velocity
@@ -901,11 +1256,11 @@ pub fn apply_boss_knockback(
         transform.translation.y += knockback.velocity.y * time.delta_secs();
 
         // Keep boss within boundaries even during knockback
-        transform.translation.x = transform.translation.x.clamp(BOUNDARY_LEFT, BOUNDARY_RIGHT);
-        transform.translation.y = transform.translation.y.clamp(BOUNDARY_BOTTOM, BOUNDARY_TOP);
+        transform.translation.x = arena.clamp_x(transform.translation.x);
+        transform.translation.y = arena.clamp_y(transform.translation.y);
 
         // Decay knockback over time
-        knockback.velocity *= KNOCKBACK_DECAY_RATE; // Reduce velocity each frame
+        knockback.velocity *= decay_factor(config.knockback_decay_rate, time.delta_secs());
         knockback.timer -= time.delta_secs();
 
         // Remove knockback when timer expires
@@ -915,8 +1270,32 @@ pub fn apply_boss_knockback(
     }
 }
 
-/// System to handle projectile-boss collision (boss takes damage, projectile despawns)
-pub fn projectile_boss_collision(
+/// Damage a player shot deals, and whether it was charged enough to knock bosses back
+fn player_shot_damage(projectile: &Projectile, config: &GameConfig) -> (f32, bool) {
+    // Base damage for uncharged shots, multiplied for charged shots
+    let is_charged_shot = projectile.charge_level >= CHARGE_SHOT_MIN_TIME / CHARGE_SHOT_MAX_TIME;
+    let damage = if is_charged_shot {
+        // Charged shot: damage scales with charge level
+        let damage_multiplier = 1.0 + (projectile.charge_level * (CHARGE_SHOT_DAMAGE_MULTIPLIER - 1.0));
+        config.player_projectile_damage * damage_multiplier
+    } else {
+        // Normal shot: base damage
+        config.player_projectile_damage
+    };
+
+    // Boss weapons hit harder than the default shot
+    let damage = if projectile.weapon.is_some() {
+        damage * BOSS_WEAPON_DAMAGE_MULTIPLIER
+    } else {
+        damage
+    };
+    (damage, is_charged_shot)
+}
+
+/// System to handle player shots hitting enemies: bosses take damage (and knockback from charged
+/// shots), other enemies with a hitbox (destructible hazards, minions) lose HP and are destroyed
+/// when it runs out. The projectile despawns on its first hit.
+pub fn projectile_hits_enemies(
     mut commands: Commands,
     projectile_query: Query<
         (Entity, &Transform, &Projectile),
@@ -927,97 +1306,324 @@ pub fn projectile_boss_collision(
             Without<crate::systems::boss::BossProjectile>,
         ),
     >,
-    mut boss_query: Query<(Entity, &Transform, &mut Hp), With<Boss>>,
+    mut boss_query: Query<(&mut Hp, Has<BossInvulnerable>), With<Boss>>,
+    mut enemy_query: Query<(&mut Hp, Has<BreakableBlock>), (With<Enemy>, Without<Boss>)>,
+    collisions: CollisionLookup,
+    mut hit_stop: ResMut<crate::systems::hit_stop::HitStop>,
+    config: Res<GameConfig>,
+    run_stats: Res<RunStats>,
+    mut boss_damaged: MessageWriter<BossDamaged>,
+    mut projectile_hit: MessageWriter<ProjectileHit>,
 ) {
-    const BASE_PROJECTILE_SIZE: Vec2 = Vec2::new(10.0, 10.0);
-    const BOSS_SIZE: Vec2 = Vec2::new(32.0, 64.0);
-
     for (projectile_entity, projectile_transform, projectile) in &projectile_query {
-        // Calculate projectile size based on charge level (for collision detection)
-        let charge_multiplier = 1.0 + (projectile.charge_level * 1.5);
-        let projectile_size = BASE_PROJECTILE_SIZE * charge_multiplier;
-
-        for (boss_entity, boss_transform, mut boss_hp) in &mut boss_query {
-            if check_aabb_collision(
-                projectile_transform.translation,
-                projectile_size,
-                boss_transform.translation,
-                BOSS_SIZE,
-            ) {
-                // Calculate damage based on charge level
-                // Base damage for uncharged shots, multiplied for charged shots
-                let is_charged_shot = projectile.charge_level >= CHARGE_SHOT_MIN_TIME / CHARGE_SHOT_MAX_TIME;
-                let damage = if is_charged_shot {
-                    // Charged shot: damage scales with charge level
-                    let damage_multiplier = 1.0 + (projectile.charge_level * (CHARGE_SHOT_DAMAGE_MULTIPLIER - 1.0));
-                    PLAYER_PROJECTILE_DAMAGE * damage_multiplier
-                } else {
-                    // Normal shot: base damage
-                    PLAYER_PROJECTILE_DAMAGE
-                };
-
-                // Boss takes damage
+        // Projectile size grows with charge level, so charged shots are easier to land
+        let center = projectile_transform.translation.truncate();
+        let hitbox_size = projectile_hitbox_size(projectile);
+        let (damage, is_charged_shot) = player_shot_damage(projectile, &config);
+        let damage = damage * run_stats.damage_dealt_multiplier();
+
+        // Bosses take the hit first
+        let hit_boss = collisions
+            .overlapping(center, hitbox_size, ColliderKind::Boss)
+            .into_iter()
+            .find(|boss_entity| boss_query.contains(*boss_entity));
+        if let Some(boss_entity) = hit_boss
+            && let Ok((mut boss_hp, is_invulnerable)) = boss_query.get_mut(boss_entity)
+        {
+            // Invulnerable bosses (survival phases) absorb shots without taking damage
+            if !is_invulnerable {
                 boss_hp.current = (boss_hp.current - damage).max(0.0);
+                boss_damaged.write(BossDamaged {
+                    boss: boss_entity,
+                    amount: damage,
+                    weapon: projectile.weapon,
+                });
 
                 // Apply knockback to boss if hit by charged shot
                 if is_charged_shot {
                     // Knockback direction is the same as projectile direction (pushes boss away from player)
                     let knockback_direction = projectile.direction.normalize_or_zero();
                     commands.entity(boss_entity).insert(Knockback {
-                        velocity: knockback_direction * KNOCKBACK_FORCE,
-                        timer: KNOCKBACK_DURATION,
+                        velocity: knockback_direction * config.knockback_force,
+                        timer: config.knockback_duration,
                     });
-                }
 
-                // Mark projectile as hit (prevents multiple hits before despawn)
-                commands.entity(projectile_entity).insert(ProjectileHasHit);
+                    // Brief slow-motion to sell the heavy impact
+                    hit_stop.trigger();
+                }
+            }
 
-                // Despawn projectile
-                commands.entity(projectile_entity).despawn();
+            // Spent: no other collision sees it again, and it's despawned after the collisions
+            commands.entity(projectile_entity).insert(ProjectileHasHit);
+            projectile_hit.write(ProjectileHit(projectile_entity));
+            continue;
+        }
 
-                // Only process one collision per projectile
-                break;
+        // Then any other enemy in the way
+        let hit_enemy = collisions
+            .overlapping(center, hitbox_size, ColliderKind::Enemy)
+            .into_iter()
+            .find(|enemy_entity| enemy_query.contains(*enemy_entity));
+        if let Some(enemy_entity) = hit_enemy
+            && let Ok((mut enemy_hp, is_block)) = enemy_query.get_mut(enemy_entity)
+        {
+            // Charged shots smash breakable blocks in one hit
+            let damage = if is_block && is_charged_shot {
+                enemy_hp.current
+            } else {
+                damage
+            };
+            enemy_hp.current = (enemy_hp.current - damage).max(0.0);
+            // Blocks break apart in `break_blocks` instead
+            if enemy_hp.current <= 0.0 && !is_block {
+                // Two shots can finish off the same enemy in one frame
+                commands.entity(enemy_entity).try_despawn();
             }
+
+            commands.entity(projectile_entity).insert(ProjectileHasHit);
+            projectile_hit.write(ProjectileHit(projectile_entity));
+        }
+    }
+}
+
+/// Message sent when a projectile hits something. The collision systems mark the projectile
+/// with `ProjectileHasHit` so it can't hit anything else, and `despawn_spent_projectiles`
+/// removes it once every system in `ProjectileHitSet` has run.
+#[derive(Message)]
+pub struct ProjectileHit(pub Entity);
+
+/// System set of every collision system that spends projectiles (inserts `ProjectileHasHit` and
+/// sends `ProjectileHit`). Runs after `UpdateColliders` and before `despawn_spent_projectiles`.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectileHitSet;
+
+/// System to despawn projectiles that hit something this frame or ran out their `Lifetime`
+pub fn despawn_spent_projectiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut projectile_hit: MessageReader<ProjectileHit>,
+    mut lifetime_query: Query<(Entity, &mut Lifetime)>,
+) {
+    for ProjectileHit(projectile) in projectile_hit.read() {
+        // A shot can also leave the arena in the frame it hits
+        commands.entity(*projectile).try_despawn();
+    }
+    for (entity, mut lifetime) in &mut lifetime_query {
+        lifetime.remaining -= time.delta_secs();
+        if lifetime.remaining <= 0.0 {
+            commands.entity(entity).try_despawn();
         }
     }
 }
 
-/// System to persist player HP to PlayerUpgrades resource
+/// Message sent whenever a player shot damages a boss (read by damage-reactive upgrades like lifesteal)
+#[derive(Message)]
+pub struct BossDamaged {
+    pub boss: Entity,
+    pub amount: f32,
+    pub weapon: Option<BossType>, // Boss weapon that fired the shot (None = default shot)
+}
+
+/// Message sent once when the player's HP reaches 0
+#[derive(Message)]
+pub struct PlayerDied;
+
+/// Message sent once when a boss's HP reaches 0
+#[derive(Message)]
+pub struct BossDefeated {
+    pub entity: Entity,
+    pub boss_type: BossType,
+}
+
+/// System to report defeats: turns `EntityDied` into `PlayerDied` / `BossDefeated`.
+/// `Defeated` stops a death being reported twice.
+pub fn detect_defeats(
+    mut commands: Commands,
+    mut entity_died: MessageReader<EntityDied>,
+    player_query: Query<(), (With<Player>, Without<Defeated>)>,
+    boss_query: Query<&BossType, (With<Boss>, Without<Defeated>)>,
+    mut player_died: MessageWriter<PlayerDied>,
+    mut boss_defeated: MessageWriter<BossDefeated>,
+) {
+    for message in entity_died.read() {
+        let entity = message.entity;
+        if player_query.contains(entity) {
+            commands.entity(entity).insert(Defeated);
+            player_died.write(PlayerDied);
+        } else if let Ok(boss_type) = boss_query.get(entity) {
+            commands.entity(entity).insert(Defeated);
+            boss_defeated.write(BossDefeated {
+                entity,
+                boss_type: *boss_type,
+            });
+        }
+    }
+}
+
+/// System to persist player HP to PlayerUpgrades resource when the fight ends
 pub fn persist_player_hp(
+    mut player_died: MessageReader<PlayerDied>,
+    mut boss_defeated: MessageReader<BossDefeated>,
     player_query: Query<&Hp, With<Player>>,
     mut player_upgrades: ResMut<PlayerUpgrades>,
 ) {
+    // Read both readers so neither keeps stale messages around
+    let player_died = player_died.read().count() > 0;
+    let boss_defeated = boss_defeated.read().count() > 0;
+    if !player_died && !boss_defeated {
+        return;
+    }
+
     if let Ok(player_hp) = player_query.single() {
         // Update the persisted current HP
         player_upgrades.current_hp = player_hp.current;
     }
 }
 
-/// System to check for win/lose conditions
+/// Ends a won fight: moves to the GameWin screen, or opens the arena doors first in stages with
+/// arena doors
+pub fn finish_fight(arena_doors: &mut ArenaDoors, next_state: &mut NextState<GameState>) {
+    // The handle_stage_progression system will check if we should continue to next stage
+    if arena_doors.is_locked() {
+        arena_doors.open();
+    } else {
+        next_state.set(GameState::GameWin);
+    }
+}
+
+/// System to move to the win/lose screen when a defeat is reported.
+/// In fights with several bosses, each defeated boss leaves the arena and the fight is won with the last one.
+/// A kill cam on the final blow plays out before the fight is over.
 pub fn check_game_outcome(
-    player_query: Query<&Hp, With<Player>>,
-    boss_query: Query<(&Hp, &BossType), With<Boss>>,
+    mut commands: Commands,
+    mut player_died: MessageReader<PlayerDied>,
+    mut boss_defeated: MessageReader<BossDefeated>,
+    remaining_boss_query: Query<(), (With<Boss>, Without<Defeated>)>,
     mut next_state: ResMut<NextState<GameState>>,
     mut defeated_boss: ResMut<DefeatedBoss>,
-    _current_stage: ResMut<crate::stages::game_menu::CurrentStage>,
+    mut arena_doors: ResMut<ArenaDoors>,
+    mut kill_cam: ResMut<KillCam>,
 ) {
-    // Check if player is dead (lose condition)
-    if let Ok(player_hp) = player_query.single() {
-        if player_hp.current <= 0.0 {
-            next_state.set(GameState::GameOver);
-            return;
+    let player_died = player_died.read().count() > 0;
+    let defeated: Vec<(Entity, BossType)> = boss_defeated
+        .read()
+        .map(|message| (message.entity, message.boss_type))
+        .collect();
+
+    // Losing takes priority if both fall on the same frame
+    if player_died {
+        next_state.set(GameState::GameOver);
+        return;
+    }
+
+    if !remaining_boss_query.is_empty() {
+        // Other bosses are still fighting: clear the defeated ones out of their way
+        for (entity, _) in defeated {
+            commands.entity(entity).despawn();
         }
+        return;
     }
 
-    // Check if boss is dead (win condition)
-    if let Ok((boss_hp, boss_type)) = boss_query.single() {
-        if boss_hp.current <= 0.0 {
-            // Store which boss was defeated
-            defeated_boss.boss_type = Some(*boss_type);
+    if let Some(&(_, boss_type)) = defeated.last() {
+        // Store which boss was defeated
+        defeated_boss.boss_type = Some(boss_type);
 
-            // Always transition to GameWin screen
-            // The handle_stage_progression system will check if we should continue to next stage
-            next_state.set(GameState::GameWin);
+        // Always transition to GameWin screen (after the doors open, in stages with arena doors),
+        // once the kill cam is over if it's playing
+        if kill_cam.is_playing() {
+            kill_cam.finish_pending = true;
+        } else {
+            finish_fight(&mut arena_doors, &mut next_state);
         }
     }
 }
+
+#[cfg(all(test, not(feature = "avian")))]
+mod tests {
+    use super::*;
+    use crate::systems::collision::Hitbox;
+    use crate::systems::collision_grid::{CollisionGrid, update_collision_grid};
+    use crate::systems::hit_stop::HitStop;
+
+    #[derive(Resource, Default)]
+    struct HitCount(usize);
+
+    fn count_projectile_hits(mut hits: MessageReader<ProjectileHit>, mut count: ResMut<HitCount>) {
+        count.0 += hits.read().count();
+    }
+
+    fn hp_of(app: &App, entity: Entity) -> f32 {
+        app.world().get::<Hp>(entity).unwrap().current
+    }
+
+    #[test]
+    fn projectile_overlapping_boss_and_enemy_hits_once() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<CollisionGrid>()
+            .init_resource::<HitStop>()
+            .init_resource::<GameConfig>()
+            .init_resource::<RunStats>()
+            .init_resource::<HitCount>()
+            .add_message::<ProjectileHit>()
+            .add_message::<BossDamaged>()
+            .configure_sets(Update, ProjectileHitSet.before(despawn_spent_projectiles))
+            .add_systems(
+                Update,
+                (
+                    update_collision_grid.before(ProjectileHitSet),
+                    projectile_hits_enemies.in_set(ProjectileHitSet),
+                    count_projectile_hits.after(ProjectileHitSet),
+                    despawn_spent_projectiles,
+                ),
+            );
+
+        let boss = app
+            .world_mut()
+            .spawn((
+                Boss,
+                Transform::default(),
+                Hp {
+                    current: 100.0,
+                    max: 100.0,
+                },
+            ))
+            .id();
+        let enemy = app
+            .world_mut()
+            .spawn((
+                Enemy,
+                Hitbox(Vec2::splat(40.0)),
+                Transform::default(),
+                Hp {
+                    current: 30.0,
+                    max: 30.0,
+                },
+            ))
+            .id();
+        let projectile = app
+            .world_mut()
+            .spawn((
+                Projectile {
+                    direction: Vec2::X,
+                    charge_level: 0.0,
+                    weapon: None,
+                },
+                Transform::default(),
+                Lifetime { remaining: 10.0 },
+            ))
+            .id();
+
+        app.update();
+
+        // Bosses take the hit first; the enemy behind it is spared
+        assert!(hp_of(&app, boss) < 100.0);
+        assert_eq!(hp_of(&app, enemy), 30.0);
+        assert_eq!(app.world().resource::<HitCount>().0, 1);
+        assert!(app.world().get_entity(projectile).is_err());
+
+        app.update();
+        assert_eq!(app.world().resource::<HitCount>().0, 1);
+        assert_eq!(hp_of(&app, enemy), 30.0);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::components::player::{Player, PlayerVelocity};
+use crate::systems::config::{
+    PLAYER_BLINK_DURATION, PLAYER_BLINK_INTERVAL, PLAYER_BREATH_PERIOD, PLAYER_BREATH_SQUASH,
+    PLAYER_IDLE_DELAY,
+};
+use crate::systems::input::InputAction;
+use bevy::prelude::*;
+
+/// Size of each of the player's eyes
+pub const PLAYER_EYE_SIZE: Vec2 = Vec2::new(4.0, 8.0);
+/// Where the eyes sit on the player, from its center, when facing right
+const PLAYER_EYE_OFFSETS: [Vec2; 2] = [Vec2::new(4.0, 18.0), Vec2::new(12.0, 18.0)];
+
+/// What the player's animation is doing
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlayerAnimState {
+    #[default]
+    Active, // The player is being controlled
+    Idle, // No input for PLAYER_IDLE_DELAY: the player breathes and blinks
+}
+
+/// Component for the animation state machine of the player's sprite
+#[derive(Component, Default)]
+pub struct PlayerAnimation {
+    pub state: PlayerAnimState,
+    pub idle_timer: f32,  // Seconds since the last input
+    pub state_time: f32,  // Seconds spent in the current state
+    pub blink_timer: f32, // Seconds until the next blink while idle
+}
+
+impl PlayerAnimation {
+    fn enter(&mut self, state: PlayerAnimState) {
+        self.state = state;
+        self.state_time = 0.0;
+        self.blink_timer = PLAYER_BLINK_INTERVAL;
+    }
+}
+
+/// Marker component for the player's eyes (children of the player)
+#[derive(Component)]
+pub struct PlayerEye;
+
+/// Spawns the player's eyes as children of `player`
+pub fn spawn_player_eyes(
+    commands: &mut Commands,
+    player: Entity,
+    mesh: &Handle<Mesh>,
+    material: &Handle<ColorMaterial>,
+) {
+    for offset in PLAYER_EYE_OFFSETS {
+        commands.spawn((
+            Mesh2d(mesh.clone()),
+            MeshMaterial2d(material.clone()),
+            // Just in front of the player's body
+            Transform::from_translation(offset.extend(0.1)),
+            PlayerEye,
+            ChildOf(player),
+        ));
+    }
+}
+
+/// System to move the player's animation between active and idle: any held gameplay input
+/// keeps it active, and PLAYER_IDLE_DELAY seconds without one make it idle
+pub fn update_player_animation_state(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut animation_query: Query<&mut PlayerAnimation, With<Player>>,
+) {
+    let any_input = InputAction::ALL
+        .iter()
+        .any(|action| action.pressed(&keyboard_input));
+
+    for mut animation in &mut animation_query {
+        animation.state_time += time.delta_secs();
+        if any_input {
+            animation.idle_timer = 0.0;
+            if animation.state != PlayerAnimState::Active {
+                animation.enter(PlayerAnimState::Active);
+            }
+            continue;
+        }
+
+        animation.idle_timer += time.delta_secs();
+        if animation.state == PlayerAnimState::Active && animation.idle_timer >= PLAYER_IDLE_DELAY {
+            animation.enter(PlayerAnimState::Idle);
+        }
+    }
+}
+
+/// System to play the idle animation: the player squashes slightly with each breath and shuts
+/// its eyes every PLAYER_BLINK_INTERVAL. The eyes also look the way the player faces.
+pub fn animate_player(
+    time: Res<Time>,
+    mut player_query: Query<
+        (
+            &mut PlayerAnimation,
+            &PlayerVelocity,
+            &mut Transform,
+            &Children,
+        ),
+        With<Player>,
+    >,
+    mut eye_query: Query<&mut Transform, (With<PlayerEye>, Without<Player>)>,
+) {
+    for (mut animation, velocity, mut transform, children) in &mut player_query {
+        let mut breath = 1.0;
+        let mut eyes_open = true;
+        if animation.state == PlayerAnimState::Idle {
+            let phase = animation.state_time / PLAYER_BREATH_PERIOD * std::f32::consts::TAU;
+            breath = 1.0 - PLAYER_BREATH_SQUASH * 0.5 * (1.0 - phase.cos());
+
+            animation.blink_timer -= time.delta_secs();
+            if animation.blink_timer <= 0.0 {
+                animation.blink_timer += PLAYER_BLINK_INTERVAL;
+            }
+            eyes_open = animation.blink_timer > PLAYER_BLINK_DURATION;
+        }
+        if transform.scale.y != breath {
+            transform.scale.y = breath;
+        }
+
+        let mut eyes = eye_query.iter_many_mut(children);
+        let mut offsets = PLAYER_EYE_OFFSETS.iter();
+        while let (Some(mut eye), Some(offset)) = (eyes.fetch_next(), offsets.next()) {
+            // Aiming straight up keeps the eyes on the side they were looking at
+            let side = match velocity.facing_direction.x {
+                x if x < 0.0 => -1.0,
+                x if x > 0.0 => 1.0,
+                _ => eye.translation.x.signum(),
+            };
+            eye.translation.x = offset.x * side;
+            eye.scale.y = if eyes_open { 1.0 } else { 0.2 };
+        }
+    }
+}
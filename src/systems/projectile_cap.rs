@@ -0,0 +1,222 @@
+use crate::components::gameplay::Lifetime;
+use crate::plugins::camera_plugin::GameCamera;
+use crate::stages::game_menu::GameState;
+use crate::systems::boss::BossProjectile;
+use crate::systems::config::PROJECTILE_CAP_LEAVING_TIME;
+use crate::systems::game_config::GameConfig;
+use bevy::prelude::*;
+
+/// Resource with the boss projectile counts, shown in the debug overlay and on the pattern
+/// preview's status line
+#[derive(Resource, Default)]
+pub struct ProjectileCapStats {
+    pub active: usize, // Boss projectiles in play after this frame's culling
+    pub peak: usize,   // Most boss projectiles in play at once this fight (before culling)
+    pub culled: u32,   // Boss projectiles culled this fight for going over the cap
+}
+
+impl ProjectileCapStats {
+    /// One-line summary of the counts against `cap` (0 = no cap)
+    pub fn summary(&self, cap: usize) -> String {
+        let cap = match cap {
+            0 => "no cap".to_string(),
+            cap => format!("cap {}", cap),
+        };
+        format!(
+            "projectiles {} (peak {}, {}, {} culled)",
+            self.active, self.peak, cap, self.culled
+        )
+    }
+}
+
+/// Marker component for boss projectiles the cap leaves alone (the benchmark's filler projectiles)
+#[derive(Component)]
+pub struct UncappedProjectile;
+
+/// Whether a projectile at `position` flying at `velocity` is on its way out of the camera `view`:
+/// already outside it and not heading back in, or about to cross its edge within
+/// `PROJECTILE_CAP_LEAVING_TIME` seconds. Gravity and turning are left out of the heading.
+pub fn is_leaving_view(position: Vec2, velocity: Vec2, view: Rect) -> bool {
+    let heading_out =
+        |p: f32, v: f32, min: f32, max: f32| (p < min && v <= 0.0) || (p > max && v >= 0.0);
+    if heading_out(position.x, velocity.x, view.min.x, view.max.x)
+        || heading_out(position.y, velocity.y, view.min.y, view.max.y)
+    {
+        return true;
+    }
+    if !view.contains(position) {
+        return false; // Off screen, but heading back in
+    }
+
+    // Time until the straight path crosses the view's edge on each axis
+    let exit_time = |p: f32, v: f32, min: f32, max: f32| {
+        if v > 0.0 {
+            (max - p) / v
+        } else if v < 0.0 {
+            (min - p) / v
+        } else {
+            f32::INFINITY
+        }
+    };
+    let exit_x = exit_time(position.x, velocity.x, view.min.x, view.max.x);
+    let exit_y = exit_time(position.y, velocity.y, view.min.y, view.max.y);
+    exit_x.min(exit_y) <= PROJECTILE_CAP_LEAVING_TIME
+}
+
+/// World-space rect the game camera shows
+fn camera_view(transform: &Transform, projection: &Projection) -> Option<Rect> {
+    let Projection::Orthographic(orthographic) = projection else {
+        return None;
+    };
+    let center = transform.translation.truncate();
+    Some(Rect::from_corners(
+        orthographic.area.min + center,
+        orthographic.area.max + center,
+    ))
+}
+
+/// System to keep the boss projectiles in play under `GameConfig::boss_projectile_cap`. Past the
+/// cap, the oldest projectiles on their way out of the camera view (no longer a threat) are
+/// culled first, then the oldest of the rest.
+pub fn cap_boss_projectiles(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    mut stats: ResMut<ProjectileCapStats>,
+    projectile_query: Query<
+        (Entity, &Transform, &BossProjectile, &Lifetime),
+        Without<UncappedProjectile>,
+    >,
+    camera_query: Query<(&Transform, &Projection), With<GameCamera>>,
+) {
+    let count = projectile_query.iter().count();
+    stats.peak = stats.peak.max(count);
+    stats.active = count;
+    let cap = config.boss_projectile_cap;
+    if cap == 0 || count <= cap {
+        return;
+    }
+
+    let view = camera_query
+        .single()
+        .ok()
+        .and_then(|(transform, projection)| camera_view(transform, projection));
+    let mut candidates: Vec<(Entity, bool, f32)> = projectile_query
+        .iter()
+        .map(|(entity, transform, projectile, lifetime)| {
+            let position = transform.translation.truncate();
+            let leaving =
+                view.is_some_and(|view| is_leaving_view(position, projectile.velocity, view));
+            (entity, leaving, lifetime.remaining)
+        })
+        .collect();
+    // Leaving projectiles first, then the ones with the least lifetime left (the oldest)
+    candidates.sort_by(|a, b| b.1.cmp(&a.1).then(a.2.total_cmp(&b.2)));
+
+    let excess = count - cap;
+    for (entity, _, _) in candidates.into_iter().take(excess) {
+        commands.entity(entity).try_despawn();
+    }
+    stats.active = cap;
+    stats.culled += excess as u32;
+}
+
+/// System to start the projectile counts over for a new fight
+pub fn reset_projectile_cap_stats(mut stats: ResMut<ProjectileCapStats>) {
+    *stats = ProjectileCapStats::default();
+}
+
+/// Marker component for the debug overlay with the projectile counts
+#[derive(Component)]
+pub struct ProjectileCapOverlay;
+
+/// System to show or hide the projectile count debug overlay when F3 is pressed during a fight
+pub fn toggle_projectile_cap_overlay(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    overlay_query: Query<Entity, With<ProjectileCapOverlay>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F3) {
+        return;
+    }
+    if let Ok(overlay) = overlay_query.single() {
+        commands.entity(overlay).despawn();
+        return;
+    }
+
+    commands.spawn((
+        DespawnOnExit(GameState::InGame),
+        Node {
+            position_type: PositionType::Absolute,
+            right: px(10.0),
+            top: px(10.0),
+            padding: UiRect::all(px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+        Text::default(),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        ProjectileCapOverlay,
+    ));
+}
+
+/// System to refresh the projectile counts in the debug overlay
+pub fn update_projectile_cap_overlay(
+    config: Res<GameConfig>,
+    stats: Res<ProjectileCapStats>,
+    mut overlay_query: Query<&mut Text, With<ProjectileCapOverlay>>,
+) {
+    for mut text in &mut overlay_query {
+        text.0 = format!("Boss {}", stats.summary(config.boss_projectile_cap));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view() -> Rect {
+        Rect::new(-640.0, -360.0, 640.0, 360.0)
+    }
+
+    #[test]
+    fn projectile_near_the_edge_heading_out_is_leaving() {
+        assert!(is_leaving_view(
+            Vec2::new(600.0, 0.0),
+            Vec2::new(200.0, 0.0),
+            view()
+        ));
+    }
+
+    #[test]
+    fn projectile_heading_across_the_view_is_not_leaving() {
+        assert!(!is_leaving_view(
+            Vec2::new(600.0, 0.0),
+            Vec2::new(-200.0, 0.0),
+            view()
+        ));
+        assert!(!is_leaving_view(Vec2::ZERO, Vec2::new(0.0, 200.0), view()));
+    }
+
+    #[test]
+    fn projectile_off_screen_is_leaving_unless_heading_back_in() {
+        assert!(is_leaving_view(
+            Vec2::new(700.0, 0.0),
+            Vec2::new(0.0, 200.0),
+            view()
+        ));
+        assert!(!is_leaving_view(
+            Vec2::new(700.0, 0.0),
+            Vec2::new(-200.0, 0.0),
+            view()
+        ));
+    }
+
+    #[test]
+    fn still_projectile_inside_the_view_is_not_leaving() {
+        assert!(!is_leaving_view(Vec2::new(639.0, 0.0), Vec2::ZERO, view()));
+    }
+}
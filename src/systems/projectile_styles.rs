@@ -0,0 +1,201 @@
+use crate::components::boss::BossType;
+use crate::components::player::Projectile;
+use crate::plugins::audio_plugin::positional_sound;
+use crate::plugins::camera_plugin::EFFECTS_RENDER_LAYER;
+use crate::plugins::particle_plugin::{FeedbackParticle, FeedbackParticleAssets};
+use crate::systems::boss::BossProjectile;
+use crate::systems::config::PROJECTILE_STYLES_PATH;
+use crate::systems::player::ProjectileHit;
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Shape of a projectile's mesh (all drawn at the same size, then scaled to the shot's size)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum ProjectileShape {
+    #[default]
+    Square,
+    Circle,
+    Diamond,
+}
+
+/// Puffs a projectile leaves behind as it flies
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct TrailStyle {
+    pub interval: f32, // Seconds between puffs
+}
+
+/// Burst of particles where a projectile hits
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct ImpactStyle {
+    pub count: u32,
+    pub speed: f32, // Units/second the particles fly out at
+}
+
+/// How the shots of one weapon look and sound, and how the player looks with it equipped
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ProjectileStyle {
+    #[serde(default)]
+    pub weapon: Option<BossType>, // Boss weapon this style is for (None = default shot)
+    #[serde(default)]
+    pub shape: ProjectileShape,
+    #[serde(default)]
+    pub color: Option<[f32; 3]>, // sRGB color (None = palette color, or the boss's color for boss weapons)
+    #[serde(default)]
+    pub player_color: Option<[f32; 3]>, // sRGB color of the player with this weapon equipped (None = blended)
+    #[serde(default)]
+    pub trail: Option<TrailStyle>,
+    #[serde(default)]
+    pub impact: Option<ImpactStyle>,
+    #[serde(default)]
+    pub sound: Option<String>, // Sound played when the shot is fired (relative to the game directory)
+    #[serde(skip)]
+    pub sound_handle: Option<Handle<AudioSource>>, // Loaded `sound` (None if the file is missing)
+}
+
+impl ProjectileStyle {
+    /// The style's color, or `fallback` if it doesn't set one
+    pub fn color_or(&self, fallback: Color) -> Color {
+        self.color
+            .map_or(fallback, |[r, g, b]| Color::srgb(r, g, b))
+    }
+}
+
+/// Style of weapons without an entry in the styles file
+static DEFAULT_PROJECTILE_STYLE: ProjectileStyle = ProjectileStyle {
+    weapon: None,
+    shape: ProjectileShape::Square,
+    color: None,
+    player_color: None,
+    trail: None,
+    impact: None,
+    sound: None,
+    sound_handle: None,
+};
+
+/// Resource with the projectile style of every weapon, loaded from `PROJECTILE_STYLES_PATH`
+#[derive(Resource, Default, Deserialize)]
+pub struct ProjectileStyles {
+    pub styles: Vec<ProjectileStyle>,
+}
+
+impl ProjectileStyles {
+    /// Load the styles from a JSON file
+    pub fn load_from_file(file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let json_content = std::fs::read_to_string(file_path)?;
+        Ok(serde_json::from_str(&json_content)?)
+    }
+
+    /// Style of the shots fired by `weapon` (None = default shot)
+    pub fn get(&self, weapon: Option<BossType>) -> &ProjectileStyle {
+        self.styles
+            .iter()
+            .find(|style| style.weapon == weapon)
+            .unwrap_or(&DEFAULT_PROJECTILE_STYLE)
+    }
+}
+
+/// Component for a projectile leaving a trail of puffs behind it
+#[derive(Component, Default)]
+pub struct ProjectileTrail {
+    pub style: Option<TrailStyle>, // None = no trail
+    pub timer: f32,                // Seconds until the next puff
+}
+
+/// Loads the projectile styles and their sounds at startup (every shot keeps the plain default
+/// look if the file is missing or invalid)
+pub fn load_projectile_styles(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let mut styles = match ProjectileStyles::load_from_file(PROJECTILE_STYLES_PATH) {
+        Ok(styles) => styles,
+        Err(e) => {
+            warn!(
+                "Using default projectile styles ({}: {})",
+                PROJECTILE_STYLES_PATH, e
+            );
+            ProjectileStyles::default()
+        }
+    };
+    for style in &mut styles.styles {
+        style.sound_handle = style
+            .sound
+            .clone()
+            .filter(|path| std::path::Path::new(path).exists())
+            .map(|path| asset_server.load(path));
+    }
+    commands.insert_resource(styles);
+}
+
+/// Plays the firing sound of `style` at `position`, if it has one
+pub fn play_projectile_sound(commands: &mut Commands, style: &ProjectileStyle, position: Vec2) {
+    if let Some(sound) = &style.sound_handle {
+        commands.spawn(positional_sound(sound.clone(), position));
+    }
+}
+
+/// System to leave trail puffs (in the shot's own color) behind projectiles with a trail
+pub fn emit_projectile_trails(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<FeedbackParticleAssets>,
+    mut trail_query: Query<(
+        &Transform,
+        &MeshMaterial2d<ColorMaterial>,
+        &mut ProjectileTrail,
+    )>,
+) {
+    for (transform, material, mut trail) in &mut trail_query {
+        let Some(style) = trail.style else {
+            continue;
+        };
+        trail.timer -= time.delta_secs();
+        if trail.timer > 0.0 {
+            continue;
+        }
+        trail.timer = style.interval;
+        commands.spawn((
+            Mesh2d(assets.mesh.clone()),
+            material.clone(),
+            Transform::from_translation(transform.translation.with_z(0.0)),
+            RenderLayers::layer(EFFECTS_RENDER_LAYER),
+            FeedbackParticle {
+                velocity: Vec2::ZERO,
+                age: 0.0,
+            },
+        ));
+    }
+}
+
+/// System to burst particles (in the shot's own color) where player shots hit. Runs before the
+/// spent projectiles are despawned.
+pub fn spawn_projectile_impacts(
+    mut commands: Commands,
+    mut projectile_hit: MessageReader<ProjectileHit>,
+    styles: Res<ProjectileStyles>,
+    assets: Res<FeedbackParticleAssets>,
+    projectile_query: Query<
+        (&Transform, &Projectile, &MeshMaterial2d<ColorMaterial>),
+        Without<BossProjectile>,
+    >,
+) {
+    for ProjectileHit(projectile) in projectile_hit.read() {
+        let Ok((transform, projectile, material)) = projectile_query.get(*projectile) else {
+            continue;
+        };
+        let Some(impact) = styles.get(projectile.weapon).impact else {
+            continue;
+        };
+        for i in 0..impact.count {
+            let angle = std::f32::consts::TAU * i as f32 / impact.count as f32;
+            commands.spawn((
+                Mesh2d(assets.mesh.clone()),
+                material.clone(),
+                Transform::from_translation(transform.translation.with_z(0.0)),
+                RenderLayers::layer(EFFECTS_RENDER_LAYER),
+                FeedbackParticle {
+                    velocity: Vec2::from_angle(angle) * impact.speed,
+                    age: 0.0,
+                },
+            ));
+        }
+    }
+}
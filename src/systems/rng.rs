@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+
+/// Seeded random number generator shared by every randomized system (drop tables,
+/// pattern randomness, upgrade offerings). Reseeded at the start of each run, so a run
+/// replays identically from its seed (recorded in `RunStats`).
+#[derive(Resource, Debug, Clone)]
+pub struct GameRng {
+    seed: u64,
+    state: u64,
+    /// Seed forced with `--seed <n>`; every run uses it instead of a fresh one
+    fixed_seed: Option<u64>,
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl GameRng {
+    /// Create a generator, using `fixed_seed` for every run if given
+    pub fn new(fixed_seed: Option<u64>) -> Self {
+        let seed = fixed_seed.unwrap_or_else(entropy_seed);
+        Self {
+            seed,
+            state: seed,
+            fixed_seed,
+        }
+    }
+
+    /// Read a `--seed <n>` override from the command line arguments
+    pub fn from_args() -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        let fixed_seed = args
+            .iter()
+            .position(|arg| arg == "--seed")
+            .and_then(|index| args.get(index + 1))
+            .and_then(|value| match value.parse() {
+                Ok(seed) => Some(seed),
+                Err(_) => {
+                    warn!("Ignoring invalid --seed value '{}'", value);
+                    None
+                }
+            });
+        Self::new(fixed_seed)
+    }
+
+    /// Restart the sequence for a new run (the fixed seed if set, otherwise a fresh one).
+    /// Returns the seed now in use.
+    pub fn reseed(&mut self) -> u64 {
+        self.seed = self.fixed_seed.unwrap_or_else(entropy_seed);
+        self.state = self.seed;
+        self.seed
+    }
+
+    /// Restart the sequence from a given seed (daily challenges), ignoring the fixed seed.
+    /// Returns the seed now in use.
+    pub fn reseed_to(&mut self, seed: u64) -> u64 {
+        self.seed = seed;
+        self.state = seed;
+        self.seed
+    }
+
+    /// Next raw value (SplitMix64, which is well distributed for any seed including 0)
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Random number in 0.0..1.0
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Random index picked in proportion to `weights` (negative weights count as 0).
+    /// Returns None when every weight is 0.
+    pub fn weighted_index(&mut self, weights: &[f32]) -> Option<usize> {
+        let total: f32 = weights.iter().map(|weight| weight.max(0.0)).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut roll = self.next_f32() * total;
+        for (index, weight) in weights.iter().enumerate() {
+            let weight = weight.max(0.0);
+            if roll < weight {
+                return Some(index);
+            }
+            roll -= weight;
+        }
+        // Float rounding can leave the roll just past the end; fall back to the last weighted entry
+        weights.iter().rposition(|weight| *weight > 0.0)
+    }
+}
+
+/// Seed from the system clock, for runs without a fixed seed
+fn entropy_seed() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_nanos() as u64)
+        .unwrap_or(0)
+}
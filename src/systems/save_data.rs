@@ -0,0 +1,211 @@
+use crate::plugins::medal_plugin::Medal;
+use crate::stages::game_menu::{PlayerUpgrades, SelectedCharacter};
+use crate::systems::config::{MAX_STAGES, SAVE_FILE_PATH, SECRET_TOKENS_PER_STAGE};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Best daily challenge result for one day
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DailyRecord {
+    pub day: u64, // Days since the Unix epoch (UTC)
+    pub seed: u64,
+    pub best_score: u32,
+    pub attempts: u32,
+}
+
+/// Secret token found in a stage (`index` into the stage pattern's `secret_tokens`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FoundToken {
+    pub stage: u32,
+    pub index: usize,
+}
+
+/// Fastest full clear of a stage, and the best medal earned in it
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StageClearRecord {
+    pub stage: u32,
+    pub best_time: f32,
+    pub medal: Option<Medal>,
+}
+
+/// Crumb of Death leaderboard entry: the best score over every run of the mode
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CrumbOfDeathRecord {
+    pub best_score: u32,
+    pub attempts: u32,
+    pub cleared: bool, // A Crumb of Death run was won
+}
+
+/// Snapshot of a run in progress, taken when each stage starts, so the main menu can continue it
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SavedRun {
+    pub stage: u32,
+    pub character: SelectedCharacter,
+    pub upgrades: PlayerUpgrades,
+    pub lives: u32,
+    pub seed: u64,
+    pub deaths: u32,
+    pub bosses_defeated: u32,
+}
+
+/// Persistent progress, saved to `SAVE_FILE_PATH` (JSON) whenever a run ends, once the story intro
+/// was seen, whenever a stage of a regular run starts and whenever a secret token is found or a
+/// stage is cleared
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaveData {
+    pub daily: Vec<DailyRecord>, // Daily leaderboard, one entry per day played
+    pub endless_high_score: u32, // Most bosses defeated in one endless mode run
+    pub intro_seen: bool,        // The story intro was played (it only plays on a fresh save)
+    pub saved_run: Option<SavedRun>, // Regular run to continue from the main menu (None once it ends)
+    pub secret_tokens: Vec<FoundToken>, // Secret tokens found across all runs
+    pub clear_records: Vec<StageClearRecord>, // Best clear time and medal of every stage cleared
+    pub game_cleared: bool,          // A run was won (unlocks Crumb of Death mode)
+    pub crumb_of_death: CrumbOfDeathRecord,
+}
+
+impl SaveData {
+    /// Load save data from a JSON file
+    pub fn load_from_file(file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let json_content = std::fs::read_to_string(file_path)?;
+        Ok(serde_json::from_str(&json_content)?)
+    }
+
+    /// Save save data to a JSON file
+    pub fn save_to_file(&self, file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        std::fs::write(file_path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Load the save file, starting fresh if it is missing or invalid
+    pub fn load_or_default() -> Self {
+        match Self::load_from_file(SAVE_FILE_PATH) {
+            Ok(save_data) => save_data,
+            Err(e) => {
+                info!("No save data loaded from {}: {}", SAVE_FILE_PATH, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Best score recorded for `day`, if it was played
+    pub fn daily_best(&self, day: u64) -> Option<u32> {
+        self.daily
+            .iter()
+            .find(|record| record.day == day)
+            .map(|record| record.best_score)
+    }
+
+    /// Record a finished daily challenge run, keeping the day's best score
+    pub fn record_daily(&mut self, day: u64, seed: u64, score: u32) {
+        match self.daily.iter_mut().find(|record| record.day == day) {
+            Some(record) => {
+                record.best_score = record.best_score.max(score);
+                record.attempts += 1;
+            }
+            None => self.daily.push(DailyRecord {
+                day,
+                seed,
+                best_score: score,
+                attempts: 1,
+            }),
+        }
+    }
+
+    pub fn has_secret_token(&self, stage: u32, index: usize) -> bool {
+        self.secret_tokens.contains(&FoundToken { stage, index })
+    }
+
+    /// Number of secret tokens found in `stage`
+    pub fn secret_tokens_in(&self, stage: u32) -> usize {
+        self.secret_tokens
+            .iter()
+            .filter(|token| token.stage == stage)
+            .count()
+    }
+
+    /// Whether every regular stage's secret tokens have been found (unlocks the bonus potions)
+    pub fn all_secret_tokens_found(&self) -> bool {
+        (1..=MAX_STAGES).all(|stage| self.secret_tokens_in(stage) >= SECRET_TOKENS_PER_STAGE)
+    }
+
+    /// Record a found secret token, returning whether it was new
+    pub fn record_secret_token(&mut self, stage: u32, index: usize) -> bool {
+        let new = !self.has_secret_token(stage, index);
+        if new {
+            self.secret_tokens.push(FoundToken { stage, index });
+        }
+        new
+    }
+
+    pub fn clear_record(&self, stage: u32) -> Option<&StageClearRecord> {
+        self.clear_records
+            .iter()
+            .find(|record| record.stage == stage)
+    }
+
+    /// Record a full clear of `stage`, keeping its best time and medal. Returns whether the time
+    /// was a new best.
+    pub fn record_clear(&mut self, stage: u32, time: f32, medal: Option<Medal>) -> bool {
+        match self
+            .clear_records
+            .iter_mut()
+            .find(|record| record.stage == stage)
+        {
+            Some(record) => {
+                record.medal = record.medal.max(medal);
+                let new_best = time < record.best_time;
+                if new_best {
+                    record.best_time = time;
+                }
+                new_best
+            }
+            None => {
+                self.clear_records.push(StageClearRecord {
+                    stage,
+                    best_time: time,
+                    medal,
+                });
+                true
+            }
+        }
+    }
+
+    /// Record a finished endless run, returning whether it set a new high score
+    pub fn record_endless(&mut self, bosses_defeated: u32) -> bool {
+        let new_best = bosses_defeated > self.endless_high_score;
+        if new_best {
+            self.endless_high_score = bosses_defeated;
+        }
+        new_best
+    }
+
+    /// Record a finished Crumb of Death run, returning whether it set a new best score
+    pub fn record_crumb_of_death(&mut self, score: u32, won: bool) -> bool {
+        let record = &mut self.crumb_of_death;
+        let new_best = record.attempts == 0 || score > record.best_score;
+        if new_best {
+            record.best_score = score;
+        }
+        record.attempts += 1;
+        record.cleared |= won;
+        new_best
+    }
+}
+
+/// Today's date as days since the Unix epoch (UTC), which picks the daily challenge
+pub fn current_day() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs() / 86_400)
+        .unwrap_or(0)
+}
+
+/// Fixed run seed for a day's challenge (the same for every player on that date)
+pub fn daily_seed(day: u64) -> u64 {
+    // One SplitMix64 step, so consecutive days get unrelated seeds
+    let mut z = day.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,226 @@
+use crate::components::boss::{
+    Boss, BossAttackState, BossData, BossMovementState, BossPhaseState, BossSpawnIndex, BossType,
+};
+use crate::components::gameplay::{GameplayEntity, Lifetime};
+use crate::components::player::{
+    CharacterKit, ChargeShot, DashCooldown, Footsteps, Hp, JumpCharge, JumpType, Player,
+    PlayerVelocity, Projectile, Regeneration, Shooting,
+};
+use crate::stages::game_menu::{PlayerUpgrades, SelectedCharacter};
+use crate::systems::config::PROJECTILE_LIFETIME;
+use crate::systems::player::{PROJECTILE_MESH_SIZE, ProjectileAssets};
+use crate::systems::player_animation::{PLAYER_EYE_SIZE, PlayerAnimation, spawn_player_eyes};
+use crate::systems::projectile_styles::{ProjectileStyle, ProjectileTrail};
+use bevy::prelude::*;
+
+/// Max HP of the player before upgrades
+pub const PLAYER_BASE_MAX_HP: f32 = 100.0;
+/// Where the player starts a fight: on top of the floor (floor top -230 + half-height 32)
+pub const PLAYER_SPAWN_POSITION: Vec3 = Vec3::new(0.0, -198.0, 1.0);
+/// Where the first boss starts a fight: on the right side, at the player's height
+pub const BOSS_SPAWN_POSITION: Vec2 = Vec2::new(300.0, -198.0);
+/// Size of the player character's rectangle
+pub const PLAYER_SIZE: Vec2 = Vec2::new(32.0, 64.0);
+
+/// Meshes, materials and kit of the player character
+pub struct PlayerAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<ColorMaterial>,
+    pub eye_mesh: Handle<Mesh>,
+    pub eye_material: Handle<ColorMaterial>,
+    pub kit: CharacterKit,
+}
+
+impl PlayerAssets {
+    /// The player's rectangle (with its eyes), in the selected character's color, with that
+    /// character's kit
+    pub fn new(
+        meshes: &mut Assets<Mesh>,
+        materials: &mut Assets<ColorMaterial>,
+        character: SelectedCharacter,
+    ) -> Self {
+        Self {
+            mesh: meshes.add(Rectangle::from_size(PLAYER_SIZE)),
+            material: materials.add(character.color()),
+            eye_mesh: meshes.add(Rectangle::from_size(PLAYER_EYE_SIZE)),
+            eye_material: materials.add(Color::srgb(0.1, 0.1, 0.1)),
+            kit: character.kit(),
+        }
+    }
+}
+
+/// Spawns the player at `position`, with the max HP bonus of `upgrades` and the HP
+/// carried over from the last stage (full HP without upgrades)
+pub fn spawn_player(
+    commands: &mut Commands,
+    assets: &PlayerAssets,
+    upgrades: Option<&PlayerUpgrades>,
+    position: Vec3,
+) -> Entity {
+    let max_hp = PLAYER_BASE_MAX_HP + upgrades.map_or(0.0, |u| u.max_hp_bonus);
+    // Current HP can't be over the new max HP
+    let current_hp = upgrades.map_or(max_hp, |u| u.current_hp.min(max_hp));
+
+    let player = commands
+        .spawn((
+            GameplayEntity,
+            Mesh2d(assets.mesh.clone()),
+            MeshMaterial2d(assets.material.clone()),
+            Transform::from_translation(position),
+            Player,
+            assets.kit,
+            Hp {
+                current: current_hp,
+                max: max_hp,
+            },
+            PlayerVelocity {
+                y: 0.0,
+                jump_type: JumpType::None,
+                facing_direction: Vec2::new(1.0, 0.0),
+            },
+            JumpCharge {
+                timer: 0.0,
+                is_charging: false,
+            },
+            Regeneration { delay_timer: 0.0 },
+            Shooting { timer: 0.0 },
+            DashCooldown { timer: 0.0 },
+            Footsteps { timer: 0.0 },
+            ChargeShot {
+                timer: 0.0,
+                is_charging: false,
+            },
+            PlayerAnimation::default(),
+        ))
+        .id();
+    spawn_player_eyes(commands, player, &assets.eye_mesh, &assets.eye_material);
+    player
+}
+
+/// Components of a projectile, drawn with one of the shared projectile meshes (boss projectiles
+/// also need their `BossProjectile` component)
+#[derive(Bundle)]
+pub struct ProjectileBundle {
+    pub mesh: Mesh2d,
+    pub material: MeshMaterial2d<ColorMaterial>,
+    pub transform: Transform,
+    pub projectile: Projectile,
+    pub lifetime: Lifetime,
+    pub trail: ProjectileTrail,
+}
+
+impl ProjectileBundle {
+    /// A player shot of side `size` fired from `position`, shaped and trailed as `style` says
+    /// (`weapon` is the boss weapon that fired it, None for the default shot)
+    pub fn player(
+        assets: &ProjectileAssets,
+        style: &ProjectileStyle,
+        material: Handle<ColorMaterial>,
+        position: Vec2,
+        size: f32,
+        direction: Vec2,
+        charge_level: f32,
+        weapon: Option<BossType>,
+    ) -> Self {
+        Self {
+            mesh: Mesh2d(assets.mesh(style.shape)),
+            material: MeshMaterial2d(material),
+            // The shared mesh is scaled to the shot's size
+            transform: Transform::from_translation(position.extend(0.0))
+                .with_scale(Vec3::splat(size / PROJECTILE_MESH_SIZE)),
+            projectile: Projectile {
+                direction,
+                charge_level,
+                weapon,
+            },
+            lifetime: Lifetime {
+                remaining: PROJECTILE_LIFETIME,
+            },
+            trail: ProjectileTrail {
+                style: style.trail,
+                timer: 0.0,
+            },
+        }
+    }
+
+    /// A boss shot at `position` flying along `velocity` (always uncharged)
+    pub fn boss(
+        mesh: Handle<Mesh>,
+        material: Handle<ColorMaterial>,
+        position: Vec2,
+        velocity: Vec2,
+    ) -> Self {
+        Self {
+            mesh: Mesh2d(mesh),
+            material: MeshMaterial2d(material),
+            transform: Transform::from_translation(position.extend(0.0)),
+            projectile: Projectile {
+                direction: velocity.normalize_or_zero(),
+                charge_level: 0.0,
+                weapon: None,
+            },
+            lifetime: Lifetime {
+                remaining: PROJECTILE_LIFETIME,
+            },
+            trail: ProjectileTrail::default(),
+        }
+    }
+}
+
+/// Components of a boss at the start of its fight
+#[derive(Bundle)]
+pub struct BossBundle {
+    pub gameplay: GameplayEntity,
+    pub mesh: Mesh2d,
+    pub material: MeshMaterial2d<ColorMaterial>,
+    pub transform: Transform,
+    pub boss: Boss,
+    pub boss_type: BossType,
+    pub data: BossData,
+    pub hp: Hp,
+    pub attack_state: BossAttackState,
+    pub movement_state: BossMovementState,
+    pub phase_state: BossPhaseState,
+    pub spawn_index: BossSpawnIndex,
+}
+
+impl BossBundle {
+    /// A boss described by `data` with full `max_hp`, standing at `position` on the floor
+    pub fn from_data(
+        data: &BossData,
+        mesh: Handle<Mesh>,
+        material: Handle<ColorMaterial>,
+        position: Vec2,
+        max_hp: f32,
+    ) -> Self {
+        Self {
+            gameplay: GameplayEntity,
+            mesh: Mesh2d(mesh),
+            material: MeshMaterial2d(material),
+            transform: Transform::from_translation(position.extend(1.0)),
+            boss: Boss,
+            boss_type: data.boss_type,
+            data: data.clone(),
+            hp: Hp {
+                current: max_hp,
+                max: max_hp,
+            },
+            attack_state: BossAttackState::default(),
+            movement_state: BossMovementState::default(),
+            phase_state: BossPhaseState::default(),
+            spawn_index: BossSpawnIndex::default(),
+        }
+    }
+
+    /// Holds the boss's first attack back by `delay` seconds
+    pub fn with_attack_delay(mut self, delay: f32) -> Self {
+        self.attack_state.timer = delay;
+        self
+    }
+
+    /// Marks the boss as the `index`th spawned in its fight
+    pub fn with_spawn_index(mut self, index: usize) -> Self {
+        self.spawn_index = BossSpawnIndex(index);
+        self
+    }
+}
@@ -0,0 +1,168 @@
+use crate::systems::boss::Vec2Config;
+use crate::systems::config::{BOUNDARY_BOTTOM, STAGE_LAYOUT_DIR};
+use crate::systems::hazards::HazardKind;
+use crate::systems::platforms::SurfaceMaterial;
+use serde::{Deserialize, Serialize};
+
+/// JSON structure for a solid platform (center position and size in world units). `material`
+/// sets its footstep sounds and landing dust.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlatformConfig {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    #[serde(default)]
+    pub material: SurfaceMaterial,
+}
+
+/// JSON structure for a ladder, standing at `x` from `bottom` up to `top` (its top is a ledge)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LadderConfig {
+    pub x: f32,
+    pub bottom: f32,
+    pub top: f32,
+}
+
+/// JSON structure for a water zone, a volume centered at (`x`, `y`) with altered physics inside
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaterConfig {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// JSON structure for a breakable block (center position and size in world units). Blocks are solid
+/// until shot down; `pickup` hides a shield pickup inside.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlockConfig {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    #[serde(default)]
+    pub hp: Option<f32>,
+    #[serde(default)]
+    pub pickup: bool,
+}
+
+/// JSON structure for a wind zone centered at (`x`, `y`), pushing whatever is inside sideways at
+/// `speed` (pixels per second, negative blows left)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WindConfig {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub speed: f32,
+}
+
+/// JSON structure for a conveyor floor (centered at `x` on the floor), carrying whatever stands on
+/// it along at `speed` (pixels per second, negative runs left)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConveyorConfig {
+    pub x: f32,
+    pub width: f32,
+    pub speed: f32,
+}
+
+/// JSON structure for a permanent ground hazard placed in the arena (centered at `x` on the floor)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlacedHazardConfig {
+    pub x: f32,
+    #[serde(default)]
+    pub kind: HazardKind,
+    pub width: f32,
+    pub damage_per_second: f32,
+}
+
+/// JSON structure for a stage arena layout (platforms, breakable blocks, ladders, water, wind,
+/// conveyors, hazards, spawn points and what the floor is made of)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageLayout {
+    #[serde(default)]
+    pub floor_material: SurfaceMaterial,
+    #[serde(default)]
+    pub platforms: Vec<PlatformConfig>,
+    #[serde(default)]
+    pub blocks: Vec<BlockConfig>,
+    #[serde(default)]
+    pub ladders: Vec<LadderConfig>,
+    #[serde(default)]
+    pub water: Vec<WaterConfig>,
+    #[serde(default)]
+    pub wind: Vec<WindConfig>,
+    #[serde(default)]
+    pub conveyors: Vec<ConveyorConfig>,
+    #[serde(default)]
+    pub hazards: Vec<PlacedHazardConfig>,
+    #[serde(default = "default_player_spawn")]
+    pub player_spawn: Vec2Config,
+    #[serde(default = "default_boss_spawn")]
+    pub boss_spawn: Vec2Config,
+}
+
+fn default_player_spawn() -> Vec2Config {
+    Vec2Config {
+        x: -300.0,
+        y: BOUNDARY_BOTTOM,
+    }
+}
+
+fn default_boss_spawn() -> Vec2Config {
+    Vec2Config {
+        x: 300.0,
+        y: BOUNDARY_BOTTOM,
+    }
+}
+
+impl Default for StageLayout {
+    fn default() -> Self {
+        Self {
+            floor_material: SurfaceMaterial::default(),
+            platforms: Vec::new(),
+            blocks: Vec::new(),
+            ladders: Vec::new(),
+            water: Vec::new(),
+            wind: Vec::new(),
+            conveyors: Vec::new(),
+            hazards: Vec::new(),
+            player_spawn: default_player_spawn(),
+            boss_spawn: default_boss_spawn(),
+        }
+    }
+}
+
+/// Path of a stage's layout file (`STAGE_LAYOUT_DIR/stage_N.json`), loaded by its fight and
+/// written by the stage editor
+pub fn stage_layout_path(stage: u32) -> String {
+    format!("{}/stage_{}.json", STAGE_LAYOUT_DIR, stage)
+}
+
+impl StageLayout {
+    /// Load a layout from a JSON string
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Serialize the layout to pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Load a layout from a JSON file path
+    pub fn load_from_file(file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let json = std::fs::read_to_string(file_path)?;
+        Ok(Self::from_json(&json)?)
+    }
+
+    /// Write the layout to a JSON file path (creating its directory if needed)
+    pub fn save_to_file(&self, file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(directory) = std::path::Path::new(file_path).parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+        std::fs::write(file_path, self.to_json()?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use crate::components::gameplay::GameplayEntity;
+use crate::components::player::Player;
+use crate::plugins::camera_plugin::EFFECTS_RENDER_LAYER;
+use crate::plugins::run_plugin::RunStats;
+use crate::stages::game_menu::{CurrentStage, GameState};
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::config::{
+    CURSE_BANNER_TIME, CURSE_BOSS_SPEED_SCALE, CURSE_DARKNESS_ALPHA, CURSE_DARKNESS_RADIUS,
+    CURSE_LOW_GRAVITY_SCALE, PATTERN_PREVIEW_STAGE, TUTORIAL_STAGE,
+};
+use crate::systems::rng::GameRng;
+use bevy::camera::visibility::RenderLayers;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+
+/// Random modifier applied to a whole stage in roguelike mode
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Curse {
+    LowGravity,
+    DoubleBossSpeed,
+    MirroredControls,
+    Darkness,
+}
+
+impl Curse {
+    const ALL: [Curse; 4] = [
+        Curse::LowGravity,
+        Curse::DoubleBossSpeed,
+        Curse::MirroredControls,
+        Curse::Darkness,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Curse::LowGravity => "Low Gravity",
+            Curse::DoubleBossSpeed => "Double Boss Speed",
+            Curse::MirroredControls => "Mirrored Controls",
+            Curse::Darkness => "Darkness",
+        }
+    }
+}
+
+/// Resource holding the curse of the current stage (None outside roguelike mode).
+/// Systems affected by a curse read it through the helper methods.
+#[derive(Resource, Default)]
+pub struct StageModifier {
+    pub curse: Option<Curse>,
+    rolled_for: Option<(u64, u32)>, // (run seed, stage) the curse was rolled for, so retries keep it
+}
+
+impl StageModifier {
+    /// Multiplier for the player's gravity
+    pub fn gravity_scale(&self) -> f32 {
+        if self.curse == Some(Curse::LowGravity) {
+            CURSE_LOW_GRAVITY_SCALE
+        } else {
+            1.0
+        }
+    }
+
+    /// Multiplier for how fast bosses move
+    pub fn boss_speed_scale(&self) -> f32 {
+        if self.curse == Some(Curse::DoubleBossSpeed) {
+            CURSE_BOSS_SPEED_SCALE
+        } else {
+            1.0
+        }
+    }
+
+    /// Whether left and right are swapped
+    pub fn mirrored_controls(&self) -> bool {
+        self.curse == Some(Curse::MirroredControls)
+    }
+}
+
+/// Component for the curse announcement shown as a fight starts
+#[derive(Component)]
+pub struct CurseBanner {
+    pub timer: f32,
+}
+
+/// Component for the darkness around the player (a dark ring with the player at its center)
+#[derive(Component)]
+pub struct DarknessOverlay;
+
+/// Picks the stage's curse when a fight starts. Only regular stages in roguelike mode (or a daily
+/// challenge, which always has curses) are cursed; retrying a stage keeps the curse it was first given.
+pub fn roll_stage_modifier(
+    settings: Res<GameSettings>,
+    current_stage: Res<CurrentStage>,
+    run_stats: Res<RunStats>,
+    mut modifier: ResMut<StageModifier>,
+) {
+    let stage = current_stage.0;
+    let cursed = settings.roguelike_mode || run_stats.daily_day.is_some();
+    if !cursed || stage == TUTORIAL_STAGE || stage == PATTERN_PREVIEW_STAGE {
+        *modifier = StageModifier::default();
+        return;
+    }
+
+    let key = (run_stats.seed, stage);
+    if modifier.rolled_for != Some(key) {
+        // A generator of its own seeded from the run seed and the stage: the run's GameRng moves
+        // on as the run is played, and every daily challenge player must get the same curses
+        let mut rng = GameRng::new(Some(
+            run_stats.seed ^ u64::from(stage).wrapping_mul(0x9E37_79B9_7F4A_7C15),
+        ));
+        let index = (rng.next_u64() % Curse::ALL.len() as u64) as usize;
+        modifier.curse = Some(Curse::ALL[index]);
+        modifier.rolled_for = Some(key);
+        info!("Stage {} curse: {}", stage, Curse::ALL[index].label());
+    }
+}
+
+/// Announces the curse and sets up its visuals
+pub fn spawn_curse_effects(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    modifier: Res<StageModifier>,
+) {
+    let Some(curse) = modifier.curse else {
+        return;
+    };
+
+    commands.spawn((
+        DespawnOnExit(GameState::InGame),
+        Text::new(format!("CURSE: {}", curse.label())),
+        TextFont {
+            font_size: 40.0,
+            ..default()
+        },
+        TextColor(WHITE.into()),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100.0),
+            top: percent(30.0),
+            ..default()
+        },
+        CurseBanner {
+            timer: CURSE_BANNER_TIME,
+        },
+    ));
+
+    if curse == Curse::Darkness {
+        // The ring is big enough to cover the arena wherever the player stands
+        commands.spawn((
+            GameplayEntity,
+            Mesh2d(meshes.add(Annulus::new(CURSE_DARKNESS_RADIUS, 2000.0))),
+            MeshMaterial2d(materials.add(Color::srgba(0.0, 0.0, 0.0, CURSE_DARKNESS_ALPHA))),
+            RenderLayers::layer(EFFECTS_RENDER_LAYER),
+            DarknessOverlay,
+        ));
+    }
+}
+
+/// System to fade out and remove the curse banner
+pub fn update_curse_banner(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut banner_query: Query<(Entity, &mut CurseBanner, &mut TextColor)>,
+) {
+    for (entity, mut banner, mut text_color) in &mut banner_query {
+        banner.timer -= time.delta_secs();
+        if banner.timer <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        // Fade out over the last second
+        text_color.0 = text_color.0.with_alpha(banner.timer.min(1.0));
+    }
+}
+
+/// System to keep the darkness centered on the player
+pub fn follow_player_with_darkness(
+    player_query: Query<&Transform, (With<Player>, Without<DarknessOverlay>)>,
+    mut overlay_query: Query<&mut Transform, With<DarknessOverlay>>,
+) {
+    let Some(player_transform) = player_query.iter().next() else {
+        return;
+    };
+    for mut transform in &mut overlay_query {
+        transform.translation.x = player_transform.translation.x;
+        transform.translation.y = player_transform.translation.y;
+    }
+}
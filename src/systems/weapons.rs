@@ -0,0 +1,517 @@
+use crate::components::boss::{BossData, BossRegistry, BossType};
+use crate::components::player::{Dash, Defeated, Hp, Player, Shooting};
+use crate::stages::game_menu::{GameState, PlayerUpgrades, SelectedCharacter};
+use crate::stages::settings_menu::GameSettings;
+use crate::systems::config::{
+    LIFESTEAL_STAGE_CAP, WEAPON_COMBO_COOLDOWN, WEAPON_COMBO_ENERGY_COST, WEAPON_ENERGY_MAX,
+    WEAPON_RECOLOR_BLEND,
+};
+use crate::systems::feedback::FeedbackEvent;
+use crate::systems::input::InputAction;
+use crate::systems::player::{BossDamaged, ProjectileAssets};
+use crate::systems::projectile_styles::{ProjectileStyles, play_projectile_sound};
+use crate::systems::spawn::ProjectileBundle;
+use bevy::color::palettes::basic::WHITE;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A boss weapon the player has acquired, with its remaining energy
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BossWeapon {
+    pub boss_type: BossType,
+    pub energy: f32, // Remaining energy (0.0 to WEAPON_ENERGY_MAX)
+}
+
+impl BossWeapon {
+    pub fn new(boss_type: BossType) -> Self {
+        Self {
+            boss_type,
+            energy: WEAPON_ENERGY_MAX,
+        }
+    }
+}
+
+/// Resource to track the weapon select wheel (open while Tab is held)
+#[derive(Resource, Default)]
+pub struct WeaponWheel {
+    pub open: bool,
+    pub selected: usize, // 0 = default shot, 1.. = acquired boss weapons in order
+}
+
+/// Resource to track how much HP lifesteal has restored in the current stage (reset on every stage start)
+#[derive(Resource, Default)]
+pub struct LifestealTracker {
+    pub healed_this_stage: f32,
+}
+
+/// Marker component for the weapon wheel overlay UI root
+#[derive(Component)]
+pub struct WeaponWheelOverlay;
+
+/// Component to identify a weapon slot in the wheel (index matches `WeaponWheel::selected`)
+#[derive(Component)]
+pub struct WeaponWheelSlot(pub usize);
+
+/// Run condition: true while the weapon wheel is closed (gates player movement and shooting)
+pub fn weapon_wheel_closed(wheel: Res<WeaponWheel>) -> bool {
+    !wheel.open
+}
+
+fn slot_border_color(is_selected: bool) -> Color {
+    if is_selected {
+        Color::srgb(1.0, 0.9, 0.0) // Glowing border (bright yellow/gold)
+    } else {
+        Color::srgb(0.2, 0.2, 0.3)
+    }
+}
+
+/// Spawns the weapon wheel overlay: one slot per weapon with its icon and remaining energy
+fn spawn_weapon_wheel(
+    commands: &mut Commands,
+    player_upgrades: &PlayerUpgrades,
+    boss_registry: &BossRegistry,
+    settings: &GameSettings,
+    selected: usize,
+) {
+    // Default shot first, then boss weapons in acquisition order
+    let mut slots = vec![(
+        "Buster".to_string(),
+        settings.palette.player_projectile_color(0.0, false),
+        1.0,
+    )];
+    for weapon in &player_upgrades.boss_weapons {
+        let boss_data = boss_registry.get_boss_data(weapon.boss_type);
+        slots.push((
+            boss_data.map_or_else(
+                || format!("{:?}", weapon.boss_type),
+                |data| data.name.clone(),
+            ),
+            boss_data.map_or(Color::WHITE, |data| data.color),
+            (weapon.energy / WEAPON_ENERGY_MAX).clamp(0.0, 1.0),
+        ));
+    }
+
+    commands
+        .spawn((
+            DespawnOnExit(GameState::InGame),
+            Node {
+                position_type: PositionType::Absolute,
+                width: percent(100.0),
+                height: percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: px(20.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            GlobalZIndex(10),
+            WeaponWheelOverlay,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("WEAPONS"),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: px(20.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for (index, (name, color, energy_fraction)) in slots.into_iter().enumerate() {
+                        row.spawn((
+                            Node {
+                                width: px(120.0),
+                                flex_direction: FlexDirection::Column,
+                                align_items: AlignItems::Center,
+                                row_gap: px(8.0),
+                                padding: UiRect::all(px(10.0)),
+                                border: UiRect::all(px(4.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                            BorderColor::all(slot_border_color(index == selected)),
+                            WeaponWheelSlot(index),
+                        ))
+                        .with_children(|slot| {
+                            // Icon
+                            slot.spawn((
+                                Node {
+                                    width: px(40.0),
+                                    height: px(40.0),
+                                    ..default()
+                                },
+                                BackgroundColor(color),
+                            ));
+
+                            slot.spawn((
+                                Text::new(name),
+                                TextFont {
+                                    font_size: 18.0,
+                                    ..default()
+                                },
+                                TextColor(WHITE.into()),
+                            ));
+
+                            // Energy bar
+                            slot.spawn((
+                                Node {
+                                    width: px(90.0),
+                                    height: px(8.0),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.1, 0.1, 0.1)),
+                            ))
+                            .with_children(|bar| {
+                                bar.spawn((
+                                    Node {
+                                        width: percent(energy_fraction * 100.0),
+                                        height: percent(100.0),
+                                        ..default()
+                                    },
+                                    BackgroundColor(Color::srgb(1.0, 0.9, 0.0)),
+                                ));
+                            });
+                        });
+                    }
+                });
+
+            parent.spawn((
+                Text::new("Left/Right to select, release Tab to equip"),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+        });
+}
+
+/// System to open the weapon wheel while Tab is held, pick with arrows and equip on release
+pub fn weapon_wheel_input(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut wheel: ResMut<WeaponWheel>,
+    mut player_upgrades: ResMut<PlayerUpgrades>,
+    boss_registry: Res<BossRegistry>,
+    settings: Res<GameSettings>,
+    overlay_query: Query<Entity, With<WeaponWheelOverlay>>,
+    mut slot_query: Query<(&WeaponWheelSlot, &mut BorderColor)>,
+) {
+    if InputAction::WeaponWheel.just_pressed(&keyboard_input) && !wheel.open {
+        // Start on the equipped weapon
+        wheel.open = true;
+        wheel.selected = player_upgrades
+            .equipped_weapon
+            .and_then(|equipped| {
+                player_upgrades
+                    .boss_weapons
+                    .iter()
+                    .position(|w| w.boss_type == equipped)
+            })
+            .map_or(0, |index| index + 1);
+        spawn_weapon_wheel(
+            &mut commands,
+            &player_upgrades,
+            &boss_registry,
+            &settings,
+            wheel.selected,
+        );
+        return;
+    }
+
+    if !wheel.open {
+        return;
+    }
+
+    if InputAction::WeaponWheel.just_released(&keyboard_input) {
+        // Equip the selected weapon and close
+        player_upgrades.equipped_weapon = match wheel.selected {
+            0 => None,
+            index => player_upgrades
+                .boss_weapons
+                .get(index - 1)
+                .map(|w| w.boss_type),
+        };
+        wheel.open = false;
+        for entity in &overlay_query {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let slot_count = player_upgrades.boss_weapons.len() + 1;
+    if keyboard_input.just_pressed(KeyCode::ArrowLeft) && wheel.selected > 0 {
+        wheel.selected -= 1;
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowRight) && wheel.selected + 1 < slot_count {
+        wheel.selected += 1;
+    }
+
+    for (slot, mut border_color) in &mut slot_query {
+        *border_color = BorderColor::all(slot_border_color(slot.0 == wheel.selected));
+    }
+}
+
+/// Color of `character` with `weapon` equipped (None = default shot, which keeps the character's
+/// own color): the `player_color` of the weapon's projectile style, or else the character's color
+/// blended toward the boss's
+pub fn weapon_recolor(
+    character: SelectedCharacter,
+    weapon: Option<BossType>,
+    boss_registry: &BossRegistry,
+    projectile_styles: &ProjectileStyles,
+) -> Color {
+    let Some(boss_type) = weapon else {
+        return character.color();
+    };
+    if let Some([r, g, b]) = projectile_styles.get(weapon).player_color {
+        return Color::srgb(r, g, b);
+    }
+    boss_registry
+        .get_boss_data(boss_type)
+        .map_or(character.color(), |data| {
+            character.color().mix(&data.color, WEAPON_RECOLOR_BLEND)
+        })
+}
+
+/// System to recolor the player after their weapon, classic Mega Man style: the one under the
+/// cursor while the weapon wheel is open, so each pick is previewed, else the equipped one.
+/// Keeps the alpha the hit flash sets.
+pub fn recolor_player_for_weapon(
+    wheel: Res<WeaponWheel>,
+    player_upgrades: Res<PlayerUpgrades>,
+    selected_character: Res<SelectedCharacter>,
+    boss_registry: Res<BossRegistry>,
+    projectile_styles: Res<ProjectileStyles>,
+    player_query: Query<&MeshMaterial2d<ColorMaterial>, With<Player>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let weapon = if wheel.open {
+        // Slot 0 is the default shot, then the boss weapons in order
+        wheel
+            .selected
+            .checked_sub(1)
+            .and_then(|index| player_upgrades.boss_weapons.get(index))
+            .map(|weapon| weapon.boss_type)
+    } else {
+        player_upgrades.equipped_weapon
+    };
+    let color = weapon_recolor(
+        *selected_character,
+        weapon,
+        &boss_registry,
+        &projectile_styles,
+    );
+
+    for mesh_material in &player_query {
+        // Only touch the material when the color actually changes
+        if let Some(material) = materials.get(&mesh_material.0)
+            && material.color.with_alpha(1.0) != color
+            && let Some(material) = materials.get_mut(&mesh_material.0)
+        {
+            material.color = color.with_alpha(material.color.alpha());
+        }
+    }
+}
+
+/// System to close the weapon wheel when leaving gameplay (so time doesn't stay slowed)
+pub fn close_weapon_wheel(mut wheel: ResMut<WeaponWheel>) {
+    wheel.open = false;
+}
+
+/// System to reset the per-stage lifesteal cap when a stage (or a retry of it) starts
+pub fn reset_lifesteal_tracker(mut tracker: ResMut<LifestealTracker>) {
+    tracker.healed_this_stage = 0.0;
+}
+
+/// System to heal the player by the lifesteal share of the damage boss weapon shots deal,
+/// until `LIFESTEAL_STAGE_CAP` HP has been restored this stage
+pub fn apply_lifesteal(
+    mut boss_damaged: MessageReader<BossDamaged>,
+    player_upgrades: Res<PlayerUpgrades>,
+    mut tracker: ResMut<LifestealTracker>,
+    mut player_query: Query<&mut Hp, (With<Player>, Without<Defeated>)>,
+) {
+    for damaged in boss_damaged.read() {
+        if damaged.weapon.is_none() || player_upgrades.lifesteal_ratio <= 0.0 {
+            continue;
+        }
+
+        let heal = (damaged.amount * player_upgrades.lifesteal_ratio)
+            .min(LIFESTEAL_STAGE_CAP - tracker.healed_this_stage);
+        if heal <= 0.0 {
+            continue;
+        }
+        tracker.healed_this_stage += heal;
+
+        for mut hp in &mut player_query {
+            hp.current = (hp.current + heal).min(hp.max);
+        }
+    }
+}
+
+/// Shot pattern of a fusion attack
+#[derive(Clone, Copy, Debug)]
+pub struct FusionAttack {
+    pub shots: u32,  // Shots fired at once, fanned out evenly
+    pub spread: f32, // Angle between the outermost shots (radians)
+    pub size: f32,   // Size of each shot
+    pub charge: f32, // Charge level of each shot (charged shots hit harder and knock the boss back)
+}
+
+/// Fusion attack fired by a pair of boss weapons (the order of the pair doesn't matter)
+pub struct WeaponCombo {
+    pub weapons: (BossType, BossType),
+    pub attack: FusionAttack,
+}
+
+/// Fusion attack of any pair of weapons without an entry in `WEAPON_COMBOS`
+pub const DEFAULT_FUSION_ATTACK: FusionAttack = FusionAttack {
+    shots: 3,
+    spread: 0.5,
+    size: 16.0,
+    charge: 0.5,
+};
+
+/// Fusion attacks of specific weapon pairs. Empty for now: every boss drops the same weapon
+/// type, so no two acquired weapons can differ yet.
+pub const WEAPON_COMBOS: &[WeaponCombo] = &[];
+
+/// The fusion attack fired by weapons `a` and `b`
+pub fn fusion_attack(a: BossType, b: BossType) -> FusionAttack {
+    WEAPON_COMBOS
+        .iter()
+        .find(|combo| combo.weapons == (a, b) || combo.weapons == (b, a))
+        .map_or(DEFAULT_FUSION_ATTACK, |combo| combo.attack)
+}
+
+/// Shared fusion attack materials, one per pair of registered bosses (both bosses' shot colors
+/// mixed half and half)
+#[derive(Resource, Default)]
+pub struct FusionMaterials {
+    materials: HashMap<(BossType, BossType), Handle<ColorMaterial>>,
+}
+
+impl FusionMaterials {
+    /// Material of the fusion of `first` and `second` (the default material for unregistered bosses)
+    pub fn get(&self, first: BossType, second: BossType) -> Handle<ColorMaterial> {
+        self.materials
+            .get(&(first, second))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+/// Creates the fusion attack materials once at startup, from the loaded projectile styles
+pub fn setup_fusion_materials(
+    mut commands: Commands,
+    boss_registry: Res<BossRegistry>,
+    projectile_styles: Res<ProjectileStyles>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let color_of = |boss: &BossData| {
+        projectile_styles
+            .get(Some(boss.boss_type))
+            .color_or(boss.color)
+    };
+    let mut fusion_materials = FusionMaterials::default();
+    for first in &boss_registry.bosses {
+        for second in &boss_registry.bosses {
+            let material = materials.add(color_of(first).mix(&color_of(second), 0.5));
+            fusion_materials
+                .materials
+                .insert((first.boss_type, second.boss_type), material);
+        }
+    }
+    commands.insert_resource(fusion_materials);
+}
+
+/// Indices in `upgrades.boss_weapons` of the two weapons a fusion attack would use: the equipped
+/// weapon (or the first one acquired) and the next one after it, both with enough energy left
+pub fn combo_weapon_indices(upgrades: &PlayerUpgrades) -> Option<(usize, usize)> {
+    let weapons = &upgrades.boss_weapons;
+    let has_energy = |index: usize| weapons[index].energy >= WEAPON_COMBO_ENERGY_COST;
+    let first = upgrades
+        .equipped_weapon
+        .and_then(|equipped| weapons.iter().position(|w| w.boss_type == equipped))
+        .unwrap_or(0);
+    if weapons.len() < 2 || !has_energy(first) {
+        return None;
+    }
+    (1..weapons.len())
+        .map(|offset| (first + offset) % weapons.len())
+        .find(|&index| has_energy(index))
+        .map(|second| (first, second))
+}
+
+/// System to fire a fusion attack when the player shoots mid-dash while owning two boss weapons
+/// with energy: both weapons pay for it, and the shots take on both bosses' colors. Runs before
+/// `player_shooting`, whose cooldown then keeps the same press from firing a normal shot too.
+pub fn fire_weapon_combo(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut player_query: Query<(&Transform, &Dash, &mut Shooting), With<Player>>,
+    mut player_upgrades: ResMut<PlayerUpgrades>,
+    projectile_assets: Res<ProjectileAssets>,
+    projectile_styles: Res<ProjectileStyles>,
+    fusion_materials: Res<FusionMaterials>,
+    mut feedback: MessageWriter<FeedbackEvent>,
+) {
+    if !InputAction::Shoot.just_pressed(&keyboard_input) {
+        return;
+    }
+
+    for (player_transform, dash, mut shooting) in &mut player_query {
+        if shooting.timer > 0.0 {
+            continue;
+        }
+        let Some((first, second)) = combo_weapon_indices(&player_upgrades) else {
+            return;
+        };
+        let weapons = &mut player_upgrades.boss_weapons;
+        weapons[first].energy -= WEAPON_COMBO_ENERGY_COST;
+        weapons[second].energy -= WEAPON_COMBO_ENERGY_COST;
+        let (first_type, second_type) = (weapons[first].boss_type, weapons[second].boss_type);
+
+        let attack = fusion_attack(first_type, second_type);
+        let material = fusion_materials.get(first_type, second_type);
+        // Shaped, trailed and voiced like the first weapon's shots
+        let style = projectile_styles.get(Some(first_type));
+        play_projectile_sound(
+            &mut commands,
+            style,
+            player_transform.translation.truncate(),
+        );
+
+        // Fanned out around the dash direction
+        let forward = Vec2::X * dash.direction.signum();
+        for shot in 0..attack.shots {
+            let angle = if attack.shots > 1 {
+                attack.spread * (shot as f32 / (attack.shots - 1) as f32 - 0.5)
+            } else {
+                0.0
+            };
+            commands.spawn(ProjectileBundle::player(
+                &projectile_assets,
+                style,
+                material.clone(),
+                player_transform.translation.truncate(),
+                attack.size,
+                Vec2::from_angle(angle).rotate(forward),
+                attack.charge,
+                Some(first_type),
+            ));
+        }
+        shooting.timer = WEAPON_COMBO_COOLDOWN;
+        feedback.write(FeedbackEvent::ChargedShotFired);
+    }
+}
@@ -0,0 +1 @@
+pub mod orb_math;
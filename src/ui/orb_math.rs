@@ -0,0 +1,153 @@
+use std::f32::consts::PI;
+
+/// Newton steps taken by `segment_height_for_fraction`. The starting guess is already close, so
+/// a few steps reach f32 precision.
+const NEWTON_STEPS: usize = 4;
+
+/// Area of the slice of a circle of `radius` cut off by a horizontal line `height` above its
+/// bottom (the part of the orb covered by liquid filled up to that height). Worked out in f64:
+/// near an empty orb both terms are almost equal and f32 loses most of their difference.
+pub fn circular_segment_area(height: f32, radius: f32) -> f32 {
+    if radius <= 0.0 {
+        return 0.0;
+    }
+
+    let r = f64::from(radius);
+    let h = f64::from(height).clamp(0.0, 2.0 * r);
+    let theta = ((r - h) / r).clamp(-1.0, 1.0).acos();
+    let half_chord = (2.0 * r * h - h * h).max(0.0).sqrt();
+    (r * r * theta - (r - h) * half_chord) as f32
+}
+
+/// Liquid height in a circle of `radius` that covers `fraction` of its area, so the visible
+/// liquid in the HP orb matches the remaining HP fraction
+pub fn segment_height_for_fraction(fraction: f32, radius: f32) -> f32 {
+    if radius <= 0.0 {
+        return 0.0;
+    }
+
+    let fraction = fraction.clamp(0.0, 1.0);
+    if fraction <= f32::EPSILON {
+        return 0.0;
+    }
+    if fraction >= 1.0 - f32::EPSILON {
+        return 2.0 * radius;
+    }
+    // The circle is symmetric: the top half mirrors the bottom half
+    if fraction > 0.5 {
+        return 2.0 * radius - segment_height_for_fraction(1.0 - fraction, radius);
+    }
+
+    let target = fraction * PI * radius * radius;
+    // Shallow segments have an area of about 4/3 * sqrt(2r) * h^(3/2)
+    let mut h = (0.75 * target / (2.0 * radius).sqrt())
+        .powf(2.0 / 3.0)
+        .min(radius);
+    for _ in 0..NEWTON_STEPS {
+        // The area grows by the chord length (2 * sqrt(2rh - h^2)) per unit of height
+        let chord = 2.0 * (2.0 * radius * h - h * h).max(0.0).sqrt();
+        if chord <= f32::EPSILON {
+            break;
+        }
+        h = (h - (circular_segment_area(h, radius) - target) / chord).clamp(0.0, radius);
+    }
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Share of the orb's area covered by liquid filled up to `height`
+    fn covered_fraction(height: f32, radius: f32) -> f32 {
+        circular_segment_area(height, radius) / (PI * radius * radius)
+    }
+
+    proptest! {
+        #[test]
+        fn segment_height_covers_the_requested_fraction(
+            fraction in 0.0f32..=1.0,
+            radius in 0.5f32..500.0,
+        ) {
+            let height = segment_height_for_fraction(fraction, radius);
+            prop_assert!((0.0..=2.0 * radius).contains(&height));
+            let covered = covered_fraction(height, radius);
+            prop_assert!(
+                (covered - fraction).abs() < 1e-4,
+                "fraction {} filled to {} covers {}",
+                fraction,
+                height,
+                covered
+            );
+        }
+
+        #[test]
+        fn upper_half_mirrors_the_lower_half(
+            fraction in 0.5f32..=1.0,
+            radius in 0.5f32..500.0,
+        ) {
+            let height = segment_height_for_fraction(fraction, radius);
+            let mirrored = segment_height_for_fraction(1.0 - fraction, radius);
+            prop_assert!(height >= radius * (1.0 - 1e-4));
+            prop_assert!((height + mirrored - 2.0 * radius).abs() <= radius * 1e-5);
+            prop_assert!((covered_fraction(height, radius) - fraction).abs() < 1e-4);
+        }
+
+        #[test]
+        fn more_hp_never_lowers_the_liquid(
+            fraction in 0.0f32..=1.0,
+            step in 0.0f32..=0.1,
+            radius in 0.5f32..500.0,
+        ) {
+            let higher = (fraction + step).min(1.0);
+            prop_assert!(
+                segment_height_for_fraction(higher, radius)
+                    >= segment_height_for_fraction(fraction, radius) - radius * 1e-5
+            );
+        }
+    }
+
+    #[test]
+    fn newton_steps_converge_near_empty() {
+        for radius in [1.0, 24.0, 300.0] {
+            for fraction in [1e-5, 1e-4, 1e-3, 1e-2] {
+                let covered =
+                    covered_fraction(segment_height_for_fraction(fraction, radius), radius);
+                assert!(
+                    (covered - fraction).abs() <= fraction * 1e-3,
+                    "fraction {fraction} at radius {radius} covers {covered}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn newton_steps_converge_near_half() {
+        for radius in [1.0, 24.0, 300.0] {
+            for fraction in [0.45, 0.49, 0.499, 0.5, 0.501, 0.51, 0.55] {
+                let covered =
+                    covered_fraction(segment_height_for_fraction(fraction, radius), radius);
+                assert!(
+                    (covered - fraction).abs() < 1e-5,
+                    "fraction {fraction} at radius {radius} covers {covered}"
+                );
+            }
+            let half = segment_height_for_fraction(0.5, radius);
+            assert!(
+                (half - radius).abs() <= radius * 1e-5,
+                "half fills to {half}"
+            );
+        }
+    }
+
+    #[test]
+    fn empty_and_full_orbs_need_no_solving() {
+        assert_eq!(segment_height_for_fraction(0.0, 24.0), 0.0);
+        assert_eq!(segment_height_for_fraction(1.0, 24.0), 48.0);
+        assert_eq!(segment_height_for_fraction(-0.5, 24.0), 0.0);
+        assert_eq!(segment_height_for_fraction(1.5, 24.0), 48.0);
+        assert_eq!(segment_height_for_fraction(0.5, 0.0), 0.0);
+        assert_eq!(circular_segment_area(10.0, 0.0), 0.0);
+    }
+}